@@ -0,0 +1,55 @@
+#!/bin/bash
+
+# Fetches the ONNX models bundled as app resources into src-tauri/models
+# and verifies each against the SHA-256 pinned in models/manifest.txt.
+# Entries without a pinned checksum are refused; pins are only ever added by hand in a reviewed commit.
+#
+# Usage: npm run fetch-models
+
+set -euo pipefail
+
+cd "$(dirname "$0")/../src-tauri/models"
+
+sha256() {
+    if command -v sha256sum >/dev/null; then
+        sha256sum "$1" | cut -d' ' -f1
+    else
+        shasum -a 256 "$1" | cut -d' ' -f1
+    fi
+}
+
+status=0
+while read -r file checksum url; do
+    case "$file" in ''|'#'*) continue ;; esac
+
+    if ! [[ "$checksum" =~ ^[0-9a-f]{64}$ ]]; then
+        echo "❌ $file has no pinned SHA-256 in manifest.txt; see models/README.md"
+        status=1
+        continue
+    fi
+
+    candidate="$file"
+    if [ ! -f "$file" ]; then
+        if [ "$url" = "-" ]; then
+            echo "⚠️  $file is not downloadable; see models/README.md to provision it"
+            status=1
+            continue
+        fi
+        echo "⬇️  Fetching $file"
+        candidate="$file.download"
+        curl -fsSL -o "$candidate" "$url"
+    fi
+
+    actual=$(sha256 "$candidate")
+    if [ "$actual" != "$checksum" ]; then
+        echo "❌ Checksum mismatch for $file: expected $checksum, got $actual"
+        [ "$candidate" != "$file" ] && rm -f "$candidate"
+        status=1
+        continue
+    fi
+
+    [ "$candidate" != "$file" ] && mv "$candidate" "$file"
+    echo "✅ $file"
+done < manifest.txt
+
+exit $status
@@ -0,0 +1,61 @@
+/*!
+ * API module - Request and response plumbing for the local HTTP API
+ *
+ * Shared by the desktop app's server, the headless server and the `jarvisx` client so all three agree on the
+ * address, the bearer token and the JSON envelope.
+ */
+
+use std::collections::HashMap;
+use std::io::Read;
+
+pub const DEFAULT_BIND: &str = "127.0.0.1:8765";
+const MAX_BODY_BYTES: u64 = 2 * 1024 * 1024;
+
+pub type HttpResponse = tiny_http::Response<std::io::Cursor<Vec<u8>>>;
+
+/// Where the API listens; set JARVISX_HTTP_BIND (e.g. "0.0.0.0:8765") to expose it beyond loopback.
+pub fn bind_address() -> String {
+    std::env::var("JARVISX_HTTP_BIND").unwrap_or_else(|_| DEFAULT_BIND.to_string())
+}
+
+/// Where a local client reaches the API; a server bound to all interfaces still answers on loopback.
+pub fn client_address() -> String {
+    bind_address().replace("0.0.0.0", "127.0.0.1")
+}
+
+pub fn json_response(status: u16, body: serde_json::Value) -> HttpResponse {
+    let header = tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap();
+    tiny_http::Response::from_data(body.to_string().into_bytes())
+        .with_status_code(status)
+        .with_header(header)
+}
+
+pub fn lowercase_headers(request: &tiny_http::Request) -> HashMap<String, String> {
+    request
+        .headers()
+        .iter()
+        .map(|header| (header.field.as_str().as_str().to_lowercase(), header.value.as_str().to_string()))
+        .collect()
+}
+
+pub fn read_body(request: &mut tiny_http::Request) -> Result<Vec<u8>, String> {
+    let mut body = Vec::new();
+    request.as_reader().take(MAX_BODY_BYTES).read_to_end(&mut body).map_err(|e| e.to_string())?;
+    Ok(body)
+}
+
+/// The capability token from `Authorization: Bearer <token>`.
+pub fn bearer_token(headers: &HashMap<String, String>) -> Option<String> {
+    headers
+        .get("authorization")
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(|token| token.trim().to_string())
+}
+
+/// Command arguments from an invoke body; an empty body means no arguments.
+pub fn invoke_args(body: &[u8]) -> Result<serde_json::Value, String> {
+    if body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(serde_json::json!({}));
+    }
+    serde_json::from_slice(body).map_err(|e| e.to_string())
+}
@@ -0,0 +1,99 @@
+/*!
+ * jarvisx-headless - The native core served over the local HTTP API, without Tauri or a window
+ *
+ * For servers, kiosks and scripts that don't need the GUI: no webview or event loop is started, so no
+ * display session is required (except for `capture_display` itself). Commands are invoked like on the
+ * desktop app, with `POST /invoke/<command>` and a capability token granted in the app; tokens are read
+ * from the active profile's capabilities.json on every call, so grants and revocations apply immediately.
+ */
+
+use jarvisx_core::api::{self, HttpResponse};
+use std::process::ExitCode;
+use std::sync::Mutex;
+
+/// Commands the core implements on its own; everything else needs the desktop app.
+const HEADLESS_COMMANDS: &[&str] = &["get_system_info", "get_running_processes", "capture_display"];
+
+fn dispatch(system: &Mutex<sysinfo::System>, command: &str, args: &serde_json::Value) -> Result<serde_json::Value, String> {
+    match command {
+        "get_system_info" => Ok(jarvisx_core::system::system_info(&mut system.lock().unwrap())),
+        "get_running_processes" => Ok(serde_json::json!(jarvisx_core::system::running_processes(&mut system.lock().unwrap()))),
+        "capture_display" => {
+            use base64::{Engine as _, engine::general_purpose};
+
+            // Displays are numbered from 1, matching what users see in system settings
+            let display = args.get("display").and_then(|value| value.as_u64()).unwrap_or(1).max(1);
+            let png = jarvisx_core::display::display_png(display as usize - 1)?;
+            Ok(serde_json::json!({
+                "display": display,
+                "contentType": "image/png",
+                "data": general_purpose::STANDARD.encode(png),
+            }))
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn invoke(system: &Mutex<sysinfo::System>, request: &mut tiny_http::Request, command: &str) -> HttpResponse {
+    let Some(token) = api::bearer_token(&api::lowercase_headers(request)) else {
+        return api::json_response(401, serde_json::json!({ "error": "Missing capability token" }));
+    };
+    let args = match api::read_body(request).and_then(|body| api::invoke_args(&body)) {
+        Ok(args) => args,
+        Err(e) => return api::json_response(400, serde_json::json!({ "error": e })),
+    };
+
+    let tokens = match jarvisx_core::paths::active_profile_dir() {
+        Ok(dir) => jarvisx_core::capabilities::load_tokens(&dir.join("capabilities.json")),
+        Err(e) => return api::json_response(500, serde_json::json!({ "error": e })),
+    };
+    let authorized = tokens
+        .get(&token)
+        .ok_or_else(|| "❌ Unknown capability token".to_string())
+        .and_then(|capability| jarvisx_core::capabilities::check(capability, command, &args));
+    if let Err(e) = authorized {
+        return api::json_response(403, serde_json::json!({ "error": e }));
+    }
+
+    if !HEADLESS_COMMANDS.contains(&command) {
+        return api::json_response(404, serde_json::json!({
+            "error": format!("{} needs the desktop app; headless mode serves {}", command, HEADLESS_COMMANDS.join(", ")),
+        }));
+    }
+
+    println!("🌐 API invoke: {}", command);
+    match dispatch(system, command, &args) {
+        Ok(result) => api::json_response(200, serde_json::json!({ "result": result })),
+        Err(e) => api::json_response(400, serde_json::json!({ "error": e })),
+    }
+}
+
+fn route(system: &Mutex<sysinfo::System>, request: &mut tiny_http::Request) -> HttpResponse {
+    let path = request.url().split('?').next().unwrap_or("").to_string();
+    let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+
+    match (request.method(), segments.as_slice()) {
+        (tiny_http::Method::Get, ["health"]) => api::json_response(200, serde_json::json!({ "status": "ok", "headless": true })),
+        (tiny_http::Method::Post, ["invoke", command]) => invoke(system, request, command),
+        _ => api::json_response(404, serde_json::json!({ "error": "Not found" })),
+    }
+}
+
+fn main() -> ExitCode {
+    let address = api::bind_address();
+    let server = match tiny_http::Server::http(&address) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("⚠️  Could not bind {}: {}", address, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("🌐 JarvisX headless core listening on {}", address);
+    let system = Mutex::new(sysinfo::System::new_all());
+    for mut request in server.incoming_requests() {
+        let response = route(&system, &mut request);
+        let _ = request.respond(response);
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,139 @@
+/*!
+ * jarvisx - Command-line client for a running JarvisX instance
+ *
+ * Calls the local API (JARVISX_HTTP_BIND, default 127.0.0.1:8765) of the desktop app or `jarvisx-headless`
+ * with the capability token from JARVISX_TOKEN or --token, and prints each result as JSON.
+ */
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::ExitCode;
+use std::time::Duration;
+
+const USAGE: &str = "Usage: jarvisx [--token TOKEN] <command> [args]
+
+Commands:
+  say <text> [--language CODE]          Speak text aloud
+  capture [--display N] [-o FILE]       Capture a display (1 = primary) as PNG
+  run-workflow <name>                   Ask the app to run a UI workflow
+  run-script <name>                     Run a saved script
+  invoke <command> [JSON args]          Call any API-enabled command";
+
+fn post(command: &str, args: &serde_json::Value, token: &str) -> Result<serde_json::Value, String> {
+    let address = jarvisx_core::api::client_address();
+    let mut stream = TcpStream::connect(&address).map_err(|e| format!("JarvisX is not reachable on {}: {}", address, e))?;
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(120)));
+
+    let body = args.to_string();
+    write!(
+        stream,
+        "POST /invoke/{} HTTP/1.1\r\nHost: {}\r\nAuthorization: Bearer {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        command,
+        address,
+        token,
+        body.len(),
+        body
+    )
+    .map_err(|e| e.to_string())?;
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).map_err(|e| e.to_string())?;
+    let response = String::from_utf8_lossy(&response);
+    let (head, body) = response.split_once("\r\n\r\n").ok_or("Malformed response from JarvisX")?;
+    let status: u16 = head.split_whitespace().nth(1).and_then(|code| code.parse().ok()).unwrap_or(0);
+    let body: serde_json::Value = serde_json::from_str(body).map_err(|e| e.to_string())?;
+
+    if status == 200 {
+        Ok(body["result"].clone())
+    } else {
+        Err(body["error"].as_str().map(String::from).unwrap_or_else(|| format!("JarvisX returned {}", status)))
+    }
+}
+
+/// Removes `--flag value` from `args`, returning the value.
+fn take_option(args: &mut Vec<String>, names: &[&str]) -> Result<Option<String>, String> {
+    match args.iter().position(|arg| names.contains(&arg.as_str())) {
+        Some(index) if index + 1 < args.len() => {
+            let value = args.remove(index + 1);
+            args.remove(index);
+            Ok(Some(value))
+        }
+        Some(index) => Err(format!("{} needs a value", args[index])),
+        None => Ok(None),
+    }
+}
+
+fn run(mut args: Vec<String>) -> Result<serde_json::Value, String> {
+    let token = match take_option(&mut args, &["--token"])? {
+        Some(token) => token,
+        None => std::env::var("JARVISX_TOKEN").map_err(|_| "Set JARVISX_TOKEN or pass --token (see grant_capability)")?,
+    };
+    if args.is_empty() {
+        return Err("No command given".to_string());
+    }
+    let command = args.remove(0);
+
+    match command.as_str() {
+        "say" => {
+            let language = take_option(&mut args, &["--language", "-l"])?;
+            if args.is_empty() {
+                return Err("Nothing to say".to_string());
+            }
+            post("speak_text", &serde_json::json!({ "text": args.join(" "), "language": language }), &token)
+        }
+        "capture" => {
+            use base64::{Engine as _, engine::general_purpose};
+
+            let display: u64 = take_option(&mut args, &["--display", "-d"])?
+                .map(|display| display.parse().map_err(|_| format!("Invalid display: {}", display)))
+                .transpose()?
+                .unwrap_or(1);
+            let output = take_option(&mut args, &["--output", "-o"])?;
+            let captured = post("capture_display", &serde_json::json!({ "display": display }), &token)?;
+
+            match output {
+                Some(path) => {
+                    let png = general_purpose::STANDARD
+                        .decode(captured["data"].as_str().unwrap_or_default())
+                        .map_err(|e| e.to_string())?;
+                    std::fs::write(&path, &png).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+                    Ok(serde_json::json!({ "display": display, "path": path, "bytes": png.len() }))
+                }
+                None => Ok(captured),
+            }
+        }
+        "run-workflow" | "run-script" => {
+            let name = args.first().ok_or_else(|| format!("{} needs a name", command))?;
+            let target = if command == "run-workflow" { "run_workflow" } else { "run_script" };
+            post(target, &serde_json::json!({ "name": name }), &token)
+        }
+        "invoke" => {
+            let name = args.first().ok_or("invoke needs a command name")?;
+            let payload = match args.get(1) {
+                Some(json) => serde_json::from_str(json).map_err(|e| format!("Invalid JSON arguments: {}", e))?,
+                None => serde_json::json!({}),
+            };
+            post(name, &payload, &token)
+        }
+        other => Err(format!("Unknown command: {}\n\n{}", other, USAGE)),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.is_empty() || args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    match run(args) {
+        Ok(result) => {
+            println!("{}", serde_json::to_string_pretty(&result).unwrap_or_default());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", serde_json::json!({ "error": e }));
+            ExitCode::FAILURE
+        }
+    }
+}
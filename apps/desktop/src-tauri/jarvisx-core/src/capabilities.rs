@@ -0,0 +1,212 @@
+/*!
+ * Capabilities module - Capability tokens and the checks that scope a call to one
+ *
+ * The desktop app grants and stores tokens per profile; the headless server reads the same
+ * `capabilities.json`, so a token works against either.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const CAPABILITY_FIELD: &str = "capabilityToken";
+
+/// Payload keys treated as filesystem resources; a key may hold one path or an array of them.
+const PATH_KEYS: &[&str] = &[
+    "path",
+    "paths",
+    "filePath",
+    "source",
+    "sources",
+    "destination",
+    "dest",
+    "target",
+    "folder",
+    "directory",
+    "outputPath",
+];
+/// Payload keys treated as application resources.
+const APP_KEYS: &[&str] = &["appName", "app", "application", "windowTitle"];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SandboxLevel {
+    /// No restrictions; only when the policy or a capability explicitly asks for it
+    Unrestricted,
+    /// Resource limits, no privilege escalation, no writes to system locations
+    #[default]
+    Standard,
+    /// Standard plus no network, a tight memory cap and no child processes where the platform allows
+    Strict,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CapabilityToken {
+    pub token: String,
+    /// "plugin:<id>", "device:<peer id>" or "orchestrator:<id>"
+    pub principal: String,
+    /// Command names, with a trailing `*` matching any suffix
+    pub commands: Vec<String>,
+    /// Empty means path arguments are not allowed at all
+    pub path_prefixes: Vec<String>,
+    /// Empty means app arguments are not allowed at all
+    pub app_names: Vec<String>,
+    pub created_at: u64,
+    pub expires_at: Option<u64>,
+    /// Restrictions for processes this principal spawns
+    #[serde(default)]
+    pub sandbox: SandboxLevel,
+}
+
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Reads a profile's `capabilities.json`, keyed by token; missing or unreadable files hold no tokens.
+pub fn load_tokens(path: &Path) -> HashMap<String, CapabilityToken> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str::<Vec<CapabilityToken>>(&json).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|token| (token.token.clone(), token))
+        .collect()
+}
+
+pub fn command_allowed(token: &CapabilityToken, command: &str) -> bool {
+    token.commands.iter().any(|pattern| match pattern.strip_suffix('*') {
+        Some(prefix) => command.starts_with(prefix),
+        None => pattern == command,
+    })
+}
+
+/// Resolves `..` and symlinks through the deepest existing ancestor, so paths that don't exist yet
+/// (archive and download destinations) are checked where they would actually land.
+pub fn resolve(path: &Path) -> Option<PathBuf> {
+    let mut existing = path;
+    let mut rest = Vec::new();
+    let base = loop {
+        match existing.canonicalize() {
+            Ok(resolved) => break resolved,
+            Err(_) => {
+                rest.push(existing.file_name()?);
+                existing = existing.parent()?;
+            }
+        }
+    };
+
+    let remainder = rest.iter().rev().collect::<PathBuf>();
+    if remainder.components().any(|component| !matches!(component, Component::Normal(_))) {
+        return None;
+    }
+    Some(base.join(remainder))
+}
+
+pub fn path_allowed(token: &CapabilityToken, path: &str) -> bool {
+    let path = Path::new(path);
+    if !path.is_absolute() {
+        return false;
+    }
+    let Some(path) = resolve(path) else {
+        return false;
+    };
+
+    token
+        .path_prefixes
+        .iter()
+        .filter_map(|prefix| resolve(Path::new(prefix)))
+        .any(|prefix| path.starts_with(prefix))
+}
+
+pub fn app_allowed(token: &CapabilityToken, app_name: &str) -> bool {
+    token
+        .app_names
+        .iter()
+        .any(|allowed| app_name.to_lowercase().contains(&allowed.to_lowercase()))
+}
+
+/// Checks one call against a capability: expiry, the command, and every path and app argument in the payload.
+pub fn check(capability: &CapabilityToken, command: &str, payload: &serde_json::Value) -> Result<(), String> {
+    if capability.expires_at.is_some_and(|expires_at| expires_at <= now_secs()) {
+        return Err(format!("❌ Capability for {} has expired", capability.principal));
+    }
+    if !command_allowed(capability, command) {
+        return Err(format!("❌ {} may not invoke {}", capability.principal, command));
+    }
+
+    for key in PATH_KEYS {
+        let paths = match payload.get(*key) {
+            Some(serde_json::Value::String(path)) => vec![path.as_str()],
+            Some(serde_json::Value::Array(items)) => items.iter().filter_map(|item| item.as_str()).collect(),
+            _ => Vec::new(),
+        };
+        for path in paths {
+            if !path_allowed(capability, path) {
+                return Err(format!("❌ {} may not access {}", capability.principal, path));
+            }
+        }
+    }
+    for key in APP_KEYS {
+        if let Some(app_name) = payload.get(*key).and_then(|value| value.as_str()) {
+            if !app_allowed(capability, app_name) {
+                return Err(format!("❌ {} may not control {}", capability.principal, app_name));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn capability(commands: &[&str], path_prefixes: Vec<String>) -> CapabilityToken {
+        CapabilityToken {
+            token: "cap_test".to_string(),
+            principal: "plugin:test".to_string(),
+            commands: commands.iter().map(|command| command.to_string()).collect(),
+            path_prefixes,
+            app_names: vec!["Spotify".to_string()],
+            created_at: 0,
+            expires_at: None,
+            sandbox: SandboxLevel::Strict,
+        }
+    }
+
+    #[test]
+    fn matches_exact_and_wildcard_commands() {
+        let capability = capability(&["get_system_info", "capture_*"], Vec::new());
+        assert!(command_allowed(&capability, "get_system_info"));
+        assert!(command_allowed(&capability, "capture_display"));
+        assert!(!command_allowed(&capability, "get_system_uptime"));
+    }
+
+    #[test]
+    fn rejects_paths_that_climb_out_of_a_prefix() {
+        let root = std::env::temp_dir().join("jarvisx-core-capabilities");
+        std::fs::create_dir_all(root.join("allowed")).unwrap();
+        let capability = capability(&["read_file"], vec![root.join("allowed").to_string_lossy().to_string()]);
+
+        let inside = root.join("allowed").join("new.txt");
+        let escaped = format!("{}/allowed/../secret.txt", root.to_string_lossy());
+        assert!(check(&capability, "read_file", &serde_json::json!({ "path": inside })).is_ok());
+        assert!(check(&capability, "read_file", &serde_json::json!({ "paths": [inside, escaped] })).is_err());
+        assert!(check(&capability, "read_file", &serde_json::json!({ "path": "relative.txt" })).is_err());
+    }
+
+    #[test]
+    fn rejects_expired_capabilities_and_other_apps() {
+        let mut capability = capability(&["open_application"], Vec::new());
+        assert!(check(&capability, "open_application", &serde_json::json!({ "appName": "spotify" })).is_ok());
+        assert!(check(&capability, "open_application", &serde_json::json!({ "appName": "Terminal" })).is_err());
+
+        capability.expires_at = Some(1);
+        assert!(check(&capability, "open_application", &serde_json::json!({ "appName": "spotify" })).is_err());
+    }
+}
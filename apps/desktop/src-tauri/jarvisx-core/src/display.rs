@@ -0,0 +1,23 @@
+/*!
+ * Display module - Screen capture to PNG
+ */
+
+use std::io::Cursor;
+
+fn encode_png(image: &image::RgbaImage) -> Result<Vec<u8>, String> {
+    let mut cursor = Cursor::new(Vec::new());
+    image.write_to(&mut cursor, image::ImageOutputFormat::Png).map_err(|e| e.to_string())?;
+    Ok(cursor.into_inner())
+}
+
+/// PNG bytes of a whole screen.
+pub fn screen_png(screen: &screenshots::Screen) -> Result<Vec<u8>, String> {
+    encode_png(&screen.capture().map_err(|e| e.to_string())?)
+}
+
+/// PNG bytes of the screen at `index` in enumeration order (0 = primary).
+pub fn display_png(index: usize) -> Result<Vec<u8>, String> {
+    let screens = screenshots::Screen::all().map_err(|e| e.to_string())?;
+    let screen = screens.get(index).ok_or_else(|| format!("❌ Display not found: {}", index + 1))?;
+    screen_png(screen)
+}
@@ -0,0 +1,12 @@
+/*!
+ * jarvisx-core - Command implementations and API plumbing that don't need Tauri
+ *
+ * The desktop app wraps these in `#[tauri::command]`s; `jarvisx-headless` serves them over the local HTTP API
+ * without a window or event loop, and `jarvisx` is the command-line client for either.
+ */
+
+pub mod api;
+pub mod capabilities;
+pub mod display;
+pub mod paths;
+pub mod system;
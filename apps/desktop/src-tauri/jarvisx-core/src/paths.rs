@@ -0,0 +1,34 @@
+/*!
+ * Paths module - Locations of the desktop app's data, readable without a running app
+ */
+
+use std::path::PathBuf;
+
+/// Bundle identifier from tauri.conf.json; the app data directory is named after it.
+pub const APP_IDENTIFIER: &str = "com.jarvisx.desktop";
+pub const DEFAULT_PROFILE_ID: &str = "default";
+
+/// The same directory Tauri's `app_data_dir` resolves to.
+pub fn app_data_dir() -> Result<PathBuf, String> {
+    dirs_next::data_dir()
+        .map(|dir| dir.join(APP_IDENTIFIER))
+        .ok_or_else(|| "App data directory not available".to_string())
+}
+
+pub fn profiles_root() -> Result<PathBuf, String> {
+    Ok(app_data_dir()?.join("profiles"))
+}
+
+/// The profile last made active in the desktop app, from its `profiles.json`.
+pub fn active_profile_id() -> String {
+    profiles_root()
+        .ok()
+        .and_then(|root| std::fs::read_to_string(root.join("profiles.json")).ok())
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+        .and_then(|registry| registry["activeProfile"].as_str().map(String::from))
+        .unwrap_or_else(|| DEFAULT_PROFILE_ID.to_string())
+}
+
+pub fn active_profile_dir() -> Result<PathBuf, String> {
+    Ok(profiles_root()?.join(active_profile_id()))
+}
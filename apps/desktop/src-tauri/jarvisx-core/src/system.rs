@@ -0,0 +1,32 @@
+/*!
+ * System module - System information and process listings from a sysinfo handle
+ */
+
+use sysinfo::System;
+
+pub fn system_info(sys: &mut System) -> serde_json::Value {
+    sys.refresh_memory();
+
+    serde_json::json!({
+        "os": std::env::consts::OS,
+        "arch": std::env::consts::ARCH,
+        "hostname": System::host_name(),
+        "cpuCount": sys.cpus().len(),
+        "totalMemory": sys.total_memory(),
+        "usedMemory": sys.used_memory(),
+        "uptime": System::uptime(),
+    })
+}
+
+/// "<pid>: <name>" for every running process.
+pub fn running_processes(sys: &mut System) -> Vec<String> {
+    sys.refresh_processes();
+    sys.processes()
+        .iter()
+        .map(|(pid, process)| format!("{}: {}", pid, process.name()))
+        .collect()
+}
+
+pub fn uptime_secs() -> u64 {
+    System::uptime()
+}
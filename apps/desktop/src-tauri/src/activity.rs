@@ -0,0 +1,211 @@
+/**
+ * Activity module - Opt-in observer of coarse input activity (typing bursts, mouse idle, app switches)
+ *
+ * Only timings, keystroke counts and the frontmost app name are tracked; keys and text are never recorded.
+ */
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+
+/// Gap after which a typing burst is considered over
+const TYPING_PAUSE: Duration = Duration::from_secs(2);
+const MOUSE_IDLE_AFTER: Duration = Duration::from_secs(60);
+const TICK: Duration = Duration::from_secs(1);
+
+#[derive(Default)]
+pub struct ActivityState {
+    pub enabled: bool,
+    generation: u64,
+    last_key: Option<Instant>,
+    typing_since: Option<Instant>,
+    burst_keys: u32,
+    last_mouse: Option<Instant>,
+    mouse_idle: bool,
+    frontmost_app: Option<String>,
+}
+
+#[cfg(target_os = "macos")]
+pub fn frontmost_app() -> Option<String> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let output = Command::new("osascript")
+        .args(&["-e", "tell application \"System Events\" to get name of first application process whose frontmost is true"])
+        .output_within(Duration::from_secs(2))
+        .ok()?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string()).filter(|name| !name.is_empty())
+}
+
+#[cfg(target_os = "windows")]
+fn frontmost_pid() -> Option<u32> {
+    use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId};
+
+    let mut pid = 0u32;
+    unsafe {
+        GetWindowThreadProcessId(GetForegroundWindow(), Some(&mut pid as *mut u32));
+    }
+    Some(pid).filter(|pid| *pid != 0)
+}
+
+#[cfg(target_os = "linux")]
+fn frontmost_pid() -> Option<u32> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let output = Command::new("xdotool")
+        .args(&["getactivewindow", "getwindowpid"])
+        .output_within(Duration::from_secs(2))
+        .ok()?;
+    String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn frontmost_app() -> Option<String> {
+    use sysinfo::{ProcessExt, SystemExt};
+
+    let pid = sysinfo::Pid::from(frontmost_pid()? as usize);
+    crate::subsystems::with_system(|sys| {
+        sys.refresh_process(pid);
+        sys.process(pid).map(|process| process.name().to_string())
+    })
+}
+
+/// Updates activity timings from one global input event; called by the shared input listener.
+fn observe(app: &AppHandle, event: &rdev::Event) {
+    use rdev::EventType;
+
+    let state = app.state::<Mutex<ActivityState>>();
+    let mut activity = state.lock().unwrap();
+    if !activity.enabled {
+        return;
+    }
+
+    let now = Instant::now();
+    match event.event_type {
+        EventType::KeyPress(_) => {
+            activity.last_key = Some(now);
+            activity.burst_keys += 1;
+            if activity.typing_since.is_none() {
+                activity.typing_since = Some(now);
+                drop(activity);
+                let _ = app.emit_all("activity://typing-started", ());
+            }
+        }
+        EventType::MouseMove { .. } | EventType::ButtonPress(_) | EventType::Wheel { .. } => {
+            activity.last_mouse = Some(now);
+            if activity.mouse_idle {
+                activity.mouse_idle = false;
+                drop(activity);
+                let _ = app.emit_all("activity://mouse-active", ());
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Runs the single global input hook shared by snippet expansion, input verification, stuck-key tracking and the
+/// activity observer.
+pub fn spawn_input_listener(app: AppHandle) {
+    std::thread::spawn(move || {
+        crate::input_verify::set_hook_active(true);
+        let result = rdev::listen(move |event| {
+            crate::snippets::handle_key_event(&app, &event);
+            crate::input_verify::observe(&event);
+            crate::stuck_keys::observe(&event);
+            crate::automation_guard::observe(&event);
+            observe(&app, &event);
+        });
+        if let Err(e) = result {
+            crate::input_verify::set_hook_active(false);
+            println!("⚠️  Global input listener unavailable: {:?}", e);
+        }
+    });
+}
+
+/// Emits the time-based transitions (typing stopped, mouse idle) and app switches.
+async fn run_observer(app: AppHandle, generation: u64) {
+    let mut tick: u64 = 0;
+
+    loop {
+        tokio::time::sleep(TICK).await;
+        tick += 1;
+
+        let mut events: Vec<(&str, serde_json::Value)> = Vec::new();
+        {
+            let state = app.state::<Mutex<ActivityState>>();
+            let mut activity = state.lock().unwrap();
+            if !activity.enabled || activity.generation != generation {
+                break;
+            }
+
+            if let (Some(since), Some(last_key)) = (activity.typing_since, activity.last_key) {
+                if last_key.elapsed() >= TYPING_PAUSE {
+                    events.push(("activity://typing-stopped", serde_json::json!({
+                        "durationSecs": last_key.duration_since(since).as_secs_f64(),
+                        "keystrokes": activity.burst_keys,
+                    })));
+                    activity.typing_since = None;
+                    activity.burst_keys = 0;
+                }
+            }
+
+            if !activity.mouse_idle && activity.last_mouse.map_or(false, |last| last.elapsed() >= MOUSE_IDLE_AFTER) {
+                activity.mouse_idle = true;
+                events.push(("activity://mouse-idle", serde_json::json!({ "idleSecs": MOUSE_IDLE_AFTER.as_secs() })));
+            }
+        }
+
+        if tick % 2 == 0 {
+            let current = tauri::async_runtime::spawn_blocking(frontmost_app).await.ok().flatten();
+            let state = app.state::<Mutex<ActivityState>>();
+            let mut activity = state.lock().unwrap();
+            if current.is_some() && current != activity.frontmost_app {
+                events.push(("activity://app-switched", serde_json::json!({
+                    "from": activity.frontmost_app,
+                    "to": current,
+                })));
+                activity.frontmost_app = current;
+            }
+        }
+
+        for (event, payload) in events {
+            let _ = app.emit_all(event, payload);
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn set_activity_observer(app: AppHandle, state: State<'_, Mutex<ActivityState>>, enabled: bool) -> Result<bool, String> {
+    let generation = {
+        let mut activity = state.lock().unwrap();
+        *activity = ActivityState {
+            enabled,
+            generation: activity.generation + 1,
+            last_mouse: Some(Instant::now()),
+            ..Default::default()
+        };
+        activity.generation
+    };
+
+    println!("👀 Activity observer {}", if enabled { "on" } else { "off" });
+    if enabled {
+        tauri::async_runtime::spawn(run_observer(app, generation));
+    }
+    Ok(enabled)
+}
+
+/// Current activity summary; `interruptible` is false mid-typing-burst.
+#[tauri::command]
+pub async fn get_activity_status(state: State<'_, Mutex<ActivityState>>) -> Result<serde_json::Value, String> {
+    let activity = state.lock().unwrap();
+
+    Ok(serde_json::json!({
+        "enabled": activity.enabled,
+        "typing": activity.typing_since.is_some(),
+        "mouseIdle": activity.mouse_idle,
+        "secondsSinceInput": activity.last_key.max(activity.last_mouse).map(|last| last.elapsed().as_secs()),
+        "frontmostApp": activity.frontmost_app,
+        "interruptible": activity.typing_since.is_none(),
+    }))
+}
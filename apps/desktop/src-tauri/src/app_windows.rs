@@ -0,0 +1,147 @@
+/**
+ * App windows module - Native-side creation and tracking of extra webview windows (caption overlay, settings,
+ * remote-session viewer, ...)
+ *
+ * Child windows load app routes only (e.g. "index.html#/settings"), so remote pages never get a webview with IPC.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, State, WindowEvent};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChildWindowOptions {
+    pub title: Option<String>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub resizable: Option<bool>,
+    pub decorations: Option<bool>,
+    pub always_on_top: Option<bool>,
+    pub transparent: Option<bool>,
+    pub skip_taskbar: Option<bool>,
+    pub focused: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChildWindowInfo {
+    pub label: String,
+    pub url: String,
+    pub options: ChildWindowOptions,
+    pub created_at: u64,
+}
+
+#[derive(Default)]
+pub struct AppWindowsState {
+    children: BTreeMap<String, ChildWindowInfo>,
+}
+
+fn validate_label(label: &str) -> Result<(), String> {
+    if label.is_empty() || !label.chars().all(|c| c.is_ascii_alphanumeric() || "-/:_".contains(c)) {
+        return Err(format!("❌ Invalid window label: {} (letters, digits, '-', '/', ':' and '_' only)", label));
+    }
+    if label == crate::headless::MAIN_LABEL {
+        return Err("❌ The main window is managed by the app".to_string());
+    }
+    Ok(())
+}
+
+fn app_url(url: &str) -> Result<tauri::WindowUrl, String> {
+    if url.contains("://") || url.starts_with("//") {
+        return Err(format!("❌ Child windows can only load app pages, not {}", url));
+    }
+    // "#/settings" is shorthand for the route inside the main bundle
+    let path = if url.starts_with('#') { format!("index.html{}", url) } else { url.trim_start_matches('/').to_string() };
+    Ok(tauri::WindowUrl::App(path.into()))
+}
+
+#[tauri::command]
+pub async fn create_child_window(
+    app: AppHandle,
+    state: State<'_, Mutex<AppWindowsState>>,
+    label: String,
+    url: String,
+    options: Option<ChildWindowOptions>,
+) -> Result<ChildWindowInfo, String> {
+    validate_label(&label)?;
+    if app.get_window(&label).is_some() {
+        return Err(format!("❌ A window labelled {} already exists", label));
+    }
+
+    let options = options.unwrap_or_default();
+    let mut builder = tauri::WindowBuilder::new(&app, &label, app_url(&url)?)
+        .title(options.title.clone().unwrap_or_else(|| "JarvisX".to_string()))
+        .inner_size(options.width.unwrap_or(800.0), options.height.unwrap_or(600.0))
+        .resizable(options.resizable.unwrap_or(true))
+        .decorations(options.decorations.unwrap_or(true))
+        .always_on_top(options.always_on_top.unwrap_or(false))
+        .transparent(options.transparent.unwrap_or(false))
+        .skip_taskbar(options.skip_taskbar.unwrap_or(false))
+        .focused(options.focused.unwrap_or(true));
+    if let (Some(x), Some(y)) = (options.x, options.y) {
+        builder = builder.position(x, y);
+    } else {
+        builder = builder.center();
+    }
+    let window = builder.build().map_err(|e| e.to_string())?;
+
+    let info = ChildWindowInfo {
+        label: label.clone(),
+        url,
+        options,
+        created_at: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0),
+    };
+    state.lock().unwrap().children.insert(label.clone(), info.clone());
+
+    // Windows closed by the user are forgotten as well
+    let handle = app.clone();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Destroyed = event {
+            handle.state::<Mutex<AppWindowsState>>().lock().unwrap().children.remove(&label);
+            let _ = handle.emit_all("window://closed", serde_json::json!({ "label": label }));
+        }
+    });
+
+    println!("🪟 Opened child window: {}", info.label);
+    let _ = app.emit_all("window://created", &info);
+    Ok(info)
+}
+
+/// Every open webview window, with creation details for the ones opened through create_child_window.
+#[tauri::command]
+pub async fn list_app_windows(app: AppHandle, state: State<'_, Mutex<AppWindowsState>>) -> Result<Vec<serde_json::Value>, String> {
+    let children = state.lock().unwrap().children.clone();
+
+    let windows = app
+        .windows()
+        .into_iter()
+        .map(|(label, window)| {
+            serde_json::json!({
+                "label": label,
+                "title": window.title().unwrap_or_default(),
+                "visible": window.is_visible().unwrap_or(false),
+                "focused": window.is_focused().unwrap_or(false),
+                "child": children.get(&label),
+            })
+        })
+        .collect();
+    Ok(windows)
+}
+
+#[tauri::command]
+pub async fn close_app_window(app: AppHandle, label: String) -> Result<(), String> {
+    if label == crate::headless::MAIN_LABEL {
+        return Err("❌ The main window cannot be closed from here".to_string());
+    }
+
+    let window = app.get_window(&label).ok_or_else(|| format!("❌ Window not found: {}", label))?;
+    window.close().map_err(|e| e.to_string())?;
+
+    println!("🪟 Closed window: {}", label);
+    Ok(())
+}
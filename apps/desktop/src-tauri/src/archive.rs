@@ -0,0 +1,350 @@
+/**
+ * Archive module - Native zip / tar.gz / 7z extraction and creation
+ */
+
+use std::fs::File;
+use std::path::{Component, Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ArchiveFormat {
+    Zip,
+    TarGz,
+    SevenZ,
+}
+
+fn detect_format(path: &Path) -> Option<ArchiveFormat> {
+    let name = path.file_name()?.to_str()?.to_lowercase();
+
+    if name.ends_with(".zip") {
+        Some(ArchiveFormat::Zip)
+    } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        Some(ArchiveFormat::TarGz)
+    } else if name.ends_with(".7z") {
+        Some(ArchiveFormat::SevenZ)
+    } else {
+        None
+    }
+}
+
+fn parse_format(format: &str) -> Result<ArchiveFormat, String> {
+    match format.to_lowercase().as_str() {
+        "zip" => Ok(ArchiveFormat::Zip),
+        "tar.gz" | "tgz" | "targz" => Ok(ArchiveFormat::TarGz),
+        "7z" => Ok(ArchiveFormat::SevenZ),
+        _ => Err(format!("Unsupported archive format: {}", format)),
+    }
+}
+
+/// Joins an archive entry name onto dest, rejecting absolute paths and `..` escapes.
+fn safe_join(dest: &Path, entry: &Path) -> Result<PathBuf, String> {
+    let mut target = dest.to_path_buf();
+
+    for component in entry.components() {
+        match component {
+            Component::Normal(part) => target.push(part),
+            Component::CurDir => {}
+            _ => return Err(format!("❌ Unsafe path in archive: {}", entry.display())),
+        }
+    }
+
+    Ok(target)
+}
+
+fn emit_progress(app: &AppHandle, operation: &str, done: usize, total: usize, current: &str) {
+    let _ = app.emit_all("archive-progress", serde_json::json!({
+        "operation": operation,
+        "done": done,
+        "total": total,
+        "current": current,
+    }));
+}
+
+fn extract_zip(app: &AppHandle, source: &Path, dest: &Path) -> Result<usize, String> {
+    let file = File::open(source).map_err(|e| e.to_string())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+    let total = archive.len();
+
+    for index in 0..total {
+        let mut entry = archive.by_index(index).map_err(|e| e.to_string())?;
+        let name = entry.name().to_string();
+        let target = safe_join(dest, Path::new(&name))?;
+
+        if entry.is_dir() {
+            std::fs::create_dir_all(&target).map_err(|e| e.to_string())?;
+        } else {
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+            let mut out = File::create(&target).map_err(|e| e.to_string())?;
+            std::io::copy(&mut entry, &mut out).map_err(|e| e.to_string())?;
+        }
+
+        emit_progress(app, "extract", index + 1, total, &name);
+    }
+
+    Ok(total)
+}
+
+fn extract_tar_gz(app: &AppHandle, source: &Path, dest: &Path) -> Result<usize, String> {
+    let file = File::open(source).map_err(|e| e.to_string())?;
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(file));
+    let mut count = 0;
+
+    for entry in archive.entries().map_err(|e| e.to_string())? {
+        let mut entry = entry.map_err(|e| e.to_string())?;
+        let name = entry.path().map_err(|e| e.to_string())?.to_path_buf();
+        let target = safe_join(dest, &name)?;
+
+        // Links could point outside dest even with a safe name
+        let entry_type = entry.header().entry_type();
+        if entry_type.is_symlink() || entry_type.is_hard_link() {
+            continue;
+        }
+
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        entry.unpack(&target).map_err(|e| e.to_string())?;
+
+        count += 1;
+        // Total is unknown for a gzip stream without a second pass
+        emit_progress(app, "extract", count, 0, &name.display().to_string());
+    }
+
+    Ok(count)
+}
+
+fn extract_7z(app: &AppHandle, source: &Path, dest: &Path) -> Result<usize, String> {
+    let mut count = 0;
+
+    sevenz_rust::decompress_file_with_extract_fn(source, dest, |entry, reader, _default_path| {
+        let target = safe_join(dest, Path::new(entry.name()))
+            .map_err(|e| sevenz_rust::Error::other(e))?;
+
+        if entry.is_directory() {
+            std::fs::create_dir_all(&target)?;
+        } else {
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let mut out = File::create(&target)?;
+            std::io::copy(reader, &mut out)?;
+        }
+
+        count += 1;
+        emit_progress(app, "extract", count, 0, entry.name());
+        Ok(true)
+    })
+    .map_err(|e| e.to_string())?;
+
+    Ok(count)
+}
+
+/// Expands the input paths into (file on disk, name inside archive) pairs.
+pub fn collect_entries(paths: &[String]) -> Result<Vec<(PathBuf, PathBuf)>, String> {
+    let mut entries = Vec::new();
+
+    for path in paths {
+        let root = PathBuf::from(path);
+        if !root.exists() {
+            return Err(format!("❌ Path not found: {}", path));
+        }
+
+        let base = root.parent().map(Path::to_path_buf).unwrap_or_default();
+        let mut stack = vec![root.clone()];
+
+        while let Some(current) = stack.pop() {
+            // Links inside the tree are not followed, so a link back to an ancestor can't loop
+            let metadata = if current == root { std::fs::metadata(&current) } else { std::fs::symlink_metadata(&current) };
+            let metadata = metadata.map_err(|e| e.to_string())?;
+            if metadata.file_type().is_symlink() {
+                println!("🔗 Skipping symbolic link: {}", current.display());
+            } else if metadata.is_dir() {
+                for child in std::fs::read_dir(&current).map_err(|e| e.to_string())? {
+                    stack.push(child.map_err(|e| e.to_string())?.path());
+                }
+            } else {
+                let name = current.strip_prefix(&base).unwrap_or(&current).to_path_buf();
+                entries.push((current, name));
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+pub fn create_zip(app: &AppHandle, entries: &[(PathBuf, PathBuf)], dest: &Path) -> Result<(), String> {
+    use std::io::Write;
+
+    let file = File::create(dest).map_err(|e| e.to_string())?;
+    let mut writer = zip::ZipWriter::new(file);
+    let options = zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    for (index, (source, name)) in entries.iter().enumerate() {
+        // Zip entry names always use forward slashes
+        let entry_name = name.to_string_lossy().replace('\\', "/");
+        writer.start_file(entry_name.as_str(), options).map_err(|e| e.to_string())?;
+        let bytes = std::fs::read(source).map_err(|e| e.to_string())?;
+        writer.write_all(&bytes).map_err(|e| e.to_string())?;
+        emit_progress(app, "create", index + 1, entries.len(), &entry_name);
+    }
+
+    writer.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+fn create_tar_gz(app: &AppHandle, entries: &[(PathBuf, PathBuf)], dest: &Path) -> Result<(), String> {
+    let file = File::create(dest).map_err(|e| e.to_string())?;
+    let encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+
+    for (index, (source, name)) in entries.iter().enumerate() {
+        builder.append_path_with_name(source, name).map_err(|e| e.to_string())?;
+        emit_progress(app, "create", index + 1, entries.len(), &name.display().to_string());
+    }
+
+    builder
+        .into_inner()
+        .and_then(|encoder| encoder.finish())
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+fn create_7z(app: &AppHandle, entries: &[(PathBuf, PathBuf)], dest: &Path) -> Result<(), String> {
+    let mut writer = sevenz_rust::SevenZWriter::create(dest).map_err(|e| e.to_string())?;
+
+    for (index, (source, name)) in entries.iter().enumerate() {
+        let entry = sevenz_rust::SevenZArchiveEntry::from_path(source, name.to_string_lossy().to_string());
+        let reader = File::open(source).map_err(|e| e.to_string())?;
+        writer.push_archive_entry(entry, Some(reader)).map_err(|e| e.to_string())?;
+        emit_progress(app, "create", index + 1, entries.len(), &name.display().to_string());
+    }
+
+    writer.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Extracts an archive into dest; shared with the downloads "extract" action.
+pub fn extract_archive_to(app: &AppHandle, path: &Path, dest: &Path) -> Result<usize, String> {
+    let format = detect_format(path).ok_or_else(|| format!("Unsupported archive: {}", path.display()))?;
+    std::fs::create_dir_all(dest).map_err(|e| e.to_string())?;
+
+    match format {
+        ArchiveFormat::Zip => extract_zip(app, path, dest),
+        ArchiveFormat::TarGz => extract_tar_gz(app, path, dest),
+        ArchiveFormat::SevenZ => extract_7z(app, path, dest),
+    }
+}
+
+#[tauri::command]
+pub async fn extract_archive(app: AppHandle, path: String, dest: Option<String>) -> Result<serde_json::Value, String> {
+    let source = PathBuf::from(&path);
+
+    // Default to a sibling folder named after the archive
+    let dest = match dest {
+        Some(dest) => PathBuf::from(dest),
+        None => {
+            let name = source.file_name().and_then(|n| n.to_str()).unwrap_or("archive");
+            let stem = name
+                .trim_end_matches(".tar.gz")
+                .trim_end_matches(".tgz")
+                .trim_end_matches(".zip")
+                .trim_end_matches(".7z");
+            source.with_file_name(stem)
+        }
+    };
+
+    println!("📦 Extracting {} -> {}", path, dest.display());
+
+    let task_app = app.clone();
+    let task_dest = dest.clone();
+    let count = tauri::async_runtime::spawn_blocking(move || extract_archive_to(&task_app, &source, &task_dest))
+        .await
+        .map_err(|e| e.to_string())??;
+
+    Ok(serde_json::json!({
+        "destination": dest.display().to_string(),
+        "entries": count,
+    }))
+}
+
+#[tauri::command]
+pub async fn create_archive(
+    app: AppHandle,
+    paths: Vec<String>,
+    dest: String,
+    format: String,
+) -> Result<serde_json::Value, String> {
+    let format = parse_format(&format)?;
+    let dest_path = PathBuf::from(&dest);
+
+    println!("📦 Creating archive {} from {} paths", dest, paths.len());
+
+    let entries = collect_entries(&paths)?;
+    let count = entries.len();
+
+    let task_app = app.clone();
+    let task_dest = dest_path.clone();
+    tauri::async_runtime::spawn_blocking(move || match format {
+        ArchiveFormat::Zip => create_zip(&task_app, &entries, &task_dest),
+        ArchiveFormat::TarGz => create_tar_gz(&task_app, &entries, &task_dest),
+        ArchiveFormat::SevenZ => create_7z(&task_app, &entries, &task_dest),
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    let size = std::fs::metadata(&dest_path).map(|m| m.len()).unwrap_or(0);
+
+    Ok(serde_json::json!({
+        "path": dest,
+        "entries": count,
+        "sizeBytes": size,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_join_keeps_plain_names_under_dest() {
+        let dest = Path::new("/tmp/out");
+        assert_eq!(safe_join(dest, Path::new("docs/readme.txt")).unwrap(), dest.join("docs").join("readme.txt"));
+        assert_eq!(safe_join(dest, Path::new("./docs/./readme.txt")).unwrap(), dest.join("docs").join("readme.txt"));
+    }
+
+    #[test]
+    fn safe_join_rejects_parent_components() {
+        let dest = Path::new("/tmp/out");
+        assert!(safe_join(dest, Path::new("../evil.txt")).is_err());
+        assert!(safe_join(dest, Path::new("docs/../../evil.txt")).is_err());
+        assert!(safe_join(dest, Path::new("docs/..")).is_err());
+    }
+
+    #[test]
+    fn safe_join_rejects_absolute_paths() {
+        let dest = Path::new("/tmp/out");
+        assert!(safe_join(dest, Path::new("/etc/passwd")).is_err());
+    }
+
+    #[test]
+    fn safe_join_never_leaves_dest_for_windows_style_names() {
+        let dest = Path::new("/tmp/out");
+        for name in ["C:\\Windows\\evil.dll", "C:evil.dll", "\\\\server\\share\\evil.dll", "..\\evil.dll"] {
+            if let Ok(target) = safe_join(dest, Path::new(name)) {
+                assert!(target.starts_with(dest), "{} escaped to {}", name, target.display());
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn safe_join_rejects_windows_prefixes() {
+        let dest = Path::new("C:\\out");
+        assert!(safe_join(dest, Path::new("C:\\Windows\\evil.dll")).is_err());
+        assert!(safe_join(dest, Path::new("C:evil.dll")).is_err());
+        assert!(safe_join(dest, Path::new("\\\\server\\share\\evil.dll")).is_err());
+        assert!(safe_join(dest, Path::new("..\\evil.dll")).is_err());
+    }
+}
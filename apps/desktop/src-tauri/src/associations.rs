@@ -0,0 +1,258 @@
+/**
+ * Associations module - Default application lookups and changes for file extensions and URI schemes
+ *
+ * Targets are written ".svg" / "svg" for extensions and "mailto:" / "slack://" for schemes.
+ */
+
+use serde::Serialize;
+use std::process::Command;
+
+use crate::watchdog::WatchdogCommand;
+
+#[derive(Debug, Clone)]
+enum AssociationTarget {
+    Extension(String),
+    Scheme(String),
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefaultApp {
+    pub target: String,
+    pub app_name: Option<String>,
+    /// Desktop entry id (Linux), bundle id (macOS) or ProgId (Windows)
+    pub app_id: String,
+    pub path: Option<String>,
+}
+
+impl AssociationTarget {
+    fn value(&self) -> &str {
+        match self {
+            AssociationTarget::Extension(value) | AssociationTarget::Scheme(value) => value,
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn kind(&self) -> &'static str {
+        match self {
+            AssociationTarget::Extension(_) => "extension",
+            AssociationTarget::Scheme(_) => "scheme",
+        }
+    }
+
+    fn display(&self) -> String {
+        match self {
+            AssociationTarget::Extension(extension) => format!(".{}", extension),
+            AssociationTarget::Scheme(scheme) => format!("{}:", scheme),
+        }
+    }
+}
+
+fn parse_target(input: &str) -> Result<AssociationTarget, String> {
+    let input = input.trim().to_lowercase();
+
+    let target = if let Some(scheme) = input.strip_suffix("://").or_else(|| input.strip_suffix(':')) {
+        AssociationTarget::Scheme(scheme.to_string())
+    } else {
+        AssociationTarget::Extension(input.trim_start_matches('.').to_string())
+    };
+
+    // Values end up in registry paths and MIME types, so keep them to plain identifier characters
+    let value = target.value();
+    if value.is_empty() || !value.chars().all(|c| c.is_ascii_alphanumeric() || "+.-_".contains(c)) {
+        return Err(format!("❌ Invalid extension or scheme: {}", input));
+    }
+
+    Ok(target)
+}
+
+fn run(command: &mut Command) -> Result<String, String> {
+    let output = command.output_with_timeout()?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn mime_type(target: &AssociationTarget) -> Option<String> {
+    let extension = match target {
+        AssociationTarget::Scheme(scheme) => return Some(format!("x-scheme-handler/{}", scheme)),
+        AssociationTarget::Extension(extension) => extension,
+    };
+
+    // shared-mime-info globs: "weight:mime/type:*.ext"
+    let mut dirs = vec![std::path::PathBuf::from("/usr/share/mime")];
+    if let Some(home) = std::env::var_os("HOME") {
+        dirs.push(std::path::PathBuf::from(home).join(".local/share/mime"));
+    }
+    let pattern = format!("*.{}", extension);
+
+    dirs.iter()
+        .filter_map(|dir| std::fs::read_to_string(dir.join("globs2")).ok())
+        .flat_map(|globs| globs.lines().map(String::from).collect::<Vec<_>>())
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, ':');
+            let weight: u32 = fields.next()?.parse().ok()?;
+            let mime = fields.next()?.to_string();
+            let glob = fields.next()?.split(':').next()?.to_lowercase();
+            (glob == pattern).then_some((weight, mime))
+        })
+        .max_by_key(|(weight, _)| *weight)
+        .map(|(_, mime)| mime)
+}
+
+#[cfg(target_os = "linux")]
+fn query_default(target: &AssociationTarget) -> Result<Option<DefaultApp>, String> {
+    let mime = mime_type(target).ok_or_else(|| format!("Unknown file type: {}", target.display()))?;
+    let entry = run(Command::new("xdg-mime").args(&["query", "default", &mime]))?;
+    if entry.is_empty() {
+        return Ok(None);
+    }
+
+    let id = entry.trim_end_matches(".desktop").to_string();
+    let installed = crate::commands::enumerate_installed_applications()?
+        .into_iter()
+        .find(|app| {
+            std::path::Path::new(app["path"].as_str().unwrap_or(""))
+                .file_stem()
+                .map_or(false, |stem| stem.to_string_lossy() == id)
+        });
+
+    Ok(Some(DefaultApp {
+        target: target.display(),
+        app_name: installed.as_ref().and_then(|app| app["name"].as_str().map(String::from)),
+        path: installed.as_ref().and_then(|app| app["path"].as_str().map(String::from)),
+        app_id: id,
+    }))
+}
+
+#[cfg(target_os = "linux")]
+fn change_default(target: &AssociationTarget, app_name: &str) -> Result<(), String> {
+    let mime = mime_type(target).ok_or_else(|| format!("Unknown file type: {}", target.display()))?;
+    let id = crate::commands::desktop_entry_id(app_name).ok_or_else(|| format!("❌ Application not found: {}", app_name))?;
+    run(Command::new("xdg-mime").args(&["default", &format!("{}.desktop", id), &mime])).map(|_| ())
+}
+
+#[cfg(target_os = "macos")]
+fn query_default(target: &AssociationTarget) -> Result<Option<DefaultApp>, String> {
+    // Extensions are resolved through an empty probe file, since Launch Services works on URLs
+    let script = r#"ObjC.import('AppKit');
+function run(argv) {
+    var url;
+    if (argv[0] === 'scheme') {
+        url = $.NSURL.URLWithString(argv[1] + ':');
+    } else {
+        var path = $.NSTemporaryDirectory().js + 'jarvisx-probe.' + argv[1];
+        $.NSFileManager.defaultManager.createFileAtPathContentsAttributes(path, $(), $());
+        url = $.NSURL.fileURLWithPath(path);
+    }
+    var app = $.NSWorkspace.sharedWorkspace.URLForApplicationToOpenURL(url);
+    if (app.isNil()) return '';
+    var bundle = $.NSBundle.bundleWithURL(app);
+    return JSON.stringify({ path: app.path.js, bundleId: bundle.bundleIdentifier.js, name: bundle.objectForInfoDictionaryKey('CFBundleName').js });
+}"#;
+    let output = run(Command::new("osascript").args(&["-l", "JavaScript", "-e", script, target.kind(), target.value()]))?;
+    if output.is_empty() {
+        return Ok(None);
+    }
+
+    let app: serde_json::Value = serde_json::from_str(&output).map_err(|e| e.to_string())?;
+    Ok(Some(DefaultApp {
+        target: target.display(),
+        app_name: app["name"].as_str().map(String::from),
+        app_id: app["bundleId"].as_str().unwrap_or_default().to_string(),
+        path: app["path"].as_str().map(String::from),
+    }))
+}
+
+#[cfg(target_os = "macos")]
+fn change_default(target: &AssociationTarget, app_name: &str) -> Result<(), String> {
+    let script = r#"ObjC.import('CoreServices');
+function run(argv) {
+    var bundleId = Application(argv[2]).id();
+    if (argv[0] === 'scheme') {
+        return String($.LSSetDefaultHandlerForURLScheme($(argv[1]), $(bundleId)));
+    }
+    var uti = $.UTTypeCreatePreferredIdentifierForTag($.kUTTagClassFilenameExtension, $(argv[1]), $());
+    return String($.LSSetDefaultRoleHandlerForContentType(uti, $.kLSRolesAll, $(bundleId)));
+}"#;
+    let status = run(Command::new("osascript").args(&["-l", "JavaScript", "-e", script, target.kind(), target.value(), app_name]))?;
+
+    if status == "0" {
+        Ok(())
+    } else {
+        Err(format!("❌ Launch Services refused the change (status {})", status))
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn query_default(target: &AssociationTarget) -> Result<Option<DefaultApp>, String> {
+    // The per-user choice wins over the machine-wide class registration
+    let script = r#"$kind = $env:JARVISX_ASSOCIATION_KIND; $value = $env:JARVISX_ASSOCIATION_VALUE
+if ($kind -eq 'scheme') {
+    $choice = "HKCU:\Software\Microsoft\Windows\Shell\Associations\UrlAssociations\$value\UserChoice"
+    $fallback = $value
+} else {
+    $choice = "HKCU:\Software\Microsoft\Windows\CurrentVersion\Explorer\FileExts\.$value\UserChoice"
+    $fallback = (Get-ItemProperty -LiteralPath "Registry::HKEY_CLASSES_ROOT\.$value" -ErrorAction SilentlyContinue).'(default)'
+}
+$progId = (Get-ItemProperty -LiteralPath $choice -ErrorAction SilentlyContinue).ProgId
+if (-not $progId) { $progId = $fallback }
+if (-not $progId) { return }
+$command = (Get-ItemProperty -LiteralPath "Registry::HKEY_CLASSES_ROOT\$progId\shell\open\command" -ErrorAction SilentlyContinue).'(default)'
+$name = (Get-ItemProperty -LiteralPath "Registry::HKEY_CLASSES_ROOT\$progId" -ErrorAction SilentlyContinue).'(default)'
+@{ progId = $progId; command = $command; name = $name } | ConvertTo-Json -Compress"#;
+    // The extension or scheme reaches the script as data, never as script text
+    let output = run(&mut crate::commands::powershell(
+        script,
+        &[("JARVISX_ASSOCIATION_KIND", target.kind()), ("JARVISX_ASSOCIATION_VALUE", target.value())],
+    ))?;
+    if output.is_empty() {
+        return Ok(None);
+    }
+
+    let app: serde_json::Value = serde_json::from_str(&output).map_err(|e| e.to_string())?;
+    Ok(Some(DefaultApp {
+        target: target.display(),
+        app_name: app["name"].as_str().filter(|name| !name.is_empty()).map(String::from),
+        app_id: app["progId"].as_str().unwrap_or_default().to_string(),
+        path: app["command"].as_str().map(String::from),
+    }))
+}
+
+#[cfg(target_os = "windows")]
+fn change_default(_target: &AssociationTarget, _app_name: &str) -> Result<(), String> {
+    // UserChoice keys are hash-protected; Windows only accepts changes made in Settings
+    let _ = Command::new("explorer").arg("ms-settings:defaultapps").spawn();
+    Err("Windows only allows changing default apps in Settings; opened Default apps for you".to_string())
+}
+
+/// Blocking lookup of the app that opens files with `extension`.
+pub fn default_app_for_extension(extension: &str) -> Option<DefaultApp> {
+    query_default(&parse_target(extension).ok()?).ok().flatten()
+}
+
+#[tauri::command]
+pub async fn get_default_app(extension_or_scheme: String) -> Result<Option<DefaultApp>, String> {
+    let target = parse_target(&extension_or_scheme)?;
+
+    tauri::async_runtime::spawn_blocking(move || query_default(&target))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+pub async fn set_default_app(extension_or_scheme: String, app_name: String) -> Result<Option<DefaultApp>, String> {
+    let target = parse_target(&extension_or_scheme)?;
+    println!("🔗 Setting default for {} to {}", target.display(), app_name);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        change_default(&target, &app_name)?;
+        query_default(&target)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
@@ -0,0 +1,303 @@
+/**
+ * Audio sessions module - Which applications are playing audio, with per-app volume and mute
+ *
+ * Windows enumerates WASAPI sessions and Linux PulseAudio/PipeWire sink inputs, both of which can be muted or
+ * turned down per app (e.g. the game lowered while the call stays audible). macOS exposes no per-app mixer: playing
+ * apps are found through the power assertions CoreAudio takes on their behalf, and volume changes are refused.
+ * Volumes set while ducking is active become the level restored afterwards.
+ */
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioSession {
+    /// Platform session id: the process id on Windows and macOS, the sink input index on Linux
+    pub session_id: String,
+    pub pid: Option<u32>,
+    pub app: String,
+    /// 0..1; None where the OS doesn't report it
+    pub volume: Option<f32>,
+    pub muted: bool,
+    /// Producing sound right now, as opposed to an open but idle or paused stream
+    pub playing: bool,
+}
+
+/// All sessions of one application, as get_app_volumes reports them.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppVolume {
+    pub app: String,
+    pub pids: Vec<u32>,
+    /// Loudest session's volume
+    pub volume: Option<f32>,
+    /// True only when every session is muted
+    pub muted: bool,
+    pub playing: bool,
+}
+
+#[cfg(not(target_os = "macos"))]
+fn app_name(pid: u32) -> String {
+    crate::window_ids::process_info(pid).map(|process| process.name).unwrap_or_default()
+}
+
+#[cfg(target_os = "windows")]
+fn platform_sessions() -> Result<Vec<AudioSession>, String> {
+    use windows::Win32::Media::Audio::AudioSessionStateActive;
+
+    let mut sessions = Vec::new();
+    crate::ducking::for_each_session(|id, control, volume| unsafe {
+        let pid = id.parse::<u32>().ok();
+        sessions.push(AudioSession {
+            app: pid.map(app_name).unwrap_or_default(),
+            pid,
+            session_id: id,
+            volume: Some(volume.GetMasterVolume()?),
+            muted: volume.GetMute()?.as_bool(),
+            playing: control.GetState()? == AudioSessionStateActive,
+        });
+        Ok(())
+    })?;
+    Ok(sessions)
+}
+
+#[cfg(target_os = "windows")]
+fn apply_platform(session: &AudioSession, volume: Option<f32>, muted: Option<bool>) -> Result<(), String> {
+    crate::ducking::for_each_session(|id, _, control| unsafe {
+        if id == session.session_id {
+            if let Some(volume) = volume {
+                control.SetMasterVolume(volume, std::ptr::null())?;
+            }
+            if let Some(muted) = muted {
+                control.SetMute(muted, std::ptr::null())?;
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Value of a `key = "value"` property line in a `pactl list` block.
+#[cfg(target_os = "linux")]
+fn property<'a>(block: &'a str, key: &str) -> Option<&'a str> {
+    block
+        .lines()
+        .map(str::trim)
+        .find_map(|line| line.strip_prefix(key)?.trim_start().strip_prefix('=')?.trim().strip_prefix('"')?.strip_suffix('"'))
+}
+
+#[cfg(target_os = "linux")]
+fn platform_sessions() -> Result<Vec<AudioSession>, String> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let output = Command::new("pactl").args(&["list", "sink-inputs"]).output_with_timeout()?;
+    if !output.status.success() {
+        return Err("pactl is not available".to_string());
+    }
+
+    let field = |block: &str, name: &str| {
+        block.lines().map(str::trim).find_map(|line| line.strip_prefix(name).map(|value| value.trim().to_string()))
+    };
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split("Sink Input #")
+        .skip(1)
+        .filter_map(|block| {
+            let pid = property(block, "application.process.id").and_then(|pid| pid.parse::<u32>().ok());
+            if pid == Some(std::process::id()) {
+                return None;
+            }
+            let volume = field(block, "Volume:")
+                .and_then(|line| line.split('/').nth(1).map(str::to_string))
+                .and_then(|percent| percent.trim().trim_end_matches('%').parse::<f32>().ok());
+
+            Some(AudioSession {
+                session_id: block.lines().next().unwrap_or("").trim().to_string(),
+                app: property(block, "application.name").map(str::to_string).or_else(|| pid.map(app_name)).unwrap_or_default(),
+                pid,
+                volume: volume.map(|percent| percent / 100.0),
+                muted: field(block, "Mute:").as_deref() == Some("yes"),
+                // Corked streams are paused
+                playing: field(block, "Corked:").as_deref() != Some("yes"),
+            })
+        })
+        .collect())
+}
+
+#[cfg(target_os = "linux")]
+fn apply_platform(session: &AudioSession, volume: Option<f32>, muted: Option<bool>) -> Result<(), String> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let pactl = |args: &[&str]| -> Result<(), String> {
+        let output = Command::new("pactl").args(args).output_with_timeout()?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+        }
+    };
+
+    if let Some(volume) = volume {
+        pactl(&["set-sink-input-volume", &session.session_id, &format!("{}%", (volume * 100.0).round() as u32)])?;
+    }
+    if let Some(muted) = muted {
+        pactl(&["set-sink-input-mute", &session.session_id, if muted { "1" } else { "0" }])?;
+    }
+    Ok(())
+}
+
+/// Apps CoreAudio is keeping the system awake for, i.e. those with live output.
+#[cfg(target_os = "macos")]
+fn platform_sessions() -> Result<Vec<AudioSession>, String> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let output = Command::new("pmset").args(&["-g", "assertions"]).output_with_timeout()?;
+    if !output.status.success() {
+        return Err("Could not read power assertions".to_string());
+    }
+
+    let mut sessions: Vec<AudioSession> = Vec::new();
+    // "pid 456(Music): [0x…] 00:01:23 PreventUserIdleSleep named: "com.apple.audio.context123.preventuseridlesleep""
+    for line in String::from_utf8_lossy(&output.stdout).lines().filter(|line| line.contains("com.apple.audio")) {
+        let Some(rest) = line.trim().strip_prefix("pid ") else { continue };
+        let Some((pid, rest)) = rest.split_once('(') else { continue };
+        let (Ok(pid), Some((name, _))) = (pid.trim().parse::<u32>(), rest.split_once(')')) else { continue };
+        if name == "coreaudiod" || sessions.iter().any(|session| session.pid == Some(pid)) {
+            continue;
+        }
+        sessions.push(AudioSession {
+            session_id: pid.to_string(),
+            pid: Some(pid),
+            app: name.to_string(),
+            volume: None,
+            muted: false,
+            playing: true,
+        });
+    }
+    Ok(sessions)
+}
+
+#[cfg(target_os = "macos")]
+fn apply_platform(_session: &AudioSession, _volume: Option<f32>, _muted: Option<bool>) -> Result<(), String> {
+    Err("❌ Per-app volume isn't supported on macOS".to_string())
+}
+
+pub fn sessions() -> Result<Vec<AudioSession>, String> {
+    // The mock platform has no audio
+    if crate::mock_platform::is_active() {
+        return Ok(Vec::new());
+    }
+
+    let mut sessions = platform_sessions()?;
+    // Report what a ducked session will return to, not its temporarily lowered level
+    for session in &mut sessions {
+        if let Some(volume) = crate::ducking::restore_volume(&session.session_id) {
+            session.volume = Some(volume);
+        }
+    }
+    Ok(sessions)
+}
+
+/// Sessions of `app`: a process id, or a case-insensitive part of the application name.
+fn sessions_of(app: &str) -> Result<Vec<AudioSession>, String> {
+    let pid = app.trim().parse::<u32>().ok();
+    let needle = app.trim().to_lowercase();
+    let matched: Vec<AudioSession> = sessions()?
+        .into_iter()
+        .filter(|session| match pid {
+            Some(pid) => session.pid == Some(pid),
+            None => session.app.to_lowercase().contains(&needle),
+        })
+        .collect();
+
+    if matched.is_empty() {
+        return Err(format!("❌ No audio session for: {}", app));
+    }
+    Ok(matched)
+}
+
+fn group_by_app(sessions: Vec<AudioSession>) -> Vec<AppVolume> {
+    let mut apps: Vec<AppVolume> = Vec::new();
+    for session in sessions {
+        let name = if session.app.is_empty() { session.session_id.clone() } else { session.app.clone() };
+        let index = match apps.iter().position(|app| app.app == name) {
+            Some(index) => index,
+            None => {
+                apps.push(AppVolume { app: name, pids: Vec::new(), volume: None, muted: true, playing: false });
+                apps.len() - 1
+            }
+        };
+
+        let app = &mut apps[index];
+        if let Some(pid) = session.pid.filter(|pid| !app.pids.contains(pid)) {
+            app.pids.push(pid);
+        }
+        app.volume = match (app.volume, session.volume) {
+            (Some(current), Some(volume)) => Some(current.max(volume)),
+            (current, volume) => current.or(volume),
+        };
+        app.muted &= session.muted;
+        app.playing |= session.playing;
+    }
+    apps
+}
+
+/// Applies `volume` and/or `muted` to every session of `app`, returning its updated state.
+fn apply_to_app(app: &str, volume: Option<f32>, muted: Option<bool>) -> Result<Vec<AppVolume>, String> {
+    for session in sessions_of(app)? {
+        let volume = volume.map(|volume| crate::ducking::set_restore_volume(&session.session_id, volume));
+        apply_platform(&session, volume, muted)?;
+    }
+    Ok(group_by_app(sessions_of(app)?))
+}
+
+/// Apps with an audio session; idle (paused or silent) ones only with `include_idle`.
+#[tauri::command]
+pub async fn get_apps_playing_audio(include_idle: Option<bool>) -> Result<Vec<AudioSession>, String> {
+    let include_idle = include_idle.unwrap_or(false);
+    let sessions = tauri::async_runtime::spawn_blocking(sessions).await.map_err(|e| e.to_string())??;
+    Ok(sessions.into_iter().filter(|session| include_idle || session.playing).collect())
+}
+
+/// Volume and mute state of every app with an audio session.
+#[tauri::command]
+pub async fn get_app_volumes() -> Result<Vec<AppVolume>, String> {
+    let sessions = tauri::async_runtime::spawn_blocking(sessions).await.map_err(|e| e.to_string())??;
+    Ok(group_by_app(sessions))
+}
+
+/// Sets the volume (0..1) of every session of `app`, given as a process id or application name.
+#[tauri::command]
+pub async fn set_app_volume(app: String, level: f32) -> Result<Vec<AppVolume>, String> {
+    if !(0.0..=1.0).contains(&level) {
+        return Err("❌ Volume must be between 0 and 1".to_string());
+    }
+    println!("🔊 Setting volume of {} to {:.0}%", app, level * 100.0);
+    tauri::async_runtime::spawn_blocking(move || apply_to_app(&app, Some(level), None))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+pub async fn set_app_muted(app: String, muted: bool) -> Result<Vec<AppVolume>, String> {
+    println!("{} {}", if muted { "🔇 Muting" } else { "🔊 Unmuting" }, app);
+    tauri::async_runtime::spawn_blocking(move || apply_to_app(&app, None, Some(muted)))
+        .await
+        .map_err(|e| e.to_string())?
+}
+/// Mutes every app currently playing ("mute whatever is making that noise") and returns what was muted.
+#[tauri::command]
+pub async fn mute_apps_playing_audio() -> Result<Vec<AudioSession>, String> {
+    tauri::async_runtime::spawn_blocking(|| {
+        let playing: Vec<AudioSession> = sessions()?.into_iter().filter(|session| session.playing && !session.muted).collect();
+        for session in &playing {
+            apply_platform(session, None, Some(true))?;
+        }
+        println!("🔇 Muted {} playing audio sessions", playing.len());
+        Ok(playing)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
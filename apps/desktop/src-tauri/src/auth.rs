@@ -0,0 +1,354 @@
+/**
+ * Auth module - Device-code login against the JarvisX backend with token refresh
+ */
+
+use serde::Deserialize;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, State};
+
+pub const REFRESH_TOKEN_KEY: &str = "refresh_token";
+/// Refresh this long before the access token actually expires.
+const REFRESH_MARGIN_MS: u64 = 60_000;
+/// Backoff for refreshes the backend didn't reject outright (5xx, 429, network errors); the refresh token is kept.
+const REFRESH_RETRY: crate::retry::RetryPolicy = crate::retry::RetryPolicy {
+    max_attempts: 4,
+    initial_delay_ms: 1_000,
+    max_delay_ms: 15_000,
+    multiplier: 2.0,
+    jitter: 0.25,
+};
+
+pub struct AuthState {
+    pub access_token: Option<String>,
+    pub expires_at: u64,
+    pub user: Option<serde_json::Value>,
+    pub login_in_progress: bool,
+}
+
+impl Default for AuthState {
+    fn default() -> Self {
+        AuthState {
+            access_token: None,
+            expires_at: 0,
+            user: None,
+            login_in_progress: false,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DeviceCodeResponse {
+    device_code: String,
+    user_code: String,
+    verification_uri: String,
+    expires_in: u64,
+    interval: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AuthToken {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_at: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    token: Option<AuthToken>,
+    user: Option<serde_json::Value>,
+    error: Option<String>,
+}
+
+pub fn api_url() -> String {
+    std::env::var("JARVISX_API_URL").unwrap_or_else(|_| "http://localhost:3000".to_string())
+}
+
+fn device_id() -> String {
+    use sysinfo::{System, SystemExt};
+
+    let sys = System::new();
+    format!("desktop-{}", sys.host_name().unwrap_or_else(|| "unknown".to_string()))
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn secrets_namespace(app: &AppHandle) -> String {
+    crate::profiles::active_secrets_namespace(app)
+}
+
+fn apply_token(app: &AppHandle, token: AuthToken, user: Option<serde_json::Value>) -> Result<(), String> {
+    if let Some(refresh_token) = &token.refresh_token {
+        crate::secrets::store_secret(&secrets_namespace(app), REFRESH_TOKEN_KEY, refresh_token)?;
+    }
+
+    let state = app.state::<Mutex<AuthState>>();
+    let mut auth_state = state.lock().unwrap();
+    auth_state.access_token = Some(token.access_token);
+    auth_state.expires_at = token.expires_at;
+    if user.is_some() {
+        auth_state.user = user;
+    }
+
+    Ok(())
+}
+
+fn clear_session(app: &AppHandle) {
+    let _ = crate::secrets::delete_secret(&secrets_namespace(app), REFRESH_TOKEN_KEY);
+
+    let state = app.state::<Mutex<AuthState>>();
+    let mut auth_state = state.lock().unwrap();
+    auth_state.access_token = None;
+    auth_state.expires_at = 0;
+    auth_state.user = None;
+}
+
+fn emit_auth_changed(app: &AppHandle) {
+    let authenticated = app.state::<Mutex<AuthState>>().lock().unwrap().access_token.is_some();
+    let _ = app.emit_all("auth-changed", serde_json::json!({ "authenticated": authenticated }));
+}
+
+async fn refresh_access_token(app: &AppHandle) -> Result<(), String> {
+    let refresh_token = crate::secrets::load_secret(&secrets_namespace(app), REFRESH_TOKEN_KEY)?
+        .ok_or("No refresh token available")?;
+
+    let mut attempts = 0;
+    let response = loop {
+        attempts += 1;
+        let outcome = crate::egress::send(
+            crate::bandwidth::Feature::Account,
+            crate::proxy::client()
+                .post(format!("{}/auth/refresh", api_url()))
+                .json(&serde_json::json!({
+                    "refreshToken": refresh_token,
+                    "deviceId": device_id(),
+                })),
+        )
+        .await;
+
+        let error = match outcome {
+            Ok(response) if response.status().is_success() => break response,
+            // Only an explicit rejection of the refresh token (invalid_grant) ends the session
+            Ok(response) if matches!(response.status().as_u16(), 400 | 401 | 403) => {
+                clear_session(app);
+                emit_auth_changed(app);
+                return Err(format!("❌ Token refresh failed: {}", response.status()));
+            }
+            Ok(response) if response.status().is_server_error() || response.status().as_u16() == 429 => {
+                format!("❌ Token refresh failed: {}", response.status())
+            }
+            Ok(response) => return Err(format!("❌ Token refresh failed: {}", response.status())),
+            Err(e) => e,
+        };
+
+        if attempts >= REFRESH_RETRY.max_attempts {
+            return Err(crate::retry::with_attempts(error, attempts));
+        }
+        let delay = crate::retry::backoff_delay(&REFRESH_RETRY, attempts);
+        println!("🔁 Token refresh failed (attempt {}/{}), retrying in {:?}: {}", attempts, REFRESH_RETRY.max_attempts, delay, error);
+        tokio::time::sleep(delay).await;
+    };
+
+    let body: TokenResponse = response.json().await.map_err(|e| e.to_string())?;
+    let token = body.token.ok_or("Refresh response missing token")?;
+    apply_token(app, token, body.user)?;
+
+    println!("🔑 Access token refreshed");
+    Ok(())
+}
+
+/// Returns a valid access token, refreshing it first if it is about to expire.
+pub async fn access_token(app: &AppHandle) -> Result<String, String> {
+    let (token, expires_at) = {
+        let state = app.state::<Mutex<AuthState>>();
+        let auth_state = state.lock().unwrap();
+        (auth_state.access_token.clone(), auth_state.expires_at)
+    };
+
+    match token {
+        Some(token) if expires_at > now_ms() + REFRESH_MARGIN_MS => Ok(token),
+        _ => {
+            refresh_access_token(app).await?;
+            app.state::<Mutex<AuthState>>()
+                .lock()
+                .unwrap()
+                .access_token
+                .clone()
+                .ok_or_else(|| "Not authenticated".to_string())
+        }
+    }
+}
+
+/// Restores the session from the vault on startup and keeps the access token fresh.
+pub fn spawn_token_refresher(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let has_refresh_token = matches!(
+                crate::secrets::load_secret(&secrets_namespace(&app), REFRESH_TOKEN_KEY),
+                Ok(Some(_))
+            );
+
+            if has_refresh_token {
+                let was_authenticated = app.state::<Mutex<AuthState>>().lock().unwrap().access_token.is_some();
+                match access_token(&app).await {
+                    Ok(_) if !was_authenticated => emit_auth_changed(&app),
+                    Ok(_) => {}
+                    Err(e) => println!("⚠️  {}", e),
+                }
+            }
+
+            tokio::time::sleep(Duration::from_secs(30)).await;
+        }
+    });
+}
+
+async fn poll_device_token(app: AppHandle, device_code: DeviceCodeResponse) {
+    let client = crate::proxy::client();
+    let deadline = now_ms() + device_code.expires_in * 1000;
+    let mut interval = Duration::from_secs(device_code.interval.unwrap_or(5));
+
+    let outcome = loop {
+        if now_ms() >= deadline {
+            break Err("Device code expired".to_string());
+        }
+
+        tokio::time::sleep(interval).await;
+
+        let response = crate::egress::send(
+            crate::bandwidth::Feature::Account,
+            client
+                .post(format!("{}/auth/device/token", api_url()))
+                .json(&serde_json::json!({
+                    "deviceCode": device_code.device_code,
+                    "deviceId": device_id(),
+                })),
+        )
+        .await;
+
+        let body: TokenResponse = match response {
+            Ok(response) => match response.json().await {
+                Ok(body) => body,
+                Err(e) => break Err(e.to_string()),
+            },
+            Err(e) => {
+                println!("⚠️  Device token poll failed: {}", e);
+                continue;
+            }
+        };
+
+        match (body.token, body.error.as_deref()) {
+            (Some(token), _) => break apply_token(&app, token, body.user),
+            (None, Some("authorization_pending")) => continue,
+            (None, Some("slow_down")) => interval += Duration::from_secs(5),
+            (None, Some(error)) => break Err(error.to_string()),
+            (None, None) => break Err("Unexpected device token response".to_string()),
+        }
+    };
+
+    app.state::<Mutex<AuthState>>().lock().unwrap().login_in_progress = false;
+
+    match outcome {
+        Ok(()) => println!("✅ Logged in"),
+        Err(e) => {
+            println!("❌ Login failed: {}", e);
+            let _ = app.emit_all("auth-error", e);
+        }
+    }
+    emit_auth_changed(&app);
+}
+
+#[tauri::command]
+pub async fn login(app: AppHandle) -> Result<serde_json::Value, String> {
+    {
+        let state = app.state::<Mutex<AuthState>>();
+        let mut auth_state = state.lock().unwrap();
+        if auth_state.login_in_progress {
+            return Err("Login already in progress".to_string());
+        }
+        auth_state.login_in_progress = true;
+    }
+
+    let response = crate::egress::send(
+        crate::bandwidth::Feature::Account,
+        crate::proxy::client()
+            .post(format!("{}/auth/device/code", api_url()))
+            .json(&serde_json::json!({
+                "deviceId": device_id(),
+                "deviceType": "desktop",
+                "platform": std::env::consts::OS,
+            })),
+    )
+    .await;
+
+    let device_code = match response {
+        Ok(response) => response.json::<DeviceCodeResponse>().await.map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    };
+
+    let device_code = match device_code {
+        Ok(device_code) => device_code,
+        Err(e) => {
+            app.state::<Mutex<AuthState>>().lock().unwrap().login_in_progress = false;
+            return Err(e);
+        }
+    };
+
+    let info = serde_json::json!({
+        "userCode": device_code.user_code,
+        "verificationUri": device_code.verification_uri,
+        "expiresIn": device_code.expires_in,
+    });
+
+    println!("🔑 Device login started, code: {}", device_code.user_code);
+    let _ = tauri::api::shell::open(&app.shell_scope(), &device_code.verification_uri, None);
+
+    tauri::async_runtime::spawn(poll_device_token(app.clone(), device_code));
+
+    Ok(info)
+}
+
+#[tauri::command]
+pub async fn logout(app: AppHandle) -> Result<(), String> {
+    let token = app.state::<Mutex<AuthState>>().lock().unwrap().access_token.clone();
+
+    if let Some(token) = token {
+        // Best effort: the local session is cleared even if the backend is unreachable
+        let _ = crate::egress::send(
+            crate::bandwidth::Feature::Account,
+            crate::proxy::client()
+                .post(format!("{}/auth/logout", api_url()))
+                .bearer_auth(token)
+                .json(&serde_json::json!({ "deviceId": device_id() })),
+        )
+        .await;
+    }
+
+    clear_session(&app);
+    emit_auth_changed(&app);
+    println!("🔑 Logged out");
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_auth_status(state: State<'_, Mutex<AuthState>>) -> Result<serde_json::Value, String> {
+    let auth_state = state.lock().unwrap();
+
+    Ok(serde_json::json!({
+        "authenticated": auth_state.access_token.is_some() && auth_state.expires_at > now_ms(),
+        "expiresAt": auth_state.expires_at,
+        "user": auth_state.user,
+        "loginInProgress": auth_state.login_in_progress,
+    }))
+}
+
+#[tauri::command]
+pub async fn get_access_token(app: AppHandle) -> Result<String, String> {
+    access_token(&app).await
+}
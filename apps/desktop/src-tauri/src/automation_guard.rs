@@ -0,0 +1,253 @@
+/**
+ * Automation guard module - Keeps background automations from hijacking the user's cursor and focus
+ *
+ * Plans and scripts snapshot the cursor position and frontmost app before they run and restore both afterwards.
+ * Input injected by JarvisX is bracketed with `injecting()`, so everything else the global hook sees while an
+ * automation runs is the user; it interrupts the automation (pause or abort, per policy) with an
+ * `automation://interrupted` event instead of fighting the user for the mouse.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, AtomicU8, AtomicUsize, Ordering};
+use std::sync::RwLock;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+/// Injected events can reach the hook shortly after the enigo call returns
+const INJECTION_TAIL_MS: u64 = 150;
+const POLL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InterruptAction {
+    /// Let the current step finish, then wait until the user is idle
+    Pause,
+    /// Stop the automation immediately
+    Abort,
+    Ignore,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutomationGuardPolicy {
+    pub restore_cursor: bool,
+    pub restore_focus: bool,
+    pub on_user_input: InterruptAction,
+    /// How long the user must be idle before a paused automation resumes
+    pub resume_after_idle_ms: u64,
+    /// Give up (and fail the automation) after waiting this long for the user to stop
+    pub max_pause_ms: u64,
+}
+
+impl Default for AutomationGuardPolicy {
+    fn default() -> Self {
+        DEFAULT_POLICY
+    }
+}
+
+const DEFAULT_POLICY: AutomationGuardPolicy = AutomationGuardPolicy {
+    restore_cursor: true,
+    restore_focus: true,
+    on_user_input: InterruptAction::Pause,
+    resume_after_idle_ms: 2_000,
+    max_pause_ms: 120_000,
+};
+
+static POLICY: RwLock<AutomationGuardPolicy> = RwLock::new(DEFAULT_POLICY);
+static INJECTING: AtomicUsize = AtomicUsize::new(0);
+static INJECTION_ENDED_MS: AtomicU64 = AtomicU64::new(0);
+static USER_EVENTS: AtomicU64 = AtomicU64::new(0);
+/// 1 = keyboard, 2 = mouse
+static LAST_USER_INPUT: AtomicU8 = AtomicU8::new(0);
+
+pub fn current_policy() -> AutomationGuardPolicy {
+    *POLICY.read().unwrap()
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+/// Held while JarvisX injects input; hook events in that window (plus a short tail) are not the user's.
+pub struct Injection(());
+
+impl Drop for Injection {
+    fn drop(&mut self) {
+        INJECTION_ENDED_MS.store(now_ms(), Ordering::Relaxed);
+        INJECTING.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+pub fn injecting() -> Injection {
+    INJECTING.fetch_add(1, Ordering::Relaxed);
+    Injection(())
+}
+
+/// Counts real user input; called by the shared input listener.
+pub fn observe(event: &rdev::Event) {
+    if INJECTING.load(Ordering::Relaxed) > 0 || now_ms().saturating_sub(INJECTION_ENDED_MS.load(Ordering::Relaxed)) < INJECTION_TAIL_MS {
+        return;
+    }
+
+    let kind = match event.event_type {
+        rdev::EventType::KeyPress(_) | rdev::EventType::KeyRelease(_) => 1,
+        _ => 2,
+    };
+    LAST_USER_INPUT.store(kind, Ordering::Relaxed);
+    USER_EVENTS.fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn input_mark() -> u64 {
+    USER_EVENTS.load(Ordering::Relaxed)
+}
+
+fn last_input_kind() -> &'static str {
+    match LAST_USER_INPUT.load(Ordering::Relaxed) {
+        1 => "keyboard",
+        _ => "mouse",
+    }
+}
+
+fn watching(policy: &AutomationGuardPolicy) -> bool {
+    policy.on_user_input != InterruptAction::Ignore && !crate::mock_platform::is_active()
+}
+
+/// What the user had before an automation started.
+#[derive(Debug, Clone)]
+pub struct UserSnapshot {
+    cursor: Option<(i32, i32)>,
+    frontmost: Option<String>,
+    /// WindowId of the focused window, so the exact window comes back rather than the app's first one
+    window_id: Option<String>,
+}
+
+pub fn snapshot() -> UserSnapshot {
+    use enigo::{Enigo, Mouse, Settings};
+
+    let policy = current_policy();
+    if crate::mock_platform::is_active() {
+        return UserSnapshot { cursor: None, frontmost: None, window_id: None };
+    }
+
+    UserSnapshot {
+        cursor: if policy.restore_cursor {
+            Enigo::new(&Settings::default()).ok().and_then(|enigo| enigo.location().ok())
+        } else {
+            None
+        },
+        frontmost: if policy.restore_focus { crate::activity::frontmost_app() } else { None },
+        window_id: if policy.restore_focus { crate::window_ids::active_window().map(|window| window.window_id) } else { None },
+    }
+}
+
+/// Puts the cursor and frontmost app back; best effort, since the user may have closed the app meanwhile.
+pub async fn restore(snapshot: UserSnapshot) {
+    use enigo::{Coordinate, Enigo, Mouse, Settings};
+
+    if let Some(frontmost) = snapshot.frontmost {
+        if crate::activity::frontmost_app().as_deref() != Some(frontmost.as_str()) {
+            if let Err(e) = crate::commands::focus_window(Some(frontmost.clone()), snapshot.window_id, None).await {
+                println!("⚠️  Could not refocus {}: {}", frontmost, e);
+            }
+        }
+    }
+
+    // Location and move share enigo's coordinate space, so no calibration correction applies
+    if let Some((x, y)) = snapshot.cursor {
+        let _injection = injecting();
+        if let Ok(mut enigo) = Enigo::new(&Settings::default()) {
+            let _ = enigo.move_mouse(x, y, Coordinate::Abs);
+        }
+    }
+}
+
+fn emit_interrupted(app: &AppHandle, automation_id: &str, step_id: Option<&str>, action: InterruptAction) {
+    println!("✋ {} interrupted by {} input ({:?})", automation_id, last_input_kind(), action);
+    let _ = app.emit_all(
+        "automation://interrupted",
+        serde_json::json!({ "automationId": automation_id, "stepId": step_id, "input": last_input_kind(), "action": action }),
+    );
+}
+
+/// Runs one automated step while watching for the user. On Abort the step is dropped mid-way and an error returned;
+/// on Pause it finishes and the pause happens in `wait_if_user_active` before the next step.
+pub async fn guard_step<T>(app: &AppHandle, automation_id: &str, step_id: &str, step: impl Future<Output = T>) -> Result<T, String> {
+    let policy = current_policy();
+    if !watching(&policy) {
+        return Ok(step.await);
+    }
+
+    let mark = input_mark();
+    tokio::pin!(step);
+    tokio::select! {
+        output = &mut step => return Ok(output),
+        _ = async { while input_mark() == mark { tokio::time::sleep(POLL).await } } => {}
+    }
+
+    emit_interrupted(app, automation_id, Some(step_id), policy.on_user_input);
+    if policy.on_user_input == InterruptAction::Abort {
+        return Err(format!("❌ {} aborted: user input during step {}", automation_id, step_id));
+    }
+    Ok(step.await)
+}
+
+/// If the user provided input since `mark`, aborts or waits until they have been idle for a while (per policy).
+/// Blocking, so scripts can call it from their progress callback.
+pub fn wait_if_user_active_blocking(app: &AppHandle, automation_id: &str, mark: u64) -> Result<(), String> {
+    let policy = current_policy();
+    if !watching(&policy) || input_mark() == mark {
+        return Ok(());
+    }
+
+    if policy.on_user_input == InterruptAction::Abort {
+        emit_interrupted(app, automation_id, None, InterruptAction::Abort);
+        return Err(format!("❌ {} aborted: user input detected", automation_id));
+    }
+
+    println!("⏸️  {} paused: user input detected", automation_id);
+    let _ = app.emit_all("automation://paused", serde_json::json!({ "automationId": automation_id, "input": last_input_kind() }));
+
+    let started = Instant::now();
+    let idle_for = Duration::from_millis(policy.resume_after_idle_ms);
+    let mut last_count = input_mark();
+    let mut idle_since = Instant::now();
+
+    loop {
+        std::thread::sleep(POLL);
+        let count = input_mark();
+        if count != last_count {
+            last_count = count;
+            idle_since = Instant::now();
+        }
+        if idle_since.elapsed() >= idle_for {
+            break;
+        }
+        if started.elapsed() >= Duration::from_millis(policy.max_pause_ms) {
+            let _ = app.emit_all("automation://resumed", serde_json::json!({ "automationId": automation_id, "timedOut": true }));
+            return Err(format!("❌ {} stopped: the user kept working for {}s", automation_id, policy.max_pause_ms / 1000));
+        }
+    }
+
+    println!("▶️  {} resumed", automation_id);
+    let _ = app.emit_all("automation://resumed", serde_json::json!({ "automationId": automation_id, "timedOut": false }));
+    Ok(())
+}
+
+pub async fn wait_if_user_active(app: &AppHandle, automation_id: &str, mark: u64) -> Result<(), String> {
+    let (app, automation_id) = (app.clone(), automation_id.to_string());
+    tauri::async_runtime::spawn_blocking(move || wait_if_user_active_blocking(&app, &automation_id, mark))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+pub async fn set_automation_guard(policy: AutomationGuardPolicy) -> Result<AutomationGuardPolicy, String> {
+    *POLICY.write().unwrap() = policy;
+    Ok(policy)
+}
+
+#[tauri::command]
+pub async fn get_automation_guard() -> Result<AutomationGuardPolicy, String> {
+    Ok(current_policy())
+}
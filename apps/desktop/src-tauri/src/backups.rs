@@ -0,0 +1,525 @@
+/**
+ * Backups module - Scheduled, incremental, verified backups of user folders
+ *
+ * A job mirrors its sources into `<destination>/<job>/current`, copying only files whose size, modification time
+ * and then SHA-256 differ from the manifest of the previous run. Files that get replaced or deleted are moved into
+ * `versions/<run time>` rather than lost, and the job's retention prunes old versions. Every copy is re-hashed at
+ * the destination. Jobs run manually, on an interval, daily, or when a removable volume (the backup drive) is
+ * attached, pausing when the scheduler needs the CPU and keeping the machine awake meanwhile. Events:
+ * `backup://progress`, `backup://completed` and `backup://failed`.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+const TASK_NAME: &str = "backups";
+const SCHEDULE_INTERVAL: Duration = Duration::from_secs(60);
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+const VERSION_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum BackupSchedule {
+    Manual,
+    /// Every `every` ("6h", "1h30m") since the last run
+    Interval { every: String },
+    /// Once a day at local "HH:MM"
+    Daily { at: String },
+    /// Whenever a removable volume whose name contains `volume` is attached
+    OnVolumeAttached { volume: String },
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupRetention {
+    /// Version snapshots (one per run that replaced or deleted files) to keep, newest first
+    #[serde(default)]
+    pub keep_versions: Option<u32>,
+    #[serde(default)]
+    pub max_age_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupRun {
+    pub started_at: u64,
+    pub finished_at: u64,
+    /// "completed", "failed" or "cancelled"
+    pub status: String,
+    pub files_copied: u64,
+    pub files_unchanged: u64,
+    pub files_removed: u64,
+    pub bytes_copied: u64,
+    /// Copies whose destination hash didn't match the source
+    pub verify_failures: Vec<String>,
+    pub versions_pruned: u64,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupJob {
+    pub id: String,
+    pub name: String,
+    pub sources: Vec<String>,
+    pub destination: String,
+    pub schedule: BackupSchedule,
+    #[serde(default)]
+    pub retention: BackupRetention,
+    pub created_at: u64,
+    #[serde(default)]
+    pub last_run: Option<BackupRun>,
+}
+
+/// What the previous run copied, keyed by "<source folder>/<relative path>".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileRecord {
+    size: u64,
+    modified_ms: u64,
+    sha256: String,
+}
+
+/// Cancel flags of running jobs
+static RUNNING: Mutex<BTreeMap<String, Arc<AtomicBool>>> = Mutex::new(BTreeMap::new());
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn jobs_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("backups.json"))
+}
+
+fn load_jobs(app: &AppHandle) -> Vec<BackupJob> {
+    jobs_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_jobs(app: &AppHandle, jobs: &[BackupJob]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(jobs).map_err(|e| e.to_string())?;
+    std::fs::write(jobs_path(app)?, json).map_err(|e| e.to_string())
+}
+
+fn find_job(app: &AppHandle, id: &str) -> Result<BackupJob, String> {
+    load_jobs(app)
+        .into_iter()
+        .find(|job| job.id == id || job.name.eq_ignore_ascii_case(id))
+        .ok_or_else(|| format!("❌ Backup job not found: {}", id))
+}
+
+fn job_dir(job: &BackupJob) -> PathBuf {
+    let folder: String = job.name.chars().map(|c| if c.is_alphanumeric() || "-_ ".contains(c) { c } else { '_' }).collect();
+    Path::new(&job.destination).join(folder)
+}
+
+fn source_name(source: &str) -> String {
+    Path::new(source).file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_else(|| "root".to_string())
+}
+
+fn sha256_file(path: &Path) -> Result<String, String> {
+    use sha2::{Digest, Sha256};
+
+    let mut file = File::open(path).map_err(|e| e.to_string())?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher).map_err(|e| e.to_string())?;
+    Ok(hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+fn modified_ms(metadata: &std::fs::Metadata) -> u64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Every file under the sources as (path on disk, manifest key).
+fn scan(sources: &[String]) -> Result<Vec<(PathBuf, String)>, String> {
+    let mut files = Vec::new();
+    for source in sources {
+        let root = PathBuf::from(source);
+        let prefix = source_name(source);
+        let mut stack = vec![root.clone()];
+
+        while let Some(current) = stack.pop() {
+            if current.is_dir() {
+                for child in std::fs::read_dir(&current).map_err(|e| e.to_string())? {
+                    stack.push(child.map_err(|e| e.to_string())?.path());
+                }
+            } else if current.is_file() {
+                let relative = current.strip_prefix(&root).unwrap_or(&current).to_string_lossy().replace('\\', "/");
+                files.push((current, format!("{}/{}", prefix, relative)));
+            }
+        }
+    }
+    files.sort_by(|a, b| a.1.cmp(&b.1));
+    Ok(files)
+}
+
+/// Moves the current copy of `key` into this run's version folder.
+fn keep_version(dir: &Path, version: &str, key: &str) -> Result<(), String> {
+    let target = dir.join("versions").join(version).join(key);
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::rename(dir.join("current").join(key), target).map_err(|e| e.to_string())
+}
+
+fn prune_versions(dir: &Path, retention: &BackupRetention) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir.join("versions")) else { return 0 };
+    let mut versions: Vec<String> = entries.flatten().map(|entry| entry.file_name().to_string_lossy().to_string()).collect();
+    versions.sort_by(|a, b| b.cmp(a));
+
+    let cutoff = retention.max_age_days.map(|days| chrono::Local::now().naive_local() - chrono::Duration::days(days as i64));
+    let mut pruned = 0;
+    for (index, version) in versions.iter().enumerate() {
+        let too_many = retention.keep_versions.map_or(false, |keep| index >= keep as usize);
+        let too_old = match (cutoff, chrono::NaiveDateTime::parse_from_str(version, VERSION_FORMAT)) {
+            (Some(cutoff), Ok(taken)) => taken < cutoff,
+            _ => false,
+        };
+        if (too_many || too_old) && std::fs::remove_dir_all(dir.join("versions").join(version)).is_ok() {
+            pruned += 1;
+        }
+    }
+    pruned
+}
+
+fn emit_progress(app: &AppHandle, job: &BackupJob, phase: &str, done: usize, total: usize, bytes: u64, current: &str) {
+    let _ = app.emit_all("backup://progress", serde_json::json!({
+        "jobId": job.id,
+        "name": job.name,
+        "phase": phase,
+        "done": done,
+        "total": total,
+        "bytesCopied": bytes,
+        "current": current,
+    }));
+}
+
+/// One incremental pass; `verify_all` also re-hashes files that weren't copied this time.
+fn run_job(app: &AppHandle, job: &BackupJob, verify_all: bool, cancel: &AtomicBool) -> Result<BackupRun, String> {
+    if !Path::new(&job.destination).is_dir() {
+        return Err(format!("❌ Backup destination not available: {}", job.destination));
+    }
+    if let Some(missing) = job.sources.iter().find(|source| !Path::new(source).is_dir()) {
+        return Err(format!("❌ Backup source not found: {}", missing));
+    }
+
+    let dir = job_dir(job);
+    std::fs::create_dir_all(dir.join("current")).map_err(|e| e.to_string())?;
+    let manifest_path = dir.join("manifest.json");
+    let mut manifest: BTreeMap<String, FileRecord> = std::fs::read_to_string(&manifest_path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+
+    let mut run = BackupRun { started_at: now_secs(), ..Default::default() };
+    let version = chrono::Local::now().format(VERSION_FORMAT).to_string();
+    emit_progress(app, job, "scanning", 0, 0, 0, "");
+    let files = scan(&job.sources)?;
+    let mut last_progress = Instant::now();
+
+    for (index, (source, key)) in files.iter().enumerate() {
+        while crate::scheduler::is_paused(app, TASK_NAME) && !cancel.load(Ordering::SeqCst) {
+            std::thread::sleep(Duration::from_secs(1));
+        }
+        if cancel.load(Ordering::SeqCst) {
+            run.status = "cancelled".to_string();
+            break;
+        }
+
+        let metadata = std::fs::metadata(source).map_err(|e| format!("{}: {}", source.display(), e))?;
+        let (size, modified) = (metadata.len(), modified_ms(&metadata));
+        let target = dir.join("current").join(key);
+        let previous = manifest.get(key).filter(|_| target.exists()).cloned();
+
+        // Unchanged size and time: trust the previous hash without reading the file
+        if previous.as_ref().map_or(false, |record| record.size == size && record.modified_ms == modified) {
+            run.files_unchanged += 1;
+            continue;
+        }
+        let hash = sha256_file(source)?;
+        if previous.as_ref().map_or(false, |record| record.sha256 == hash) {
+            manifest.insert(key.clone(), FileRecord { size, modified_ms: modified, sha256: hash });
+            run.files_unchanged += 1;
+            continue;
+        }
+
+        if previous.is_some() {
+            keep_version(&dir, &version, key)?;
+        }
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        // Copy beside the target and rename, so an interrupted copy never looks complete
+        let partial = target.with_file_name(format!("{}.jxpartial", target.file_name().unwrap_or_default().to_string_lossy()));
+        std::fs::copy(source, &partial).map_err(|e| format!("{}: {}", source.display(), e))?;
+        std::fs::rename(&partial, &target).map_err(|e| e.to_string())?;
+
+        if sha256_file(&target)? == hash {
+            manifest.insert(key.clone(), FileRecord { size, modified_ms: modified, sha256: hash });
+            run.files_copied += 1;
+            run.bytes_copied += size;
+        } else {
+            // Left out of the manifest so the next run copies it again
+            manifest.remove(key);
+            run.verify_failures.push(key.clone());
+        }
+
+        if last_progress.elapsed() >= PROGRESS_INTERVAL {
+            emit_progress(app, job, "copying", index + 1, files.len(), run.bytes_copied, key);
+            last_progress = Instant::now();
+        }
+    }
+
+    if run.status != "cancelled" {
+        let present: std::collections::BTreeSet<&String> = files.iter().map(|(_, key)| key).collect();
+        let deleted: Vec<String> = manifest.keys().filter(|key| !present.contains(key)).cloned().collect();
+        for key in deleted {
+            if dir.join("current").join(&key).exists() {
+                keep_version(&dir, &version, &key)?;
+            }
+            manifest.remove(&key);
+            run.files_removed += 1;
+        }
+
+        if verify_all {
+            let total = manifest.len();
+            for (index, (key, record)) in manifest.iter().enumerate() {
+                if sha256_file(&dir.join("current").join(key)).ok().as_deref() != Some(record.sha256.as_str()) {
+                    run.verify_failures.push(key.clone());
+                }
+                if last_progress.elapsed() >= PROGRESS_INTERVAL {
+                    emit_progress(app, job, "verifying", index + 1, total, run.bytes_copied, key);
+                    last_progress = Instant::now();
+                }
+            }
+            for key in &run.verify_failures {
+                manifest.remove(key);
+            }
+        }
+        run.versions_pruned = prune_versions(&dir, &job.retention);
+        run.status = if run.verify_failures.is_empty() { "completed" } else { "failed" }.to_string();
+        if !run.verify_failures.is_empty() {
+            run.error = Some(format!("{} file(s) failed verification and will be copied again", run.verify_failures.len()));
+        }
+    }
+
+    let json = serde_json::to_string_pretty(&manifest).map_err(|e| e.to_string())?;
+    std::fs::write(&manifest_path, json).map_err(|e| e.to_string())?;
+    emit_progress(app, job, "done", files.len(), files.len(), run.bytes_copied, "");
+    run.finished_at = now_secs();
+    Ok(run)
+}
+
+/// Runs a job in the background unless it is already running; the outcome is saved as its last run.
+pub fn start_job(app: &AppHandle, job: BackupJob, verify_all: bool) -> Result<(), String> {
+    let cancel = Arc::new(AtomicBool::new(false));
+    {
+        let mut running = RUNNING.lock().unwrap();
+        if running.contains_key(&job.id) {
+            return Err(format!("Backup {} is already running", job.name));
+        }
+        running.insert(job.id.clone(), cancel.clone());
+    }
+
+    let app = app.clone();
+    std::thread::spawn(move || {
+        println!("💼 Backup started: {}", job.name);
+        let keep_awake = crate::power::acquire(&format!("Backup {}", job.name), false, None).ok();
+        let run = run_job(&app, &job, verify_all, &cancel).unwrap_or_else(|e| BackupRun {
+            started_at: now_secs(),
+            finished_at: now_secs(),
+            status: "failed".to_string(),
+            error: Some(e),
+            ..Default::default()
+        });
+        if let Some(id) = keep_awake {
+            crate::power::release(id);
+        }
+        RUNNING.lock().unwrap().remove(&job.id);
+
+        let mut jobs = load_jobs(&app);
+        if let Some(saved) = jobs.iter_mut().find(|saved| saved.id == job.id) {
+            saved.last_run = Some(run.clone());
+            let _ = save_jobs(&app, &jobs);
+        }
+
+        let event = serde_json::json!({ "jobId": job.id, "name": job.name, "run": run });
+        if run.status == "failed" {
+            println!("⚠️  Backup {} failed: {}", job.name, run.error.clone().unwrap_or_default());
+            crate::system::show_notification(&app, "Backup failed", &format!("{}: {}", job.name, run.error.clone().unwrap_or_default()));
+            let _ = app.emit_all("backup://failed", event);
+        } else {
+            println!("💼 Backup {} {}: {} copied, {} unchanged, {} removed", job.name, run.status, run.files_copied, run.files_unchanged, run.files_removed);
+            let _ = app.emit_all("backup://completed", event);
+        }
+    });
+    Ok(())
+}
+
+fn is_due(job: &BackupJob, now: chrono::DateTime<chrono::Local>) -> bool {
+    let last = job.last_run.as_ref().map_or(0, |run| run.started_at) as i64;
+    match &job.schedule {
+        BackupSchedule::Interval { every } => crate::timers::parse_duration(every).map_or(false, |secs| now.timestamp() - last >= secs as i64),
+        BackupSchedule::Daily { at } => {
+            let Ok(time) = chrono::NaiveTime::parse_from_str(at, "%H:%M") else { return false };
+            let Some(slot) = now.date_naive().and_time(time).and_local_timezone(chrono::Local).earliest() else { return false };
+            now >= slot && last < slot.timestamp()
+        }
+        BackupSchedule::Manual | BackupSchedule::OnVolumeAttached { .. } => false,
+    }
+}
+
+/// Starts due interval and daily jobs once a minute.
+pub fn spawn_backup_scheduler(app: AppHandle) {
+    crate::scheduler::register_task(&app, TASK_NAME, crate::scheduler::TaskPriority::Low);
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(SCHEDULE_INTERVAL).await;
+            let now = chrono::Local::now();
+            for job in load_jobs(&app).into_iter().filter(|job| is_due(job, now)) {
+                if !RUNNING.lock().unwrap().contains_key(&job.id) {
+                    let _ = start_job(&app, job, false);
+                }
+            }
+        }
+    });
+}
+
+/// Starts jobs waiting for this volume; called by the removable-drive watcher.
+pub fn on_volume_attached(app: &AppHandle, volume: &crate::removable::RemovableVolume) {
+    let name = volume.name.to_lowercase();
+    for job in load_jobs(app) {
+        if let BackupSchedule::OnVolumeAttached { volume: wanted } = &job.schedule {
+            if name.contains(&wanted.to_lowercase()) {
+                let _ = start_job(app, job, false);
+            }
+        }
+    }
+}
+
+fn job_json(job: &BackupJob) -> serde_json::Value {
+    let mut value = serde_json::to_value(job).unwrap_or(serde_json::Value::Null);
+    value["running"] = serde_json::json!(RUNNING.lock().unwrap().contains_key(&job.id));
+    value
+}
+
+#[tauri::command]
+pub async fn create_backup_job(
+    app: AppHandle,
+    name: Option<String>,
+    sources: Vec<String>,
+    destination: String,
+    schedule: BackupSchedule,
+    retention: Option<BackupRetention>,
+) -> Result<BackupJob, String> {
+    if sources.is_empty() {
+        return Err("❌ A backup needs at least one source folder".to_string());
+    }
+    for source in &sources {
+        if !Path::new(source).is_dir() {
+            return Err(format!("❌ Backup source not found: {}", source));
+        }
+        if Path::new(&destination).starts_with(source) {
+            return Err(format!("❌ The destination can't be inside a source: {}", source));
+        }
+    }
+    let mut names: Vec<String> = sources.iter().map(|source| source_name(source)).collect();
+    names.sort();
+    if let Some(pair) = names.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(format!("❌ Two sources are both named {}", pair[0]));
+    }
+    match &schedule {
+        BackupSchedule::Interval { every } => {
+            crate::timers::parse_duration(every)?;
+        }
+        BackupSchedule::Daily { at } => {
+            chrono::NaiveTime::parse_from_str(at, "%H:%M").map_err(|_| format!("❌ Daily time must be HH:MM: {}", at))?;
+        }
+        BackupSchedule::Manual | BackupSchedule::OnVolumeAttached { .. } => {}
+    }
+
+    let mut jobs = load_jobs(&app);
+    let job = BackupJob {
+        id: format!("{:016x}", rand::random::<u64>()),
+        name: name.unwrap_or_else(|| format!("Backup of {}", names.join(", "))),
+        sources,
+        destination,
+        schedule,
+        retention: retention.unwrap_or_default(),
+        created_at: now_secs(),
+        last_run: None,
+    };
+    if jobs.iter().any(|existing| existing.name.eq_ignore_ascii_case(&job.name)) {
+        return Err(format!("❌ A backup job named {} already exists", job.name));
+    }
+    jobs.push(job.clone());
+    save_jobs(&app, &jobs)?;
+
+    println!("💼 Backup job created: {} → {}", job.name, job.destination);
+    Ok(job)
+}
+
+#[tauri::command]
+pub async fn list_backup_jobs(app: AppHandle) -> Result<Vec<serde_json::Value>, String> {
+    Ok(load_jobs(&app).iter().map(job_json).collect())
+}
+
+/// Starts a job now in the background; progress and the outcome arrive as events.
+#[tauri::command]
+pub async fn run_backup_job(app: AppHandle, id: String, verify: Option<bool>) -> Result<serde_json::Value, String> {
+    let job = find_job(&app, &id)?;
+    start_job(&app, job.clone(), verify.unwrap_or(false))?;
+    Ok(job_json(&job))
+}
+
+#[tauri::command]
+pub async fn cancel_backup_job(app: AppHandle, id: String) -> Result<(), String> {
+    let job = find_job(&app, &id)?;
+    match RUNNING.lock().unwrap().get(&job.id) {
+        Some(cancel) => {
+            cancel.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+        None => Err(format!("Backup {} is not running", job.name)),
+    }
+}
+
+/// Removes a job; the backed-up files are deleted too only with `delete_data`.
+#[tauri::command]
+pub async fn delete_backup_job(app: AppHandle, id: String, delete_data: Option<bool>) -> Result<(), String> {
+    let job = find_job(&app, &id)?;
+    if RUNNING.lock().unwrap().contains_key(&job.id) {
+        return Err(format!("❌ Backup {} is running; cancel it first", job.name));
+    }
+
+    let mut jobs = load_jobs(&app);
+    jobs.retain(|existing| existing.id != job.id);
+    save_jobs(&app, &jobs)?;
+
+    if delete_data.unwrap_or(false) {
+        let dir = job_dir(&job);
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir).map_err(|e| e.to_string())?;
+        }
+    }
+    println!("💼 Backup job deleted: {}", job.name);
+    Ok(())
+}
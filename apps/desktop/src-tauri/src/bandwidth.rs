@@ -0,0 +1,300 @@
+/**
+ * Bandwidth module - Bytes sent and received per feature, with daily caps that pause non-essential traffic on
+ * metered connections
+ *
+ * Outbound HTTP is counted by egress::send, the local HTTP server counts plugin and webhook traffic, and the screen
+ * stream counts the frames it produces. Metered status comes from the Windows connection cost, NetworkManager on
+ * Linux and the DHCP metered hint on macOS (a phone hotspot), unless the policy overrides it.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+/// How long a metered-status reading is trusted
+const METERED_REFRESH: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Feature {
+    ScreenStream,
+    SpeechToText,
+    TextToSpeech,
+    Translation,
+    Account,
+    MarketData,
+    /// Broker quotes and orders
+    Trading,
+    Feeds,
+    ModelDownloads,
+    Plugins,
+    Webhooks,
+    /// Screenshots uploaded for share links
+    Sharing,
+    /// Images sent to the OCR service
+    Ocr,
+}
+
+impl Feature {
+    /// Traffic that can wait for an unmetered connection without breaking a conversation or sign-in
+    pub fn is_essential(self) -> bool {
+        !matches!(self, Feature::ScreenStream | Feature::MarketData | Feature::Feeds | Feature::ModelDownloads)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BandwidthPolicy {
+    /// Daily byte caps (sent + received) for non-essential features, enforced only on metered connections
+    #[serde(default)]
+    pub metered_caps: BTreeMap<Feature, u64>,
+    /// Pause every non-essential feature without a cap while metered
+    #[serde(default)]
+    pub pause_non_essential_when_metered: bool,
+    /// Forces metered (true) or unmetered (false) instead of asking the OS
+    #[serde(default)]
+    pub metered_override: Option<bool>,
+}
+
+impl Default for BandwidthPolicy {
+    fn default() -> Self {
+        DEFAULT_POLICY
+    }
+}
+
+const DEFAULT_POLICY: BandwidthPolicy = BandwidthPolicy {
+    metered_caps: BTreeMap::new(),
+    pause_non_essential_when_metered: false,
+    metered_override: None,
+};
+
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Usage {
+    pub sent: u64,
+    pub received: u64,
+    pub requests: u64,
+}
+
+impl Usage {
+    fn total(&self) -> u64 {
+        self.sent + self.received
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureReport {
+    pub feature: Feature,
+    pub essential: bool,
+    pub session: Usage,
+    pub today: Usage,
+    pub metered_cap: Option<u64>,
+    /// Why the feature's transfers are currently held back
+    pub paused: Option<String>,
+}
+
+struct Counters {
+    session: BTreeMap<Feature, Usage>,
+    today: BTreeMap<Feature, Usage>,
+    /// Local date `today` counts for
+    day: String,
+}
+
+static POLICY: RwLock<BandwidthPolicy> = RwLock::new(DEFAULT_POLICY);
+static COUNTERS: Mutex<Counters> = Mutex::new(Counters { session: BTreeMap::new(), today: BTreeMap::new(), day: String::new() });
+static METERED: Mutex<Option<(bool, Instant)>> = Mutex::new(None);
+
+fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
+
+fn policy_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("bandwidth.json"))
+}
+
+/// Loads the active profile's policy; called at startup, on profile switches and when bandwidth.json changes.
+pub fn load_bandwidth_policy(app: &AppHandle) {
+    *POLICY.write().unwrap() = policy_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    *METERED.lock().unwrap() = None;
+}
+
+/// Adds one transfer to `feature`'s counters.
+pub fn record(feature: Feature, sent: u64, received: u64) {
+    let mut guard = COUNTERS.lock().unwrap();
+    let counters = &mut *guard;
+    let day = today();
+    if counters.day != day {
+        counters.today.clear();
+        counters.day = day;
+    }
+
+    for usage in [counters.session.entry(feature).or_default(), counters.today.entry(feature).or_default()] {
+        usage.sent += sent;
+        usage.received += received;
+        usage.requests += 1;
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn detect_metered() -> Option<bool> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let script = "$profile = [Windows.Networking.Connectivity.NetworkInformation,Windows.Networking.Connectivity,ContentType=WindowsRuntime]::GetInternetConnectionProfile(); \
+                  if ($profile) { $profile.GetConnectionCost().NetworkCostType }";
+    let output = Command::new("powershell").args(&["-NoProfile", "-Command", script]).output_with_timeout().ok()?;
+    // "Unrestricted", "Fixed", "Variable" or "Unknown"
+    match String::from_utf8_lossy(&output.stdout).trim() {
+        "Fixed" | "Variable" => Some(true),
+        "Unrestricted" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_metered() -> Option<bool> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    // "GENERAL.METERED:yes (guessed)" per device; "unknown" for devices without a connection
+    let output = Command::new("nmcli").args(&["-t", "-f", "GENERAL.METERED", "device", "show"]).output_with_timeout().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8_lossy(&output.stdout).to_string();
+    let values: Vec<&str> = text.lines().filter_map(|line| line.strip_prefix("GENERAL.METERED:")).collect();
+    if values.iter().any(|value| value.starts_with("yes")) {
+        Some(true)
+    } else if values.iter().any(|value| value.starts_with("no")) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Android hotspots (and iPhones sharing over USB) advertise themselves as metered in the DHCP vendor options.
+#[cfg(target_os = "macos")]
+fn detect_metered() -> Option<bool> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let route = Command::new("route").args(&["-n", "get", "default"]).output_with_timeout().ok()?;
+    let route = String::from_utf8_lossy(&route.stdout).to_string();
+    let interface = route.lines().find_map(|line| line.trim().strip_prefix("interface: "))?.trim().to_string();
+
+    let packet = Command::new("ipconfig").args(&["getpacket", &interface]).output_with_timeout().ok()?;
+    Some(String::from_utf8_lossy(&packet.stdout).contains("ANDROID_METERED"))
+}
+
+pub fn is_metered() -> bool {
+    if let Some(metered) = POLICY.read().unwrap().metered_override {
+        return metered;
+    }
+
+    let mut cached = METERED.lock().unwrap();
+    match *cached {
+        Some((metered, checked_at)) if checked_at.elapsed() < METERED_REFRESH => metered,
+        _ => {
+            let metered = detect_metered().unwrap_or(false);
+            *cached = Some((metered, Instant::now()));
+            metered
+        }
+    }
+}
+
+fn pause_reason(policy: &BandwidthPolicy, feature: Feature, today: Usage, metered: bool) -> Option<String> {
+    if feature.is_essential() || !metered {
+        return None;
+    }
+    match policy.metered_caps.get(&feature) {
+        Some(cap) if today.total() >= *cap => Some(format!("Daily cap of {} bytes reached on a metered connection", cap)),
+        Some(_) => None,
+        None if policy.pause_non_essential_when_metered => Some("Paused on a metered connection".to_string()),
+        None => None,
+    }
+}
+
+/// Err when `feature`'s transfers are paused; checked before every counted transfer.
+pub fn admit(feature: Feature) -> Result<(), String> {
+    let policy = POLICY.read().unwrap().clone();
+    if feature.is_essential() || (policy.metered_caps.is_empty() && !policy.pause_non_essential_when_metered) {
+        return Ok(());
+    }
+
+    let today = {
+        let counters = COUNTERS.lock().unwrap();
+        if counters.day == today() { counters.today.get(&feature).copied().unwrap_or_default() } else { Usage::default() }
+    };
+    match pause_reason(&policy, feature, today, is_metered()) {
+        Some(reason) => Err(format!("⏸️  {:?} transfer held back: {}", feature, reason)),
+        None => Ok(()),
+    }
+}
+
+/// Re-reads the metered status and emits `network://metered-changed` when it flips; polled by the connectivity watcher.
+pub fn refresh_metered(app: &AppHandle) {
+    let before = METERED.lock().unwrap().map(|(metered, _)| metered);
+    *METERED.lock().unwrap() = None;
+    let metered = is_metered();
+
+    if before.is_some() && before != Some(metered) {
+        println!("📶 Connection is now {}", if metered { "metered" } else { "unmetered" });
+        let _ = app.emit_all("network://metered-changed", serde_json::json!({ "metered": metered }));
+    }
+}
+
+#[tauri::command]
+pub async fn get_bandwidth_report() -> Result<serde_json::Value, String> {
+    let policy = POLICY.read().unwrap().clone();
+    let metered = tauri::async_runtime::spawn_blocking(is_metered).await.map_err(|e| e.to_string())?;
+    let counters = COUNTERS.lock().unwrap();
+    let current_day = counters.day == today();
+
+    let features: Vec<FeatureReport> = counters
+        .session
+        .iter()
+        .map(|(feature, session)| {
+            let today = if current_day { counters.today.get(feature).copied().unwrap_or_default() } else { Usage::default() };
+            FeatureReport {
+                feature: *feature,
+                essential: feature.is_essential(),
+                session: *session,
+                today,
+                metered_cap: policy.metered_caps.get(feature).copied(),
+                paused: pause_reason(&policy, *feature, today, metered),
+            }
+        })
+        .collect();
+
+    Ok(serde_json::json!({
+        "metered": metered,
+        "sentTotal": features.iter().map(|report| report.session.sent).sum::<u64>(),
+        "receivedTotal": features.iter().map(|report| report.session.received).sum::<u64>(),
+        "features": features,
+        "policy": policy,
+    }))
+}
+
+#[tauri::command]
+pub async fn set_bandwidth_policy(app: AppHandle, policy: BandwidthPolicy) -> Result<BandwidthPolicy, String> {
+    if let Some((feature, _)) = policy.metered_caps.iter().find(|(feature, _)| feature.is_essential()) {
+        return Err(format!("❌ {:?} is essential and can't be capped", feature));
+    }
+
+    let json = serde_json::to_string_pretty(&policy).map_err(|e| e.to_string())?;
+    std::fs::write(policy_path(&app)?, json).map_err(|e| e.to_string())?;
+    *POLICY.write().unwrap() = policy.clone();
+    Ok(policy)
+}
+
+#[tauri::command]
+pub async fn get_bandwidth_policy() -> Result<BandwidthPolicy, String> {
+    Ok(POLICY.read().unwrap().clone())
+}
@@ -0,0 +1,89 @@
+/**
+ * Batch module - Runs several native commands in a single IPC round trip
+ */
+
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+use tauri::AppHandle;
+
+const MAX_BATCH_SIZE: usize = 50;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchItem {
+    pub command: String,
+    #[serde(default)]
+    pub args: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchResult {
+    pub command: String,
+    pub ok: bool,
+    pub result: Option<serde_json::Value>,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+async fn run_item(app: &AppHandle, item: &BatchItem, capability_token: Option<&str>) -> BatchResult {
+    let started = Instant::now();
+
+    // The handler wrapper only saw "invoke_batch", so scope each item individually
+    let outcome = match crate::permissions::authorize_nested(app, capability_token, &item.command, &item.args) {
+        Ok(args) => crate::plans::dispatch_native(app, &item.command, &args).await,
+        Err(e) => Err(e),
+    };
+
+    BatchResult {
+        command: item.command.clone(),
+        ok: outcome.is_ok(),
+        result: outcome.as_ref().ok().cloned(),
+        error: outcome.err(),
+        duration_ms: started.elapsed().as_millis() as u64,
+    }
+}
+
+/// With `abort_on_error` items run in order and stop at the first failure;
+/// otherwise they run concurrently. Results always match the input order.
+#[tauri::command]
+pub async fn invoke_batch(
+    app: AppHandle,
+    commands: Vec<BatchItem>,
+    abort_on_error: Option<bool>,
+    capability_token: Option<String>,
+) -> Result<Vec<BatchResult>, String> {
+    if commands.len() > MAX_BATCH_SIZE {
+        return Err(format!("❌ Batch too large: {} commands (max {})", commands.len(), MAX_BATCH_SIZE));
+    }
+    if commands.iter().any(|item| item.command == "invoke_batch") {
+        return Err("❌ Batches cannot be nested".to_string());
+    }
+
+    if abort_on_error.unwrap_or(false) {
+        let mut results = Vec::with_capacity(commands.len());
+        for item in &commands {
+            let result = run_item(&app, item, capability_token.as_deref()).await;
+            let failed = !result.ok;
+            results.push(result);
+            if failed {
+                break;
+            }
+        }
+        return Ok(results);
+    }
+
+    let handles: Vec<_> = commands
+        .into_iter()
+        .map(|item| {
+            let (app, token) = (app.clone(), capability_token.clone());
+            tauri::async_runtime::spawn(async move { run_item(&app, &item, token.as_deref()).await })
+        })
+        .collect();
+
+    let mut results = Vec::with_capacity(handles.len());
+    for handle in handles {
+        results.push(handle.await.map_err(|e| e.to_string())?);
+    }
+    Ok(results)
+}
@@ -0,0 +1,149 @@
+/**
+ * Benchmarks module - Timing suite for native operations
+ */
+
+use std::time::{Duration, Instant};
+
+struct Timings {
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    fn new() -> Self {
+        Timings { samples: Vec::new() }
+    }
+
+    fn summary(&mut self) -> serde_json::Value {
+        if self.samples.is_empty() {
+            return serde_json::json!({ "available": false });
+        }
+
+        self.samples.sort();
+        let ms = |d: &Duration| d.as_secs_f64() * 1000.0;
+        let total: Duration = self.samples.iter().sum();
+        let p95_index = ((self.samples.len() as f64 * 0.95).ceil() as usize).saturating_sub(1);
+
+        serde_json::json!({
+            "available": true,
+            "iterations": self.samples.len(),
+            "meanMs": ms(&total) / self.samples.len() as f64,
+            "minMs": ms(&self.samples[0]),
+            "medianMs": ms(&self.samples[self.samples.len() / 2]),
+            "p95Ms": ms(&self.samples[p95_index]),
+            "maxMs": ms(&self.samples[self.samples.len() - 1]),
+        })
+    }
+}
+
+fn unavailable(reason: &str) -> serde_json::Value {
+    serde_json::json!({ "available": false, "reason": reason })
+}
+
+async fn bench_capture(iterations: usize) -> serde_json::Value {
+    let mut timings = Timings::new();
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        if let Err(e) = crate::commands::capture_screen_png().await {
+            return unavailable(&e);
+        }
+        timings.samples.push(start.elapsed());
+    }
+
+    timings.summary()
+}
+
+fn bench_input_round_trip(iterations: usize) -> serde_json::Value {
+    use enigo::{Coordinate, Enigo, Mouse, Settings};
+
+    let mut enigo = match Enigo::new(&Settings::default()) {
+        Ok(enigo) => enigo,
+        Err(e) => return unavailable(&e.to_string()),
+    };
+    let (origin_x, origin_y) = match enigo.location() {
+        Ok(position) => position,
+        Err(e) => return unavailable(&e.to_string()),
+    };
+
+    let mut timings = Timings::new();
+    let mut mismatches = 0;
+
+    // Nudge the cursor back and forth by one pixel and wait until the OS reports it
+    for i in 0..iterations {
+        let target_x = origin_x + if i % 2 == 0 { 1 } else { 0 };
+        let start = Instant::now();
+        if enigo.move_mouse(target_x, origin_y, Coordinate::Abs).is_err() {
+            break;
+        }
+        let observed = enigo.location().map(|(x, _)| x).unwrap_or(origin_x - 1);
+        timings.samples.push(start.elapsed());
+        if observed != target_x {
+            mismatches += 1;
+        }
+    }
+
+    let _ = enigo.move_mouse(origin_x, origin_y, Coordinate::Abs);
+
+    let mut summary = timings.summary();
+    summary["positionMismatches"] = serde_json::json!(mismatches);
+    summary
+}
+
+fn bench_ipc_payload(sizes_kb: &[usize]) -> serde_json::Value {
+    use base64::{Engine as _, engine::general_purpose};
+
+    // Tauri IPC is JSON, so encode + decode cost dominates for large payloads
+    let results: Vec<serde_json::Value> = sizes_kb
+        .iter()
+        .map(|size_kb| {
+            let payload = vec![0xA5u8; size_kb * 1024];
+            let start = Instant::now();
+            let encoded = serde_json::json!({ "data": general_purpose::STANDARD.encode(&payload) }).to_string();
+            let decoded: serde_json::Value = serde_json::from_str(&encoded).unwrap_or_default();
+            let elapsed = start.elapsed();
+
+            serde_json::json!({
+                "payloadKb": size_kb,
+                "wireKb": encoded.len() / 1024,
+                "overheadRatio": encoded.len() as f64 / payload.len() as f64,
+                "roundTripMs": elapsed.as_secs_f64() * 1000.0,
+                "ok": decoded.get("data").is_some(),
+            })
+        })
+        .collect();
+
+    serde_json::json!({ "available": true, "results": results })
+}
+
+#[tauri::command]
+pub async fn run_benchmarks(iterations: Option<usize>) -> Result<serde_json::Value, String> {
+    use sysinfo::{System, SystemExt};
+
+    let iterations = iterations.unwrap_or(10).clamp(1, 200);
+    println!("⏱️  Running benchmarks ({} iterations)", iterations);
+
+    let started = Instant::now();
+    let capture = bench_capture(iterations).await;
+    let input = tauri::async_runtime::spawn_blocking(move || bench_input_round_trip(iterations))
+        .await
+        .map_err(|e| e.to_string())?;
+    let ipc = bench_ipc_payload(&[1, 64, 512, 4096]);
+
+    let mut sys = System::new_all();
+    sys.refresh_all();
+
+    Ok(serde_json::json!({
+        "machine": {
+            "os": std::env::consts::OS,
+            "arch": std::env::consts::ARCH,
+            "cpuCount": sys.cpus().len(),
+            "totalMemory": sys.total_memory(),
+        },
+        "captureLatency": capture,
+        "inputRoundTrip": input,
+        "ipcPayload": ipc,
+        "ocrThroughput": unavailable("No native OCR engine in this build"),
+        "sttRealTimeFactor": unavailable("No local speech-to-text engine in this build"),
+        "totalMs": started.elapsed().as_secs_f64() * 1000.0,
+    }))
+}
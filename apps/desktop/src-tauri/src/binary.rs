@@ -0,0 +1,145 @@
+/**
+ * Binary module - `jarvisx://` protocol for moving frames, audio and files without base64/JSON overhead
+ *
+ * Producers park bytes with `store_blob` and hand the UI a `BinaryRef`; the webview fetches the URL
+ * and gets the raw bytes with a proper Content-Type. Only the app's own pages get CORS access to the scheme.
+ */
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::http::{Request, Response, ResponseBuilder};
+use tauri::AppHandle;
+
+pub const PROTOCOL: &str = "jarvisx";
+/// Unfetched blobs are dropped after this long, or once the store is full.
+const BLOB_TTL: Duration = Duration::from_secs(60);
+const MAX_BLOBS: usize = 64;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinaryRef {
+    pub id: String,
+    pub url: String,
+    pub content_type: String,
+    pub size: usize,
+}
+
+struct Blob {
+    id: String,
+    content_type: String,
+    bytes: Vec<u8>,
+    stored_at: Instant,
+}
+
+static BLOBS: Mutex<VecDeque<Blob>> = Mutex::new(VecDeque::new());
+
+fn blob_url(id: &str) -> String {
+    // Windows webviews only load custom schemes through the https://<scheme>.localhost form
+    if cfg!(windows) {
+        format!("https://{}.localhost/blob/{}", PROTOCOL, id)
+    } else {
+        format!("{}://localhost/blob/{}", PROTOCOL, id)
+    }
+}
+
+/// Parks bytes for one fetch by the webview.
+pub fn store_blob(bytes: Vec<u8>, content_type: &str) -> BinaryRef {
+    let id = format!("{:032x}", rand::random::<u128>());
+    let reference = BinaryRef {
+        url: blob_url(&id),
+        id: id.clone(),
+        content_type: content_type.to_string(),
+        size: bytes.len(),
+    };
+
+    let mut blobs = BLOBS.lock().unwrap();
+    blobs.retain(|blob| blob.stored_at.elapsed() < BLOB_TTL);
+    while blobs.len() >= MAX_BLOBS {
+        blobs.pop_front();
+    }
+    blobs.push_back(Blob {
+        id,
+        content_type: content_type.to_string(),
+        bytes,
+        stored_at: Instant::now(),
+    });
+
+    reference
+}
+
+fn take_blob(id: &str) -> Option<Blob> {
+    let mut blobs = BLOBS.lock().unwrap();
+    let index = blobs.iter().position(|blob| blob.id == id)?;
+    blobs.remove(index).filter(|blob| blob.stored_at.elapsed() < BLOB_TTL)
+}
+
+/// The request's Origin when it is one of the app's own pages; other origins get no CORS headers.
+fn allowed_origin(request: &Request) -> Option<String> {
+    let origin = request.headers().get("Origin")?.to_str().ok()?;
+    let url = tauri::Url::parse(origin).ok()?;
+    crate::permissions::is_local_origin(&url).then(|| origin.to_string())
+}
+
+fn respond(
+    request: &Request,
+    status: u16,
+    content_type: &str,
+    body: Vec<u8>,
+) -> Result<Response, Box<dyn std::error::Error>> {
+    let mut response = ResponseBuilder::new().status(status).mimetype(content_type).header("Cache-Control", "no-store");
+    if let Some(origin) = allowed_origin(request) {
+        response = response.header("Access-Control-Allow-Origin", origin).header("Vary", "Origin");
+    }
+    response.body(body)
+}
+
+fn respond_error(request: &Request, status: u16, message: &str) -> Result<Response, Box<dyn std::error::Error>> {
+    respond(request, status, "application/json", serde_json::json!({ "error": message }).to_string().into_bytes())
+}
+
+/// Handler for the `jarvisx` URI scheme.
+pub fn handle_protocol(_app: &AppHandle, request: &Request) -> Result<Response, Box<dyn std::error::Error>> {
+    let uri = request.uri();
+    let path = uri
+        .split_once("localhost")
+        .map(|(_, rest)| rest)
+        .unwrap_or(uri)
+        .split('?')
+        .next()
+        .unwrap_or("");
+
+    if let Some(id) = path.strip_prefix("/blob/") {
+        return match take_blob(id) {
+            Some(blob) => respond(request, 200, &blob.content_type, blob.bytes),
+            None => respond_error(request, 404, "Blob not found or expired"),
+        };
+    }
+
+    respond_error(request, 404, "Unknown jarvisx:// route")
+}
+
+fn content_type_for(path: &std::path::Path) -> &'static str {
+    match path.extension().and_then(|ext| ext.to_str()).map(|ext| ext.to_lowercase()).as_deref() {
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("wav") => "audio/wav",
+        Some("mp3") => "audio/mpeg",
+        Some("mp4") => "video/mp4",
+        Some("pdf") => "application/pdf",
+        Some("json") => "application/json",
+        Some("txt") | Some("md") | Some("log") => "text/plain; charset=utf-8",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Parks a file for the webview; the path is checked like any other path argument (see `permissions`).
+#[tauri::command]
+pub async fn read_file_binary(app: AppHandle, path: String, capability_token: Option<String>) -> Result<BinaryRef, String> {
+    let path = crate::permissions::authorize_path(&app, capability_token.as_deref(), &path)?;
+    let bytes = tokio::fs::read(&path).await.map_err(|e| e.to_string())?;
+    Ok(store_blob(bytes, content_type_for(&path)))
+}
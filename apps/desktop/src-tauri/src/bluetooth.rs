@@ -0,0 +1,238 @@
+/**
+ * Bluetooth module - Device listing and connection control (BLE via btleplug, classic via platform tools)
+ */
+
+use btleplug::api::{Central, Manager as _, Peripheral as _, ScanFilter};
+use btleplug::platform::{Manager, Peripheral};
+use serde::Serialize;
+use std::time::Duration;
+
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+use crate::watchdog::WatchdogCommand;
+
+const SCAN_DURATION: Duration = Duration::from_secs(4);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BluetoothDevice {
+    pub address: String,
+    pub name: Option<String>,
+    pub connected: bool,
+    pub paired: bool,
+    pub rssi: Option<i16>,
+    /// "classic" for paired audio/HID devices, "le" for Bluetooth Low Energy peripherals
+    pub transport: String,
+}
+
+fn normalize_address(address: &str) -> String {
+    address.replace('-', ":").to_uppercase()
+}
+
+/// Paired classic devices (earbuds, headsets, keyboards) from the platform Bluetooth stack.
+#[cfg(target_os = "macos")]
+fn classic_devices() -> Vec<BluetoothDevice> {
+    use std::process::Command;
+
+    // blueutil is the de-facto CLI for IOBluetooth
+    let output = match Command::new("blueutil").args(&["--paired", "--format", "json"]).output_with_timeout() {
+        Ok(output) if output.status.success() => output,
+        _ => return Vec::new(),
+    };
+
+    serde_json::from_slice::<Vec<serde_json::Value>>(&output.stdout)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|device| {
+            Some(BluetoothDevice {
+                address: normalize_address(device["address"].as_str()?),
+                name: device["name"].as_str().map(|name| name.to_string()),
+                connected: device["connected"].as_bool().unwrap_or(false),
+                paired: true,
+                rssi: None,
+                transport: "classic".to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn classic_devices() -> Vec<BluetoothDevice> {
+    use std::process::Command;
+
+    let list = |filter: &str| -> Vec<(String, String)> {
+        Command::new("bluetoothctl")
+            .args(&["devices", filter])
+            .output_with_timeout()
+            .map(|output| {
+                String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .filter_map(|line| {
+                        // "Device AA:BB:CC:DD:EE:FF My Earbuds"
+                        let mut parts = line.splitn(3, ' ');
+                        match (parts.next(), parts.next(), parts.next()) {
+                            (Some("Device"), Some(address), name) => {
+                                Some((normalize_address(address), name.unwrap_or("").to_string()))
+                            }
+                            _ => None,
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let connected: Vec<String> = list("Connected").into_iter().map(|(address, _)| address).collect();
+
+    list("Paired")
+        .into_iter()
+        .map(|(address, name)| BluetoothDevice {
+            connected: connected.contains(&address),
+            address,
+            name: if name.is_empty() { None } else { Some(name) },
+            paired: true,
+            rssi: None,
+            transport: "classic".to_string(),
+        })
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn classic_devices() -> Vec<BluetoothDevice> {
+    // Windows has no scriptable classic-BT connect; paired devices still show up via BLE where supported
+    Vec::new()
+}
+
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+fn set_classic_connection(address: &str, connect: bool) -> Result<(), String> {
+    use std::process::Command;
+
+    #[cfg(target_os = "macos")]
+    let output = Command::new("blueutil")
+        .args(&[if connect { "--connect" } else { "--disconnect" }, &address.replace(':', "-")])
+        .output_with_timeout()?;
+
+    #[cfg(target_os = "linux")]
+    let output = Command::new("bluetoothctl")
+        .args(&[if connect { "connect" } else { "disconnect" }, address])
+        .output_with_timeout()?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn set_classic_connection(_address: &str, _connect: bool) -> Result<(), String> {
+    Err("Classic Bluetooth connection control is not supported on Windows".to_string())
+}
+
+/// Scans for BLE peripherals on the first adapter.
+async fn scan_le_peripherals() -> Result<Vec<Peripheral>, String> {
+    let manager = Manager::new().await.map_err(|e| e.to_string())?;
+    let adapter = manager
+        .adapters()
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .next()
+        .ok_or("No Bluetooth adapter found")?;
+
+    adapter.start_scan(ScanFilter::default()).await.map_err(|e| e.to_string())?;
+    tokio::time::sleep(SCAN_DURATION).await;
+    let peripherals = adapter.peripherals().await.map_err(|e| e.to_string())?;
+    let _ = adapter.stop_scan().await;
+
+    Ok(peripherals)
+}
+
+async fn le_device_info(peripheral: &Peripheral) -> BluetoothDevice {
+    let properties = peripheral.properties().await.ok().flatten();
+
+    BluetoothDevice {
+        address: normalize_address(&peripheral.address().to_string()),
+        name: properties.as_ref().and_then(|props| props.local_name.clone()),
+        connected: peripheral.is_connected().await.unwrap_or(false),
+        paired: false,
+        rssi: properties.and_then(|props| props.rssi),
+        transport: "le".to_string(),
+    }
+}
+
+/// Matches either an exact address or a case-insensitive name fragment ("earbuds").
+fn matches_device(device: &BluetoothDevice, query: &str) -> bool {
+    device.address == normalize_address(query)
+        || device
+            .name
+            .as_ref()
+            .map_or(false, |name| name.to_lowercase().contains(&query.to_lowercase()))
+}
+
+#[tauri::command]
+pub async fn list_bluetooth_devices() -> Result<Vec<BluetoothDevice>, String> {
+    println!("📶 Scanning for Bluetooth devices");
+
+    let mut devices = tauri::async_runtime::spawn_blocking(classic_devices)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    match scan_le_peripherals().await {
+        Ok(peripherals) => {
+            for peripheral in &peripherals {
+                let info = le_device_info(peripheral).await;
+                if !devices.iter().any(|device| device.address == info.address) {
+                    devices.push(info);
+                }
+            }
+        }
+        // Paired classic devices are still useful without a BLE scan
+        Err(e) if !devices.is_empty() => println!("⚠️  BLE scan failed: {}", e),
+        Err(e) => return Err(e),
+    }
+
+    Ok(devices)
+}
+
+async fn set_connection(device: String, connect: bool) -> Result<BluetoothDevice, String> {
+    let classic = tauri::async_runtime::spawn_blocking(classic_devices)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if let Some(mut target) = classic.into_iter().find(|candidate| matches_device(candidate, &device)) {
+        let address = target.address.clone();
+        tauri::async_runtime::spawn_blocking(move || set_classic_connection(&address, connect))
+            .await
+            .map_err(|e| e.to_string())??;
+        target.connected = connect;
+        return Ok(target);
+    }
+
+    for peripheral in scan_le_peripherals().await? {
+        let info = le_device_info(&peripheral).await;
+        if !matches_device(&info, &device) {
+            continue;
+        }
+
+        if connect {
+            peripheral.connect().await.map_err(|e| e.to_string())?;
+        } else {
+            peripheral.disconnect().await.map_err(|e| e.to_string())?;
+        }
+        return Ok(le_device_info(&peripheral).await);
+    }
+
+    Err(format!("❌ Bluetooth device not found: {}", device))
+}
+
+#[tauri::command]
+pub async fn connect_bluetooth_device(device: String) -> Result<BluetoothDevice, String> {
+    println!("📶 Connecting Bluetooth device: {}", device);
+    set_connection(device, true).await
+}
+
+#[tauri::command]
+pub async fn disconnect_bluetooth_device(device: String) -> Result<BluetoothDevice, String> {
+    println!("📶 Disconnecting Bluetooth device: {}", device);
+    set_connection(device, false).await
+}
@@ -0,0 +1,44 @@
+/**
+ * Cache module - TTL cache for expensive info commands polled by the UI
+ */
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+static ENTRIES: Mutex<BTreeMap<String, (Instant, serde_json::Value)>> = Mutex::new(BTreeMap::new());
+
+/// Returns the cached value for `key` while younger than `ttl`, otherwise recomputes it.
+/// `refresh` forces a recompute regardless of age.
+pub async fn cached<F, Fut>(key: &str, ttl: Duration, refresh: bool, compute: F) -> Result<serde_json::Value, String>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<serde_json::Value, String>>,
+{
+    if !refresh {
+        if let Some((stored_at, value)) = ENTRIES.lock().unwrap().get(key) {
+            if stored_at.elapsed() < ttl {
+                return Ok(value.clone());
+            }
+        }
+    }
+
+    // Errors are not cached so the next poll tries again
+    let value = compute().await?;
+    ENTRIES.lock().unwrap().insert(key.to_string(), (Instant::now(), value.clone()));
+    Ok(value)
+}
+
+pub fn invalidate(key: &str) {
+    ENTRIES.lock().unwrap().remove(key);
+}
+
+#[tauri::command]
+pub async fn clear_response_cache(key: Option<String>) -> Result<(), String> {
+    match key {
+        Some(key) => invalidate(&key),
+        None => ENTRIES.lock().unwrap().clear(),
+    }
+    Ok(())
+}
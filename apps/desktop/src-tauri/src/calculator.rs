@@ -0,0 +1,535 @@
+/**
+ * Calculator module - Arithmetic, unit conversion and currency conversion answered locally
+ */
+
+use serde::Serialize;
+use std::time::Duration;
+use tauri::AppHandle;
+
+const RATES_TTL: Duration = Duration::from_secs(6 * 60 * 60);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Evaluation {
+    /// "math", "unit" or "currency"
+    pub kind: String,
+    pub value: f64,
+    pub unit: Option<String>,
+    pub formatted: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Word(String),
+    Op(char),
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() || c == ',' {
+            i += 1;
+        } else if c.is_ascii_digit() || c == '.' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.' || chars[i] == ',') {
+                i += 1;
+            }
+            let number: String = chars[start..i].iter().filter(|c| **c != ',').collect();
+            tokens.push(Token::Number(number.parse().map_err(|_| format!("Bad number: {}", number))?));
+        } else if c.is_alphabetic() {
+            let start = i;
+            while i < chars.len() && chars[i].is_alphabetic() {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            match word.as_str() {
+                "plus" => tokens.push(Token::Op('+')),
+                "minus" => tokens.push(Token::Op('-')),
+                "times" | "x" | "of" | "multiplied" => tokens.push(Token::Op('*')),
+                "divided" | "over" => tokens.push(Token::Op('/')),
+                "by" => {}
+                _ => tokens.push(Token::Word(word)),
+            }
+        } else {
+            let op = match c {
+                '×' => '*',
+                '÷' => '/',
+                '+' | '-' | '*' | '/' | '^' | '(' | ')' | '%' => c,
+                _ => return Err(format!("Unexpected character: {}", c)),
+            };
+            tokens.push(Token::Op(op));
+            i += 1;
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn eat(&mut self, op: char) -> bool {
+        if self.peek() == Some(&Token::Op(op)) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expression(&mut self) -> Result<f64, String> {
+        let mut value = self.term()?;
+        loop {
+            if self.eat('+') {
+                value += self.term()?;
+            } else if self.eat('-') {
+                value -= self.term()?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<f64, String> {
+        let mut value = self.power()?;
+        loop {
+            if self.eat('*') {
+                value *= self.power()?;
+            } else if self.eat('/') {
+                let divisor = self.power()?;
+                if divisor == 0.0 {
+                    return Err("Division by zero".to_string());
+                }
+                value /= divisor;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn power(&mut self) -> Result<f64, String> {
+        let base = self.unary()?;
+        if self.eat('^') {
+            // Right-associative: 2^3^2 = 2^9
+            Ok(base.powf(self.power()?))
+        } else {
+            Ok(base)
+        }
+    }
+
+    fn unary(&mut self) -> Result<f64, String> {
+        if self.eat('-') {
+            Ok(-self.unary()?)
+        } else if self.eat('+') {
+            self.unary()
+        } else {
+            self.postfix()
+        }
+    }
+
+    fn postfix(&mut self) -> Result<f64, String> {
+        let mut value = self.primary()?;
+        while self.eat('%') {
+            value /= 100.0;
+        }
+        Ok(value)
+    }
+
+    fn primary(&mut self) -> Result<f64, String> {
+        match self.next() {
+            Some(Token::Number(value)) => Ok(value),
+            Some(Token::Op('(')) => {
+                let value = self.expression()?;
+                if !self.eat(')') {
+                    return Err("Missing closing parenthesis".to_string());
+                }
+                Ok(value)
+            }
+            Some(Token::Word(word)) => match word.as_str() {
+                "pi" => Ok(std::f64::consts::PI),
+                "e" => Ok(std::f64::consts::E),
+                function => {
+                    let argument = self.primary()?;
+                    match function {
+                        "sqrt" => Ok(argument.sqrt()),
+                        "abs" => Ok(argument.abs()),
+                        "ln" => Ok(argument.ln()),
+                        "log" => Ok(argument.log10()),
+                        "sin" => Ok(argument.to_radians().sin()),
+                        "cos" => Ok(argument.to_radians().cos()),
+                        "tan" => Ok(argument.to_radians().tan()),
+                        "round" => Ok(argument.round()),
+                        _ => Err(format!("Unknown function: {}", function)),
+                    }
+                }
+            },
+            Some(token) => Err(format!("Unexpected token: {:?}", token)),
+            None => Err("Incomplete expression".to_string()),
+        }
+    }
+}
+
+fn evaluate_math(text: &str) -> Result<f64, String> {
+    let mut parser = Parser {
+        tokens: tokenize(text)?,
+        position: 0,
+    };
+
+    if parser.tokens.is_empty() {
+        return Err("Empty expression".to_string());
+    }
+
+    let value = parser.expression()?;
+    if parser.position < parser.tokens.len() {
+        return Err("Trailing input after expression".to_string());
+    }
+    if !value.is_finite() {
+        return Err("Result is not a finite number".to_string());
+    }
+
+    Ok(value)
+}
+
+/// (aliases, dimension, factor to the dimension's base unit)
+const UNITS: &[(&[&str], &str, f64)] = &[
+    (&["mm", "millimeter", "millimetre"], "length", 0.001),
+    (&["cm", "centimeter", "centimetre"], "length", 0.01),
+    (&["m", "meter", "metre"], "length", 1.0),
+    (&["km", "kilometer", "kilometre"], "length", 1000.0),
+    (&["in", "inch", "inches"], "length", 0.0254),
+    (&["ft", "foot", "feet"], "length", 0.3048),
+    (&["yd", "yard"], "length", 0.9144),
+    (&["mi", "mile"], "length", 1609.344),
+    (&["mg", "milligram"], "mass", 0.000001),
+    (&["g", "gram"], "mass", 0.001),
+    (&["kg", "kilo", "kilogram"], "mass", 1.0),
+    (&["t", "tonne", "ton"], "mass", 1000.0),
+    (&["lb", "lbs", "pound"], "mass", 0.45359237),
+    (&["oz", "ounce"], "mass", 0.028349523125),
+    (&["ml", "milliliter", "millilitre"], "volume", 0.001),
+    (&["l", "liter", "litre"], "volume", 1.0),
+    (&["cup"], "volume", 0.2365882365),
+    (&["gal", "gallon"], "volume", 3.785411784),
+    (&["ms", "millisecond"], "time", 0.001),
+    (&["s", "sec", "second"], "time", 1.0),
+    (&["min", "minute"], "time", 60.0),
+    (&["h", "hr", "hour"], "time", 3600.0),
+    (&["day"], "time", 86400.0),
+    (&["week"], "time", 604800.0),
+    (&["b", "byte"], "data", 1.0),
+    (&["kb", "kilobyte"], "data", 1e3),
+    (&["mb", "megabyte"], "data", 1e6),
+    (&["gb", "gigabyte"], "data", 1e9),
+    (&["tb", "terabyte"], "data", 1e12),
+    (&["kib"], "data", 1024.0),
+    (&["mib"], "data", 1048576.0),
+    (&["gib"], "data", 1073741824.0),
+    (&["mps"], "speed", 1.0),
+    (&["kph", "kmh"], "speed", 1.0 / 3.6),
+    (&["mph"], "speed", 0.44704),
+    (&["knot"], "speed", 0.514444),
+];
+
+fn find_unit(name: &str) -> Option<(&'static str, &'static str, f64)> {
+    let lookup = |name: &str| {
+        UNITS
+            .iter()
+            .find(|(aliases, _, _)| aliases.contains(&name))
+            .map(|(aliases, dimension, factor)| (aliases[0], *dimension, *factor))
+    };
+
+    lookup(name).or_else(|| name.strip_suffix('s').and_then(lookup))
+}
+
+fn temperature_unit(name: &str) -> Option<char> {
+    match name {
+        "c" | "celsius" | "°c" => Some('c'),
+        "f" | "fahrenheit" | "°f" => Some('f'),
+        "k" | "kelvin" => Some('k'),
+        _ => None,
+    }
+}
+
+fn convert_temperature(value: f64, from: char, to: char) -> f64 {
+    let celsius = match from {
+        'f' => (value - 32.0) * 5.0 / 9.0,
+        'k' => value - 273.15,
+        _ => value,
+    };
+
+    match to {
+        'f' => celsius * 9.0 / 5.0 + 32.0,
+        'k' => celsius + 273.15,
+        _ => celsius,
+    }
+}
+
+fn currency_code(name: &str) -> Option<String> {
+    let code = match name {
+        "$" | "dollar" | "dollars" => "usd",
+        "€" | "euro" | "euros" => "eur",
+        "£" => "gbp",
+        "¥" | "yen" => "jpy",
+        "₹" | "rupee" | "rupees" => "inr",
+        "rs" | "lkr" => "lkr",
+        other if other.len() == 3 && other.chars().all(|c| c.is_ascii_alphabetic()) => other,
+        _ => return None,
+    };
+    Some(code.to_uppercase())
+}
+
+fn rates_url() -> String {
+    std::env::var("JARVISX_RATES_URL").unwrap_or_else(|_| "https://open.er-api.com/v6/latest/USD".to_string())
+}
+
+fn rates_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("currency_rates.json"))
+}
+
+/// USD-based rates, refreshed every few hours and persisted so conversions keep working offline.
+async fn currency_rates(app: &AppHandle) -> Result<serde_json::Value, String> {
+    crate::cache::cached("currency_rates", RATES_TTL, false, || async {
+        let fetched = async {
+            let request = crate::proxy::client().get(rates_url()).timeout(Duration::from_secs(10));
+            let body: serde_json::Value = crate::egress::send(crate::bandwidth::Feature::MarketData, request)
+                .await?
+                .json()
+                .await
+                .map_err(|e| e.to_string())?;
+            body.get("rates").cloned().ok_or_else(|| "Rates response has no rates".to_string())
+        }
+        .await;
+
+        match fetched {
+            Ok(rates) => {
+                if let Ok(path) = rates_path(app) {
+                    let _ = std::fs::write(path, rates.to_string());
+                }
+                Ok(rates)
+            }
+            Err(e) => {
+                println!("⚠️  Currency rates unavailable ({}), using saved rates", e);
+                rates_path(app)
+                    .ok()
+                    .and_then(|path| std::fs::read_to_string(path).ok())
+                    .and_then(|json| serde_json::from_str(&json).ok())
+                    .ok_or(e)
+            }
+        }
+    })
+    .await
+}
+
+fn format_value(value: f64, decimals: usize) -> String {
+    let formatted = crate::locale::format_number(value, decimals);
+    if formatted.contains('.') {
+        formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        formatted
+    }
+}
+
+/// Splits "150 usd to lkr" into ("150", "usd", "lkr").
+fn split_conversion(text: &str) -> Option<(String, String, String)> {
+    let (left, target) = [" to ", " in ", " into "]
+        .iter()
+        .filter_map(|separator| text.rfind(separator).map(|at| (at, separator.len())))
+        .max_by_key(|(at, _)| *at)
+        .map(|(at, len)| (text[..at].trim(), text[at + len..].trim()))?;
+
+    // Leading currency symbol: "$150"
+    for symbol in ["$", "€", "£", "¥", "₹"] {
+        if let Some(amount) = left.strip_prefix(symbol) {
+            return Some((amount.trim().to_string(), symbol.to_string(), target.to_string()));
+        }
+    }
+
+    // Trailing unit: "5.5 km", "100f", "2 (3 + 4) kg"
+    let unit_start = left
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphabetic() || *c == '°' || *c == ' ')
+        .last()
+        .map(|(at, _)| at)?;
+    let amount = left[..unit_start].trim();
+    let unit = left[unit_start..].trim();
+
+    Some((
+        if amount.is_empty() { "1".to_string() } else { amount.to_string() },
+        unit.to_string(),
+        target.to_string(),
+    ))
+}
+
+pub async fn evaluate(app: &AppHandle, text: &str) -> Result<Evaluation, String> {
+    let text = text
+        .trim()
+        .trim_end_matches('?')
+        .to_lowercase()
+        .replace("what is", "")
+        .replace("what's", "")
+        .replace("convert", "")
+        .replace("calculate", "");
+    let text = text.trim();
+
+    if let Some((amount, from, to)) = split_conversion(text) {
+        let amount = evaluate_math(&amount)?;
+
+        if let (Some(from), Some(to)) = (temperature_unit(&from), temperature_unit(&to)) {
+            let value = convert_temperature(amount, from, to);
+            return Ok(Evaluation {
+                kind: "unit".to_string(),
+                value,
+                unit: Some(to.to_uppercase().to_string()),
+                formatted: format!("{}°{}", format_value(value, 2), to.to_uppercase()),
+            });
+        }
+
+        if let (Some(from), Some(to)) = (find_unit(&from), find_unit(&to)) {
+            if from.1 != to.1 {
+                return Err(format!("Cannot convert {} to {}", from.1, to.1));
+            }
+            let value = amount * from.2 / to.2;
+            return Ok(Evaluation {
+                kind: "unit".to_string(),
+                value,
+                unit: Some(to.0.to_string()),
+                formatted: format!("{} {}", format_value(value, 4), to.0),
+            });
+        }
+
+        if let (Some(from), Some(to)) = (currency_code(&from), currency_code(&to)) {
+            let rates = currency_rates(app).await?;
+            let rate = |code: &str| {
+                rates
+                    .get(code)
+                    .and_then(|rate| rate.as_f64())
+                    .ok_or_else(|| format!("Unknown currency: {}", code))
+            };
+            let value = amount / rate(&from)? * rate(&to)?;
+            return Ok(Evaluation {
+                kind: "currency".to_string(),
+                value,
+                unit: Some(to.clone()),
+                formatted: format!("{} {}", format_value(value, 2), to),
+            });
+        }
+    }
+
+    let value = evaluate_math(text)?;
+    Ok(Evaluation {
+        kind: "math".to_string(),
+        value,
+        unit: None,
+        formatted: format_value(value, 6),
+    })
+}
+
+#[tauri::command]
+pub async fn evaluate_expression(app: AppHandle, text: String) -> Result<Evaluation, String> {
+    evaluate(&app, &text).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn follows_operator_precedence() {
+        assert_eq!(evaluate_math("2 + 3 * 4"), Ok(14.0));
+        assert_eq!(evaluate_math("(2 + 3) * 4"), Ok(20.0));
+        assert_eq!(evaluate_math("10 - 4 - 3"), Ok(3.0));
+        assert_eq!(evaluate_math("2 ^ 3 ^ 2"), Ok(512.0));
+        assert_eq!(evaluate_math("1,250 / 5"), Ok(250.0));
+    }
+
+    #[test]
+    fn reads_spoken_operators_and_percentages() {
+        assert_eq!(evaluate_math("6 times 7"), Ok(42.0));
+        assert_eq!(evaluate_math("12 divided by 4 plus 1"), Ok(4.0));
+        assert_eq!(evaluate_math("10 minus 15"), Ok(-5.0));
+        assert_eq!(evaluate_math("15% of 200"), Ok(30.0));
+        assert_eq!(evaluate_math("3 × 4 ÷ 2"), Ok(6.0));
+    }
+
+    #[test]
+    fn evaluates_functions_and_constants() {
+        assert_eq!(evaluate_math("sqrt(16)"), Ok(4.0));
+        assert_eq!(evaluate_math("sqrt 81 + 1"), Ok(10.0));
+        assert_eq!(evaluate_math("abs(-3)"), Ok(3.0));
+        assert_eq!(evaluate_math("log 1000"), Ok(3.0));
+        assert!(close(evaluate_math("sin 90").unwrap(), 1.0));
+        assert!(close(evaluate_math("2 * pi").unwrap(), std::f64::consts::TAU));
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        assert_eq!(evaluate_math(""), Err("Empty expression".to_string()));
+        assert_eq!(evaluate_math("1 / 0"), Err("Division by zero".to_string()));
+        assert_eq!(evaluate_math("(1 + 2"), Err("Missing closing parenthesis".to_string()));
+        assert_eq!(evaluate_math("1 +"), Err("Incomplete expression".to_string()));
+        assert_eq!(evaluate_math("2 3"), Err("Trailing input after expression".to_string()));
+        assert_eq!(evaluate_math("sqrt(-1)"), Err("Result is not a finite number".to_string()));
+        assert_eq!(evaluate_math("foo 2"), Err("Unknown function: foo".to_string()));
+        assert_eq!(evaluate_math("2 & 3"), Err("Unexpected character: &".to_string()));
+    }
+
+    #[test]
+    fn finds_units_by_alias_and_plural() {
+        assert_eq!(find_unit("km"), Some(("km", "length", 1000.0)));
+        assert_eq!(find_unit("miles"), Some(("mi", "length", 1609.344)));
+        assert_eq!(find_unit("kilograms"), Some(("kg", "mass", 1.0)));
+        assert_eq!(find_unit("parsec"), None);
+    }
+
+    #[test]
+    fn converts_temperatures() {
+        assert!(close(convert_temperature(100.0, 'c', 'f'), 212.0));
+        assert!(close(convert_temperature(32.0, 'f', 'c'), 0.0));
+        assert!(close(convert_temperature(0.0, 'k', 'c'), -273.15));
+        assert!(close(convert_temperature(-40.0, 'f', 'c'), -40.0));
+    }
+
+    #[test]
+    fn recognizes_currency_names_and_symbols() {
+        assert_eq!(currency_code("$"), Some("USD".to_string()));
+        assert_eq!(currency_code("euros"), Some("EUR".to_string()));
+        assert_eq!(currency_code("rs"), Some("LKR".to_string()));
+        assert_eq!(currency_code("chf"), Some("CHF".to_string()));
+        assert_eq!(currency_code("bitcoin"), None);
+    }
+
+    #[test]
+    fn splits_conversion_requests() {
+        let owned = |a: &str, b: &str, c: &str| Some((a.to_string(), b.to_string(), c.to_string()));
+
+        assert_eq!(split_conversion("5.5 km to miles"), owned("5.5", "km", "miles"));
+        assert_eq!(split_conversion("100f in c"), owned("100", "f", "c"));
+        assert_eq!(split_conversion("$150 to lkr"), owned("150", "$", "lkr"));
+        assert_eq!(split_conversion("(2 + 3) kg into lb"), owned("(2 + 3)", "kg", "lb"));
+        assert_eq!(split_conversion("gallons to liters"), owned("1", "gallons", "liters"));
+        assert_eq!(split_conversion("2 + 2"), None);
+    }
+}
@@ -0,0 +1,223 @@
+/**
+ * Calibration module - Measures where synthetic clicks really land on each display and corrects later mouse moves
+ *
+ * DPI scaling and multi-monitor origins can make enigo coordinates differ from physical screen pixels. The
+ * calibration window (route "#/calibration") draws a target at each position announced on `calibration://target`
+ * and reports every click on it as `calibration://hit` with its `id`, `clientX` and `clientY`.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::sync::{mpsc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize, Window};
+
+const WINDOW_LABEL: &str = "calibration";
+const HIT_TIMEOUT: Duration = Duration::from_secs(2);
+const SETTLE: Duration = Duration::from_millis(300);
+/// Targets as fractions of the display; the outer two fit the correction, the centre checks it
+const TARGETS: [(f64, f64); 3] = [(0.2, 0.2), (0.8, 0.8), (0.5, 0.5)];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayBounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl DisplayBounds {
+    fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x && y >= self.y && x < self.x + self.width as i32 && y < self.y + self.height as i32
+    }
+}
+
+/// Landing point = sent point × scale + offset, per axis, in physical pixels.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayCorrection {
+    pub display: String,
+    pub bounds: DisplayBounds,
+    pub scale_x: f64,
+    pub scale_y: f64,
+    pub offset_x: f64,
+    pub offset_y: f64,
+    /// Distance in pixels between the centre target and where the corrected click landed
+    pub residual: f64,
+    pub calibrated_at: u64,
+}
+
+static CORRECTIONS: RwLock<Vec<DisplayCorrection>> = RwLock::new(Vec::new());
+
+fn calibration_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("pointer_calibration.json"))
+}
+
+/// Loads the active profile's corrections; called at startup, on profile switches and when the file changes.
+pub fn load_pointer_calibration(app: &AppHandle) {
+    *CORRECTIONS.write().unwrap() = calibration_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+}
+
+/// Where to send the pointer so it lands on physical (x, y); unchanged on uncalibrated displays.
+pub fn correct(x: i32, y: i32) -> (i32, i32) {
+    let corrections = CORRECTIONS.read().unwrap();
+    match corrections.iter().find(|correction| correction.bounds.contains(x, y)) {
+        Some(c) => (
+            ((x as f64 - c.offset_x) / c.scale_x).round() as i32,
+            ((y as f64 - c.offset_y) / c.scale_y).round() as i32,
+        ),
+        None => (x, y),
+    }
+}
+
+/// Shows a target at `point` (physical) and clicks at `sent`, returning where the click landed.
+fn measure(app: &AppHandle, window: &Window, id: usize, point: (i32, i32), sent: (i32, i32)) -> Result<(f64, f64), String> {
+    use enigo::{Button, Coordinate, Direction, Enigo, Mouse, Settings};
+
+    let origin = window.inner_position().map_err(|e| e.to_string())?;
+    let scale = window.scale_factor().map_err(|e| e.to_string())?;
+
+    let (sender, receiver) = mpsc::channel();
+    app.once_global("calibration://hit", move |event| {
+        let hit = event.payload().and_then(|payload| serde_json::from_str::<serde_json::Value>(payload).ok());
+        let _ = sender.send(hit);
+    });
+
+    window
+        .emit("calibration://target", serde_json::json!({
+            "id": id,
+            "x": (point.0 - origin.x) as f64 / scale,
+            "y": (point.1 - origin.y) as f64 / scale,
+        }))
+        .map_err(|e| e.to_string())?;
+    std::thread::sleep(SETTLE);
+
+    let _injection = crate::automation_guard::injecting();
+    let mut enigo = Enigo::new(&Settings::default()).map_err(|e| e.to_string())?;
+    enigo.move_mouse(sent.0, sent.1, Coordinate::Abs).map_err(|e| e.to_string())?;
+    enigo.button(Button::Left, Direction::Click).map_err(|e| e.to_string())?;
+
+    let hit = receiver
+        .recv_timeout(HIT_TIMEOUT)
+        .ok()
+        .flatten()
+        .ok_or_else(|| format!("❌ Calibration click {} was not registered", id + 1))?;
+    let client_x = hit["clientX"].as_f64().ok_or("Calibration hit without clientX")?;
+    let client_y = hit["clientY"].as_f64().ok_or("Calibration hit without clientY")?;
+
+    Ok((origin.x as f64 + client_x * scale, origin.y as f64 + client_y * scale))
+}
+
+fn calibrate_display(app: &AppHandle, window: &Window, monitor: &tauri::Monitor) -> Result<DisplayCorrection, String> {
+    let position = *monitor.position();
+    let size = *monitor.size();
+    window.set_position(PhysicalPosition::new(position.x, position.y)).map_err(|e| e.to_string())?;
+    window.set_size(PhysicalSize::new(size.width, size.height)).map_err(|e| e.to_string())?;
+    std::thread::sleep(SETTLE);
+
+    let origin = window.inner_position().map_err(|e| e.to_string())?;
+    let inner = window.inner_size().map_err(|e| e.to_string())?;
+    let target = |(fx, fy): (f64, f64)| {
+        (origin.x + (inner.width as f64 * fx) as i32, origin.y + (inner.height as f64 * fy) as i32)
+    };
+
+    // Fit from two uncorrected clicks
+    let (p1, p2) = (target(TARGETS[0]), target(TARGETS[1]));
+    let a1 = measure(app, window, 0, p1, p1)?;
+    let a2 = measure(app, window, 1, p2, p2)?;
+    let scale_x = (a2.0 - a1.0) / (p2.0 - p1.0) as f64;
+    let scale_y = (a2.1 - a1.1) / (p2.1 - p1.1) as f64;
+    if !(0.25..=4.0).contains(&scale_x) || !(0.25..=4.0).contains(&scale_y) {
+        return Err(format!("❌ Implausible pointer scale on {}: {:.2} × {:.2}", monitor.name().cloned().unwrap_or_default(), scale_x, scale_y));
+    }
+    let offset_x = a1.0 - p1.0 as f64 * scale_x;
+    let offset_y = a1.1 - p1.1 as f64 * scale_y;
+
+    // Check with a corrected click on the centre
+    let centre = target(TARGETS[2]);
+    let sent = (
+        ((centre.0 as f64 - offset_x) / scale_x).round() as i32,
+        ((centre.1 as f64 - offset_y) / scale_y).round() as i32,
+    );
+    let landed = measure(app, window, 2, centre, sent)?;
+    let residual = ((landed.0 - centre.0 as f64).powi(2) + (landed.1 - centre.1 as f64).powi(2)).sqrt();
+
+    Ok(DisplayCorrection {
+        display: monitor.name().cloned().unwrap_or_else(|| format!("{},{}", position.x, position.y)),
+        bounds: DisplayBounds { x: position.x, y: position.y, width: size.width, height: size.height },
+        scale_x,
+        scale_y,
+        offset_x,
+        offset_y,
+        residual,
+        calibrated_at: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0),
+    })
+}
+
+/// Clicks known targets on every display and stores the per-display corrections used by all mouse commands.
+#[tauri::command]
+pub async fn run_pointer_calibration(app: AppHandle) -> Result<Vec<DisplayCorrection>, String> {
+    if crate::mock_platform::is_active() {
+        return Err("Pointer calibration needs a real display".to_string());
+    }
+    if app.get_window(WINDOW_LABEL).is_some() {
+        return Err("Pointer calibration already running".to_string());
+    }
+
+    let window = tauri::WindowBuilder::new(&app, WINDOW_LABEL, tauri::WindowUrl::App("index.html#/calibration".into()))
+        .title("Pointer calibration")
+        .decorations(false)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    println!("🎯 Running pointer calibration");
+    let task_app = app.clone();
+    let task_window = window.clone();
+    let calibrated = tauri::async_runtime::spawn_blocking(move || {
+        // Give the page time to load and subscribe
+        std::thread::sleep(Duration::from_secs(1));
+        let monitors = task_window.available_monitors().map_err(|e| e.to_string())?;
+        monitors
+            .iter()
+            .map(|monitor| calibrate_display(&task_app, &task_window, monitor))
+            .collect::<Result<Vec<_>, String>>()
+    })
+    .await
+    .map_err(|e| e.to_string());
+    let _ = window.close();
+    let corrections = calibrated??;
+
+    let json = serde_json::to_string_pretty(&corrections).map_err(|e| e.to_string())?;
+    std::fs::write(calibration_path(&app)?, json).map_err(|e| e.to_string())?;
+    *CORRECTIONS.write().unwrap() = corrections.clone();
+
+    for correction in &corrections {
+        println!(
+            "🎯 {}: scale {:.3} × {:.3}, offset ({:.1}, {:.1}), residual {:.1}px",
+            correction.display, correction.scale_x, correction.scale_y, correction.offset_x, correction.offset_y, correction.residual
+        );
+    }
+    let _ = app.emit_all("calibration://completed", &corrections);
+    Ok(corrections)
+}
+
+#[tauri::command]
+pub async fn get_pointer_calibration() -> Result<Vec<DisplayCorrection>, String> {
+    Ok(CORRECTIONS.read().unwrap().clone())
+}
+
+#[tauri::command]
+pub async fn reset_pointer_calibration(app: AppHandle) -> Result<(), String> {
+    let path = calibration_path(&app)?;
+    if path.exists() {
+        std::fs::remove_file(path).map_err(|e| e.to_string())?;
+    }
+    CORRECTIONS.write().unwrap().clear();
+    Ok(())
+}
@@ -0,0 +1,253 @@
+/**
+ * Camera module - Webcam listing, photo capture and streaming
+ */
+
+use nokhwa::pixel_format::RgbFormat;
+use nokhwa::utils::{ApiBackend, CameraIndex, RequestedFormat, RequestedFormatType};
+use nokhwa::Camera;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+
+const INDICATOR_LABEL: &str = "camera-indicator";
+
+pub struct CameraState {
+    pub permission_granted: bool,
+    pub stream_stop: Option<Arc<AtomicBool>>,
+    pub active_camera: Option<u32>,
+}
+
+impl Default for CameraState {
+    fn default() -> Self {
+        CameraState {
+            permission_granted: false,
+            stream_stop: None,
+            active_camera: None,
+        }
+    }
+}
+
+/// Opens a camera with its highest frame-rate format and starts the stream.
+pub fn open_camera(camera_id: u32) -> Result<Camera, String> {
+    let format = RequestedFormat::new::<RgbFormat>(RequestedFormatType::AbsoluteHighestFrameRate);
+    let mut camera = Camera::new(CameraIndex::Index(camera_id), format).map_err(|e| e.to_string())?;
+    camera.open_stream().map_err(|e| e.to_string())?;
+    Ok(camera)
+}
+
+/// Grabs and decodes a single RGB frame from an open camera.
+pub fn grab_frame(camera: &mut Camera) -> Result<image::RgbImage, String> {
+    let frame = camera.frame().map_err(|e| e.to_string())?;
+    frame.decode_image::<RgbFormat>().map_err(|e| e.to_string())
+}
+
+/// Asks the user once per run before any camera access, remembering the answer.
+pub fn ensure_camera_permission(app: &AppHandle) -> Result<(), String> {
+    let state = app.state::<Mutex<CameraState>>();
+
+    if state.lock().unwrap().permission_granted {
+        return Ok(());
+    }
+
+    let parent = app.get_window("main");
+    let granted = tauri::api::dialog::blocking::ask(
+        parent.as_ref(),
+        "Camera access",
+        "JarvisX wants to use your camera. Allow access for this session?",
+    );
+
+    if !granted {
+        return Err("❌ Camera permission denied".to_string());
+    }
+
+    state.lock().unwrap().permission_granted = true;
+    Ok(())
+}
+
+/// Shows or hides the small always-on-top "camera in use" window.
+pub fn set_camera_indicator(app: &AppHandle, visible: bool) {
+    let _ = app.emit_all("camera-indicator", serde_json::json!({ "active": visible }));
+
+    if visible {
+        if app.get_window(INDICATOR_LABEL).is_none() {
+            let _ = tauri::WindowBuilder::new(
+                app,
+                INDICATOR_LABEL,
+                tauri::WindowUrl::App("index.html#/camera-indicator".into()),
+            )
+            .title("Camera in use")
+            .inner_size(140.0, 36.0)
+            .position(16.0, 16.0)
+            .decorations(false)
+            .resizable(false)
+            .always_on_top(true)
+            .skip_taskbar(true)
+            .focused(false)
+            .build();
+        }
+    } else if let Some(window) = app.get_window(INDICATOR_LABEL) {
+        let _ = window.close();
+    }
+}
+
+#[tauri::command]
+pub async fn list_cameras() -> Result<Vec<serde_json::Value>, String> {
+    println!("📷 Listing cameras");
+
+    let cameras = nokhwa::query(ApiBackend::Auto).map_err(|e| e.to_string())?;
+
+    Ok(cameras
+        .iter()
+        .map(|info| serde_json::json!({
+            "id": info.index().as_index().ok(),
+            "name": info.human_name(),
+            "description": info.description(),
+        }))
+        .collect())
+}
+
+#[tauri::command]
+pub async fn capture_photo(app: AppHandle, camera_id: Option<u32>) -> Result<crate::binary::BinaryRef, String> {
+    ensure_camera_permission(&app)?;
+
+    let camera_id = camera_id.unwrap_or(0);
+    println!("📷 Capturing photo from camera {}", camera_id);
+
+    set_camera_indicator(&app, true);
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        let mut camera = open_camera(camera_id)?;
+        // The first frames are often dark while auto-exposure settles
+        for _ in 0..5 {
+            let _ = camera.frame();
+        }
+        let image = grab_frame(&mut camera)?;
+        let _ = camera.stop_stream();
+        Ok::<_, String>(image)
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let streaming = app.state::<Mutex<CameraState>>().lock().unwrap().stream_stop.is_some();
+    if !streaming {
+        set_camera_indicator(&app, false);
+    }
+
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    image::DynamicImage::ImageRgb8(result?)
+        .write_to(&mut cursor, image::ImageOutputFormat::Png)
+        .map_err(|e| e.to_string())?;
+    Ok(crate::binary::store_blob(cursor.into_inner(), "image/png"))
+}
+
+#[tauri::command]
+pub async fn start_camera_stream(
+    app: AppHandle,
+    camera_id: Option<u32>,
+    fps: Option<u32>,
+) -> Result<String, String> {
+    ensure_camera_permission(&app)?;
+
+    let camera_id = camera_id.unwrap_or(0);
+    let frame_interval = Duration::from_millis(1000 / fps.unwrap_or(10).clamp(1, 30) as u64);
+    let stop = Arc::new(AtomicBool::new(false));
+
+    {
+        let state = app.state::<Mutex<CameraState>>();
+        let mut camera_state = state.lock().unwrap();
+
+        if camera_state.stream_stop.is_some() {
+            return Err("Camera stream already active".to_string());
+        }
+
+        camera_state.stream_stop = Some(stop.clone());
+        camera_state.active_camera = Some(camera_id);
+    }
+
+    set_camera_indicator(&app, true);
+    crate::scheduler::register_task(&app, "camera-stream", crate::scheduler::TaskPriority::Realtime);
+    crate::recovery::track("stream:camera", "stream", serde_json::json!({ "source": "camera", "cameraId": camera_id }));
+    println!("📹 Camera stream started: camera {}", camera_id);
+
+    // Camera handles are not Send on every backend, so the stream owns its own thread; it reports back once the
+    // camera is open so a failure reaches the caller instead of leaving the stream marked active
+    let (opened, open_result) = tokio::sync::oneshot::channel();
+    let handle = app.clone();
+    let started = stop.clone();
+    std::thread::spawn(move || {
+        use std::io::Cursor;
+
+        let mut camera = match open_camera(camera_id) {
+            Ok(camera) => {
+                let _ = opened.send(Ok(()));
+                camera
+            }
+            Err(e) => {
+                let _ = opened.send(Err(e));
+                return;
+            }
+        };
+
+        while !stop.load(Ordering::SeqCst) {
+            if let Ok(frame) = grab_frame(&mut camera) {
+                let mut cursor = Cursor::new(Vec::new());
+                if image::DynamicImage::ImageRgb8(frame)
+                    .write_to(&mut cursor, image::ImageOutputFormat::Jpeg(70))
+                    .is_ok()
+                {
+                    let _ = handle.emit_all("camera-frame", crate::binary::store_blob(cursor.into_inner(), "image/jpeg"));
+                }
+            }
+            std::thread::sleep(frame_interval);
+        }
+
+        let _ = camera.stop_stream();
+    });
+
+    let outcome = open_result.await.unwrap_or_else(|_| Err("❌ Camera stream thread exited".to_string()));
+    if let Err(e) = outcome {
+        {
+            let state = app.state::<Mutex<CameraState>>();
+            let mut camera_state = state.lock().unwrap();
+            // Only undo our own start; a stop and a new start may have happened meanwhile
+            if camera_state.stream_stop.as_ref().map_or(false, |current| Arc::ptr_eq(current, &started)) {
+                camera_state.stream_stop = None;
+                camera_state.active_camera = None;
+            }
+        }
+        set_camera_indicator(&app, false);
+        crate::scheduler::unregister_task(&app, "camera-stream");
+        crate::recovery::untrack("stream:camera");
+        println!("📹 Camera stream failed to start: {}", e);
+        return Err(e);
+    }
+
+    Ok("Camera stream started".to_string())
+}
+
+#[tauri::command]
+pub async fn stop_camera_stream(app: AppHandle, state: State<'_, Mutex<CameraState>>) -> Result<String, String> {
+    let stop = {
+        let mut camera_state = state.lock().unwrap();
+        camera_state.active_camera = None;
+        camera_state.stream_stop.take()
+    };
+
+    match stop {
+        Some(stop) => {
+            stop.store(true, Ordering::SeqCst);
+            set_camera_indicator(&app, false);
+            crate::scheduler::unregister_task(&app, "camera-stream");
+            crate::recovery::untrack("stream:camera");
+            println!("📹 Camera stream stopped");
+            Ok("Camera stream stopped".to_string())
+        }
+        None => Err("Camera stream not active".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn revoke_camera_permission(state: State<'_, Mutex<CameraState>>) -> Result<(), String> {
+    state.lock().unwrap().permission_granted = false;
+    Ok(())
+}
@@ -0,0 +1,498 @@
+/**
+ * Captions module - Live captions of whatever the machine is playing, via system audio loopback
+ */
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+
+const OVERLAY_LABEL: &str = "captions-overlay";
+/// RMS below which a chunk is treated as silence and never sent to STT (Whisper hallucinates on silence)
+const SILENCE_RMS: f32 = 0.005;
+
+pub type SampleBuffer = Arc<Mutex<Vec<f32>>>;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Caption {
+    pub text: String,
+    pub start_secs: f64,
+    pub end_secs: f64,
+    pub language: String,
+    /// Set when the microphone is captured alongside system audio: "You" or "Others"
+    pub speaker: Option<String>,
+    /// Present when auto-translate is on and the caption is not in the preferred language
+    pub translation: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CaptureSource {
+    SystemAudio,
+    /// The named input device, or the default one
+    Microphone(Option<String>),
+}
+
+pub struct CaptionState {
+    pub stop: Option<Arc<AtomicBool>>,
+    pub source: Option<String>,
+    pub language: String,
+    pub started_at: Option<Instant>,
+    pub captions: Vec<Caption>,
+}
+
+impl Default for CaptionState {
+    fn default() -> Self {
+        CaptionState {
+            stop: None,
+            source: None,
+            language: "en".to_string(),
+            started_at: None,
+            captions: Vec::new(),
+        }
+    }
+}
+
+/// Averages interleaved frames down to mono and appends them to the buffer.
+fn push_frames(buffer: &SampleBuffer, samples: impl Iterator<Item = f32>, channels: usize) {
+    let samples: Vec<f32> = samples.collect();
+    let mut buffer = buffer.lock().unwrap();
+    buffer.extend(
+        samples
+            .chunks(channels.max(1))
+            .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32),
+    );
+}
+
+/// PulseAudio/PipeWire expose the mix of everything playing as the default sink's monitor source.
+#[cfg(target_os = "linux")]
+fn run_capture(buffer: SampleBuffer, stop: Arc<AtomicBool>, ready: mpsc::Sender<Result<(String, u32), String>>) {
+    use std::io::Read;
+    use std::process::{Command, Stdio};
+
+    const SAMPLE_RATE: u32 = 16000;
+
+    let child = Command::new("parec")
+        .args(&[
+            "--device=@DEFAULT_MONITOR@",
+            "--format=s16le",
+            "--rate=16000",
+            "--channels=1",
+            "--raw",
+        ])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            let _ = ready.send(Err(format!("Could not open the monitor source (is parec installed?): {}", e)));
+            return;
+        }
+    };
+
+    let mut stdout = match child.stdout.take() {
+        Some(stdout) => stdout,
+        None => {
+            let _ = ready.send(Err("parec produced no output stream".to_string()));
+            let _ = child.kill();
+            return;
+        }
+    };
+    let _ = ready.send(Ok(("@DEFAULT_MONITOR@".to_string(), SAMPLE_RATE)));
+
+    let mut bytes = [0u8; 3200];
+    while !stop.load(Ordering::SeqCst) {
+        if stdout.read_exact(&mut bytes).is_err() {
+            break;
+        }
+        push_frames(
+            &buffer,
+            bytes
+                .chunks(2)
+                .map(|pair| i16::from_le_bytes([pair[0], pair[1]]) as f32 / i16::MAX as f32),
+            1,
+        );
+    }
+
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// WASAPI records an output device when an input stream is built on it.
+#[cfg(target_os = "windows")]
+fn loopback_device() -> Result<(cpal::Device, cpal::SupportedStreamConfig), String> {
+    use cpal::traits::{DeviceTrait, HostTrait};
+
+    let device = cpal::default_host()
+        .default_output_device()
+        .ok_or("No output device to loop back")?;
+    let config = device.default_output_config().map_err(|e| e.to_string())?;
+    Ok((device, config))
+}
+
+/// macOS has no built-in loopback; a virtual device such as BlackHole must receive the output.
+#[cfg(target_os = "macos")]
+fn loopback_device() -> Result<(cpal::Device, cpal::SupportedStreamConfig), String> {
+    use cpal::traits::{DeviceTrait, HostTrait};
+
+    let device = cpal::default_host()
+        .input_devices()
+        .map_err(|e| e.to_string())?
+        .find(|device| {
+            device.name().map_or(false, |name| {
+                let name = name.to_lowercase();
+                ["blackhole", "soundflower", "loopback"].iter().any(|virtual_device| name.contains(virtual_device))
+            })
+        })
+        .ok_or("No loopback device found. Install BlackHole and add it to a Multi-Output Device")?;
+    let config = device.default_input_config().map_err(|e| e.to_string())?;
+    Ok((device, config))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn run_capture(buffer: SampleBuffer, stop: Arc<AtomicBool>, ready: mpsc::Sender<Result<(String, u32), String>>) {
+    match loopback_device() {
+        Ok((device, config)) => run_device_capture(device, config, buffer, stop, ready),
+        Err(e) => {
+            let _ = ready.send(Err(e));
+        }
+    }
+}
+
+fn run_microphone_capture(
+    device_name: Option<String>,
+    buffer: SampleBuffer,
+    stop: Arc<AtomicBool>,
+    ready: mpsc::Sender<Result<(String, u32), String>>,
+) {
+    use cpal::traits::{DeviceTrait, HostTrait};
+
+    let host = cpal::default_host();
+    // A selected device that has just gone away falls back to the default until the watcher picks another
+    let selected = device_name.and_then(|wanted| {
+        host.input_devices().ok()?.find(|device| device.name().map_or(false, |name| name == wanted))
+    });
+    let opened = selected
+        .or_else(|| host.default_input_device())
+        .ok_or_else(|| "No microphone available".to_string())
+        .and_then(|device| {
+            let config = device.default_input_config().map_err(|e| e.to_string())?;
+            Ok((device, config))
+        });
+
+    match opened {
+        Ok((device, config)) => run_device_capture(device, config, buffer, stop, ready),
+        Err(e) => {
+            let _ = ready.send(Err(e));
+        }
+    }
+}
+
+fn open_stream(
+    device: cpal::Device,
+    config: cpal::SupportedStreamConfig,
+    buffer: SampleBuffer,
+) -> Result<(cpal::Stream, String, u32), String> {
+    use cpal::traits::{DeviceTrait, StreamTrait};
+
+    let name = device.name().unwrap_or_else(|_| "unknown device".to_string());
+    let channels = config.channels() as usize;
+    let sample_rate = config.sample_rate().0;
+    let err_fn = |e| println!("⚠️  Audio stream error: {}", e);
+
+    let stream = match config.sample_format() {
+        cpal::SampleFormat::F32 => device.build_input_stream(
+            &config.into(),
+            move |data: &[f32], _: &cpal::InputCallbackInfo| push_frames(&buffer, data.iter().copied(), channels),
+            err_fn,
+            None,
+        ),
+        cpal::SampleFormat::I16 => {
+            device.build_input_stream(
+                &config.into(),
+                move |data: &[i16], _: &cpal::InputCallbackInfo| {
+                    push_frames(&buffer, data.iter().map(|s| *s as f32 / i16::MAX as f32), channels)
+                },
+                err_fn,
+                None,
+            )
+        }
+        other => return Err(format!("Unsupported sample format: {:?}", other)),
+    }
+    .map_err(|e| e.to_string())?;
+
+    stream.play().map_err(|e| e.to_string())?;
+    Ok((stream, name, sample_rate))
+}
+
+fn run_device_capture(
+    device: cpal::Device,
+    config: cpal::SupportedStreamConfig,
+    buffer: SampleBuffer,
+    stop: Arc<AtomicBool>,
+    ready: mpsc::Sender<Result<(String, u32), String>>,
+) {
+    // cpal streams are not Send, so this thread owns the stream until stopped
+    match open_stream(device, config, buffer) {
+        Ok((stream, name, sample_rate)) => {
+            let _ = ready.send(Ok((name, sample_rate)));
+            while !stop.load(Ordering::SeqCst) {
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            drop(stream);
+        }
+        Err(e) => {
+            let _ = ready.send(Err(e));
+        }
+    }
+}
+
+pub fn rms(samples: &[f32]) -> f32 {
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len().max(1) as f32).sqrt()
+}
+
+async fn run_captions(
+    app: AppHandle,
+    buffer: SampleBuffer,
+    stop: Arc<AtomicBool>,
+    sample_rate: u32,
+    language: String,
+    speaker: Option<String>,
+    chunk: Duration,
+) {
+    let mut offset_secs = 0.0;
+
+    while !stop.load(Ordering::SeqCst) {
+        tokio::time::sleep(chunk).await;
+
+        let samples = std::mem::take(&mut *buffer.lock().unwrap());
+        if samples.is_empty() {
+            continue;
+        }
+
+        let start_secs = offset_secs;
+        offset_secs += samples.len() as f64 / sample_rate as f64;
+
+        if rms(&samples) < SILENCE_RMS {
+            continue;
+        }
+
+        let pcm: Vec<i16> = samples
+            .iter()
+            .map(|s| (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+            .collect();
+
+        match crate::voice::transcribe_wav(crate::voice::encode_wav(&pcm, sample_rate), &language, false).await {
+            Ok(result) => {
+                let text = result.get("text").and_then(|t| t.as_str()).unwrap_or("").trim().to_string();
+                if text.is_empty() {
+                    continue;
+                }
+
+                let caption_language = result
+                    .get("language")
+                    .and_then(|l| l.as_str())
+                    .unwrap_or(&language)
+                    .to_string();
+                let translation = crate::translation::auto_translate(&app, &text, &caption_language).await;
+
+                let caption = Caption {
+                    text,
+                    start_secs,
+                    end_secs: offset_secs,
+                    language: caption_language,
+                    speaker: speaker.clone(),
+                    translation,
+                };
+                app.state::<Mutex<CaptionState>>().lock().unwrap().captions.push(caption.clone());
+                let _ = app.emit_all("captions://caption", caption);
+            }
+            Err(e) => {
+                let _ = app.emit_all("captions://error", e);
+            }
+        }
+    }
+}
+
+/// Shows or hides the always-on-top caption strip.
+fn set_caption_overlay(app: &AppHandle, visible: bool) {
+    if visible {
+        if app.get_window(OVERLAY_LABEL).is_none() {
+            let _ = tauri::WindowBuilder::new(
+                app,
+                OVERLAY_LABEL,
+                tauri::WindowUrl::App("index.html#/captions".into()),
+            )
+            .title("Live captions")
+            .inner_size(720.0, 120.0)
+            .decorations(false)
+            .always_on_top(true)
+            .skip_taskbar(true)
+            .focused(false)
+            .build();
+        }
+    } else if let Some(window) = app.get_window(OVERLAY_LABEL) {
+        let _ = window.close();
+    }
+}
+
+/// Starts a capture thread for one source and waits until its device is open.
+pub async fn open_source(source: CaptureSource, stop: Arc<AtomicBool>) -> Result<(SampleBuffer, String, u32), String> {
+    let buffer: SampleBuffer = Arc::new(Mutex::new(Vec::new()));
+    let (ready_tx, ready_rx) = mpsc::channel();
+    {
+        let buffer = buffer.clone();
+        std::thread::spawn(move || match source {
+            CaptureSource::SystemAudio => run_capture(buffer, stop, ready_tx),
+            CaptureSource::Microphone(device) => run_microphone_capture(device, buffer, stop, ready_tx),
+        });
+    }
+
+    let (name, sample_rate) = tauri::async_runtime::spawn_blocking(move || {
+        ready_rx
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap_or_else(|_| Err("Timed out opening the audio device".to_string()))
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    Ok((buffer, name, sample_rate))
+}
+
+/// Starts loopback capture and captioning; returns the capture device name.
+///
+/// With `include_microphone` the mic is captioned too, and captions are attributed
+/// to "You" (mic) or "Others" (system audio).
+pub async fn start_capture(
+    app: &AppHandle,
+    language: Option<String>,
+    chunk_secs: Option<u64>,
+    include_microphone: bool,
+) -> Result<String, String> {
+    let language = language.unwrap_or_else(|| {
+        crate::locale::current_locale().split('-').next().unwrap_or("en").to_string()
+    });
+    let stop = Arc::new(AtomicBool::new(false));
+
+    {
+        let state = app.state::<Mutex<CaptionState>>();
+        let mut caption_state = state.lock().unwrap();
+
+        if caption_state.stop.is_some() {
+            return Err("System audio capture already active".to_string());
+        }
+
+        caption_state.stop = Some(stop.clone());
+    }
+
+    let sources = if include_microphone {
+        vec![
+            (CaptureSource::SystemAudio, Some("Others".to_string())),
+            (CaptureSource::Microphone(crate::voice::capture_device(app)), Some("You".to_string())),
+        ]
+    } else {
+        vec![(CaptureSource::SystemAudio, None)]
+    };
+
+    let mut opened = Vec::new();
+    for (source, speaker) in sources {
+        match open_source(source, stop.clone()).await {
+            Ok((buffer, name, sample_rate)) => opened.push((buffer, name, sample_rate, speaker)),
+            Err(e) => {
+                stop.store(true, Ordering::SeqCst);
+                app.state::<Mutex<CaptionState>>().lock().unwrap().stop = None;
+                return Err(e);
+            }
+        }
+    }
+
+    let source_name = opened[0].1.clone();
+    {
+        let state = app.state::<Mutex<CaptionState>>();
+        let mut caption_state = state.lock().unwrap();
+        caption_state.source = Some(source_name.clone());
+        caption_state.language = language.clone();
+        caption_state.started_at = Some(Instant::now());
+        caption_state.captions.clear();
+    }
+
+    crate::scheduler::register_task(app, "system-audio-capture", crate::scheduler::TaskPriority::Realtime);
+    crate::recovery::track("capture:audio", "capture", serde_json::json!({ "source": source_name, "language": language }));
+
+    let chunk = Duration::from_secs(chunk_secs.unwrap_or(4).clamp(2, 30));
+    for (buffer, name, sample_rate, speaker) in opened {
+        println!("🎧 Audio capture started from {} ({} Hz)", name, sample_rate);
+        tauri::async_runtime::spawn(run_captions(
+            app.clone(),
+            buffer,
+            stop.clone(),
+            sample_rate,
+            language.clone(),
+            speaker,
+            chunk,
+        ));
+    }
+
+    Ok(source_name)
+}
+
+/// Stops capture and returns the captions gathered since it started.
+pub fn stop_capture(app: &AppHandle) -> Result<Vec<Caption>, String> {
+    let captions = {
+        let state = app.state::<Mutex<CaptionState>>();
+        let mut caption_state = state.lock().unwrap();
+
+        let stop = caption_state.stop.take().ok_or("System audio capture not active")?;
+        stop.store(true, Ordering::SeqCst);
+        caption_state.source = None;
+        caption_state.started_at = None;
+
+        let mut captions = caption_state.captions.clone();
+        captions.sort_by(|a, b| a.start_secs.total_cmp(&b.start_secs));
+        captions
+    };
+
+    crate::scheduler::unregister_task(app, "system-audio-capture");
+    crate::recovery::untrack("capture:audio");
+    println!("🎧 System audio capture stopped");
+    Ok(captions)
+}
+
+#[tauri::command]
+pub async fn start_system_audio_capture(
+    app: AppHandle,
+    language: Option<String>,
+    chunk_secs: Option<u64>,
+    show_overlay: Option<bool>,
+) -> Result<String, String> {
+    let source = start_capture(&app, language, chunk_secs, false).await?;
+
+    if show_overlay.unwrap_or(true) {
+        set_caption_overlay(&app, true);
+    }
+
+    Ok(source)
+}
+
+#[tauri::command]
+pub async fn stop_system_audio_capture(app: AppHandle) -> Result<Vec<Caption>, String> {
+    let captions = stop_capture(&app)?;
+    set_caption_overlay(&app, false);
+    Ok(captions)
+}
+
+#[tauri::command]
+pub async fn get_caption_status(state: State<'_, Mutex<CaptionState>>) -> Result<serde_json::Value, String> {
+    let caption_state = state.lock().unwrap();
+
+    Ok(serde_json::json!({
+        "isCapturing": caption_state.stop.is_some(),
+        "source": caption_state.source,
+        "language": caption_state.language,
+        "elapsedSecs": caption_state.started_at.map(|started| started.elapsed().as_secs()),
+        "captionCount": caption_state.captions.len(),
+    }))
+}
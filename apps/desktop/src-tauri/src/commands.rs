@@ -2,72 +2,345 @@
  * Tauri Commands - Native system control from JavaScript
  */
 
-use tauri::Window;
+use std::time::Duration;
+use tauri::{AppHandle, Window};
+
+use crate::input_verify::InputResult;
+use crate::watchdog::WatchdogCommand;
+
+fn validate_launch_name(name: &str) -> Result<(), String> {
+    if name.trim().is_empty() || name.starts_with('-') || name.chars().any(char::is_control) {
+        return Err(format!("❌ Invalid application name: {:?}", name));
+    }
+    Ok(())
+}
+
+/// Accepts `scheme:rest` URIs (slack://, vscode://, mailto:, https://) with no whitespace or control characters.
+fn validate_uri(uri: &str) -> Result<(), String> {
+    let (scheme, rest) = uri.split_once(':').ok_or_else(|| format!("❌ Not a URI: {}", uri))?;
+    let scheme_valid = scheme.chars().next().map_or(false, |c| c.is_ascii_alphabetic())
+        && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+.-".contains(c));
+
+    if !scheme_valid || rest.is_empty() || uri.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(format!("❌ Not a URI: {}", uri));
+    }
+    if matches!(scheme.to_lowercase().as_str(), "javascript" | "vbscript" | "data") {
+        return Err(format!("❌ URI scheme not allowed: {}", scheme));
+    }
+    Ok(())
+}
+
+/// Quotes one argument so CommandLineToArgvW hands it back unchanged.
+#[cfg(target_os = "windows")]
+fn quote_windows_arg(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains(|c: char| c == ' ' || c == '\t' || c == '"') {
+        return arg.to_string();
+    }
+
+    let mut quoted = String::from("\"");
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                quoted.push_str(&"\\".repeat(backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.push_str(&"\\".repeat(backslashes));
+                quoted.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+    quoted.push_str(&"\\".repeat(backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+/// Desktop entry id (file stem) for an installed application's display name or id.
+#[cfg(target_os = "linux")]
+pub fn desktop_entry_id(app_name: &str) -> Option<String> {
+    enumerate_installed_applications()
+        .ok()?
+        .iter()
+        .filter_map(|entry| {
+            let path = std::path::Path::new(entry["path"].as_str()?);
+            let id = path.file_stem()?.to_str()?.to_string();
+            let matches = entry["name"].as_str().map_or(false, |name| name.eq_ignore_ascii_case(app_name))
+                || id.eq_ignore_ascii_case(app_name);
+            matches.then_some(id)
+        })
+        .next()
+}
+
+fn check_output(output: std::process::Output, what: &str) -> Result<(), String> {
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        Err(format!("❌ Failed to open {}{}", what, if stderr.is_empty() { String::new() } else { format!(": {}", stderr) }))
+    }
+}
+
+/// Opens `target` (a file, shortcut or URI) through the shell like a double-click, with `parameters` as its
+/// command line. Both are handed over as data, so nothing in them is ever parsed as script.
+#[cfg(target_os = "windows")]
+pub fn shell_execute(verb: Option<&str>, target: &str, parameters: Option<&str>) -> Result<(), String> {
+    use windows::core::{HSTRING, PCWSTR};
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::UI::Shell::ShellExecuteW;
+    use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
+
+    let (verb, target, parameters) = (verb.map(HSTRING::from), HSTRING::from(target), parameters.map(HSTRING::from));
+    let pointer = |value: &Option<HSTRING>| value.as_ref().map_or(PCWSTR::null(), |value| PCWSTR(value.as_ptr()));
+    let instance = unsafe { ShellExecuteW(HWND(0), pointer(&verb), &target, pointer(&parameters), PCWSTR::null(), SW_SHOWNORMAL) };
+
+    // Values of 32 and below are error codes
+    if instance.0 > 32 {
+        Ok(())
+    } else {
+        Err(format!("❌ Failed to open {} (shell error {})", target, instance.0))
+    }
+}
+
+/// PowerShell running the fixed `script`, with each value in an environment variable the script reads as
+/// `$env:NAME`. Values never become part of the script text, so they can't be run as code.
+#[cfg(any(target_os = "windows", test))]
+pub fn powershell(script: &str, values: &[(&str, &str)]) -> std::process::Command {
+    let mut command = std::process::Command::new("powershell");
+    command.args(&["-NoProfile", "-NonInteractive", "-Command", script]);
+    for (name, value) in values {
+        command.env(name, value);
+    }
+    command
+}
+
+/// Start menu shortcut (.lnk) for an installed application's display name.
+#[cfg(target_os = "windows")]
+fn start_menu_shortcut(app_name: &str) -> Option<std::path::PathBuf> {
+    fn find(dir: &std::path::Path, app_name: &str) -> Option<std::path::PathBuf> {
+        std::fs::read_dir(dir).ok()?.filter_map(|entry| entry.ok()).find_map(|entry| {
+            let path = entry.path();
+            if path.is_dir() {
+                return find(&path, app_name);
+            }
+            let is_shortcut = path.extension().and_then(|ext| ext.to_str()).map_or(false, |ext| ext.eq_ignore_ascii_case("lnk"));
+            let matches = path.file_stem().and_then(|stem| stem.to_str()).map_or(false, |stem| stem.eq_ignore_ascii_case(app_name));
+            (is_shortcut && matches).then_some(path)
+        })
+    }
+
+    ["ProgramData", "APPDATA"]
+        .iter()
+        .filter_map(std::env::var_os)
+        .map(|root| std::path::PathBuf::from(root).join("Microsoft\\Windows\\Start Menu\\Programs"))
+        .find_map(|dir| find(&dir, app_name))
+}
+
+/// Bundle path of an application in the Applications folders.
+#[cfg(target_os = "macos")]
+fn application_bundle(app_name: &str) -> Option<String> {
+    enumerate_installed_applications().ok()?.iter().find_map(|entry| {
+        entry["name"].as_str().filter(|name| name.eq_ignore_ascii_case(app_name))?;
+        entry["path"].as_str().map(String::from)
+    })
+}
+
+/// Starts the installed application `app_name` with `args` (and optional `file`) without going through a shell.
+/// Only applications the OS lists as installed can be started: app bundles on macOS, Start menu shortcuts on
+/// Windows and desktop entries on Linux; arbitrary executables are refused.
+fn launch_application(app_name: &str, file: Option<&std::path::Path>, args: &[String]) -> Result<(), String> {
+    #[cfg(not(target_os = "windows"))]
+    use std::process::Command;
 
-#[tauri::command]
-pub async fn open_application(app_name: String) -> Result<String, String> {
     #[cfg(target_os = "macos")]
     {
-        use std::process::Command;
-        let output = Command::new("open")
-            .arg("-a")
-            .arg(&app_name)
-            .output()
-            .map_err(|e| e.to_string())?;
-
-        if output.status.success() {
-            Ok(format!("✅ Opened: {}", app_name))
-        } else {
-            Err(format!("❌ Failed to open: {}", app_name))
+        let bundle = application_bundle(app_name).ok_or_else(|| format!("❌ Not an installed application: {}", app_name))?;
+        let mut command = Command::new("open");
+        command.arg("-a").arg(&bundle);
+        if let Some(file) = file {
+            command.arg(file);
         }
+        if !args.is_empty() {
+            command.arg("--args").args(args);
+        }
+        check_output(command.output_with_timeout()?, app_name)
     }
 
     #[cfg(target_os = "windows")]
     {
-        use std::process::Command;
-        let output = Command::new("cmd")
-            .args(&["/C", "start", "", &app_name])
-            .output()
-            .map_err(|e| e.to_string())?;
-
-        if output.status.success() {
-            Ok(format!("✅ Opened: {}", app_name))
-        } else {
-            Err(format!("❌ Failed to open: {}", app_name))
-        }
+        let shortcut = start_menu_shortcut(app_name).ok_or_else(|| format!("❌ Not an installed application: {}", app_name))?;
+        let mut arguments: Vec<String> = file.map(|file| file.to_string_lossy().to_string()).into_iter().collect();
+        arguments.extend(args.iter().cloned());
+        let argument_line = arguments.iter().map(|arg| quote_windows_arg(arg)).collect::<Vec<_>>().join(" ");
+
+        // Extra arguments are appended to the shortcut's own
+        shell_execute(None, &shortcut.to_string_lossy(), Some(&argument_line).filter(|line| !line.is_empty()))
+            .map_err(|_| format!("❌ Failed to open {}", app_name))
     }
 
     #[cfg(target_os = "linux")]
     {
-        use std::process::Command;
-        let output = Command::new("xdg-open")
-            .arg(&app_name)
-            .output()
-            .map_err(|e| e.to_string())?;
+        let id = desktop_entry_id(app_name).ok_or_else(|| format!("❌ Not an installed application: {}", app_name))?;
+        let mut command = Command::new("gtk-launch");
+        command.arg(&id).args(file).args(args);
+        check_output(command.output_with_timeout()?, app_name)
+    }
+}
 
-        if output.status.success() {
-            Ok(format!("✅ Opened: {}", app_name))
-        } else {
-            Err(format!("❌ Failed to open: {}", app_name))
+const READY_TIMEOUT: Duration = Duration::from_secs(15);
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchResult {
+    pub message: String,
+    /// Set when the launch waited for the app to be ready
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_id: Option<String>,
+}
+
+fn frontmost_matches(app_name: &str) -> bool {
+    let frontmost = if crate::mock_platform::is_active() {
+        Some(crate::mock_platform::active_window())
+    } else {
+        crate::activity::frontmost_app()
+    };
+    frontmost.map_or(false, |frontmost| frontmost.to_lowercase().contains(&app_name.to_lowercase()))
+}
+
+/// Polls until a window of `app_name` exists and its app is frontmost, returning the window's id.
+/// Windows that were already open before the launch are only accepted once the app is frontmost.
+async fn wait_for_app_ready(app_name: &str, existing: &[String], timeout: Duration) -> Result<String, String> {
+    let needle = app_name.to_lowercase();
+    let started = std::time::Instant::now();
+
+    loop {
+        let windows = query_window_list().await.unwrap_or_default();
+        let mut candidates: Vec<&serde_json::Value> = windows
+            .iter()
+            .filter(|window| {
+                let name = window.get("name").or_else(|| window.get("Name")).and_then(|name| name.as_str()).unwrap_or("");
+                name.to_lowercase().contains(&needle) || crate::window_registry::window_title(window).to_lowercase().contains(&needle)
+            })
+            .collect();
+        // Prefer a window that appeared after the launch
+        candidates.sort_by_key(|window| crate::window_registry::window_key(window).map_or(true, |key| existing.contains(&key)));
+
+        if let Some(key) = candidates.first().and_then(|window| crate::window_registry::window_key(window)) {
+            if frontmost_matches(app_name) {
+                return Ok(key);
+            }
         }
+
+        if started.elapsed() >= timeout {
+            return Err(format!("❌ {} did not become ready within {}s", app_name, timeout.as_secs()));
+        }
+        tokio::time::sleep(READY_POLL_INTERVAL).await;
+    }
+}
+
+/// Opens an installed application; passing `args` needs supervised mode, since they reach the app's command line.
+#[tauri::command]
+pub async fn open_application(
+    app: AppHandle,
+    app_name: String,
+    args: Option<Vec<String>>,
+    wait_until_ready: Option<bool>,
+    timeout_ms: Option<u64>,
+) -> Result<LaunchResult, String> {
+    validate_launch_name(&app_name)?;
+    let args = args.unwrap_or_default();
+    if !args.is_empty() {
+        crate::supervised::require_supervised(&app, "open_application", serde_json::json!({ "appName": app_name, "args": args }))?;
+    }
+
+    let wait = wait_until_ready.unwrap_or(false);
+    let existing: Vec<String> = if wait {
+        query_window_list().await.unwrap_or_default().iter().filter_map(crate::window_registry::window_key).collect()
+    } else {
+        Vec::new()
+    };
+
+    let name = app_name.clone();
+    tauri::async_runtime::spawn_blocking(move || launch_application(&name, None, &args))
+        .await
+        .map_err(|e| e.to_string())??;
+
+    if !wait {
+        return Ok(LaunchResult { message: format!("✅ Opened: {}", app_name), window_id: None });
     }
+
+    let timeout = timeout_ms.map(Duration::from_millis).unwrap_or(READY_TIMEOUT);
+    let window_id = wait_for_app_ready(&app_name, &existing, timeout).await?;
+    println!("🚀 {} is ready (window {})", app_name, window_id);
+    Ok(LaunchResult { message: format!("✅ Opened and ready: {}", app_name), window_id: Some(window_id) })
+}
+
+#[tauri::command]
+pub async fn open_with(path: String, app_name: String) -> Result<String, String> {
+    validate_launch_name(&app_name)?;
+
+    // Absolute paths can't be mistaken for options by the launcher
+    let file = std::fs::canonicalize(&path).map_err(|_| format!("❌ File not found: {}", path))?;
+
+    let name = app_name.clone();
+    tauri::async_runtime::spawn_blocking(move || launch_application(&name, Some(&file), &[]))
+        .await
+        .map_err(|e| e.to_string())??;
+
+    Ok(format!("✅ Opened {} with {}", path, app_name))
 }
 
 #[tauri::command]
-pub async fn execute_command(command: String, args: Vec<String>) -> Result<String, String> {
+pub async fn open_uri(uri: String) -> Result<String, String> {
+    #[cfg(not(target_os = "windows"))]
     use std::process::Command;
 
+    validate_uri(&uri)?;
+
+    let target = uri.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        #[cfg(target_os = "macos")]
+        let opened = check_output(Command::new("open").arg(&target).output_with_timeout()?, &target);
+        #[cfg(target_os = "windows")]
+        let opened = shell_execute(None, &target, None);
+        #[cfg(target_os = "linux")]
+        let opened = check_output(Command::new("xdg-open").arg(&target).output_with_timeout()?, &target);
+
+        opened
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    Ok(format!("✅ Opened: {}", uri))
+}
+
+#[tauri::command]
+pub async fn execute_command(
+    app: AppHandle,
+    command: String,
+    args: Vec<String>,
+    capability_token: Option<String>,
+) -> Result<String, String> {
+    let level = crate::permissions::sandbox_level(&app, capability_token.as_deref());
+    run_command(&app, command, args, level).await
+}
+
+/// Runs a whitelisted (or supervised) command under the given sandbox level.
+pub async fn run_command(app: &AppHandle, command: String, args: Vec<String>, level: crate::process_sandbox::SandboxLevel) -> Result<String, String> {
     // Whitelist of allowed commands for security
     let allowed_commands = vec!["git", "npm", "ls", "pwd", "echo"];
     
     if !allowed_commands.contains(&command.as_str()) {
-        return Err(format!("❌ Command not whitelisted: {}", command));
+        // Arbitrary shell is only available inside a supervised window
+        crate::supervised::require_supervised(app, "execute_command", serde_json::json!({ "command": command, "args": args }))
+            .map_err(|_| format!("❌ Command not whitelisted: {}", command))?;
     }
 
-    let output = Command::new(&command)
-        .args(&args)
-        .output()
-        .map_err(|e| e.to_string())?;
+    let output = crate::process_sandbox::output_sandboxed(&command, &args, level, crate::watchdog::default_timeout())?;
 
     let stdout = String::from_utf8_lossy(&output.stdout).to_string();
     let stderr = String::from_utf8_lossy(&output.stderr).to_string();
@@ -80,67 +353,139 @@ pub async fn execute_command(command: String, args: Vec<String>) -> Result<Strin
 }
 
 #[tauri::command]
-pub async fn get_system_info() -> Result<serde_json::Value, String> {
-    use sysinfo::{System, SystemExt};
-    
-    let mut sys = System::new_all();
-    sys.refresh_all();
-
-    let info = serde_json::json!({
-        "os": std::env::consts::OS,
-        "arch": std::env::consts::ARCH,
-        "hostname": sys.host_name(),
-        "cpuCount": sys.cpus().len(),
-        "totalMemory": sys.total_memory(),
-        "usedMemory": sys.used_memory(),
-        "uptime": sys.uptime(),
-    });
+pub async fn get_system_info(refresh: Option<bool>) -> Result<serde_json::Value, String> {
+    crate::cache::cached("get_system_info", Duration::from_secs(5), refresh.unwrap_or(false), || async {
+        tauri::async_runtime::spawn_blocking(collect_system_info)
+            .await
+            .map_err(|e| e.to_string())
+    })
+    .await
+}
 
-    Ok(info)
+fn collect_system_info() -> serde_json::Value {
+    crate::subsystems::with_system(jarvisx_core::system::system_info)
 }
 
 #[tauri::command]
-pub async fn simulate_keyboard(key: String) -> Result<String, String> {
+pub async fn list_installed_applications(refresh: Option<bool>) -> Result<serde_json::Value, String> {
+    crate::cache::cached("list_installed_applications", Duration::from_secs(300), refresh.unwrap_or(false), || async {
+        let apps = tauri::async_runtime::spawn_blocking(enumerate_installed_applications)
+            .await
+            .map_err(|e| e.to_string())??;
+        Ok(serde_json::json!(apps))
+    })
+    .await
+}
+
+pub fn enumerate_installed_applications() -> Result<Vec<serde_json::Value>, String> {
+    #[cfg(target_os = "macos")]
+    {
+        let mut dirs = vec![std::path::PathBuf::from("/Applications"), std::path::PathBuf::from("/System/Applications")];
+        if let Some(home) = std::env::var_os("HOME") {
+            dirs.push(std::path::PathBuf::from(home).join("Applications"));
+        }
+
+        let mut apps: Vec<serde_json::Value> = dirs
+            .iter()
+            .filter_map(|dir| std::fs::read_dir(dir).ok())
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("app"))
+            .filter_map(|path| {
+                let name = path.file_stem()?.to_str()?.to_string();
+                Some(serde_json::json!({ "name": name, "path": path }))
+            })
+            .collect();
+        apps.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+        Ok(apps)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::process::Command;
+        let script = "Get-ItemProperty HKLM:\\Software\\Microsoft\\Windows\\CurrentVersion\\Uninstall\\*, HKLM:\\Software\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Uninstall\\*, HKCU:\\Software\\Microsoft\\Windows\\CurrentVersion\\Uninstall\\* | Where-Object DisplayName | Select-Object DisplayName, DisplayVersion, InstallLocation | Sort-Object DisplayName -Unique | ConvertTo-Json";
+        let output = Command::new("powershell")
+            .args(&["-NoProfile", "-Command", script])
+            .output_with_timeout()?;
+
+        if output.status.success() {
+            let entries: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap_or_default();
+            Ok(entries
+                .into_iter()
+                .map(|entry| serde_json::json!({
+                    "name": entry["DisplayName"],
+                    "version": entry["DisplayVersion"],
+                    "path": entry["InstallLocation"],
+                }))
+                .collect())
+        } else {
+            Err("Failed to list installed applications".to_string())
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let mut dirs = vec![std::path::PathBuf::from("/usr/share/applications")];
+        if let Some(home) = std::env::var_os("HOME") {
+            dirs.push(std::path::PathBuf::from(home).join(".local/share/applications"));
+        }
+
+        let mut apps: Vec<serde_json::Value> = dirs
+            .iter()
+            .filter_map(|dir| std::fs::read_dir(dir).ok())
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("desktop"))
+            .filter_map(|path| {
+                let contents = std::fs::read_to_string(&path).ok()?;
+                if contents.lines().any(|line| line.trim() == "NoDisplay=true") {
+                    return None;
+                }
+                let name = contents.lines().find_map(|line| line.strip_prefix("Name="))?.to_string();
+                let exec = contents.lines().find_map(|line| line.strip_prefix("Exec="));
+                Some(serde_json::json!({ "name": name, "path": path, "exec": exec }))
+            })
+            .collect();
+        apps.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+        apps.dedup_by(|a, b| a["name"] == b["name"]);
+        Ok(apps)
+    }
+}
+
+#[tauri::command]
+pub async fn simulate_keyboard(key: String, verify: Option<bool>) -> Result<InputResult, String> {
+    if crate::mock_platform::is_active() {
+        let detail = serde_json::json!({ "key": key });
+        crate::mock_platform::record_input("key", detail.clone());
+        let verified = verify.unwrap_or(false).then(|| crate::mock_platform::last_input_is("key", &detail));
+        return Ok(InputResult::new(format!("Pressed: {}", key), verified));
+    }
+
+    let mark = crate::input_verify::mark();
+    press_key(&key)?;
+
+    let verified = if verify.unwrap_or(false) { crate::input_verify::key_registered(mark).await } else { None };
+    Ok(InputResult::new(format!("Pressed: {}", key), verified))
+}
+
+fn press_key(key: &str) -> Result<(), String> {
     use enigo::{Enigo, Mouse, Keyboard, Direction, Settings, Key};
     
+    let _injection = crate::automation_guard::injecting();
     let mut enigo = Enigo::new(&Settings::default(), Mouse, Keyboard).map_err(|e| e.to_string())?;
     
     // Parse key and simulate press
-    let key_enum = match key.to_lowercase().as_str() {
-        "enter" | "return" => Key::Return,
-        "space" => Key::Space,
-        "tab" => Key::Tab,
-        "escape" | "esc" => Key::Escape,
-        "backspace" => Key::Backspace,
-        "delete" | "del" => Key::Delete,
-        "up" => Key::UpArrow,
-        "down" => Key::DownArrow,
-        "left" => Key::LeftArrow,
-        "right" => Key::RightArrow,
-        "home" => Key::Home,
-        "end" => Key::End,
-        "pageup" | "pgup" => Key::PageUp,
-        "pagedown" | "pgdown" => Key::PageDown,
-        "f1" => Key::F1,
-        "f2" => Key::F2,
-        "f3" => Key::F3,
-        "f4" => Key::F4,
-        "f5" => Key::F5,
-        "f6" => Key::F6,
-        "f7" => Key::F7,
-        "f8" => Key::F8,
-        "f9" => Key::F9,
-        "f10" => Key::F10,
-        "f11" => Key::F11,
-        "f12" => Key::F12,
-        "ctrl" => Key::Control,
-        "alt" => Key::Alt,
-        "shift" => Key::Shift,
-        "cmd" | "command" | "meta" => Key::Meta,
-        _ => {
-            // For single characters, try to parse as Key::Unicode
-            if key.len() == 1 {
-                Key::Unicode(key.chars().next().unwrap())
+    let key_enum = match crate::keyboard::named_key(key) {
+        Some(key_enum) => key_enum,
+        None => {
+            // For single characters, type them through the active keyboard layout
+            if key.chars().count() == 1 {
+                let layout = crate::keyboard::current_layout();
+                crate::keyboard::type_char(&mut enigo, &layout.code, key.chars().next().unwrap())?;
+                println!("🎹 Simulating keyboard: {} ({} layout)", key, layout.code);
+                return Ok(());
             } else {
                 return Err(format!("Unsupported key: {}", key));
             }
@@ -151,56 +496,101 @@ pub async fn simulate_keyboard(key: String) -> Result<String, String> {
     enigo.key(key_enum, Direction::Release).map_err(|e| e.to_string())?;
     
     println!("🎹 Simulating keyboard: {}", key);
-    Ok(format!("Pressed: {}", key))
+    Ok(())
 }
 
 #[tauri::command]
-pub async fn simulate_mouse_click(x: i32, y: i32) -> Result<String, String> {
+pub async fn simulate_mouse_click(x: i32, y: i32, verify: Option<bool>) -> Result<InputResult, String> {
     use enigo::{Enigo, Mouse, Keyboard, Direction, Settings};
     
-    let mut enigo = Enigo::new(&Settings::default(), Mouse, Keyboard).map_err(|e| e.to_string())?;
-    
-    // Move to position and click
-    enigo.move_mouse(x, y, Direction::Absolute).map_err(|e| e.to_string())?;
-    enigo.button(Mouse::Left, Direction::Press).map_err(|e| e.to_string())?;
-    enigo.button(Mouse::Left, Direction::Release).map_err(|e| e.to_string())?;
+    let message = format!("Clicked at: ({}, {})", x, y);
+    if crate::mock_platform::is_active() {
+        crate::mock_platform::record_input("click", serde_json::json!({ "x": x, "y": y }));
+        let verified = verify.unwrap_or(false).then(|| crate::mock_platform::cursor() == (x, y));
+        return Ok(InputResult::new(message, verified));
+    }
+
+    {
+        let _injection = crate::automation_guard::injecting();
+        let mut enigo = Enigo::new(&Settings::default(), Mouse, Keyboard).map_err(|e| e.to_string())?;
+        
+        // Move to position and click
+        let target = crate::calibration::correct(x, y);
+        enigo.move_mouse(target.0, target.1, Direction::Absolute).map_err(|e| e.to_string())?;
+        enigo.button(Mouse::Left, Direction::Press).map_err(|e| e.to_string())?;
+        enigo.button(Mouse::Left, Direction::Release).map_err(|e| e.to_string())?;
+    }
     
     println!("🖱️  Mouse click at: ({}, {})", x, y);
-    Ok(format!("Clicked at: ({}, {})", x, y))
+    // The OS reports the cursor in the same space corrected moves are sent in
+    let (sent_x, sent_y) = crate::calibration::correct(x, y);
+    let verified = if verify.unwrap_or(false) { crate::input_verify::cursor_at(sent_x, sent_y).await } else { None };
+    Ok(InputResult::new(message, verified))
+}
+
+/// Points from start to end (start excluded) in `steps` moves, spaced by an easing curve over t in 0..=1.
+pub fn interpolate_path(start: (i32, i32), end: (i32, i32), steps: u32, easing: impl Fn(f32) -> f32) -> Vec<(i32, i32)> {
+    let dx = (end.0 - start.0) as f32;
+    let dy = (end.1 - start.1) as f32;
+
+    (1..=steps.max(1))
+        .map(|i| {
+            let t = easing(i as f32 / steps.max(1) as f32);
+            (start.0 + (dx * t) as i32, start.1 + (dy * t) as i32)
+        })
+        .collect()
 }
 
 #[tauri::command]
-pub async fn simulate_mouse_drag(x1: i32, y1: i32, x2: i32, y2: i32) -> Result<String, String> {
+pub async fn simulate_mouse_drag(x1: i32, y1: i32, x2: i32, y2: i32, verify: Option<bool>) -> Result<InputResult, String> {
     use enigo::{Enigo, Mouse, Keyboard, Direction, Settings};
     
-    let mut enigo = Enigo::new(&Settings::default(), Mouse, Keyboard).map_err(|e| e.to_string())?;
-    
-    // Move to start position, press mouse, drag to end position, release
-    enigo.move_mouse(x1, y1, Direction::Absolute).map_err(|e| e.to_string())?;
-    enigo.button(Mouse::Left, Direction::Press).map_err(|e| e.to_string())?;
-    
-    // Smooth drag by moving in steps
-    let steps = 10;
-    let dx = (x2 - x1) as f32 / steps as f32;
-    let dy = (y2 - y1) as f32 / steps as f32;
-    
-    for i in 1..=steps {
-        let x = x1 + (dx * i as f32) as i32;
-        let y = y1 + (dy * i as f32) as i32;
-        enigo.move_mouse(x, y, Direction::Absolute).map_err(|e| e.to_string())?;
-        std::thread::sleep(std::time::Duration::from_millis(10));
+    let message = format!("Dragged from ({}, {}) to ({}, {})", x1, y1, x2, y2);
+    if crate::mock_platform::is_active() {
+        crate::mock_platform::record_input("drag", serde_json::json!({ "fromX": x1, "fromY": y1, "x": x2, "y": y2 }));
+        let verified = verify.unwrap_or(false).then(|| crate::mock_platform::cursor() == (x2, y2));
+        return Ok(InputResult::new(message, verified));
+    }
+
+    {
+        let _injection = crate::automation_guard::injecting();
+        let mut enigo = Enigo::new(&Settings::default(), Mouse, Keyboard).map_err(|e| e.to_string())?;
+        
+        // Move to start position, press mouse, drag to end position, release
+        let target = crate::calibration::correct(x1, y1);
+        enigo.move_mouse(target.0, target.1, Direction::Absolute).map_err(|e| e.to_string())?;
+        enigo.button(Mouse::Left, Direction::Press).map_err(|e| e.to_string())?;
+        
+        // Smooth drag by moving in steps
+        for (x, y) in interpolate_path((x1, y1), (x2, y2), 10, |t| t) {
+            let target = crate::calibration::correct(x, y);
+            enigo.move_mouse(target.0, target.1, Direction::Absolute).map_err(|e| e.to_string())?;
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        
+        enigo.button(Mouse::Left, Direction::Release).map_err(|e| e.to_string())?;
     }
-    
-    enigo.button(Mouse::Left, Direction::Release).map_err(|e| e.to_string())?;
     
     println!("🖱️  Mouse drag from: ({}, {}) to ({}, {})", x1, y1, x2, y2);
-    Ok(format!("Dragged from ({}, {}) to ({}, {})", x1, y1, x2, y2))
+    let (sent_x, sent_y) = crate::calibration::correct(x2, y2);
+    let verified = if verify.unwrap_or(false) { crate::input_verify::cursor_at(sent_x, sent_y).await } else { None };
+    Ok(InputResult::new(message, verified))
 }
 
 #[tauri::command]
-pub async fn simulate_mouse_scroll(direction: String, amount: i32) -> Result<String, String> {
+pub async fn simulate_mouse_scroll(direction: String, amount: i32, verify: Option<bool>) -> Result<InputResult, String> {
     use enigo::{Enigo, Mouse, Keyboard, Direction, Settings};
     
+    let message = format!("Scrolled {} by {}", direction, amount);
+    if crate::mock_platform::is_active() {
+        let detail = serde_json::json!({ "direction": direction, "amount": amount });
+        crate::mock_platform::record_input("scroll", detail.clone());
+        let verified = verify.unwrap_or(false).then(|| crate::mock_platform::last_input_is("scroll", &detail));
+        return Ok(InputResult::new(message, verified));
+    }
+
+    let mark = crate::input_verify::mark();
+    let _injection = crate::automation_guard::injecting();
     let mut enigo = Enigo::new(&Settings::default(), Mouse, Keyboard).map_err(|e| e.to_string())?;
     
     // Scroll based on direction
@@ -213,15 +603,23 @@ pub async fn simulate_mouse_scroll(direction: String, amount: i32) -> Result<Str
     };
     
     enigo.scroll(scroll_amount, Direction::Absolute).map_err(|e| e.to_string())?;
+    drop(enigo);
+    drop(_injection);
     
     println!("🖱️  Mouse scroll: {} by {}", direction, amount);
-    Ok(format!("Scrolled {} by {}", direction, amount))
+    let verified = if verify.unwrap_or(false) { crate::input_verify::wheel_registered(mark).await } else { None };
+    Ok(InputResult::new(message, verified))
 }
 
 #[tauri::command]
 pub async fn get_mouse_position() -> Result<serde_json::Value, String> {
     use enigo::{Enigo, Mouse, Keyboard, Direction, Settings};
     
+    if crate::mock_platform::is_active() {
+        let (x, y) = crate::mock_platform::cursor();
+        return Ok(serde_json::json!({ "x": x, "y": y }));
+    }
+
     let mut enigo = Enigo::new(&Settings::default(), Mouse, Keyboard).map_err(|e| e.to_string())?;
     
     // Get current mouse position
@@ -238,10 +636,17 @@ pub async fn get_mouse_position() -> Result<serde_json::Value, String> {
 pub async fn simulate_mouse_hover(x: i32, y: i32) -> Result<String, String> {
     use enigo::{Enigo, Mouse, Keyboard, Direction, Settings};
     
+    if crate::mock_platform::is_active() {
+        crate::mock_platform::record_input("hover", serde_json::json!({ "x": x, "y": y }));
+        return Ok(format!("Hovered at: ({}, {})", x, y));
+    }
+
+    let _injection = crate::automation_guard::injecting();
     let mut enigo = Enigo::new(&Settings::default(), Mouse, Keyboard).map_err(|e| e.to_string())?;
     
     // Move mouse to position without clicking
-    enigo.move_mouse(x, y, Direction::Absolute).map_err(|e| e.to_string())?;
+    let target = crate::calibration::correct(x, y);
+    enigo.move_mouse(target.0, target.1, Direction::Absolute).map_err(|e| e.to_string())?;
     
     // Wait a bit to simulate hover
     std::thread::sleep(std::time::Duration::from_millis(100));
@@ -252,18 +657,7 @@ pub async fn simulate_mouse_hover(x: i32, y: i32) -> Result<String, String> {
 
 #[tauri::command]
 pub async fn get_running_processes() -> Result<Vec<String>, String> {
-    use sysinfo::{ProcessExt, System, SystemExt};
-    
-    let mut sys = System::new_all();
-    sys.refresh_all();
-
-    let processes: Vec<String> = sys
-        .processes()
-        .iter()
-        .map(|(pid, process)| format!("{}: {}", pid, process.name()))
-        .collect();
-
-    Ok(processes)
+    Ok(crate::subsystems::with_system(jarvisx_core::system::running_processes))
 }
 
 #[tauri::command]
@@ -274,8 +668,7 @@ pub async fn switch_to_application(app_name: String) -> Result<String, String> {
         let output = Command::new("osascript")
             .arg("-e")
             .arg(format!("tell application \"{}\" to activate", app_name))
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Switched to: {}", app_name))
@@ -289,8 +682,7 @@ pub async fn switch_to_application(app_name: String) -> Result<String, String> {
         use std::process::Command;
         let output = Command::new("powershell")
             .args(&["-Command", &format!("Get-Process {} | ForEach-Object {{ $_.MainWindowTitle }}", app_name)])
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Switched to: {}", app_name))
@@ -305,8 +697,7 @@ pub async fn switch_to_application(app_name: String) -> Result<String, String> {
         let output = Command::new("wmctrl")
             .arg("-a")
             .arg(&app_name)
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Switched to: {}", app_name))
@@ -324,8 +715,7 @@ pub async fn minimize_application(app_name: String) -> Result<String, String> {
         let output = Command::new("osascript")
             .arg("-e")
             .arg(format!("tell application \"{}\" to set minimized of window 1 to true", app_name))
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Minimized: {}", app_name))
@@ -339,8 +729,7 @@ pub async fn minimize_application(app_name: String) -> Result<String, String> {
         use std::process::Command;
         let output = Command::new("powershell")
             .args(&["-Command", &format!("Get-Process {} | ForEach-Object {{ $_.MinimizeMainWindow() }}", app_name)])
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Minimized: {}", app_name))
@@ -357,8 +746,7 @@ pub async fn minimize_application(app_name: String) -> Result<String, String> {
             .arg(&app_name)
             .arg("-b")
             .arg("add,hidden")
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Minimized: {}", app_name))
@@ -376,8 +764,7 @@ pub async fn maximize_application(app_name: String) -> Result<String, String> {
         let output = Command::new("osascript")
             .arg("-e")
             .arg(format!("tell application \"{}\" to set zoomed of window 1 to true", app_name))
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Maximized: {}", app_name))
@@ -391,8 +778,7 @@ pub async fn maximize_application(app_name: String) -> Result<String, String> {
         use std::process::Command;
         let output = Command::new("powershell")
             .args(&["-Command", &format!("Get-Process {} | ForEach-Object {{ $_.MaximizeMainWindow() }}", app_name)])
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Maximized: {}", app_name))
@@ -409,8 +795,7 @@ pub async fn maximize_application(app_name: String) -> Result<String, String> {
             .arg(&app_name)
             .arg("-b")
             .arg("add,maximized_vert,maximized_horz")
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Maximized: {}", app_name))
@@ -428,8 +813,7 @@ pub async fn close_application(app_name: String) -> Result<String, String> {
         let output = Command::new("osascript")
             .arg("-e")
             .arg(format!("tell application \"{}\" to quit", app_name))
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Closed: {}", app_name))
@@ -445,8 +829,7 @@ pub async fn close_application(app_name: String) -> Result<String, String> {
             .arg("/F")
             .arg("/IM")
             .arg(&format!("{}.exe", app_name))
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Closed: {}", app_name))
@@ -460,8 +843,7 @@ pub async fn close_application(app_name: String) -> Result<String, String> {
         use std::process::Command;
         let output = Command::new("pkill")
             .arg(&app_name)
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Closed: {}", app_name))
@@ -472,88 +854,73 @@ pub async fn close_application(app_name: String) -> Result<String, String> {
 }
 
 #[tauri::command]
-pub async fn get_window_list() -> Result<Vec<serde_json::Value>, String> {
-    #[cfg(target_os = "macos")]
-    {
-        use std::process::Command;
-        let output = Command::new("osascript")
-            .arg("-e")
-            .arg("tell application \"System Events\" to get name of every process whose background only is false")
-            .output()
-            .map_err(|e| e.to_string())?;
+pub async fn get_window_list(
+    registry: tauri::State<'_, std::sync::Mutex<crate::window_registry::WindowRegistry>>,
+    refresh: Option<bool>,
+) -> Result<Vec<serde_json::Value>, String> {
+    crate::window_registry::cached_window_list(&registry, refresh.unwrap_or(false)).await
+}
 
-        if output.status.success() {
-            let windows: Vec<serde_json::Value> = String::from_utf8_lossy(&output.stdout)
-                .split(", ")
-                .map(|name| serde_json::json!({
-                    "name": name.trim(),
-                    "title": name.trim()
-                }))
-                .collect();
-            Ok(windows)
-        } else {
-            Err("Failed to get window list".to_string())
-        }
+/// Full window list from the platform, each entry carrying its stable `windowId`.
+pub async fn query_window_list() -> Result<Vec<serde_json::Value>, String> {
+    if crate::mock_platform::is_active() {
+        return Ok(crate::mock_platform::window_list());
     }
 
-    #[cfg(target_os = "windows")]
-    {
-        use std::process::Command;
-        let output = Command::new("powershell")
-            .args(&["-Command", "Get-Process | Where-Object {$_.MainWindowTitle -ne \"\"} | Select-Object Name, MainWindowTitle | ConvertTo-Json"])
-            .output()
-            .map_err(|e| e.to_string())?;
+    let windows = tauri::async_runtime::spawn_blocking(crate::window_ids::enumerate_windows)
+        .await
+        .map_err(|e| e.to_string())??;
+    Ok(windows.iter().map(crate::window_ids::NativeWindow::to_json).collect())
+}
 
-        if output.status.success() {
-            let json_str = String::from_utf8_lossy(&output.stdout);
-            let windows: Vec<serde_json::Value> = serde_json::from_str(&json_str).unwrap_or_default();
-            Ok(windows)
-        } else {
-            Err("Failed to get window list".to_string())
-        }
+/// Focuses a window by `window_id` (exact) or, failing that, by fuzzy `title` match.
+#[tauri::command]
+pub async fn focus_window(title: Option<String>, window_id: Option<String>, verify: Option<bool>) -> Result<InputResult, String> {
+    let verify = verify.unwrap_or(false);
+
+    if let Some(window_id) = window_id {
+        let target = window_id.clone();
+        let message = tauri::async_runtime::spawn_blocking(move || {
+            crate::window_ids::focus(&crate::window_ids::resolve(&target)?)
+        })
+        .await
+        .map_err(|e| e.to_string())??;
+
+        let verified = match (verify, crate::mock_platform::is_active()) {
+            (false, _) => None,
+            (true, true) => Some(crate::window_ids::active_window().map_or(false, |window| window.window_id == window_id)),
+            (true, false) => crate::input_verify::window_focused(&window_id).await,
+        };
+        return Ok(InputResult::new(message, verified));
     }
 
-    #[cfg(target_os = "linux")]
-    {
-        use std::process::Command;
-        let output = Command::new("wmctrl")
-            .arg("-l")
-            .output()
-            .map_err(|e| e.to_string())?;
+    let title = title.ok_or("❌ focus_window needs a windowId or a title")?;
+    let before = if verify && !crate::mock_platform::is_active() { crate::activity::frontmost_app() } else { None };
 
-        if output.status.success() {
-            let windows: Vec<serde_json::Value> = String::from_utf8_lossy(&output.stdout)
-                .lines()
-                .map(|line| {
-                    let parts: Vec<&str> = line.splitn(4, ' ').collect();
-                    if parts.len() >= 4 {
-                        serde_json::json!({
-                            "id": parts[0],
-                            "desktop": parts[1],
-                            "title": parts[3]
-                        })
-                    } else {
-                        serde_json::json!({})
-                    }
-                })
-                .collect();
-            Ok(windows)
-        } else {
-            Err("Failed to get window list".to_string())
-        }
-    }
+    // Windows that are still animating in often refuse focus on the first try
+    let focused = crate::retry::retry(&crate::retry::current_policy(), "focus_window", || focus_window_once(title.clone())).await?;
+    let message = crate::retry::with_attempts(focused.value, focused.attempts);
+
+    let verified = match (verify, crate::mock_platform::is_active()) {
+        (false, _) => None,
+        (true, true) => Some(crate::mock_platform::is_focused(&title)),
+        (true, false) => crate::input_verify::focus_changed(before, &title).await,
+    };
+    Ok(InputResult::new(message, verified))
 }
 
-#[tauri::command]
-pub async fn focus_window(title: String) -> Result<String, String> {
+async fn focus_window_once(title: String) -> Result<String, String> {
+    if crate::mock_platform::is_active() {
+        return crate::mock_platform::focus(&title);
+    }
+
     #[cfg(target_os = "macos")]
     {
         use std::process::Command;
         let output = Command::new("osascript")
             .arg("-e")
             .arg(format!("tell application \"System Events\" to set frontmost of first process whose name contains \"{}\" to true", title))
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Focused window: {}", title))
@@ -567,8 +934,7 @@ pub async fn focus_window(title: String) -> Result<String, String> {
         use std::process::Command;
         let output = Command::new("powershell")
             .args(&["-Command", &format!("Get-Process | Where-Object {{$_.MainWindowTitle -like \"*{}*\"}} | ForEach-Object {{ $_.SetForegroundWindow() }}", title)])
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Focused window: {}", title))
@@ -583,8 +949,7 @@ pub async fn focus_window(title: String) -> Result<String, String> {
         let output = Command::new("wmctrl")
             .arg("-a")
             .arg(&title)
-            .output()
-            .map_err(|e| e.to_string())?;
+            .output_with_timeout()?;
 
         if output.status.success() {
             Ok(format!("✅ Focused window: {}", title))
@@ -634,82 +999,146 @@ pub async fn get_avatar_state() -> Result<serde_json::Value, String> {
 }
 
 #[tauri::command]
-pub async fn capture_screen() -> Result<String, String> {
-    use screenshots::Screen;
-    use std::io::Cursor;
-    use base64::{Engine as _, engine::general_purpose};
-    
-    // Get all screens
-    let screens = Screen::all().map_err(|e| e.to_string())?;
-    
-    if screens.is_empty() {
-        return Err("No screens found".to_string());
+pub async fn capture_screen() -> Result<crate::binary::BinaryRef, String> {
+    Ok(crate::binary::store_blob(capture_screen_png().await?, "image/png"))
+}
+
+/// PNG bytes of the primary screen.
+pub async fn capture_screen_png() -> Result<Vec<u8>, String> {
+    capture_display_png(0).await
+}
+
+/// PNG bytes of the screen at `index` in enumeration order (0 = primary).
+pub async fn capture_display_png(index: usize) -> Result<Vec<u8>, String> {
+    crate::screen_permission::ensure_screen_capture()?;
+
+    // Capture can fail transiently while the permission prompt is still settling
+    let captured = crate::retry::retry(&crate::retry::current_policy(), "capture_screen", || capture_display_once(index)).await?;
+    Ok(captured.value)
+}
+
+async fn capture_display_once(index: usize) -> Result<Vec<u8>, String> {
+    if crate::mock_platform::is_active() {
+        return crate::mock_platform::capture(index, None);
     }
+
+    // Screens are enumerated once and reused
+    let screens = crate::subsystems::screens()?;
     
-    // Capture the first screen
-    let screen = &screens[0];
-    let image = screen.capture().map_err(|e| e.to_string())?;
-    
-    // Convert to base64
-    let mut cursor = Cursor::new(Vec::new());
-    image.write_to(&mut cursor, image::ImageOutputFormat::Png).map_err(|e| e.to_string())?;
-    let image_data = cursor.into_inner();
-    let base64_image = general_purpose::STANDARD.encode(&image_data);
-    
-    Ok(base64_image)
+    let screen = screens.get(index).ok_or_else(|| format!("❌ Display not found: {}", index + 1))?;
+    jarvisx_core::display::screen_png(screen).map_err(|e| {
+        crate::subsystems::invalidate_screens();
+        e
+    })
 }
 
 #[tauri::command]
 pub async fn get_system_uptime() -> Result<String, String> {
-    use sysinfo::{System, SystemExt};
-    
-    let mut sys = System::new_all();
-    sys.refresh_all();
+    use sysinfo::SystemExt;
     
-    let uptime = sys.uptime();
-    let hours = uptime / 3600;
-    let minutes = (uptime % 3600) / 60;
-    
-    Ok(format!("{}h {}m", hours, minutes))
+    Ok(crate::locale::format_duration(crate::subsystems::with_system(|sys| sys.uptime())))
 }
 
 #[tauri::command]
-pub async fn capture_screen_region(x: i32, y: i32, width: i32, height: i32) -> Result<String, String> {
-    use screenshots::Screen;
+pub async fn capture_screen_region(x: i32, y: i32, width: i32, height: i32) -> Result<crate::binary::BinaryRef, String> {
+    Ok(crate::binary::store_blob(capture_screen_region_png(x, y, width, height).await?, "image/png"))
+}
+
+/// PNG bytes of a rectangle in screen coordinates, from the display that contains it.
+pub async fn capture_screen_region_png(x: i32, y: i32, width: i32, height: i32) -> Result<Vec<u8>, String> {
+    crate::screen_permission::ensure_screen_capture()?;
+
+    let captured = crate::retry::retry(&crate::retry::current_policy(), "capture_screen_region", || {
+        capture_screen_region_once(x, y, width, height)
+    })
+    .await?;
+    Ok(captured.value)
+}
+
+async fn capture_screen_region_once(x: i32, y: i32, width: i32, height: i32) -> Result<Vec<u8>, String> {
     use std::io::Cursor;
-    use base64::{Engine as _, engine::general_purpose};
     
-    // Get all screens
-    let screens = Screen::all().map_err(|e| e.to_string())?;
-    
-    if screens.is_empty() {
-        return Err("No screens found".to_string());
+    if crate::mock_platform::is_active() {
+        return crate::mock_platform::capture(0, Some((x, y, width.max(0) as u32, height.max(0) as u32)));
     }
+
+    if width <= 0 || height <= 0 {
+        return Err("❌ Region must have a positive width and height".to_string());
+    }
+
+    // Screens are enumerated once and reused
+    let screens = crate::subsystems::screens()?;
+
+    // Displays are captured one at a time, so the region has to fit on one of them
+    let screen = screens
+        .iter()
+        .find(|screen| {
+            let info = &screen.display_info;
+            x >= info.x && y >= info.y && x + width <= info.x + info.width as i32 && y + height <= info.y + info.height as i32
+        })
+        .ok_or_else(|| format!("❌ Region {}x{} at ({}, {}) is not within a single display", width, height, x, y))?;
+    let (left, top) = (x - screen.display_info.x, y - screen.display_info.y);
+    let image = screen.capture_area(left, top, width as u32, height as u32).map_err(|e| {
+        crate::subsystems::invalidate_screens();
+        e.to_string()
+    })?;
     
-    // Capture the first screen
-    let screen = &screens[0];
-    let image = screen.capture_area(x, y, width as u32, height as u32).map_err(|e| e.to_string())?;
-    
-    // Convert to base64
     let mut cursor = Cursor::new(Vec::new());
     image.write_to(&mut cursor, image::ImageOutputFormat::Png).map_err(|e| e.to_string())?;
-    let image_data = cursor.into_inner();
-    let base64_image = general_purpose::STANDARD.encode(&image_data);
     
-    Ok(base64_image)
+    Ok(cursor.into_inner())
 }
 
 #[tauri::command]
 pub async fn get_system_uptime() -> Result<String, String> {
-    use sysinfo::{System, SystemExt};
-    
-    let mut sys = System::new_all();
-    sys.refresh_all();
-    
-    let uptime = sys.uptime();
-    let hours = uptime / 3600;
-    let minutes = (uptime % 3600) / 60;
+    use sysinfo::SystemExt;
     
-    Ok(format!("{}h {}m", hours, minutes))
+    Ok(crate::locale::format_duration(crate::subsystems::with_system(|sys| sys.uptime())))
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uris_with_shell_metacharacters_are_kept_literal() {
+        // Handed to the launcher as one argument, so `;` and `&` are part of the URI, not command separators
+        assert_eq!(validate_uri("slack:x;calc"), Ok(()));
+        assert_eq!(validate_uri("https://example.com/?a=1&b=$(calc)"), Ok(()));
+    }
+
+    #[test]
+    fn rejects_uris_that_are_not_plain_scheme_and_target() {
+        assert!(validate_uri("slack:x; calc").is_err());
+        assert!(validate_uri("calc").is_err());
+        assert!(validate_uri("-x:y").is_err());
+        assert!(validate_uri("slack:").is_err());
+        assert!(validate_uri("JavaScript:alert(1)").is_err());
+    }
+
+    #[test]
+    fn rejects_option_like_application_names() {
+        assert!(validate_launch_name("-e").is_err());
+        assert!(validate_launch_name("  ").is_err());
+        assert!(validate_launch_name("Visual Studio Code").is_ok());
+    }
+
+    #[test]
+    fn powershell_values_travel_outside_the_script() {
+        let command = powershell("Write-Output $env:JARVISX_TEXT", &[("JARVISX_TEXT", "\";calc;\"")]);
+        let args: Vec<_> = command.get_args().map(|arg| arg.to_string_lossy().to_string()).collect();
+
+        assert_eq!(args, ["-NoProfile", "-NonInteractive", "-Command", "Write-Output $env:JARVISX_TEXT"]);
+        assert!(command.get_envs().any(|(name, value)| name == "JARVISX_TEXT" && value == Some("\";calc;\"".as_ref())));
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn quotes_windows_arguments_for_argv_parsing() {
+        assert_eq!(quote_windows_arg("plain"), "plain");
+        assert_eq!(quote_windows_arg("two words"), "\"two words\"");
+        assert_eq!(quote_windows_arg("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(quote_windows_arg("C:\\dir with space\\"), "\"C:\\dir with space\\\\\"");
+    }
+}
@@ -0,0 +1,149 @@
+/**
+ * Config watch module - Applies edits to the active profile's settings and policy files without a restart
+ *
+ * Cached settings (bandwidth, capabilities, egress, environment, pointer calibration, proxy, snippets) are reloaded;
+ * the rest (backup jobs, HID button mappings, redaction, removable-drive triggers, retention, share provider, voice
+ * shortcuts, translation) are read on use and take effect immediately. Each batch of changes emits `config://reloaded`.
+ */
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+/// Editors save in several writes; changes are batched until the folder has been quiet this long
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+static WATCHER: Mutex<Option<RecommendedWatcher>> = Mutex::new(None);
+
+/// Applies one changed file, returning what was reloaded (None for files nothing caches).
+fn reload(app: &AppHandle, file: &str) -> Option<&'static str> {
+    match file {
+        "capabilities.json" => {
+            crate::permissions::load_capabilities(app);
+            Some("capabilities")
+        }
+        "bandwidth.json" => {
+            crate::bandwidth::load_bandwidth_policy(app);
+            Some("bandwidth policy")
+        }
+        "egress.json" => {
+            crate::egress::load_egress_policy(app.clone());
+            Some("egress")
+        }
+        "environment.json" => {
+            crate::environment::load_environment_profile(app);
+            Some("environment profile")
+        }
+        "proxy.json" => {
+            crate::proxy::load_proxy_settings(app);
+            Some("proxy settings")
+        }
+        "pointer_calibration.json" => {
+            crate::calibration::load_pointer_calibration(app);
+            Some("pointer calibration")
+        }
+        "snippets.json" => {
+            crate::snippets::load_snippets(app.clone());
+            Some("snippets")
+        }
+        "retention.json" => {
+            // Rules are read per run; enforce the new ones now rather than at the next hourly pass
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move { crate::retention::run_janitor(&app).await });
+            Some("retention")
+        }
+        "redaction.json" => Some("redaction"),
+        "voice_shortcuts.json" => Some("voice shortcuts"),
+        "translation.json" => Some("translation"),
+        "removable.json" => Some("removable-drive triggers"),
+        "backups.json" => Some("backup jobs"),
+        "share.json" => Some("share provider"),
+        "hid_mappings.json" => Some("HID button mappings"),
+        _ => None,
+    }
+}
+
+/// Half-written or hand-broken JSON is reported instead of resetting the setting to its defaults.
+fn check_json(path: &Path) -> Result<(), String> {
+    match std::fs::read_to_string(path) {
+        Ok(json) => serde_json::from_str::<serde_json::Value>(&json).map(|_| ()).map_err(|e| e.to_string()),
+        // Deleted: reloading falls back to the defaults
+        Err(_) => Ok(()),
+    }
+}
+
+fn apply_changes(app: &AppHandle, paths: BTreeSet<PathBuf>) {
+    let mut reloaded = Vec::new();
+
+    for path in paths {
+        let file = match path.file_name().and_then(|name| name.to_str()) {
+            Some(file) if file.ends_with(".json") => file.to_string(),
+            _ => continue,
+        };
+
+        if let Err(e) = check_json(&path) {
+            println!("⚠️  Not reloading {}: {}", file, e);
+            let _ = app.emit_all("config://reload-failed", serde_json::json!({ "file": file, "error": e }));
+            continue;
+        }
+        if let Some(setting) = reload(app, &file) {
+            reloaded.push(serde_json::json!({ "file": file, "setting": setting }));
+        }
+    }
+
+    if !reloaded.is_empty() {
+        println!("🔄 Reloaded configuration: {} files", reloaded.len());
+        let _ = app.emit_all("config://reloaded", serde_json::json!({ "changes": reloaded }));
+    }
+}
+
+/// Watches the active profile's folder, replacing any previous watch; called at startup and on profile switches.
+pub fn watch_active_profile(app: &AppHandle) {
+    let dir = match crate::profiles::active_profile_dir(app) {
+        Ok(dir) => dir,
+        Err(e) => {
+            println!("⚠️  Config watch unavailable: {}", e);
+            return;
+        }
+    };
+
+    let (sender, receiver) = mpsc::channel::<PathBuf>();
+    let watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+        if let Ok(event) = result {
+            if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)) {
+                for path in event.paths {
+                    let _ = sender.send(path);
+                }
+            }
+        }
+    })
+    .and_then(|mut watcher| watcher.watch(&dir, RecursiveMode::NonRecursive).map(|_| watcher));
+
+    let watcher = match watcher {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            println!("⚠️  Failed to watch {}: {}", dir.display(), e);
+            return;
+        }
+    };
+
+    // Replacing the watcher drops its sender, which ends the previous debounce thread
+    *WATCHER.lock().unwrap() = Some(watcher);
+
+    let app = app.clone();
+    std::thread::spawn(move || {
+        while let Ok(first) = receiver.recv() {
+            let mut paths = BTreeSet::from([first]);
+            while let Ok(path) = receiver.recv_timeout(DEBOUNCE) {
+                paths.insert(path);
+            }
+            apply_changes(&app, paths);
+        }
+    });
+
+    println!("👀 Watching configuration in {}", dir.display());
+}
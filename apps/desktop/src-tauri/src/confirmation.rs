@@ -0,0 +1,73 @@
+/**
+ * Confirmation module - Single-use tokens for two-step commands such as placing orders and wiping data
+ *
+ * The first call issues a token bound to exactly what it would do; the second call only proceeds with that
+ * token, unexpired, for the same request.
+ */
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+pub const CONFIRMATION_TTL: Duration = Duration::from_secs(60);
+
+struct Pending<T> {
+    subject: T,
+    issued: Instant,
+}
+
+pub struct Confirmations<T> {
+    pending: HashMap<String, Pending<T>>,
+}
+
+impl<T> Default for Confirmations<T> {
+    fn default() -> Self {
+        Confirmations {
+            pending: HashMap::new(),
+        }
+    }
+}
+
+impl<T: PartialEq> Confirmations<T> {
+    /// Issues a token for `subject`, dropping any that have expired.
+    pub fn issue(&mut self, subject: T) -> String {
+        let token = format!("confirm_{:032x}", rand::random::<u128>());
+        self.pending.retain(|_, pending| pending.issued.elapsed() < CONFIRMATION_TTL);
+        self.pending.insert(token.clone(), Pending { subject, issued: Instant::now() });
+        token
+    }
+
+    /// Redeems `token` for `subject`; `mismatch` is the reason given when it was issued for something else.
+    pub fn redeem(&mut self, token: &str, subject: &T, mismatch: &'static str) -> Result<(), &'static str> {
+        // Tokens are single-use: taken out before checking so a mismatch also burns it
+        match self.pending.remove(token) {
+            None => Err("Unknown or already used confirmation token"),
+            Some(pending) if pending.issued.elapsed() >= CONFIRMATION_TTL => Err("Confirmation token expired"),
+            Some(pending) if pending.subject != *subject => Err(mismatch),
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_redeem_once_for_the_same_subject() {
+        let mut confirmations = Confirmations::default();
+        let token = confirmations.issue(vec!["memory".to_string()]);
+
+        assert_eq!(confirmations.redeem(&token, &vec!["memory".to_string()], "mismatch"), Ok(()));
+        assert!(confirmations.redeem(&token, &vec!["memory".to_string()], "mismatch").is_err());
+    }
+
+    #[test]
+    fn a_mismatch_burns_the_token() {
+        let mut confirmations = Confirmations::default();
+        let token = confirmations.issue(1);
+
+        assert_eq!(confirmations.redeem(&token, &2, "mismatch"), Err("mismatch"));
+        assert!(confirmations.redeem(&token, &1, "mismatch").is_err());
+        assert!(confirmations.redeem("confirm_unknown", &1, "mismatch").is_err());
+    }
+}
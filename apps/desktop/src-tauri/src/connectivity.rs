@@ -0,0 +1,331 @@
+/**
+ * Connectivity module - Watches for lost connectivity and captive portals, and diagnoses unreachable endpoints
+ *
+ * A background probe of a "generate_204" URL tells online, offline, broken DNS and captive portals apart and emits
+ * `network://status-changed` with a plain-language summary whenever that changes. run_network_diagnostics adds
+ * DNS, TCP and TLS/HTTP probes of the JarvisX services (or any named endpoints), reporting the first stage that fails.
+ */
+
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Answers 204 with an empty body; anything else means something on the network intercepted the request
+const PORTAL_PROBE: &str = "http://connectivitycheck.gstatic.com/generate_204";
+/// Names that resolve on any working resolver, for telling DNS failures from no connection at all
+const DNS_PROBE_HOSTS: [&str; 2] = ["connectivitycheck.gstatic.com", "www.apple.com"];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointCheck {
+    pub name: String,
+    pub url: String,
+    pub ok: bool,
+    /// Stage that failed: "dns", "tcp", "tls", "http", "proxy", "timeout" or "blocked"
+    pub failed_stage: Option<String>,
+    pub detail: Option<String>,
+    pub latency_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkDiagnosis {
+    /// "online", "captivePortal", "dnsFailure", "offline" or "degraded"
+    pub status: String,
+    /// Plain-language explanation for the user
+    pub summary: String,
+    /// Where to sign in, when a captive portal redirected the probe
+    pub portal_url: Option<String>,
+    /// Source of the proxy in use ("manual", "system", "environment" or "none")
+    pub proxy: String,
+    pub endpoints: Vec<EndpointCheck>,
+    pub checked_at: i64,
+}
+
+static LAST: Mutex<Option<NetworkDiagnosis>> = Mutex::new(None);
+
+fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// The error and all its sources on one line; reqwest hides the interesting part (certificate, refused) in them.
+fn error_chain(error: &dyn std::error::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        let text = cause.to_string();
+        if !message.contains(&text) {
+            message.push_str(": ");
+            message.push_str(&text);
+        }
+        source = cause.source();
+    }
+    message
+}
+
+fn classify(error: &reqwest::Error, proxied: bool) -> &'static str {
+    let chain = error_chain(error).to_lowercase();
+    if error.is_timeout() {
+        "timeout"
+    } else if ["certificate", "tls", "ssl", "handshake"].iter().any(|needle| chain.contains(needle)) {
+        "tls"
+    } else if proxied && error.is_connect() {
+        "proxy"
+    } else if chain.contains("dns") || chain.contains("failed to lookup") || chain.contains("name or service not known") {
+        "dns"
+    } else if error.is_connect() {
+        "tcp"
+    } else {
+        "http"
+    }
+}
+
+async fn resolves(host: &str, port: u16) -> Result<Vec<std::net::SocketAddr>, String> {
+    match tokio::time::timeout(PROBE_TIMEOUT, tokio::net::lookup_host((host, port))).await {
+        Ok(Ok(addresses)) => {
+            let addresses: Vec<_> = addresses.collect();
+            if addresses.is_empty() {
+                Err(format!("{} has no addresses", host))
+            } else {
+                Ok(addresses)
+            }
+        }
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => Err(format!("Looking up {} timed out", host)),
+    }
+}
+
+enum PortalProbe {
+    Online,
+    Portal(Option<String>),
+    Failed(String),
+}
+
+async fn probe_portal() -> PortalProbe {
+    let url = match reqwest::Url::parse(PORTAL_PROBE) {
+        Ok(url) => url,
+        Err(e) => return PortalProbe::Failed(e.to_string()),
+    };
+    if let Err(e) = crate::egress::check(&url) {
+        return PortalProbe::Failed(e);
+    }
+
+    // Portals answer with a redirect to their login page, which must not be followed
+    let client = match crate::proxy::builder().redirect(reqwest::redirect::Policy::none()).timeout(PROBE_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => return PortalProbe::Failed(e.to_string()),
+    };
+
+    match client.get(url).send().await {
+        Ok(response) if response.status() == reqwest::StatusCode::NO_CONTENT => PortalProbe::Online,
+        Ok(response) if response.status().is_redirection() => PortalProbe::Portal(
+            response.headers().get(reqwest::header::LOCATION).and_then(|location| location.to_str().ok()).map(str::to_string),
+        ),
+        Ok(_) => PortalProbe::Portal(None),
+        Err(e) => PortalProbe::Failed(error_chain(&e)),
+    }
+}
+
+/// Online / captive portal / DNS failure / offline, from the portal probe plus a DNS check when it fails.
+async fn check_internet() -> (String, String, Option<String>) {
+    match probe_portal().await {
+        PortalProbe::Online => ("online".to_string(), "You're online.".to_string(), None),
+        PortalProbe::Portal(portal_url) => {
+            let summary = match &portal_url {
+                Some(url) => format!("This network needs a login before it lets you online (hotel or café Wi-Fi?). Sign in at {}.", url),
+                None => "This network needs a login before it lets you online (hotel or café Wi-Fi?). Open any website in a browser to sign in.".to_string(),
+            };
+            ("captivePortal".to_string(), summary, portal_url)
+        }
+        PortalProbe::Failed(e) => {
+            let mut resolved = false;
+            for host in DNS_PROBE_HOSTS {
+                if resolves(host, 80).await.is_ok() {
+                    resolved = true;
+                    break;
+                }
+            }
+
+            if resolved {
+                // Names resolve but the probe failed: a firewall, proxy or the probe host itself
+                ("degraded".to_string(), format!("The network is up but the connectivity check failed: {}", e), None)
+            } else if local_network_up() {
+                (
+                    "dnsFailure".to_string(),
+                    "You're connected to a network, but it can't look up website names (DNS). Try reconnecting or switching DNS servers.".to_string(),
+                    None,
+                )
+            } else {
+                ("offline".to_string(), "You're offline: no network connection.".to_string(), None)
+            }
+        }
+    }
+}
+
+/// Whether this machine has a non-loopback route out, judged by being able to pick a source address for one.
+fn local_network_up() -> bool {
+    std::net::UdpSocket::bind("0.0.0.0:0")
+        .and_then(|socket| {
+            socket.connect("8.8.8.8:53")?;
+            socket.local_addr()
+        })
+        .map_or(false, |address| !address.ip().is_unspecified() && !address.ip().is_loopback())
+}
+
+/// DNS, then TCP, then an HTTP request (which covers TLS for https URLs); stops at the first failing stage.
+async fn probe_endpoint(name: String, url: String) -> EndpointCheck {
+    let started = Instant::now();
+    let failed = |stage: &str, detail: String| EndpointCheck {
+        name: name.clone(),
+        url: url.clone(),
+        ok: false,
+        failed_stage: Some(stage.to_string()),
+        detail: Some(detail),
+        latency_ms: None,
+    };
+
+    let parsed = match reqwest::Url::parse(&url) {
+        Ok(parsed) => parsed,
+        Err(e) => return failed("http", format!("Invalid URL: {}", e)),
+    };
+    let host = parsed.host_str().unwrap_or_default().to_string();
+    let port = parsed.port_or_known_default().unwrap_or(443);
+    if let Err(e) = crate::egress::check(&parsed) {
+        return failed("blocked", e);
+    }
+
+    // Behind a proxy the proxy resolves and connects, and direct connections are often firewalled
+    let proxied = crate::proxy::proxied(&host);
+    if !proxied {
+        let addresses = match resolves(&host, port).await {
+            Ok(addresses) => addresses,
+            Err(e) => return failed("dns", format!("Can't resolve {}: {}", host, e)),
+        };
+        match tokio::time::timeout(PROBE_TIMEOUT, tokio::net::TcpStream::connect(&addresses[..])).await {
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => return failed("tcp", format!("Can't connect to {}:{}: {}", host, port, e)),
+            Err(_) => return failed("timeout", format!("Connecting to {}:{} timed out", host, port)),
+        }
+    }
+
+    let client = match crate::proxy::builder().timeout(PROBE_TIMEOUT * 2).build() {
+        Ok(client) => client,
+        Err(e) => return failed("http", e.to_string()),
+    };
+    // Any HTTP answer, even an error status, proves the service is reachable
+    match client.get(parsed).send().await {
+        Ok(response) => EndpointCheck {
+            name: name.clone(),
+            url: url.clone(),
+            ok: true,
+            failed_stage: None,
+            detail: Some(format!("HTTP {}", response.status().as_u16())),
+            latency_ms: Some(started.elapsed().as_millis() as u64),
+        },
+        Err(e) => failed(classify(&e, proxied), error_chain(&e)),
+    }
+}
+
+fn default_endpoints() -> Vec<(String, String)> {
+    vec![
+        ("orchestrator".to_string(), crate::auth::api_url()),
+        ("speech-to-text".to_string(), crate::voice::stt_url()),
+        ("text-to-speech".to_string(), crate::speech::tts_url()),
+        ("translation".to_string(), crate::translation::service_url()),
+    ]
+}
+
+fn endpoint_summary(check: &EndpointCheck) -> String {
+    match check.failed_stage.as_deref() {
+        Some("dns") => format!("Can't find {} ({}): its name doesn't resolve.", check.name, check.url),
+        Some("tcp") => format!("{} isn't accepting connections ({}). Is the service running?", check.name, check.url),
+        Some("tls") => format!(
+            "Secure connection to {} failed; a firewall or proxy may be intercepting HTTPS.",
+            check.name
+        ),
+        Some("proxy") => format!("The proxy couldn't reach {}. Check the proxy settings.", check.name),
+        Some("timeout") => format!("{} is too slow to answer.", check.name),
+        Some("blocked") => format!("{} is blocked by the egress policy.", check.name),
+        _ => format!("{} isn't responding.", check.name),
+    }
+}
+
+fn publish(app: &AppHandle, diagnosis: &NetworkDiagnosis) {
+    let mut last = LAST.lock().unwrap();
+    if last.as_ref().map(|last| &last.status) != Some(&diagnosis.status) {
+        println!("🌐 Network: {}", diagnosis.summary);
+        let _ = app.emit_all("network://status-changed", diagnosis.clone());
+    }
+    *last = Some(diagnosis.clone());
+}
+
+async fn quick_check() -> NetworkDiagnosis {
+    let (status, summary, portal_url) = check_internet().await;
+    NetworkDiagnosis {
+        status,
+        summary,
+        portal_url,
+        proxy: crate::proxy::get_proxy_config().await.map(|config| config.source).unwrap_or_default(),
+        endpoints: Vec::new(),
+        checked_at: now_secs(),
+    }
+}
+
+pub fn spawn_connectivity_watcher(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let diagnosis = quick_check().await;
+            publish(&app, &diagnosis);
+            let handle = app.clone();
+            let _ = tauri::async_runtime::spawn_blocking(move || crate::bandwidth::refresh_metered(&handle)).await;
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn get_network_status() -> Result<Option<NetworkDiagnosis>, String> {
+    Ok(LAST.lock().unwrap().clone())
+}
+
+/// Full diagnosis: the internet check plus DNS/TCP/TLS probes of `endpoints` (URLs, or the JarvisX services when
+/// omitted). Emits `network://diagnosis` with the result.
+#[tauri::command]
+pub async fn run_network_diagnostics(app: AppHandle, endpoints: Option<Vec<String>>) -> Result<NetworkDiagnosis, String> {
+    let targets = match endpoints {
+        Some(urls) => urls
+            .into_iter()
+            .map(|url| {
+                let url = if url.contains("://") { url } else { format!("https://{}", url) };
+                let name = reqwest::Url::parse(&url).ok().and_then(|parsed| parsed.host_str().map(str::to_string)).unwrap_or_else(|| url.clone());
+                (name, url)
+            })
+            .collect(),
+        None => default_endpoints(),
+    };
+    println!("🩺 Running network diagnostics for {} endpoints", targets.len());
+
+    let mut diagnosis = quick_check().await;
+    let probes: Vec<_> = targets.into_iter().map(|(name, url)| tauri::async_runtime::spawn(probe_endpoint(name, url))).collect();
+    for probe in probes {
+        diagnosis.endpoints.push(probe.await.map_err(|e| e.to_string())?);
+    }
+
+    let failures: Vec<String> = diagnosis.endpoints.iter().filter(|check| !check.ok).map(endpoint_summary).collect();
+    if diagnosis.status == "online" && !failures.is_empty() {
+        diagnosis.status = "degraded".to_string();
+        diagnosis.summary = format!("You're online, but: {}", failures.join(" "));
+    } else if !failures.is_empty() && diagnosis.status == "degraded" {
+        diagnosis.summary = format!("{} {}", diagnosis.summary, failures.join(" "));
+    }
+
+    publish(&app, &diagnosis);
+    let _ = app.emit_all("network://diagnosis", diagnosis.clone());
+    Ok(diagnosis)
+}
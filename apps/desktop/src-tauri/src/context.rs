@@ -0,0 +1,154 @@
+/**
+ * Context module - One-call desktop snapshot for the AI orchestrator's planning step
+ */
+
+use std::net::{SocketAddr, TcpStream};
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+use crate::watchdog::WatchdogCommand;
+use crate::window_registry::WindowRegistry;
+
+/// Clipboard text beyond this is summarised, not sent whole.
+const CLIPBOARD_PREVIEW_CHARS: usize = 200;
+const PROBE_TIMEOUT: Duration = Duration::from_millis(800);
+
+fn clipboard_summary(content: &str) -> serde_json::Value {
+    let trimmed = content.trim();
+    let kind = if trimmed.is_empty() {
+        "empty"
+    } else if trimmed.starts_with("http://") || trimmed.starts_with("https://") {
+        "url"
+    } else if trimmed.lines().count() > 1 && trimmed.contains(['{', ';', '(']) {
+        "code"
+    } else {
+        "text"
+    };
+
+    serde_json::json!({
+        "kind": kind,
+        "length": content.chars().count(),
+        "preview": trimmed.chars().take(CLIPBOARD_PREVIEW_CHARS).collect::<String>(),
+    })
+}
+
+fn battery_state() -> serde_json::Value {
+    let battery = battery::Manager::new()
+        .ok()
+        .and_then(|manager| manager.batteries().ok()?.next()?.ok());
+
+    match battery {
+        Some(battery) => serde_json::json!({
+            "present": true,
+            "percent": (battery.state_of_charge().value * 100.0).round(),
+            "state": battery.state().to_string(),
+        }),
+        None => serde_json::json!({ "present": false }),
+    }
+}
+
+fn network_state() -> serde_json::Value {
+    // A TCP connect to a public resolver is the cheapest reliable "am I online" probe
+    let online = ["1.1.1.1:53", "8.8.8.8:53"]
+        .iter()
+        .filter_map(|addr| addr.parse::<SocketAddr>().ok())
+        .any(|addr| TcpStream::connect_timeout(&addr, PROBE_TIMEOUT).is_ok());
+
+    serde_json::json!({ "online": online })
+}
+
+#[cfg(target_os = "macos")]
+fn current_media() -> Option<serde_json::Value> {
+    let script = r#"
+        repeat with player in {"Spotify", "Music"}
+            if application player is running then
+                tell application player
+                    if player state is playing then return (name of current track) & "|" & (artist of current track) & "|" & player
+                end tell
+            end if
+        end repeat
+        return ""
+    "#;
+    let output = Command::new("osascript").args(&["-e", script]).output_within(PROBE_TIMEOUT).ok()?;
+    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let mut parts = text.splitn(3, '|');
+
+    Some(serde_json::json!({
+        "title": parts.next().filter(|title| !title.is_empty())?,
+        "artist": parts.next(),
+        "player": parts.next(),
+        "playing": true,
+    }))
+}
+
+#[cfg(target_os = "linux")]
+fn current_media() -> Option<serde_json::Value> {
+    let output = Command::new("playerctl")
+        .args(&["metadata", "--format", "{{status}}|{{title}}|{{artist}}|{{playerName}}"])
+        .output_within(PROBE_TIMEOUT)
+        .ok()
+        .filter(|output| output.status.success())?;
+    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let parts: Vec<&str> = text.splitn(4, '|').collect();
+
+    Some(serde_json::json!({
+        "title": parts.get(1)?,
+        "artist": parts.get(2),
+        "player": parts.get(3),
+        "playing": parts.first() == Some(&"Playing"),
+    }))
+}
+
+#[cfg(target_os = "windows")]
+fn current_media() -> Option<serde_json::Value> {
+    // Media sessions live behind WinRT; the window title of common players is a cheap stand-in
+    let script = "Get-Process Spotify -ErrorAction SilentlyContinue | Where-Object { $_.MainWindowTitle -like '* - *' } | Select-Object -First 1 -ExpandProperty MainWindowTitle";
+    let output = Command::new("powershell").args(&["-NoProfile", "-Command", script]).output_within(PROBE_TIMEOUT).ok()?;
+    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let (artist, title) = text.split_once(" - ")?;
+
+    Some(serde_json::json!({
+        "title": title,
+        "artist": artist,
+        "player": "Spotify",
+        "playing": true,
+    }))
+}
+
+#[tauri::command]
+pub async fn get_context_snapshot(app: AppHandle) -> Result<serde_json::Value, String> {
+    let registry = app.state::<Mutex<WindowRegistry>>();
+
+    let (active_window, windows, clipboard, battery, network, media) = tokio::join!(
+        crate::system::get_active_window(),
+        crate::window_registry::cached_window_list(&registry, false),
+        crate::system::get_clipboard_content(),
+        tauri::async_runtime::spawn_blocking(battery_state),
+        tauri::async_runtime::spawn_blocking(network_state),
+        tauri::async_runtime::spawn_blocking(current_media),
+    );
+
+    let open_windows: Vec<String> = windows
+        .unwrap_or_default()
+        .iter()
+        .map(crate::window_registry::window_title)
+        .filter(|title| !title.is_empty())
+        .collect();
+
+    Ok(serde_json::json!({
+        "timestamp": SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0),
+        "activeWindow": active_window.ok(),
+        "openWindows": open_windows,
+        "clipboard": clipboard.ok().map(|content| clipboard_summary(&crate::redaction::redact(&app, "clipboard", &content))),
+        "battery": battery.ok(),
+        "network": network.ok(),
+        // No cross-platform API exposes other apps' unread notifications
+        "unreadNotifications": serde_json::Value::Null,
+        "media": media.ok().flatten(),
+        "locale": crate::locale::current_locale(),
+        // Lets the planner avoid capture steps that would only fail
+        "screenCaptureGranted": crate::mock_platform::is_active() || crate::screen_permission::screen_capture_access().granted,
+    }))
+}
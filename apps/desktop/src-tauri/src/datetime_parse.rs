@@ -0,0 +1,528 @@
+/**
+ * Date/time parsing module - Natural-language dates and times ("next Poya day", "tomorrow at half past six", "හෙට හවස හයට")
+ *
+ * A rule-based parser so timers and reminders can be set from speech without asking the LLM. It reads relative days and
+ * offsets, weekdays, calendar dates (day/month order follows the locale), clock times in digits or words, parts of the
+ * day, and holidays from the time context calendar, in English and Sinhala. A bare hour like "at six" means the next
+ * six o'clock when no day is given, and an afternoon hour (1-6) when one is.
+ */
+
+use chrono::{DateTime, Datelike, Duration, Local, Months, NaiveDate, TimeZone, Weekday};
+use regex::{Captures, Regex};
+use serde::Serialize;
+use std::sync::OnceLock;
+use tauri::AppHandle;
+
+const ENGLISH_NUMBERS: [&str; 13] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven", "twelve",
+];
+/// Longest first, so "එකොළහ" isn't read as "එක"
+const SINHALA_NUMBERS: [(&str, u32); 12] = [
+    ("එකොළහ", 11), ("දොළහ", 12), ("දහය", 10), ("නවය", 9), ("හතර", 4), ("හත", 7),
+    ("අට", 8), ("හය", 6), ("පහ", 5), ("තුන", 3), ("දෙක", 2), ("එක", 1),
+];
+const WEEKDAYS: [(Weekday, &str, &str); 7] = [
+    (Weekday::Mon, "monday", "සඳුදා"),
+    (Weekday::Tue, "tuesday", "අඟහරුවාදා"),
+    (Weekday::Wed, "wednesday", "බදාදා"),
+    (Weekday::Thu, "thursday", "බ්‍රහස්පතින්දා"),
+    (Weekday::Fri, "friday", "සිකුරාදා"),
+    (Weekday::Sat, "saturday", "සෙනසුරාදා"),
+    (Weekday::Sun, "sunday", "ඉරිදා"),
+];
+const MONTHS: [&str; 12] = [
+    "january", "february", "march", "april", "may", "june", "july", "august", "september", "october", "november", "december",
+];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedDateTime {
+    pub at: DateTime<Local>,
+    pub timestamp_ms: i64,
+    /// A day without a time of day, e.g. "next Poya day"; `at` is its midnight
+    pub all_day: bool,
+    /// Phrases that were understood, in the order they were read
+    pub matched: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Period {
+    Morning,
+    Afternoon,
+    Evening,
+    Night,
+}
+
+impl Period {
+    fn default_hour(self) -> u32 {
+        match self {
+            Period::Morning => 9,
+            Period::Afternoon => 14,
+            Period::Evening => 18,
+            Period::Night => 20,
+        }
+    }
+}
+
+/// A clock time as said; `hour` is 0-23 once am/pm is known, else 1-12 with `ambiguous` set.
+struct Clock {
+    hour: u32,
+    minute: u32,
+    ambiguous: bool,
+}
+
+struct Patterns {
+    offset: Regex,
+    offset_from_now: Regex,
+    sinhala_offset: Regex,
+    iso_date: Regex,
+    day_month: Regex,
+    month_day: Regex,
+    numeric_date: Regex,
+    meridiem_time: Regex,
+    colon_time: Regex,
+    past_to: Regex,
+    oclock: Regex,
+    at_hour: Regex,
+    sinhala_time: Regex,
+}
+
+fn patterns() -> &'static Patterns {
+    static PATTERNS: OnceLock<Patterns> = OnceLock::new();
+    PATTERNS.get_or_init(|| {
+        let number = format!(r"\d{{1,2}}|{}", ENGLISH_NUMBERS.join("|"));
+        let sinhala_number: Vec<&str> = SINHALA_NUMBERS.iter().map(|(word, _)| *word).collect();
+        let abbreviations: Vec<&str> = MONTHS.iter().map(|month| &month[..3]).chain(["sept"]).collect();
+        let month = format!(r"({}|{})\b\.?", MONTHS.join("|"), abbreviations.join("|"));
+        let unit = r"(minute|min|hour|hr|day|week)s?";
+
+        Patterns {
+            offset: Regex::new(&format!(r"\b(?:in|after)\s+(\d+|an?|{})\s+{}\b", number, unit)).unwrap(),
+            offset_from_now: Regex::new(&format!(r"\b(\d+|an?|{})\s+{}\s+from\s+now\b", number, unit)).unwrap(),
+            // "පැය 2කින්" (in 2 hours), "විනාඩි 10කින්" (in 10 minutes)
+            sinhala_offset: Regex::new(r"(පැය|විනාඩි|මිනිත්තු|දවස්|සති)\s*(\d+)\s*(?:ක)?කින්").unwrap(),
+            iso_date: Regex::new(r"\b(\d{4})-(\d{1,2})-(\d{1,2})\b").unwrap(),
+            day_month: Regex::new(&format!(r"\b(\d{{1,2}})(?:st|nd|rd|th)?\s+(?:of\s+)?{}(?:\s+(\d{{4}}))?\b", month)).unwrap(),
+            month_day: Regex::new(&format!(r"\b{}\s+(\d{{1,2}})(?:st|nd|rd|th)?\b(?:,?\s+(\d{{4}})\b)?", month)).unwrap(),
+            numeric_date: Regex::new(r"\b(\d{1,2})/(\d{1,2})(?:/(\d{2,4}))?\b").unwrap(),
+            meridiem_time: Regex::new(r"\b(\d{1,2})(?:[:.](\d{2}))?\s*([ap])\.?m\b\.?").unwrap(),
+            colon_time: Regex::new(r"\b(\d{1,2})[:.](\d{2})\b").unwrap(),
+            past_to: Regex::new(&format!(r"\b(half|quarter|{})(?:\s+minutes?)?\s+(past|to)\s+({})\b", number, number)).unwrap(),
+            oclock: Regex::new(&format!(r"\b({})\s*o'?\s*clock\b", number)).unwrap(),
+            at_hour: Regex::new(&format!(r"\bat\s+({})\b", number)).unwrap(),
+            // "හයට" (at six), "හයහමාරට" (half past six), "හයයි කාලට" (quarter past six), "6.30ට"
+            sinhala_time: Regex::new(&format!(
+                r"(?:^|\s)({}|\d{{1,2}})(?:[.:](\d{{2}}))?\s*(?:යි\s*)?(හමාර|කාල)?\s*ට",
+                sinhala_number.join("|")
+            ))
+            .unwrap(),
+        }
+    })
+}
+
+/// Lowercases and drops zero-width joiners, which Sinhala conjuncts may or may not carry.
+fn normalize(text: &str) -> String {
+    text.to_lowercase().replace('\u{200d}', "")
+}
+
+fn number(word: &str) -> Option<u32> {
+    match word {
+        "a" | "an" => Some(1),
+        "half" => Some(30),
+        "quarter" => Some(15),
+        _ => word
+            .parse()
+            .ok()
+            .or_else(|| ENGLISH_NUMBERS.iter().position(|name| *name == word).map(|index| index as u32))
+            .or_else(|| SINHALA_NUMBERS.iter().find(|(name, _)| *name == word).map(|(_, value)| *value)),
+    }
+}
+
+/// Whole words (or Sinhala words, which `\b` doesn't delimit reliably) contained in `text`.
+fn mentions(text: &str, word: &str) -> bool {
+    let word = normalize(word);
+    if word.is_ascii() {
+        text.split(|c: char| !c.is_alphanumeric() && c != '\'').any(|token| token == word)
+    } else {
+        text.contains(&word)
+    }
+}
+
+fn record(matched: &mut Vec<String>, captures: &Captures) {
+    matched.push(captures[0].trim().to_string());
+}
+
+/// "in 20 minutes", "2 hours from now", "පැය 2කින්"
+fn relative_offset(text: &str, matched: &mut Vec<String>) -> Option<Duration> {
+    let patterns = patterns();
+    if text.contains("half an hour") {
+        matched.push("half an hour".to_string());
+        return Some(Duration::minutes(30));
+    }
+
+    let english = patterns.offset.captures(text).or_else(|| patterns.offset_from_now.captures(text));
+    if let Some(captures) = english {
+        let amount = number(&captures[1])? as i64;
+        record(matched, &captures);
+        return Some(match &captures[2] {
+            "minute" | "min" => Duration::minutes(amount),
+            "hour" | "hr" => Duration::hours(amount),
+            "day" => Duration::days(amount),
+            _ => Duration::weeks(amount),
+        });
+    }
+
+    let captures = patterns.sinhala_offset.captures(text)?;
+    let amount = captures[2].parse::<i64>().ok()?;
+    record(matched, &captures);
+    Some(match &captures[1] {
+        "පැය" => Duration::hours(amount),
+        "දවස්" => Duration::days(amount),
+        "සති" => Duration::weeks(amount),
+        _ => Duration::minutes(amount),
+    })
+}
+
+/// A date with an unspoken year is the next one on or after `today`.
+fn with_year(today: NaiveDate, year: Option<&str>, month: u32, day: u32) -> Option<NaiveDate> {
+    match year.and_then(|year| year.parse::<i32>().ok()) {
+        Some(year) if year < 100 => NaiveDate::from_ymd_opt(2000 + year, month, day),
+        Some(year) => NaiveDate::from_ymd_opt(year, month, day),
+        None => NaiveDate::from_ymd_opt(today.year(), month, day)
+            .filter(|date| *date >= today)
+            .or_else(|| NaiveDate::from_ymd_opt(today.year() + 1, month, day)),
+    }
+}
+
+fn month_number(name: &str) -> u32 {
+    MONTHS.iter().position(|month| month.starts_with(&name[..3])).unwrap_or(0) as u32 + 1
+}
+
+/// First holiday on or after `from` whose name the text mentions, or the next Poya day.
+fn holiday_date(app: &AppHandle, text: &str, today: NaiveDate, matched: &mut Vec<String>) -> Option<NaiveDate> {
+    let next = mentions(text, "next") || mentions(text, "ලබන");
+    let from = if next { today + Duration::days(1) } else { today };
+    let upcoming = |country: &str| {
+        let mut holidays = crate::time_context::holidays(app, country, today.year());
+        holidays.extend(crate::time_context::holidays(app, country, today.year() + 1));
+        holidays.into_iter().filter(move |holiday| holiday.date >= from)
+    };
+
+    if mentions(text, "poya") || mentions(text, "පෝය") {
+        let poya = upcoming("LK").filter(|holiday| holiday.kind == "poya").min_by_key(|holiday| holiday.date)?;
+        matched.push(format!("{} ({})", if next { "next poya" } else { "poya" }, poya.name));
+        return Some(poya.date);
+    }
+
+    // Named by a distinctive first word ("vesak", "christmas", "deepavali"); short words and month names are too ambiguous
+    let country = crate::time_context::home_country(app);
+    let holiday = upcoming(&country)
+        .filter(|holiday| {
+            let word = normalize(holiday.name.split_whitespace().next().unwrap_or(""));
+            word.chars().count() >= 5 && !MONTHS.iter().any(|month| word.starts_with(&month[..3])) && mentions(text, &word)
+        })
+        .min_by_key(|holiday| holiday.date)?;
+    matched.push(holiday.name.clone());
+    Some(holiday.date)
+}
+
+fn explicit_date(app: &AppHandle, text: &str, today: NaiveDate, matched: &mut Vec<String>) -> Option<NaiveDate> {
+    let patterns = patterns();
+
+    let relative_days = [
+        (&["day after tomorrow", "අනිද්දා"][..], 2),
+        (&["tomorrow", "හෙට"][..], 1),
+        (&["yesterday", "ඊයේ"][..], -1),
+        (&["today", "tonight", "අද"][..], 0),
+    ];
+    for (words, days) in relative_days {
+        if let Some(word) = words.iter().find(|word| if word.contains(' ') { text.contains(*word) } else { mentions(text, word) }) {
+            matched.push(word.to_string());
+            return Some(today + Duration::days(days));
+        }
+    }
+
+    if let Some(date) = holiday_date(app, text, today, matched) {
+        return Some(date);
+    }
+
+    if let Some(captures) = patterns.iso_date.captures(text) {
+        let date = NaiveDate::from_ymd_opt(captures[1].parse().ok()?, captures[2].parse().ok()?, captures[3].parse().ok()?);
+        record(matched, &captures);
+        return date;
+    }
+    if let Some(captures) = patterns.day_month.captures(text) {
+        record(matched, &captures);
+        return with_year(today, captures.get(3).map(|m| m.as_str()), month_number(&captures[2]), captures[1].parse().ok()?);
+    }
+    if let Some(captures) = patterns.month_day.captures(text) {
+        record(matched, &captures);
+        return with_year(today, captures.get(3).map(|m| m.as_str()), month_number(&captures[1]), captures[2].parse().ok()?);
+    }
+    if let Some(captures) = patterns.numeric_date.captures(text) {
+        let (first, second): (u32, u32) = (captures[1].parse().ok()?, captures[2].parse().ok()?);
+        // Sri Lanka and the UK write day/month; the US month/day
+        let (month, day) = if crate::locale::current_locale() == "en-US" { (first, second) } else { (second, first) };
+        record(matched, &captures);
+        return with_year(today, captures.get(3).map(|m| m.as_str()), month, day);
+    }
+
+    for (weekday, english, sinhala) in WEEKDAYS {
+        if let Some(word) = [english, sinhala].into_iter().find(|word| mentions(text, word)) {
+            // Always the coming one: "friday" said on a Friday is next week's
+            let ahead = (weekday.num_days_from_monday() as i64 - today.weekday().num_days_from_monday() as i64 - 1).rem_euclid(7) + 1;
+            matched.push(word.to_string());
+            return Some(today + Duration::days(ahead));
+        }
+    }
+
+    if text.contains("next week") || text.contains("ලබන සතියේ") {
+        matched.push("next week".to_string());
+        return Some(today + Duration::weeks(1));
+    }
+    if text.contains("next month") || text.contains("ලබන මාසේ") {
+        matched.push("next month".to_string());
+        return today.checked_add_months(Months::new(1));
+    }
+    None
+}
+
+fn part_of_day(text: &str, matched: &mut Vec<String>) -> Option<Period> {
+    let periods = [
+        (Period::Night, &["tonight", "night", "රෑ", "රාත්රී", "රාත්රියේ"][..]),
+        (Period::Evening, &["evening", "හවස", "සවස"][..]),
+        (Period::Afternoon, &["afternoon", "දවල්", "දහවල්"][..]),
+        (Period::Morning, &["morning", "උදේ", "උදෑසන"][..]),
+    ];
+    periods.into_iter().find_map(|(period, words)| {
+        let word = words.iter().find(|word| mentions(text, word))?;
+        matched.push(word.to_string());
+        Some(period)
+    })
+}
+
+fn clock_time(text: &str, matched: &mut Vec<String>) -> Option<Clock> {
+    let patterns = patterns();
+    let clock = |hour: u32, minute: u32| Clock { hour, minute, ambiguous: (1..=12).contains(&hour) };
+
+    if mentions(text, "noon") || mentions(text, "midday") {
+        matched.push("noon".to_string());
+        return Some(Clock { hour: 12, minute: 0, ambiguous: false });
+    }
+    if mentions(text, "midnight") || mentions(text, "මැදියම") {
+        matched.push("midnight".to_string());
+        // The end of the day, i.e. the next day's 00:00
+        return Some(Clock { hour: 24, minute: 0, ambiguous: false });
+    }
+
+    if let Some(captures) = patterns.meridiem_time.captures(text) {
+        let hour: u32 = captures[1].parse().ok()?;
+        let minute: u32 = captures.get(2).map_or(Some(0), |m| m.as_str().parse().ok())?;
+        record(matched, &captures);
+        return Some(Clock { hour: hour % 12 + if &captures[3] == "p" { 12 } else { 0 }, minute, ambiguous: false });
+    }
+    if let Some(captures) = patterns.past_to.captures(text) {
+        let minutes = number(&captures[1])?;
+        let hour = number(&captures[3])?;
+        record(matched, &captures);
+        return Some(if &captures[2] == "past" {
+            clock(hour, minutes)
+        } else {
+            clock(if hour <= 1 { 12 } else { hour - 1 }, 60u32.checked_sub(minutes)? % 60)
+        });
+    }
+    if let Some(captures) = patterns.colon_time.captures(text) {
+        record(matched, &captures);
+        return Some(clock(captures[1].parse().ok()?, captures[2].parse().ok()?));
+    }
+    if let Some(captures) = patterns.sinhala_time.captures(text) {
+        let hour = number(&captures[1])?;
+        let minute = match captures.get(3).map(|m| m.as_str()) {
+            Some("හමාර") => 30,
+            Some(_) => 15,
+            None => captures.get(2).map_or(Some(0), |m| m.as_str().parse().ok())?,
+        };
+        record(matched, &captures);
+        return Some(clock(hour, minute));
+    }
+    if let Some(captures) = patterns.oclock.captures(text).or_else(|| patterns.at_hour.captures(text)) {
+        record(matched, &captures);
+        return Some(clock(number(&captures[1])?, 0));
+    }
+    None
+}
+
+fn local(date: NaiveDate, hour: u32, minute: u32) -> Result<DateTime<Local>, String> {
+    let (date, hour) = if hour >= 24 { (date + Duration::days(1), hour - 24) } else { (date, hour) };
+    let naive = date.and_hms_opt(hour, minute, 0).ok_or_else(|| format!("❌ Invalid time: {:02}:{:02}", hour, minute))?;
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .ok_or_else(|| format!("❌ {} doesn't exist in the local time zone", naive))
+}
+
+/// Reads the date and time in `text` relative to `reference`.
+pub fn parse(app: &AppHandle, text: &str, reference: DateTime<Local>) -> Result<ParsedDateTime, String> {
+    let text = normalize(text);
+    let today = reference.date_naive();
+    let mut matched = Vec::new();
+    let done = |at: DateTime<Local>, all_day: bool, matched: Vec<String>| ParsedDateTime {
+        timestamp_ms: at.timestamp_millis(),
+        at,
+        all_day,
+        matched,
+    };
+
+    let offset = relative_offset(&text, &mut matched);
+    if let Some(offset) = offset.filter(|offset| *offset < Duration::days(1)) {
+        return Ok(done(reference + offset, false, matched));
+    }
+    let date = match offset {
+        Some(offset) => Some(today + Duration::days(offset.num_days())),
+        None => explicit_date(app, &text, today, &mut matched),
+    };
+    let period = part_of_day(&text, &mut matched);
+    let clock = clock_time(&text, &mut matched);
+
+    let Some(mut clock) = clock else {
+        return match (date, period) {
+            (_, Some(period)) => Ok(done(local(date.unwrap_or(today), period.default_hour(), 0)?, false, matched)),
+            (Some(date), None) => Ok(done(local(date, 0, 0)?, true, matched)),
+            (None, None) => Err(format!("❌ No date or time found in: {}", text)),
+        };
+    };
+
+    if clock.minute >= 60 || clock.hour > 24 {
+        return Err(format!("❌ Invalid time: {:02}:{:02}", clock.hour, clock.minute));
+    }
+    if clock.ambiguous {
+        match period {
+            Some(Period::Morning) => clock.hour %= 12,
+            // "tonight at two" is early the next day
+            Some(Period::Night) if clock.hour <= 4 || clock.hour == 12 => clock.hour = clock.hour % 12 + 24,
+            Some(_) if clock.hour < 12 => clock.hour += 12,
+            Some(_) => {}
+            // "tomorrow at six" is an evening appointment, not a 6 a.m. one
+            None if date.is_some() && clock.hour <= 6 => clock.hour += 12,
+            None if date.is_some() => {}
+            None => {
+                // Whichever of the two readings comes next
+                let morning = local(today, clock.hour % 12, clock.minute)?;
+                let afternoon = local(today, clock.hour % 12 + 12, clock.minute)?;
+                let at = [morning, afternoon]
+                    .into_iter()
+                    .find(|at| *at > reference)
+                    .map_or_else(|| local(today + Duration::days(1), clock.hour % 12, clock.minute), Ok)?;
+                return Ok(done(at, false, matched));
+            }
+        }
+    }
+    let at = local(date.unwrap_or(today), clock.hour, clock.minute)?;
+    // A time alone that has already passed today means tomorrow
+    let at = if date.is_none() && at <= reference { local(today + Duration::days(1), clock.hour, clock.minute)? } else { at };
+    Ok(done(at, false, matched))
+}
+
+/// Parses `text` against `reference` (RFC 3339, default now) into a timestamp.
+#[tauri::command]
+pub async fn parse_datetime(app: AppHandle, text: String, reference: Option<String>) -> Result<ParsedDateTime, String> {
+    let reference = match reference {
+        Some(reference) => DateTime::parse_from_rfc3339(&reference)
+            .map_err(|e| format!("❌ Invalid reference time {}: {}", reference, e))?
+            .with_timezone(&Local),
+        None => Local::now(),
+    };
+    let parsed = tauri::async_runtime::spawn_blocking(move || parse(&app, &text, reference))
+        .await
+        .map_err(|e| e.to_string())??;
+    println!("📅 Parsed {:?} as {}", parsed.matched, parsed.at.to_rfc3339());
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offset(text: &str) -> Option<Duration> {
+        relative_offset(&normalize(text), &mut Vec::new())
+    }
+
+    fn clock(text: &str) -> Option<(u32, u32, bool)> {
+        clock_time(&normalize(text), &mut Vec::new()).map(|clock| (clock.hour, clock.minute, clock.ambiguous))
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn reads_numbers_in_digits_and_words() {
+        assert_eq!(number("7"), Some(7));
+        assert_eq!(number("twelve"), Some(12));
+        assert_eq!(number("an"), Some(1));
+        assert_eq!(number("quarter"), Some(15));
+        assert_eq!(number("එකොළහ"), Some(11));
+        assert_eq!(number("dozen"), None);
+    }
+
+    #[test]
+    fn reads_relative_offsets() {
+        assert_eq!(offset("remind me in 20 minutes"), Some(Duration::minutes(20)));
+        assert_eq!(offset("in an hour"), Some(Duration::hours(1)));
+        assert_eq!(offset("three days from now"), Some(Duration::days(3)));
+        assert_eq!(offset("after 2 weeks"), Some(Duration::weeks(2)));
+        assert_eq!(offset("in half an hour"), Some(Duration::minutes(30)));
+        assert_eq!(offset("පැය 2කින්"), Some(Duration::hours(2)));
+        assert_eq!(offset("විනාඩි 10කින්"), Some(Duration::minutes(10)));
+        assert_eq!(offset("at six"), None);
+    }
+
+    #[test]
+    fn reads_clock_times() {
+        assert_eq!(clock("at 7pm"), Some((19, 0, false)));
+        assert_eq!(clock("12 a.m."), Some((0, 0, false)));
+        assert_eq!(clock("6:45"), Some((6, 45, true)));
+        assert_eq!(clock("half past six"), Some((6, 30, true)));
+        assert_eq!(clock("quarter to one"), Some((12, 45, true)));
+        assert_eq!(clock("ten minutes to 8"), Some((7, 50, true)));
+        assert_eq!(clock("at noon"), Some((12, 0, false)));
+        assert_eq!(clock("midnight"), Some((24, 0, false)));
+        assert_eq!(clock("five o'clock"), Some((5, 0, true)));
+        assert_eq!(clock("at 9"), Some((9, 0, true)));
+        assert_eq!(clock("tomorrow"), None);
+    }
+
+    #[test]
+    fn reads_sinhala_clock_times() {
+        assert_eq!(clock("හෙට හවස හයට"), Some((6, 0, true)));
+        assert_eq!(clock("හයහමාරට"), Some((6, 30, true)));
+        assert_eq!(clock("හයයි කාලට"), Some((6, 15, true)));
+        assert_eq!(clock("6.30ට"), Some((6, 30, true)));
+    }
+
+    #[test]
+    fn reads_parts_of_the_day() {
+        assert_eq!(part_of_day("tomorrow morning", &mut Vec::new()), Some(Period::Morning));
+        assert_eq!(part_of_day("tonight at ten", &mut Vec::new()), Some(Period::Night));
+        assert_eq!(part_of_day("හෙට හවස", &mut Vec::new()), Some(Period::Evening));
+        // Whole words only
+        assert_eq!(part_of_day("nightly backup", &mut Vec::new()), None);
+    }
+
+    #[test]
+    fn fills_in_the_next_occurrence_of_a_date_without_a_year() {
+        let today = date(2026, 10, 16);
+        assert_eq!(with_year(today, None, 12, 25), Some(date(2026, 12, 25)));
+        assert_eq!(with_year(today, None, 10, 16), Some(date(2026, 10, 16)));
+        assert_eq!(with_year(today, None, 3, 1), Some(date(2027, 3, 1)));
+        assert_eq!(with_year(today, Some("25"), 3, 1), Some(date(2025, 3, 1)));
+        assert_eq!(with_year(today, Some("2030"), 2, 30), None);
+    }
+
+    #[test]
+    fn resolves_month_names_and_abbreviations() {
+        assert_eq!(month_number("january"), 1);
+        assert_eq!(month_number("sept"), 9);
+        assert_eq!(month_number("dec"), 12);
+    }
+}
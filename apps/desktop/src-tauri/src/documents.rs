@@ -0,0 +1,99 @@
+/**
+ * Documents module - Local text extraction from PDF, DOCX and plain text files
+ */
+
+use std::path::Path;
+
+fn extract_docx_text(bytes: &[u8]) -> Result<String, String> {
+    use docx_rs::{DocumentChild, ParagraphChild, RunChild};
+
+    let docx = docx_rs::read_docx(bytes).map_err(|e| e.to_string())?;
+    let mut paragraphs = Vec::new();
+
+    for child in docx.document.children.iter() {
+        if let DocumentChild::Paragraph(paragraph) = child {
+            let mut line = String::new();
+            for paragraph_child in paragraph.children.iter() {
+                if let ParagraphChild::Run(run) = paragraph_child {
+                    for run_child in run.children.iter() {
+                        match run_child {
+                            RunChild::Text(text) => line.push_str(&text.text),
+                            RunChild::Tab(_) => line.push('\t'),
+                            RunChild::Break(_) => line.push('\n'),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            paragraphs.push(line);
+        }
+    }
+
+    Ok(paragraphs.join("\n"))
+}
+
+/// Resolves a 1-based inclusive page range against the page count.
+fn page_bounds(page_count: usize, start_page: Option<usize>, end_page: Option<usize>) -> Result<(usize, usize), String> {
+    let start = start_page.unwrap_or(1).max(1);
+    let end = end_page.unwrap_or(page_count).min(page_count);
+
+    if start > end {
+        return Err(format!("Invalid page range {}-{} for {} pages", start, end, page_count));
+    }
+
+    Ok((start, end))
+}
+
+#[tauri::command]
+pub async fn extract_document_text(
+    path: String,
+    start_page: Option<usize>,
+    end_page: Option<usize>,
+) -> Result<serde_json::Value, String> {
+    println!("📄 Extracting text from: {}", path);
+
+    let file_path = Path::new(&path);
+    let metadata = std::fs::metadata(file_path).map_err(|e| e.to_string())?;
+    let extension = file_path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_default();
+
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs());
+
+    let (format, text, page_count, pages) = match extension.as_str() {
+        "pdf" => {
+            let all_pages = pdf_extract::extract_text_by_pages(file_path).map_err(|e| e.to_string())?;
+            let page_count = all_pages.len();
+            let (start, end) = page_bounds(page_count, start_page, end_page)?;
+            let text = all_pages[start - 1..end].join("\n\n");
+            ("pdf", text, Some(page_count), Some(serde_json::json!({ "start": start, "end": end })))
+        }
+        "docx" => {
+            let bytes = std::fs::read(file_path).map_err(|e| e.to_string())?;
+            ("docx", extract_docx_text(&bytes)?, None, None)
+        }
+        "txt" | "md" | "text" | "log" | "csv" => {
+            let bytes = std::fs::read(file_path).map_err(|e| e.to_string())?;
+            ("txt", String::from_utf8_lossy(&bytes).to_string(), None, None)
+        }
+        _ => return Err(format!("Unsupported document type: {}", extension)),
+    };
+
+    Ok(serde_json::json!({
+        "path": path,
+        "format": format,
+        "text": text,
+        "characterCount": text.chars().count(),
+        "wordCount": text.split_whitespace().count(),
+        "pageCount": page_count,
+        "pages": pages,
+        "sizeBytes": metadata.len(),
+        "modified": modified,
+    }))
+}
@@ -0,0 +1,335 @@
+/**
+ * Downloads module - Watches the Downloads folder and organizes new files
+ *
+ * New files are only reported once their size has stopped changing and the browser's partial file is gone,
+ * so a download is announced once, complete, however the browser names and renames it along the way.
+ */
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+
+use crate::watchdog::WatchdogCommand;
+
+/// How often pending files are re-measured; a file is complete once two measurements agree
+const SETTLE_INTERVAL: Duration = Duration::from_secs(1);
+const PARTIAL_SUFFIXES: &[&str] = &[".crdownload", ".part", ".download", ".tmp"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizeRule {
+    pub category: Option<String>,
+    pub extension: Option<String>,
+    pub name_contains: Option<String>,
+    pub destination: String,
+}
+
+pub struct DownloadsState {
+    pub watcher: Option<RecommendedWatcher>,
+    pub rules: Vec<OrganizeRule>,
+}
+
+impl Default for DownloadsState {
+    fn default() -> Self {
+        DownloadsState {
+            watcher: None,
+            rules: Vec::new(),
+        }
+    }
+}
+
+/// Buckets a file into installer, document, image, archive or other by extension.
+pub fn classify_download(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "dmg" | "pkg" | "exe" | "msi" | "deb" | "rpm" | "appimage" => "installer",
+        "pdf" | "doc" | "docx" | "txt" | "md" | "rtf" | "odt" | "xls" | "xlsx" | "csv" | "ppt" | "pptx" => "document",
+        "png" | "jpg" | "jpeg" | "gif" | "webp" | "bmp" | "svg" | "heic" => "image",
+        "zip" | "tar" | "gz" | "tgz" | "7z" | "rar" | "bz2" | "xz" => "archive",
+        _ => "other",
+    }
+}
+
+fn suggested_actions(category: &str) -> Vec<&'static str> {
+    match category {
+        "installer" => vec!["open", "reveal", "delete"],
+        "archive" => vec!["extract", "move", "reveal"],
+        _ => vec!["open", "move", "reveal"],
+    }
+}
+
+fn is_partial_download(path: &Path) -> bool {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    name.starts_with('.') || PARTIAL_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+}
+
+/// Firefox creates the final name empty and writes to `<name>.part` next to it until the download finishes.
+fn has_partial_sibling(path: &Path) -> bool {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    PARTIAL_SUFFIXES.iter().any(|suffix| path.with_file_name(format!("{}{}", name, suffix)).exists())
+}
+
+fn rule_matches(rule: &OrganizeRule, path: &Path, category: &str) -> bool {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("").to_lowercase();
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_default();
+
+    rule.category.as_ref().map_or(true, |c| c.eq_ignore_ascii_case(category))
+        && rule.extension.as_ref().map_or(true, |e| e.trim_start_matches('.').eq_ignore_ascii_case(&extension))
+        && rule.name_contains.as_ref().map_or(true, |n| name.contains(&n.to_lowercase()))
+        && (rule.category.is_some() || rule.extension.is_some() || rule.name_contains.is_some())
+}
+
+/// Moves a file into a directory, creating it if needed, and returns the new path.
+pub fn move_into(path: &Path, destination: &str) -> Result<PathBuf, String> {
+    let dest_dir = PathBuf::from(destination);
+    std::fs::create_dir_all(&dest_dir).map_err(|e| e.to_string())?;
+
+    let file_name = path.file_name().ok_or("Invalid file path")?;
+    let target = dest_dir.join(file_name);
+    if target.exists() {
+        return Err(format!("❌ Destination already exists: {}", target.display()));
+    }
+
+    // rename fails across volumes, so fall back to copy + remove
+    if std::fs::rename(path, &target).is_err() {
+        std::fs::copy(path, &target).map_err(|e| e.to_string())?;
+        std::fs::remove_file(path).map_err(|e| e.to_string())?;
+    }
+
+    Ok(target)
+}
+
+/// Opens a file or folder with the default handler, or reveals it in the file manager.
+pub fn open_path(path: &Path, reveal: bool) -> Result<(), String> {
+    use std::process::Command;
+
+    #[cfg(target_os = "macos")]
+    let output = if reveal {
+        Command::new("open").arg("-R").arg(path).output_with_timeout()
+    } else {
+        Command::new("open").arg(path).output_with_timeout()
+    };
+
+    #[cfg(target_os = "windows")]
+    let output = if reveal {
+        Command::new("explorer").arg(format!("/select,{}", path.display())).output_with_timeout()
+    } else {
+        Command::new("cmd").args(&["/C", "start", ""]).arg(path).output_with_timeout()
+    };
+
+    #[cfg(target_os = "linux")]
+    let output = if reveal {
+        Command::new("xdg-open").arg(path.parent().unwrap_or(path)).output_with_timeout()
+    } else {
+        Command::new("xdg-open").arg(path).output_with_timeout()
+    };
+
+    let output = output?;
+
+    // explorer.exe reports a non-zero status even on success
+    if output.status.success() || (cfg!(target_os = "windows") && reveal) {
+        Ok(())
+    } else {
+        Err(format!("❌ Failed to open: {}", path.display()))
+    }
+}
+
+fn handle_new_download(app: &AppHandle, path: PathBuf) {
+    if !path.is_file() || is_partial_download(&path) {
+        return;
+    }
+
+    let category = classify_download(&path);
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("").to_string();
+    println!("⬇️  New download: {} ({})", name, category);
+
+    let rule = {
+        let state = app.state::<Mutex<DownloadsState>>();
+        let downloads_state = state.lock().unwrap();
+        downloads_state
+            .rules
+            .iter()
+            .find(|rule| rule_matches(rule, &path, category))
+            .cloned()
+    };
+
+    if let Some(rule) = rule {
+        match move_into(&path, &rule.destination) {
+            Ok(target) => {
+                let _ = app.emit_all("download-organized", serde_json::json!({
+                    "name": name,
+                    "category": category,
+                    "from": path.display().to_string(),
+                    "to": target.display().to_string(),
+                }));
+                return;
+            }
+            Err(e) => println!("⚠️  Auto-organize failed for {}: {}", name, e),
+        }
+    }
+
+    crate::system::show_notification(app, &crate::locale::t("download.complete.title"), &format!("{} ({})", name, category));
+
+    let _ = app.emit_all("download-detected", serde_json::json!({
+        "path": path.display().to_string(),
+        "name": name,
+        "category": category,
+        "suggestedActions": suggested_actions(category),
+    }));
+}
+
+/// Hands pending files to `handle_new_download` once their size is stable; exits when the watcher is dropped.
+fn spawn_settle_thread(app: AppHandle, pending: Arc<Mutex<HashMap<PathBuf, Option<u64>>>>) {
+    std::thread::spawn(move || {
+        // The watcher's callback holds the only other reference
+        while Arc::strong_count(&pending) > 1 {
+            std::thread::sleep(SETTLE_INTERVAL);
+
+            let mut ready = Vec::new();
+            pending.lock().unwrap().retain(|path, last_size| {
+                match std::fs::metadata(path).ok().filter(|metadata| metadata.is_file()).map(|metadata| metadata.len()) {
+                    // Renamed or removed; the new name arrives as its own event
+                    None => false,
+                    Some(size) if *last_size == Some(size) && size > 0 && !has_partial_sibling(path) => {
+                        ready.push(path.clone());
+                        false
+                    }
+                    Some(size) => {
+                        *last_size = Some(size);
+                        true
+                    }
+                }
+            });
+
+            for path in ready {
+                handle_new_download(&app, path);
+            }
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn start_downloads_watcher(
+    app: AppHandle,
+    state: State<'_, Mutex<DownloadsState>>,
+) -> Result<String, String> {
+    let mut downloads_state = state.lock().unwrap();
+
+    if downloads_state.watcher.is_some() {
+        return Err("Downloads watcher already running".to_string());
+    }
+
+    let downloads_dir = tauri::api::path::download_dir().ok_or("Downloads folder not found")?;
+    let pending: Arc<Mutex<HashMap<PathBuf, Option<u64>>>> = Arc::new(Mutex::new(HashMap::new()));
+    let queue = pending.clone();
+
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+        if let Ok(event) = result {
+            // Browsers usually write to a temp name and rename when finished
+            if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(notify::event::ModifyKind::Name(_))) {
+                let mut queue = queue.lock().unwrap();
+                for path in event.paths.into_iter().filter(|path| !is_partial_download(path)) {
+                    queue.entry(path).or_insert(None);
+                }
+            }
+        }
+    })
+    .map_err(|e| e.to_string())?;
+
+    watcher
+        .watch(&downloads_dir, RecursiveMode::NonRecursive)
+        .map_err(|e| e.to_string())?;
+
+    downloads_state.watcher = Some(watcher);
+    spawn_settle_thread(app.clone(), pending);
+    println!("⬇️  Watching downloads: {}", downloads_dir.display());
+
+    Ok(format!("Watching {}", downloads_dir.display()))
+}
+
+#[tauri::command]
+pub async fn stop_downloads_watcher(state: State<'_, Mutex<DownloadsState>>) -> Result<String, String> {
+    let mut downloads_state = state.lock().unwrap();
+
+    if downloads_state.watcher.take().is_none() {
+        return Err("Downloads watcher not running".to_string());
+    }
+
+    println!("⬇️  Downloads watcher stopped");
+    Ok("Downloads watcher stopped".to_string())
+}
+
+#[tauri::command]
+pub async fn set_download_rules(
+    state: State<'_, Mutex<DownloadsState>>,
+    rules: Vec<OrganizeRule>,
+) -> Result<usize, String> {
+    let mut downloads_state = state.lock().unwrap();
+    downloads_state.rules = rules;
+    Ok(downloads_state.rules.len())
+}
+
+#[tauri::command]
+pub async fn get_download_rules(state: State<'_, Mutex<DownloadsState>>) -> Result<Vec<OrganizeRule>, String> {
+    Ok(state.lock().unwrap().rules.clone())
+}
+
+/// Acts on a file in the Downloads folder; paths elsewhere are refused and deleting needs supervised mode.
+#[tauri::command]
+pub async fn perform_download_action(
+    app: AppHandle,
+    path: String,
+    action: String,
+    destination: Option<String>,
+) -> Result<String, String> {
+    let downloads_dir = tauri::api::path::download_dir()
+        .and_then(|dir| dir.canonicalize().ok())
+        .ok_or("Downloads folder not found")?;
+    let file_path = PathBuf::from(&path).canonicalize().map_err(|_| format!("❌ File not found: {}", path))?;
+    if !file_path.starts_with(&downloads_dir) {
+        return Err(format!("❌ Not in the Downloads folder: {}", path));
+    }
+
+    match action.as_str() {
+        "open" => {
+            open_path(&file_path, false)?;
+            Ok(format!("✅ Opened: {}", path))
+        }
+        "reveal" => {
+            open_path(&file_path, true)?;
+            Ok(format!("✅ Revealed: {}", path))
+        }
+        "move" => {
+            let destination = destination.ok_or("Destination required for move")?;
+            let target = move_into(&file_path, &destination)?;
+            Ok(format!("✅ Moved to: {}", target.display()))
+        }
+        "extract" => {
+            let dest = destination
+                .map(PathBuf::from)
+                .unwrap_or_else(|| file_path.with_extension(""));
+            let count = crate::archive::extract_archive_to(&app, &file_path, &dest)?;
+            Ok(format!("✅ Extracted {} entries to: {}", count, dest.display()))
+        }
+        "delete" => {
+            let details = serde_json::json!({ "action": "delete", "path": path });
+            crate::supervised::require_supervised(&app, "perform_download_action", details)?;
+            std::fs::remove_file(&file_path).map_err(|e| e.to_string())?;
+            Ok(format!("✅ Deleted: {}", path))
+        }
+        _ => Err(format!("Unsupported download action: {}", action)),
+    }
+}
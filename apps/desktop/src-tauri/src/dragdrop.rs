@@ -0,0 +1,179 @@
+/**
+ * Drag and drop module - Native file drags into other applications' windows
+ */
+
+use enigo::{Button, Coordinate, Direction, Enigo, Mouse, Settings};
+use std::path::PathBuf;
+use std::time::Duration;
+use tauri::Window;
+
+use crate::commands::interpolate_path;
+use crate::watchdog::WatchdogCommand;
+
+/// Screen bounds (x, y, width, height) of the first window whose title contains `title`.
+pub fn find_window_bounds(title: &str) -> Result<(i32, i32, i32, i32), String> {
+    use std::process::Command;
+
+    #[cfg(target_os = "macos")]
+    let output = Command::new("osascript")
+        .arg("-e")
+        .arg(format!(
+            "tell application \"System Events\" to tell (first process whose name contains \"{0}\") to get {{position, size}} of window 1",
+            title
+        ))
+        .output_with_timeout();
+
+    #[cfg(target_os = "windows")]
+    let output = Command::new("powershell")
+        .args(&["-Command", &format!(
+            "Add-Type 'using System;using System.Runtime.InteropServices;public struct R{{public int L,T,Ri,B;}}public class W{{[DllImport(\"user32.dll\")]public static extern bool GetWindowRect(IntPtr h,out R r);}}'; \
+             $p = Get-Process | Where-Object {{$_.MainWindowTitle -like \"*{}*\"}} | Select-Object -First 1; \
+             $r = New-Object R; [W]::GetWindowRect($p.MainWindowHandle, [ref]$r) | Out-Null; \
+             \"$($r.L), $($r.T), $($r.Ri - $r.L), $($r.B - $r.T)\"",
+            title
+        )])
+        .output_with_timeout();
+
+    #[cfg(target_os = "linux")]
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg("xdotool search --onlyvisible --name \"$1\" | head -n1 | xargs -r xdotool getwindowgeometry --shell")
+        .arg("sh")
+        .arg(title)
+        .output_with_timeout();
+
+    let output = output?;
+    if !output.status.success() {
+        return Err(format!("❌ Window not found: {}", title));
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+
+    // xdotool prints KEY=VALUE lines; the others print "x, y, w, h"
+    let numbers: Vec<i32> = if text.contains('=') {
+        ["X=", "Y=", "WIDTH=", "HEIGHT="]
+            .iter()
+            .filter_map(|key| text.lines().find_map(|line| line.strip_prefix(key)))
+            .filter_map(|value| value.trim().parse().ok())
+            .collect()
+    } else {
+        text.split(',').filter_map(|part| part.trim().parse().ok()).collect()
+    };
+
+    match numbers.as_slice() {
+        [x, y, w, h] if *w > 0 && *h > 0 => Ok((*x, *y, *w, *h)),
+        _ => Err(format!("❌ Window not found: {}", title)),
+    }
+}
+
+fn glide(enigo: &mut Enigo, from: (i32, i32), to: (i32, i32)) -> Result<(), String> {
+    for (x, y) in interpolate_path(from, to, 30, |t| t) {
+        let target = crate::calibration::correct(x, y);
+        enigo.move_mouse(target.0, target.1, Coordinate::Abs).map_err(|e| e.to_string())?;
+        std::thread::sleep(Duration::from_millis(15));
+    }
+    Ok(())
+}
+
+/// Starts an OS drag session for the file from our own window, then carries it to the drop point.
+fn native_file_drag(window: &Window, path: PathBuf, drop_point: (i32, i32)) -> Result<(), String> {
+    let position = window.outer_position().map_err(|e| e.to_string())?;
+    let size = window.outer_size().map_err(|e| e.to_string())?;
+    let grab = (position.x + size.width as i32 / 2, position.y + size.height as i32 / 2);
+
+    let _injection = crate::automation_guard::injecting();
+    let mut enigo = Enigo::new(&Settings::default()).map_err(|e| e.to_string())?;
+    let target = crate::calibration::correct(grab.0, grab.1);
+    enigo.move_mouse(target.0, target.1, Coordinate::Abs).map_err(|e| e.to_string())?;
+    crate::recovery::track("input:drag", "input", serde_json::json!({ "button": "left" }));
+    enigo.button(Button::Left, Direction::Press).map_err(|e| e.to_string())?;
+
+    // The drag session has to be started on the UI thread while the button is down
+    let (tx, rx) = std::sync::mpsc::channel();
+    let drag_window = window.clone();
+    let started = window.run_on_main_thread(move || {
+        let result = drag::start_drag(
+            &drag_window,
+            drag::DragItem::Files(vec![path]),
+            drag::Image::Raw(Vec::new()),
+            |_, _| {},
+            drag::Options::default(),
+        );
+        let _ = tx.send(result.map_err(|e| e.to_string()));
+    });
+
+    let started = started
+        .map_err(|e| e.to_string())
+        .and_then(|_| rx.recv_timeout(Duration::from_secs(2)).map_err(|e| e.to_string())?);
+
+    if let Err(e) = started {
+        if enigo.button(Button::Left, Direction::Release).is_ok() {
+            crate::recovery::untrack("input:drag");
+        }
+        return Err(e);
+    }
+
+    let result = glide(&mut enigo, grab, drop_point);
+    std::thread::sleep(Duration::from_millis(150));
+    enigo.button(Button::Left, Direction::Release).map_err(|e| e.to_string())?;
+    crate::recovery::untrack("input:drag");
+    result
+}
+
+/// Plain coordinate drag, e.g. from a file icon visible in a file manager.
+fn coordinate_drag(from: (i32, i32), to: (i32, i32)) -> Result<(), String> {
+    let _injection = crate::automation_guard::injecting();
+    let mut enigo = Enigo::new(&Settings::default()).map_err(|e| e.to_string())?;
+    let target = crate::calibration::correct(from.0, from.1);
+    enigo.move_mouse(target.0, target.1, Coordinate::Abs).map_err(|e| e.to_string())?;
+    crate::recovery::track("input:drag", "input", serde_json::json!({ "button": "left" }));
+    enigo.button(Button::Left, Direction::Press).map_err(|e| e.to_string())?;
+    let result = glide(&mut enigo, from, to);
+    std::thread::sleep(Duration::from_millis(150));
+    enigo.button(Button::Left, Direction::Release).map_err(|e| e.to_string())?;
+    crate::recovery::untrack("input:drag");
+    result
+}
+
+#[tauri::command]
+pub async fn drag_file_to_window(
+    window: Window,
+    path: String,
+    target_window: String,
+    drop_x: Option<i32>,
+    drop_y: Option<i32>,
+    source_x: Option<i32>,
+    source_y: Option<i32>,
+) -> Result<String, String> {
+    let file = PathBuf::from(&path);
+    if !file.exists() {
+        return Err(format!("❌ File not found: {}", path));
+    }
+
+    let (x, y, width, height) = find_window_bounds(&target_window)?;
+
+    // Drop offsets are relative to the target window; default to its centre
+    let drop_point = (x + drop_x.unwrap_or(width / 2), y + drop_y.unwrap_or(height / 2));
+
+    println!("🫳 Dragging {} to {} at {:?}", path, target_window, drop_point);
+
+    let native = {
+        let window = window.clone();
+        let file = file.clone();
+        tauri::async_runtime::spawn_blocking(move || native_file_drag(&window, file, drop_point))
+            .await
+            .map_err(|e| e.to_string())?
+    };
+
+    match (native, source_x, source_y) {
+        (Ok(()), _, _) => Ok(format!("✅ Dropped {} onto {}", path, target_window)),
+        (Err(e), Some(sx), Some(sy)) => {
+            println!("⚠️  Native drag failed ({}), falling back to coordinate drag", e);
+            tauri::async_runtime::spawn_blocking(move || coordinate_drag((sx, sy), drop_point))
+                .await
+                .map_err(|e| e.to_string())??;
+            Ok(format!("✅ Dragged to {} via coordinates", target_window))
+        }
+        (Err(e), _, _) => Err(format!("❌ Drag failed: {}", e)),
+    }
+}
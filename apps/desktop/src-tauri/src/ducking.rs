@@ -0,0 +1,283 @@
+/**
+ * Ducking module - Lowers other applications' audio while JarvisX speaks or listens, and restores it afterwards
+ *
+ * Windows ducks each WASAPI session and Linux each PulseAudio/PipeWire sink input, leaving JarvisX's own stream alone.
+ * macOS has no per-app mixer, so it lowers the output volume, and only while listening (ducking speech would quiet
+ * the reply too).
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{Mutex, RwLock};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioDuckingPolicy {
+    pub enabled: bool,
+    /// Fraction (0..1) of their volume other apps keep while ducked
+    pub level: f32,
+    pub during_speech: bool,
+    pub during_listening: bool,
+}
+
+impl Default for AudioDuckingPolicy {
+    fn default() -> Self {
+        DEFAULT_POLICY
+    }
+}
+
+const DEFAULT_POLICY: AudioDuckingPolicy = AudioDuckingPolicy {
+    enabled: true,
+    level: 0.25,
+    during_speech: true,
+    during_listening: true,
+};
+
+struct DuckState {
+    /// Active reasons ("speech", "listening") with how many callers hold each
+    holders: BTreeMap<&'static str, usize>,
+    /// Volumes to restore, by platform session id
+    saved: Vec<(String, f32)>,
+}
+
+/// Saved volumes are pactl percentages on Linux and WASAPI scalars elsewhere
+#[cfg(target_os = "linux")]
+const SAVED_SCALE: f32 = 100.0;
+#[cfg(not(target_os = "linux"))]
+const SAVED_SCALE: f32 = 1.0;
+
+static POLICY: RwLock<AudioDuckingPolicy> = RwLock::new(DEFAULT_POLICY);
+static STATE: Mutex<DuckState> = Mutex::new(DuckState { holders: BTreeMap::new(), saved: Vec::new() });
+
+fn applies_to(policy: &AudioDuckingPolicy, reason: &str) -> bool {
+    if !policy.enabled {
+        return false;
+    }
+    match reason {
+        "speech" => policy.during_speech && cfg!(not(target_os = "macos")),
+        "listening" => policy.during_listening,
+        _ => false,
+    }
+}
+
+/// Runs `f` on every other process's WASAPI session on the default output, with the owning process id.
+#[cfg(target_os = "windows")]
+pub fn for_each_session(
+    mut f: impl FnMut(String, &windows::Win32::Media::Audio::IAudioSessionControl, &windows::Win32::Media::Audio::ISimpleAudioVolume) -> windows::core::Result<()>,
+) -> Result<(), String> {
+    use windows::core::ComInterface;
+    use windows::Win32::Media::Audio::{
+        eMultimedia, eRender, IAudioSessionControl2, IAudioSessionManager2, IMMDeviceEnumerator, ISimpleAudioVolume, MMDeviceEnumerator,
+    };
+    use windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CLSCTX_ALL, COINIT_MULTITHREADED};
+
+    let result: windows::core::Result<()> = unsafe {
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        let enumerator: IMMDeviceEnumerator = CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
+        let device = enumerator.GetDefaultAudioEndpoint(eRender, eMultimedia)?;
+        let manager: IAudioSessionManager2 = device.Activate(CLSCTX_ALL, None)?;
+        let sessions = manager.GetSessionEnumerator()?;
+
+        for index in 0..sessions.GetCount()? {
+            let control = sessions.GetSession(index)?;
+            let pid = control.cast::<IAudioSessionControl2>()?.GetProcessId().unwrap_or(0);
+            // Skip the system sounds session and our own playback
+            if pid == 0 || pid == std::process::id() {
+                continue;
+            }
+            f(pid.to_string(), &control, &control.cast::<ISimpleAudioVolume>()?)?;
+        }
+        Ok(())
+    };
+    result.map_err(|e| e.to_string())
+}
+
+#[cfg(target_os = "windows")]
+fn duck_platform(level: f32) -> Result<Vec<(String, f32)>, String> {
+    let mut saved = Vec::new();
+    for_each_session(|id, _, volume| unsafe {
+        let current = volume.GetMasterVolume()?;
+        volume.SetMasterVolume(current * level, std::ptr::null())?;
+        saved.push((id, current));
+        Ok(())
+    })?;
+    Ok(saved)
+}
+
+#[cfg(target_os = "windows")]
+fn restore_platform(saved: &[(String, f32)]) -> Result<(), String> {
+    for_each_session(|id, _, volume| unsafe {
+        if let Some((_, original)) = saved.iter().find(|(saved_id, _)| *saved_id == id) {
+            volume.SetMasterVolume(*original, std::ptr::null())?;
+        }
+        Ok(())
+    })
+}
+
+/// Sink inputs of other processes as (index, volume percent).
+#[cfg(target_os = "linux")]
+fn sink_inputs() -> Result<Vec<(String, f32)>, String> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let output = Command::new("pactl").args(&["list", "sink-inputs"]).output_with_timeout()?;
+    if !output.status.success() {
+        return Err("pactl is not available".to_string());
+    }
+
+    let own_pid = format!("application.process.id = \"{}\"", std::process::id());
+    let mut inputs = Vec::new();
+    for block in String::from_utf8_lossy(&output.stdout).split("Sink Input #").skip(1) {
+        if block.contains(&own_pid) {
+            continue;
+        }
+        let index = block.lines().next().unwrap_or("").trim().to_string();
+        let percent = block
+            .lines()
+            .find(|line| line.trim_start().starts_with("Volume:"))
+            .and_then(|line| line.split('/').nth(1))
+            .and_then(|percent| percent.trim().trim_end_matches('%').parse::<f32>().ok());
+        if let Some(percent) = percent {
+            inputs.push((index, percent));
+        }
+    }
+    Ok(inputs)
+}
+
+#[cfg(target_os = "linux")]
+fn set_sink_input_volume(index: &str, percent: f32) {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let _ = Command::new("pactl")
+        .args(&["set-sink-input-volume", index, &format!("{}%", percent.round() as u32)])
+        .output_with_timeout();
+}
+
+#[cfg(target_os = "linux")]
+fn duck_platform(level: f32) -> Result<Vec<(String, f32)>, String> {
+    let inputs = sink_inputs()?;
+    for (index, percent) in &inputs {
+        set_sink_input_volume(index, percent * level);
+    }
+    Ok(inputs)
+}
+
+#[cfg(target_os = "linux")]
+fn restore_platform(saved: &[(String, f32)]) -> Result<(), String> {
+    // Streams that ended while ducked are simply gone
+    for (index, percent) in saved {
+        set_sink_input_volume(index, *percent);
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn duck_platform(level: f32) -> Result<Vec<(String, f32)>, String> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let output = Command::new("osascript")
+        .args(&["-e", "output volume of (get volume settings)"])
+        .output_with_timeout()?;
+    let current: f32 = String::from_utf8_lossy(&output.stdout).trim().parse().map_err(|_| "Could not read the output volume".to_string())?;
+
+    Command::new("osascript")
+        .args(&["-e", &format!("set volume output volume {}", (current * level).round() as u32)])
+        .output_with_timeout()?;
+    Ok(vec![("output".to_string(), current)])
+}
+
+#[cfg(target_os = "macos")]
+fn restore_platform(saved: &[(String, f32)]) -> Result<(), String> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    for (_, volume) in saved {
+        Command::new("osascript")
+            .args(&["-e", &format!("set volume output volume {}", volume.round() as u32)])
+            .output_with_timeout()?;
+    }
+    Ok(())
+}
+
+/// Ducks other audio for `reason` ("speech" or "listening"); the first holder does the ducking.
+pub fn duck(reason: &'static str) {
+    let policy = *POLICY.read().unwrap();
+    if !applies_to(&policy, reason) {
+        return;
+    }
+
+    let mut state = STATE.lock().unwrap();
+    let was_ducked = !state.holders.is_empty();
+    *state.holders.entry(reason).or_insert(0) += 1;
+    if was_ducked {
+        return;
+    }
+
+    match duck_platform(policy.level.clamp(0.0, 1.0)) {
+        Ok(saved) => {
+            println!("🔉 Ducked {} audio sessions for {}", saved.len(), reason);
+            state.saved = saved;
+        }
+        Err(e) => println!("⚠️  Audio ducking unavailable: {}", e),
+    }
+}
+
+/// Releases one hold for `reason`; audio is restored once nothing holds it.
+pub fn restore(reason: &'static str) {
+    let mut state = STATE.lock().unwrap();
+    match state.holders.get_mut(reason) {
+        Some(count) if *count > 1 => {
+            *count -= 1;
+            return;
+        }
+        Some(_) => {
+            state.holders.remove(reason);
+        }
+        None => return,
+    }
+
+    if state.holders.is_empty() {
+        let saved = std::mem::take(&mut state.saved);
+        if let Err(e) = restore_platform(&saved) {
+            println!("⚠️  Failed to restore ducked audio: {}", e);
+        }
+    }
+}
+
+/// Volume (0..1) a ducked session goes back to on restore; None when it isn't ducked.
+pub fn restore_volume(session_id: &str) -> Option<f32> {
+    let state = STATE.lock().unwrap();
+    state.saved.iter().find(|(id, _)| id == session_id).map(|(_, volume)| volume / SAVED_SCALE)
+}
+
+/// Makes a user-chosen volume survive an ongoing duck: it becomes the restore target and the ducked equivalent is
+/// returned for applying now. Outside a duck `volume` is returned unchanged.
+pub fn set_restore_volume(session_id: &str, volume: f32) -> f32 {
+    let mut state = STATE.lock().unwrap();
+    if state.holders.is_empty() {
+        return volume;
+    }
+
+    match state.saved.iter_mut().find(|(id, _)| id == session_id) {
+        Some((_, saved)) => *saved = volume * SAVED_SCALE,
+        None => state.saved.push((session_id.to_string(), volume * SAVED_SCALE)),
+    }
+    volume * POLICY.read().unwrap().level.clamp(0.0, 1.0)
+}
+
+#[tauri::command]
+pub async fn set_audio_ducking(policy: AudioDuckingPolicy) -> Result<AudioDuckingPolicy, String> {
+    if !(0.0..=1.0).contains(&policy.level) {
+        return Err("Ducking level must be between 0 and 1".to_string());
+    }
+
+    *POLICY.write().unwrap() = policy;
+    Ok(policy)
+}
+
+#[tauri::command]
+pub async fn get_audio_ducking() -> Result<AudioDuckingPolicy, String> {
+    Ok(*POLICY.read().unwrap())
+}
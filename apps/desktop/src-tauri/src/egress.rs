@@ -0,0 +1,219 @@
+/**
+ * Egress module - Allow/block policy for outbound HTTP from the native layer, with a log of every destination
+ *
+ * All Rust-side requests go through `send`, which also counts their bytes for bandwidth accounting; loopback hosts (the
+ * local JarvisX services) are never blocked.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::AppHandle;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EgressPolicy {
+    /// Only hosts in `allowed` may be contacted; otherwise everything but `blocked` is
+    pub allowlist_only: bool,
+    pub allowed: BTreeSet<String>,
+    pub blocked: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EgressDestination {
+    pub host: String,
+    pub requests: u64,
+    pub blocked_requests: u64,
+    pub last_seen: i64,
+    /// Whether the current policy lets requests to this host through
+    pub allowed: bool,
+}
+
+static POLICY: Mutex<EgressPolicy> = Mutex::new(EgressPolicy {
+    allowlist_only: false,
+    allowed: BTreeSet::new(),
+    blocked: BTreeSet::new(),
+});
+
+static DESTINATIONS: Mutex<BTreeMap<String, EgressDestination>> = Mutex::new(BTreeMap::new());
+
+fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+fn policy_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("egress.json"))
+}
+
+/// Loads the active profile's policy; called at startup and on profile switches.
+pub fn load_egress_policy(app: AppHandle) {
+    *POLICY.lock().unwrap() = policy_path(&app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+}
+
+fn save_policy(app: &AppHandle, policy: &EgressPolicy) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(policy).map_err(|e| e.to_string())?;
+    std::fs::write(policy_path(app)?, json).map_err(|e| e.to_string())
+}
+
+/// Accepts "example.com", "https://example.com/path" or "*.example.com".
+fn normalize_host(input: &str) -> Result<String, String> {
+    let input = input.trim().to_lowercase();
+    let host = match reqwest::Url::parse(&input) {
+        Ok(url) if input.contains("://") => url.host_str().unwrap_or_default().to_string(),
+        _ => input.trim_start_matches("*.").to_string(),
+    };
+
+    if host.is_empty() || !host.chars().all(|c| c.is_ascii_alphanumeric() || ".-:[]".contains(c)) {
+        return Err(format!("❌ Invalid host: {}", input));
+    }
+    Ok(host)
+}
+
+/// True when `host` is `entry` or one of its subdomains.
+fn host_matches(host: &str, entry: &str) -> bool {
+    host == entry || host.ends_with(&format!(".{}", entry))
+}
+
+fn is_loopback(host: &str) -> bool {
+    host == "localhost"
+        || host
+            .trim_matches(|c| c == '[' || c == ']')
+            .parse::<std::net::IpAddr>()
+            .map_or(false, |ip| ip.is_loopback())
+}
+
+fn permits(policy: &EgressPolicy, host: &str) -> bool {
+    if is_loopback(host) {
+        return true;
+    }
+    if policy.blocked.iter().any(|entry| host_matches(host, entry)) {
+        return false;
+    }
+    !policy.allowlist_only || policy.allowed.iter().any(|entry| host_matches(host, entry))
+}
+
+/// Checks `url` against the policy and records the attempt.
+pub fn check(url: &reqwest::Url) -> Result<(), String> {
+    let host = url.host_str().unwrap_or_default().to_lowercase();
+    let allowed = permits(&POLICY.lock().unwrap(), &host);
+
+    let mut destinations = DESTINATIONS.lock().unwrap();
+    let destination = destinations.entry(host.clone()).or_insert_with(|| EgressDestination {
+        host: host.clone(),
+        requests: 0,
+        blocked_requests: 0,
+        last_seen: 0,
+        allowed,
+    });
+    destination.requests += 1;
+    destination.last_seen = now_secs();
+    if allowed {
+        return Ok(());
+    }
+
+    destination.blocked_requests += 1;
+    println!("🚫 Blocked outbound request to {}", host);
+    Err(format!("❌ Outbound requests to {} are blocked by the egress policy", host))
+}
+
+/// Rough size of a header block on the wire.
+fn header_bytes(headers: &reqwest::header::HeaderMap) -> u64 {
+    headers.iter().map(|(name, value)| (name.as_str().len() + value.len() + 4) as u64).sum()
+}
+
+/// Sends a request for `feature` after checking its destination against the egress policy and the feature against
+/// the bandwidth caps. The response body is read here so it can be counted.
+pub async fn send(feature: crate::bandwidth::Feature, request: reqwest::RequestBuilder) -> Result<reqwest::Response, String> {
+    let (client, request) = request.build_split();
+    let request = request.map_err(|e| e.to_string())?;
+    check(request.url())?;
+    crate::bandwidth::admit(feature)?;
+
+    let sent = request.url().as_str().len() as u64
+        + header_bytes(request.headers())
+        + request.body().and_then(|body| body.as_bytes()).map_or(0, |bytes| bytes.len() as u64);
+    let response = client.execute(request).await.map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    let version = response.version();
+    let headers = response.headers().clone();
+    let body = response.bytes().await.map_err(|e| e.to_string())?;
+    crate::bandwidth::record(feature, sent, header_bytes(&headers) + body.len() as u64);
+
+    let mut rebuilt = http::Response::new(body.to_vec());
+    *rebuilt.status_mut() = status;
+    *rebuilt.version_mut() = version;
+    *rebuilt.headers_mut() = headers;
+    Ok(reqwest::Response::from(rebuilt))
+}
+
+fn update_policy(app: &AppHandle, change: impl FnOnce(&mut EgressPolicy)) -> Result<EgressPolicy, String> {
+    let mut policy = POLICY.lock().unwrap();
+    change(&mut policy);
+    save_policy(app, &policy)?;
+    Ok(policy.clone())
+}
+
+/// Every host contacted this session plus the hosts named in the policy, busiest first.
+#[tauri::command]
+pub async fn list_egress_destinations() -> Result<serde_json::Value, String> {
+    let policy = POLICY.lock().unwrap().clone();
+    let mut destinations: Vec<EgressDestination> = DESTINATIONS.lock().unwrap().values().cloned().collect();
+
+    for host in policy.allowed.iter().chain(policy.blocked.iter()) {
+        if !destinations.iter().any(|destination| &destination.host == host) {
+            destinations.push(EgressDestination {
+                host: host.clone(),
+                requests: 0,
+                blocked_requests: 0,
+                last_seen: 0,
+                allowed: false,
+            });
+        }
+    }
+    for destination in destinations.iter_mut() {
+        destination.allowed = permits(&policy, &destination.host);
+    }
+    destinations.sort_by(|a, b| b.requests.cmp(&a.requests).then_with(|| a.host.cmp(&b.host)));
+
+    Ok(serde_json::json!({
+        "policy": policy,
+        "destinations": destinations,
+    }))
+}
+
+#[tauri::command]
+pub async fn block_egress_destination(app: AppHandle, host: String) -> Result<EgressPolicy, String> {
+    let host = normalize_host(&host)?;
+    println!("🚫 Blocking egress to {}", host);
+    update_policy(&app, |policy| {
+        policy.allowed.remove(&host);
+        policy.blocked.insert(host);
+    })
+}
+
+#[tauri::command]
+pub async fn allow_egress_destination(app: AppHandle, host: String) -> Result<EgressPolicy, String> {
+    let host = normalize_host(&host)?;
+    println!("✅ Allowing egress to {}", host);
+    update_policy(&app, |policy| {
+        policy.blocked.remove(&host);
+        policy.allowed.insert(host);
+    })
+}
+
+/// Switches between blocking only listed hosts and allowing only listed hosts.
+#[tauri::command]
+pub async fn set_egress_allowlist_only(app: AppHandle, enabled: bool) -> Result<EgressPolicy, String> {
+    println!("🌐 Egress allowlist-only mode {}", if enabled { "on" } else { "off" });
+    update_policy(&app, |policy| policy.allowlist_only = enabled)
+}
@@ -0,0 +1,139 @@
+/**
+ * Environment module - Listening presets for the acoustic environment (quiet office, noisy café, home)
+ *
+ * A preset bundles VAD aggressiveness, wake-word sensitivity and the capture's auto-gain and noise-suppression
+ * flags. Native VAD applies it directly; the frontend applies the rest (getUserMedia constraints, wake-word
+ * threshold) from `get_environment_profile` and `voice://environment-changed`.
+ */
+
+use serde::Serialize;
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager};
+
+/// Ambient levels (dBFS) separating the presets when suggesting one
+const QUIET_BELOW_DB: f32 = -55.0;
+const HOME_BELOW_DB: f32 = -40.0;
+const MEASURE_FRAME_MS: u64 = 30;
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvironmentProfile {
+    pub name: &'static str,
+    /// 0 (lenient) to 3 (only clear, loud speech counts)
+    pub vad_aggressiveness: u8,
+    /// 0..1; higher wakes on fainter or less exact matches
+    pub wake_sensitivity: f32,
+    pub auto_gain: bool,
+    pub noise_suppression: bool,
+}
+
+pub const PRESETS: [EnvironmentProfile; 3] = [
+    EnvironmentProfile { name: "quietOffice", vad_aggressiveness: 0, wake_sensitivity: 0.7, auto_gain: true, noise_suppression: false },
+    EnvironmentProfile { name: "home", vad_aggressiveness: 1, wake_sensitivity: 0.5, auto_gain: true, noise_suppression: true },
+    EnvironmentProfile { name: "noisyCafe", vad_aggressiveness: 3, wake_sensitivity: 0.3, auto_gain: false, noise_suppression: true },
+];
+
+static ACTIVE: RwLock<EnvironmentProfile> = RwLock::new(PRESETS[1]);
+
+pub fn active_environment() -> EnvironmentProfile {
+    *ACTIVE.read().unwrap()
+}
+
+fn preset(name: &str) -> Result<EnvironmentProfile, String> {
+    PRESETS
+        .iter()
+        .find(|preset| preset.name == name)
+        .copied()
+        .ok_or_else(|| format!("❌ Unknown environment profile: {} (quietOffice, home or noisyCafe)", name))
+}
+
+/// VAD start and stop RMS levels for the active preset.
+pub fn vad_thresholds() -> (f32, f32) {
+    let factor = [0.5, 1.0, 2.0, 4.0][active_environment().vad_aggressiveness.min(3) as usize];
+    (0.02 * factor, 0.01 * factor)
+}
+
+pub fn to_db(rms: f32) -> f32 {
+    20.0 * rms.max(1e-6).log10()
+}
+
+/// The preset that suits an ambient noise level.
+pub fn suggest_for(ambient_db: f32) -> &'static str {
+    if ambient_db < QUIET_BELOW_DB {
+        "quietOffice"
+    } else if ambient_db < HOME_BELOW_DB {
+        "home"
+    } else {
+        "noisyCafe"
+    }
+}
+
+fn environment_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("environment.json"))
+}
+
+/// Loads the active profile's preset; called at startup, on profile switches and when the file changes.
+pub fn load_environment_profile(app: &AppHandle) {
+    let environment = environment_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+        .and_then(|saved| saved["active"].as_str().and_then(|name| preset(name).ok()))
+        .unwrap_or(PRESETS[1]);
+    *ACTIVE.write().unwrap() = environment;
+}
+
+#[tauri::command]
+pub async fn set_environment_profile(app: AppHandle, name: String) -> Result<EnvironmentProfile, String> {
+    let environment = preset(&name)?;
+    let json = serde_json::to_string_pretty(&serde_json::json!({ "active": environment.name })).map_err(|e| e.to_string())?;
+    std::fs::write(environment_path(&app)?, json).map_err(|e| e.to_string())?;
+    *ACTIVE.write().unwrap() = environment;
+
+    println!("🎚️  Environment profile: {}", environment.name);
+    let _ = app.emit_all("voice://environment-changed", environment);
+    Ok(environment)
+}
+
+#[tauri::command]
+pub async fn get_environment_profile() -> Result<EnvironmentProfile, String> {
+    Ok(active_environment())
+}
+
+#[tauri::command]
+pub async fn list_environment_profiles() -> Result<Vec<EnvironmentProfile>, String> {
+    Ok(PRESETS.to_vec())
+}
+
+/// Listens for a few seconds and suggests a preset from the median ambient level; doesn't switch by itself.
+#[tauri::command]
+pub async fn measure_ambient_noise(app: AppHandle, duration_ms: Option<u64>) -> Result<serde_json::Value, String> {
+    let stop = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let source = crate::captions::CaptureSource::Microphone(crate::voice::capture_device(&app));
+    let (buffer, device, _) = crate::captions::open_source(source, stop.clone()).await?;
+
+    let mut levels = Vec::new();
+    let frames = duration_ms.unwrap_or(3_000).clamp(500, 10_000) / MEASURE_FRAME_MS;
+    for _ in 0..frames {
+        tokio::time::sleep(std::time::Duration::from_millis(MEASURE_FRAME_MS)).await;
+        let samples = std::mem::take(&mut *buffer.lock().unwrap());
+        if !samples.is_empty() {
+            levels.push(to_db(crate::captions::rms(&samples)));
+        }
+    }
+    stop.store(true, std::sync::atomic::Ordering::SeqCst);
+
+    if levels.is_empty() {
+        return Err(format!("❌ No audio received from {}", device));
+    }
+    // The median ignores short bursts such as a cough or a door
+    levels.sort_by(|a, b| a.total_cmp(b));
+    let ambient_db = levels[levels.len() / 2];
+
+    Ok(serde_json::json!({
+        "device": device,
+        "ambientDb": ambient_db,
+        "suggested": suggest_for(ambient_db),
+        "active": active_environment().name,
+    }))
+}
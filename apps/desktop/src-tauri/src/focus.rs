@@ -0,0 +1,257 @@
+/**
+ * Focus module - Pomodoro-style focus sessions built on the system primitives
+ */
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+
+use crate::watchdog::WatchdogCommand;
+
+pub struct FocusState {
+    pub is_active: bool,
+    pub session_id: u64,
+    pub started_at: Option<Instant>,
+    pub duration_secs: u64,
+    pub blocked_apps: Vec<String>,
+    pub blocked_attempts: u32,
+}
+
+impl Default for FocusState {
+    fn default() -> Self {
+        FocusState {
+            is_active: false,
+            session_id: 0,
+            started_at: None,
+            duration_secs: 0,
+            blocked_apps: Vec::new(),
+            blocked_attempts: 0,
+        }
+    }
+}
+
+/// macOS has no public Focus API, so Do Not Disturb goes through user-created Shortcuts with these names.
+#[cfg(target_os = "macos")]
+const DND_SHORTCUTS: [&str; 2] = ["Turn On Do Not Disturb", "Turn Off Do Not Disturb"];
+
+pub fn set_do_not_disturb(enabled: bool) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        use std::process::Command;
+        let listed = Command::new("shortcuts").arg("list").output_with_timeout()?;
+        let installed = String::from_utf8_lossy(&listed.stdout).to_string();
+        if !DND_SHORTCUTS.iter().all(|name| installed.lines().any(|line| line.trim() == *name)) {
+            return Err(format!(
+                "❌ Do Not Disturb needs the Shortcuts \"{}\" and \"{}\"; create them in the Shortcuts app with the Set Focus action",
+                DND_SHORTCUTS[0], DND_SHORTCUTS[1]
+            ));
+        }
+
+        let shortcut = if enabled { DND_SHORTCUTS[0] } else { DND_SHORTCUTS[1] };
+        let output = Command::new("shortcuts")
+            .args(&["run", shortcut])
+            .output_with_timeout()?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err("Failed to toggle Do Not Disturb".to_string())
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::process::Command;
+        let value = if enabled { "0" } else { "1" };
+        let output = Command::new("reg")
+            .args(&[
+                "add",
+                "HKCU\\Software\\Microsoft\\Windows\\CurrentVersion\\PushNotifications",
+                "/v", "ToastEnabled", "/t", "REG_DWORD", "/d", value, "/f",
+            ])
+            .output_with_timeout()?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err("Failed to toggle Do Not Disturb".to_string())
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        use std::process::Command;
+        let value = if enabled { "false" } else { "true" };
+        let output = Command::new("gsettings")
+            .args(&["set", "org.gnome.desktop.notifications", "show-banners", value])
+            .output_with_timeout()?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err("Failed to toggle Do Not Disturb".to_string())
+        }
+    }
+}
+
+fn notify(app: &AppHandle, title: &str, body: &str) {
+    crate::system::show_notification(app, title, body);
+}
+
+#[tauri::command]
+pub async fn start_focus_session(
+    app: AppHandle,
+    state: State<'_, Mutex<FocusState>>,
+    duration_minutes: u64,
+    blocked_apps: Vec<String>,
+) -> Result<String, String> {
+    if duration_minutes == 0 {
+        return Err("Focus duration must be at least one minute".to_string());
+    }
+
+    let session_id = {
+        let mut focus_state = state.lock().unwrap();
+
+        if focus_state.is_active {
+            return Err("Focus session already active".to_string());
+        }
+
+        focus_state.is_active = true;
+        focus_state.session_id += 1;
+        focus_state.started_at = Some(Instant::now());
+        focus_state.duration_secs = duration_minutes * 60;
+        focus_state.blocked_apps = blocked_apps.clone();
+        focus_state.blocked_attempts = 0;
+        focus_state.session_id
+    };
+
+    // The session still runs without Do Not Disturb, but the caller is told why notifications will show
+    let dnd_error = set_do_not_disturb(true).err();
+    if let Some(e) = &dnd_error {
+        println!("⚠️  {}", e);
+        let _ = app.emit_all("focus-dnd-unavailable", serde_json::json!({ "reason": e }));
+    }
+
+    println!("🍅 Focus session started: {} min, blocking {:?}", duration_minutes, blocked_apps);
+
+    tauri::async_runtime::spawn(run_focus_session(app, session_id));
+
+    Ok(match dnd_error {
+        Some(e) => format!("Focus session started for {} minutes without Do Not Disturb: {}", duration_minutes, e),
+        None => format!("Focus session started for {} minutes", duration_minutes),
+    })
+}
+
+async fn run_focus_session(app: AppHandle, session_id: u64) {
+    let mut ticker = tokio::time::interval(Duration::from_secs(5));
+
+    loop {
+        ticker.tick().await;
+
+        let (elapsed, duration, blocked_apps) = {
+            let state = app.state::<Mutex<FocusState>>();
+            let focus_state = state.lock().unwrap();
+
+            // A newer session or stop_focus_session has taken over
+            if !focus_state.is_active || focus_state.session_id != session_id {
+                return;
+            }
+
+            let elapsed = focus_state
+                .started_at
+                .map(|started| started.elapsed().as_secs())
+                .unwrap_or(0);
+            (elapsed, focus_state.duration_secs, focus_state.blocked_apps.clone())
+        };
+
+        if elapsed >= duration {
+            break;
+        }
+
+        let frontmost = tauri::async_runtime::spawn_blocking(crate::activity::frontmost_app).await.ok().flatten();
+        if let Some(active) = frontmost {
+            let active_lower = active.to_lowercase();
+            if let Some(app_name) = blocked_apps
+                .iter()
+                .find(|name| active_lower.contains(&name.to_lowercase()))
+            {
+                let _ = crate::commands::minimize_application(app_name.clone()).await;
+                app.state::<Mutex<FocusState>>().lock().unwrap().blocked_attempts += 1;
+                notify(&app, &crate::locale::t("focus.blocked.title"), &format!("{} is blocked during this session", app_name));
+            }
+        }
+
+        let _ = app.emit_all("focus-progress", serde_json::json!({
+            "sessionId": session_id,
+            "elapsedSecs": elapsed,
+            "remainingSecs": duration - elapsed,
+            "progress": elapsed as f64 / duration as f64,
+        }));
+    }
+
+    let summary = finish_focus_session(&app);
+    notify(&app, &crate::locale::t("focus.complete.title"), &crate::locale::t("focus.complete.body"));
+    let _ = app.emit_all("focus-complete", summary);
+}
+
+fn finish_focus_session(app: &AppHandle) -> serde_json::Value {
+    let state = app.state::<Mutex<FocusState>>();
+    let mut focus_state = state.lock().unwrap();
+
+    let elapsed = focus_state
+        .started_at
+        .map(|started| started.elapsed().as_secs())
+        .unwrap_or(0);
+
+    focus_state.is_active = false;
+    focus_state.started_at = None;
+
+    // Presentation mode turns it off itself when it ends
+    if !crate::presentation::is_active() {
+        if let Err(e) = set_do_not_disturb(false) {
+            println!("⚠️  {}", e);
+        }
+    }
+
+    println!("🍅 Focus session ended after {}s", elapsed);
+
+    serde_json::json!({
+        "sessionId": focus_state.session_id,
+        "focusedSecs": elapsed.min(focus_state.duration_secs),
+        "plannedSecs": focus_state.duration_secs,
+        "blockedApps": focus_state.blocked_apps,
+        "blockedAttempts": focus_state.blocked_attempts,
+    })
+}
+
+#[tauri::command]
+pub async fn stop_focus_session(app: AppHandle) -> Result<serde_json::Value, String> {
+    {
+        let state = app.state::<Mutex<FocusState>>();
+        if !state.lock().unwrap().is_active {
+            return Err("Focus session not active".to_string());
+        }
+    }
+
+    let summary = finish_focus_session(&app);
+    let _ = app.emit_all("focus-complete", summary.clone());
+    Ok(summary)
+}
+
+#[tauri::command]
+pub async fn get_focus_status(state: State<'_, Mutex<FocusState>>) -> Result<serde_json::Value, String> {
+    let focus_state = state.lock().unwrap();
+
+    let elapsed = focus_state
+        .started_at
+        .map(|started| started.elapsed().as_secs())
+        .unwrap_or(0);
+
+    Ok(serde_json::json!({
+        "isActive": focus_state.is_active,
+        "elapsedSecs": elapsed,
+        "remainingSecs": focus_state.duration_secs.saturating_sub(elapsed),
+        "blockedApps": focus_state.blocked_apps,
+        "blockedAttempts": focus_state.blocked_attempts,
+    }))
+}
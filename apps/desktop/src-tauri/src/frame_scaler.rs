@@ -0,0 +1,289 @@
+/**
+ * Frame scaler module - GPU downscaling and RGBA→RGB conversion for screen stream frames
+ *
+ * A wgpu compute shader box-filters each captured frame to the stream size and packs it as RGB ready for JPEG
+ * encoding. Without a usable adapter (or for frames larger than it supports) the CPU path does the same work.
+ */
+
+use image::{RgbImage, RgbaImage};
+use serde::Serialize;
+use std::sync::{Mutex, OnceLock};
+
+const WORKGROUP_SIZE: u32 = 64;
+
+const SHADER: &str = r#"
+struct Params {
+    src_size: vec2<u32>,
+    dst_size: vec2<u32>,
+}
+
+@group(0) @binding(0) var source: texture_2d<f32>;
+@group(0) @binding(1) var<uniform> params: Params;
+@group(0) @binding(2) var<storage, read_write> output: array<u32>;
+
+// Box filter over the source footprint of one output pixel (up to 4x4 taps)
+fn sample(index: u32) -> vec3<f32> {
+    if (index >= params.dst_size.x * params.dst_size.y) {
+        return vec3<f32>(0.0);
+    }
+    let x = index % params.dst_size.x;
+    let y = index / params.dst_size.x;
+    let scale = vec2<f32>(params.src_size) / vec2<f32>(params.dst_size);
+    let taps = clamp(vec2<u32>(ceil(scale)), vec2<u32>(1u), vec2<u32>(4u));
+    let origin = vec2<f32>(f32(x), f32(y)) * scale;
+
+    var sum = vec3<f32>(0.0);
+    for (var j = 0u; j < taps.y; j++) {
+        for (var i = 0u; i < taps.x; i++) {
+            let offset = (vec2<f32>(f32(i), f32(j)) + 0.5) * scale / vec2<f32>(taps);
+            let texel = min(vec2<u32>(origin + offset), params.src_size - 1u);
+            sum += textureLoad(source, texel, 0).rgb;
+        }
+    }
+    return sum / f32(taps.x * taps.y);
+}
+
+fn byte(value: f32) -> u32 {
+    return u32(clamp(value, 0.0, 1.0) * 255.0 + 0.5);
+}
+
+// Each invocation writes four RGB pixels as three little-endian words
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let first = id.x * 4u;
+    if (first >= params.dst_size.x * params.dst_size.y) {
+        return;
+    }
+    let p0 = sample(first);
+    let p1 = sample(first + 1u);
+    let p2 = sample(first + 2u);
+    let p3 = sample(first + 3u);
+
+    let word = id.x * 3u;
+    output[word] = byte(p0.r) | (byte(p0.g) << 8u) | (byte(p0.b) << 16u) | (byte(p1.r) << 24u);
+    output[word + 1u] = byte(p1.g) | (byte(p1.b) << 8u) | (byte(p2.r) << 16u) | (byte(p2.g) << 24u);
+    output[word + 2u] = byte(p2.b) | (byte(p3.r) << 8u) | (byte(p3.g) << 16u) | (byte(p3.b) << 24u);
+}
+"#;
+
+/// Per-size GPU resources, rebuilt when the source or target size changes.
+struct Targets {
+    src_size: (u32, u32),
+    dst_size: (u32, u32),
+    texture: wgpu::Texture,
+    output: wgpu::Buffer,
+    readback: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    workgroups: u32,
+}
+
+struct GpuScaler {
+    adapter_name: String,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    params: wgpu::Buffer,
+    max_dimension: u32,
+    targets: Mutex<Option<Targets>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameScalerInfo {
+    /// "gpu" or "cpu"
+    pub backend: String,
+    pub adapter: Option<String>,
+}
+
+static GPU: OnceLock<Option<GpuScaler>> = OnceLock::new();
+
+fn gpu() -> Option<&'static GpuScaler> {
+    GPU.get_or_init(|| match GpuScaler::new() {
+        Ok(scaler) => {
+            println!("🎞️  Frame scaling on GPU: {}", scaler.adapter_name);
+            Some(scaler)
+        }
+        Err(e) => {
+            println!("⚠️  GPU frame scaling unavailable, using CPU: {}", e);
+            None
+        }
+    })
+    .as_ref()
+}
+
+impl GpuScaler {
+    fn new() -> Result<Self, String> {
+        let instance = wgpu::Instance::default();
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::LowPower,
+            force_fallback_adapter: false,
+            compatible_surface: None,
+        }))
+        .ok_or("No GPU adapter")?;
+        // Software adapters are slower than the CPU path
+        if adapter.get_info().device_type == wgpu::DeviceType::Cpu {
+            return Err("Only a software adapter is available".to_string());
+        }
+
+        let (device, queue) = pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor { label: Some("frame-scaler"), required_features: wgpu::Features::empty(), required_limits: adapter.limits() },
+            None,
+        ))
+        .map_err(|e| e.to_string())?;
+
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("frame-scaler"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("frame-scaler"),
+            layout: None,
+            module: &module,
+            entry_point: "main",
+        });
+        let params = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("frame-scaler-params"),
+            size: 16,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Ok(GpuScaler {
+            adapter_name: adapter.get_info().name,
+            max_dimension: device.limits().max_texture_dimension_2d,
+            device,
+            queue,
+            pipeline,
+            params,
+            targets: Mutex::new(None),
+        })
+    }
+
+    fn targets(&self, src_size: (u32, u32), dst_size: (u32, u32)) -> Targets {
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("frame-scaler-source"),
+            size: wgpu::Extent3d { width: src_size.0, height: src_size.1, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+
+        let groups_of_four = (dst_size.0 as u64 * dst_size.1 as u64 + 3) / 4;
+        let size = groups_of_four * 12;
+        let output = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("frame-scaler-output"),
+            size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("frame-scaler-readback"),
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("frame-scaler"),
+            layout: &self.pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&view) },
+                wgpu::BindGroupEntry { binding: 1, resource: self.params.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 2, resource: output.as_entire_binding() },
+            ],
+        });
+
+        Targets {
+            src_size,
+            dst_size,
+            texture,
+            output,
+            readback,
+            bind_group,
+            workgroups: ((groups_of_four + WORKGROUP_SIZE as u64 - 1) / WORKGROUP_SIZE as u64) as u32,
+        }
+    }
+
+    fn scale(&self, frame: &RgbaImage, width: u32, height: u32) -> Result<RgbImage, String> {
+        let src_size = frame.dimensions();
+        if src_size.0 > self.max_dimension || src_size.1 > self.max_dimension {
+            return Err(format!("Frame exceeds the GPU texture limit of {}px", self.max_dimension));
+        }
+
+        let mut cached = self.targets.lock().unwrap();
+        if cached.as_ref().map_or(true, |targets| targets.src_size != src_size || targets.dst_size != (width, height)) {
+            *cached = Some(self.targets(src_size, (width, height)));
+        }
+        let targets = cached.as_ref().unwrap();
+
+        let params: Vec<u8> = [src_size.0, src_size.1, width, height].iter().flat_map(|value| value.to_le_bytes()).collect();
+        self.queue.write_buffer(&self.params, 0, &params);
+        self.queue.write_texture(
+            wgpu::ImageCopyTexture { texture: &targets.texture, mip_level: 0, origin: wgpu::Origin3d::ZERO, aspect: wgpu::TextureAspect::All },
+            frame.as_raw(),
+            wgpu::ImageDataLayout { offset: 0, bytes_per_row: Some(4 * src_size.0), rows_per_image: Some(src_size.1) },
+            wgpu::Extent3d { width: src_size.0, height: src_size.1, depth_or_array_layers: 1 },
+        );
+
+        let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some("frame-scaler") });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor { label: Some("frame-scaler"), timestamp_writes: None });
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &targets.bind_group, &[]);
+            pass.dispatch_workgroups(targets.workgroups, 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&targets.output, 0, &targets.readback, 0, targets.output.size());
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = targets.readback.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        receiver.recv().map_err(|e| e.to_string())?.map_err(|e| e.to_string())?;
+
+        let mut rgb = slice.get_mapped_range().to_vec();
+        targets.readback.unmap();
+        // Drop the padding up to the last group of four pixels
+        rgb.truncate(width as usize * height as usize * 3);
+        RgbImage::from_raw(width, height, rgb).ok_or_else(|| "GPU output has the wrong size".to_string())
+    }
+}
+
+fn scale_on_cpu(frame: &RgbaImage, width: u32, height: u32) -> RgbImage {
+    let resized = if frame.dimensions() == (width, height) {
+        frame.clone()
+    } else {
+        image::imageops::resize(frame, width, height, image::imageops::FilterType::Triangle)
+    };
+    image::DynamicImage::ImageRgba8(resized).to_rgb8()
+}
+
+/// Scales a captured RGBA frame to `width`×`height` RGB, on the GPU when one is available.
+pub fn scale_frame(frame: &RgbaImage, width: u32, height: u32) -> RgbImage {
+    let (width, height) = (width.max(1), height.max(1));
+    if let Some(scaler) = gpu() {
+        match scaler.scale(frame, width, height) {
+            Ok(scaled) => return scaled,
+            Err(e) => println!("⚠️  GPU frame scaling failed, using CPU: {}", e),
+        }
+    }
+    scale_on_cpu(frame, width, height)
+}
+
+#[tauri::command]
+pub async fn get_frame_scaler_info() -> Result<FrameScalerInfo, String> {
+    let gpu = tauri::async_runtime::spawn_blocking(|| gpu().map(|scaler| scaler.adapter_name.clone()))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(FrameScalerInfo {
+        backend: if gpu.is_some() { "gpu" } else { "cpu" }.to_string(),
+        adapter: gpu,
+    })
+}
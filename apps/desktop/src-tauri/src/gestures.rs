@@ -0,0 +1,169 @@
+/**
+ * Gestures module - Smooth multi-point mouse gestures (circles, swipes, flicks, pinch)
+ */
+
+use enigo::{Axis, Button, Coordinate, Direction, Enigo, Key, Keyboard, Mouse, Settings};
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::commands::interpolate_path;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GestureParams {
+    pub x: Option<i32>,
+    pub y: Option<i32>,
+    pub radius: Option<i32>,
+    pub turns: Option<f32>,
+    pub direction: Option<String>,
+    pub distance: Option<i32>,
+    pub duration_ms: Option<u64>,
+    /// Hold the left button while moving (scrubbing, drag-swipes)
+    pub hold_button: Option<bool>,
+    /// Pinch zoom factor: >1 zooms in, <1 zooms out
+    pub scale: Option<f32>,
+}
+
+fn ease_out_cubic(t: f32) -> f32 {
+    1.0 - (1.0 - t).powi(3)
+}
+
+fn ease_out_expo(t: f32) -> f32 {
+    if t >= 1.0 { 1.0 } else { 1.0 - 2f32.powf(-10.0 * t) }
+}
+
+fn direction_vector(direction: &str) -> Result<(i32, i32), String> {
+    match direction.to_lowercase().as_str() {
+        "up" | "north" => Ok((0, -1)),
+        "down" | "south" => Ok((0, 1)),
+        "left" | "west" => Ok((-1, 0)),
+        "right" | "east" => Ok((1, 0)),
+        _ => Err(format!("Unsupported gesture direction: {}", direction)),
+    }
+}
+
+/// Moves through the points at an even pace over the given duration.
+fn play_path(enigo: &mut Enigo, points: &[(i32, i32)], duration: Duration) -> Result<(), String> {
+    let delay = duration / points.len().max(1) as u32;
+
+    for (x, y) in points.iter() {
+        let target = crate::calibration::correct(*x, *y);
+        enigo.move_mouse(target.0, target.1, Coordinate::Abs).map_err(|e| e.to_string())?;
+        std::thread::sleep(delay);
+    }
+
+    Ok(())
+}
+
+fn with_button<F>(enigo: &mut Enigo, hold: bool, gesture: F) -> Result<(), String>
+where
+    F: FnOnce(&mut Enigo) -> Result<(), String>,
+{
+    if hold {
+        crate::recovery::track("input:gesture", "input", serde_json::json!({ "button": "left" }));
+        enigo.button(Button::Left, Direction::Press).map_err(|e| e.to_string())?;
+    }
+
+    let result = gesture(enigo);
+
+    // Always release, even when the gesture failed half way
+    if hold {
+        enigo.button(Button::Left, Direction::Release).map_err(|e| e.to_string())?;
+        crate::recovery::untrack("input:gesture");
+    }
+
+    result
+}
+
+fn run_gesture(kind: &str, params: &GestureParams) -> Result<String, String> {
+    let _injection = crate::automation_guard::injecting();
+    let mut enigo = Enigo::new(&Settings::default()).map_err(|e| e.to_string())?;
+    let (cursor_x, cursor_y) = enigo.location().map_err(|e| e.to_string())?;
+    let origin = (params.x.unwrap_or(cursor_x), params.y.unwrap_or(cursor_y));
+    let hold = params.hold_button.unwrap_or(false);
+
+    match kind {
+        "circle" => {
+            let radius = params.radius.unwrap_or(80).max(5) as f32;
+            let turns = params.turns.unwrap_or(1.0).max(0.1);
+            let duration = Duration::from_millis(params.duration_ms.unwrap_or(800));
+            let clockwise = params.direction.as_deref() != Some("counterclockwise");
+            let steps = (48.0 * turns).ceil() as usize;
+
+            // Origin is the centre; start on the circle's rightmost point
+            let start = (origin.0 + radius as i32, origin.1);
+            let points: Vec<(i32, i32)> = (0..=steps)
+                .map(|i| {
+                    let sign = if clockwise { 1.0 } else { -1.0 };
+                    let angle = sign * turns * std::f32::consts::TAU * i as f32 / steps as f32;
+                    (
+                        origin.0 + (radius * angle.cos()) as i32,
+                        origin.1 + (radius * angle.sin()) as i32,
+                    )
+                })
+                .collect();
+
+            let target = crate::calibration::correct(start.0, start.1);
+            enigo.move_mouse(target.0, target.1, Coordinate::Abs).map_err(|e| e.to_string())?;
+            with_button(&mut enigo, hold, |enigo| play_path(enigo, &points, duration))?;
+            Ok(format!("Circle gesture around ({}, {})", origin.0, origin.1))
+        }
+        "swipe" | "flick" => {
+            let direction = direction_vector(params.direction.as_deref().unwrap_or("left"))?;
+            let (default_distance, default_duration, easing): (i32, u64, fn(f32) -> f32) = if kind == "flick" {
+                (150, 120, ease_out_expo)
+            } else {
+                (400, 450, ease_out_cubic)
+            };
+            let distance = params.distance.unwrap_or(default_distance);
+            let duration = Duration::from_millis(params.duration_ms.unwrap_or(default_duration));
+            let end = (origin.0 + direction.0 * distance, origin.1 + direction.1 * distance);
+
+            // Ease-out keeps the pointer fast at release so apps apply inertia
+            let points = interpolate_path(origin, end, 24, easing);
+            let target = crate::calibration::correct(origin.0, origin.1);
+            enigo.move_mouse(target.0, target.1, Coordinate::Abs).map_err(|e| e.to_string())?;
+            with_button(&mut enigo, params.hold_button.unwrap_or(true), |enigo| play_path(enigo, &points, duration))?;
+            Ok(format!("{} gesture from ({}, {}) to ({}, {})", kind, origin.0, origin.1, end.0, end.1))
+        }
+        "pinch" => {
+            let scale = params.scale.unwrap_or(1.5);
+            if scale <= 0.0 {
+                return Err("Pinch scale must be positive".to_string());
+            }
+
+            // Browsers and most editors map Ctrl/Cmd + scroll to pinch zoom
+            let notches = ((scale.ln() / 1.1f32.ln()).round() as i32).clamp(-30, 30);
+            let modifier = if cfg!(target_os = "macos") { Key::Meta } else { Key::Control };
+
+            let target = crate::calibration::correct(origin.0, origin.1);
+            enigo.move_mouse(target.0, target.1, Coordinate::Abs).map_err(|e| e.to_string())?;
+            crate::recovery::track("input:gesture", "input", serde_json::json!({ "modifier": format!("{:?}", modifier) }));
+            enigo.key(modifier, Direction::Press).map_err(|e| e.to_string())?;
+            let mut result = Ok(());
+            for _ in 0..notches.abs() {
+                result = enigo.scroll(-notches.signum(), Axis::Vertical).map_err(|e| e.to_string());
+                if result.is_err() {
+                    break;
+                }
+                std::thread::sleep(Duration::from_millis(15));
+            }
+            enigo.key(modifier, Direction::Release).map_err(|e| e.to_string())?;
+            crate::recovery::untrack("input:gesture");
+            result?;
+
+            Ok(format!("Pinch gesture x{:.2} at ({}, {})", scale, origin.0, origin.1))
+        }
+        _ => Err(format!("Unsupported gesture: {}", kind)),
+    }
+}
+
+#[tauri::command]
+pub async fn simulate_gesture(kind: String, params: Option<GestureParams>) -> Result<String, String> {
+    let params = params.unwrap_or_default();
+    println!("🖱️  Gesture: {} {:?}", kind, params);
+
+    tauri::async_runtime::spawn_blocking(move || run_gesture(&kind, &params))
+        .await
+        .map_err(|e| e.to_string())?
+}
@@ -0,0 +1,40 @@
+/**
+ * Headless module - Windowless mode of the desktop app, serving commands over the local HTTP API
+ *
+ * Start with `--headless` (or JARVISX_HEADLESS=1) for kiosks and scripts. Commands are invoked with
+ * `POST /invoke/<command>` and a capability token, the same whitelist plans and batches dispatch through.
+ * This is the desktop executable without its main window, so Tauri's event loop still starts and a display
+ * session is required. Servers without one run `jarvisx-headless` from the jarvisx-core crate instead, which
+ * serves the core's commands with the same tokens and no Tauri at all.
+ */
+
+use tauri::{AppHandle, Manager};
+
+pub const MAIN_LABEL: &str = "main";
+
+pub fn is_headless() -> bool {
+    std::env::args().any(|arg| arg == "--headless")
+        || std::env::var("JARVISX_HEADLESS").map_or(false, |value| value == "1" || value == "true")
+}
+
+/// Creates the main assistant window; skipped in headless mode so no webview window is opened.
+pub fn open_main_window(app: &AppHandle) -> Result<(), String> {
+    tauri::WindowBuilder::new(app, MAIN_LABEL, tauri::WindowUrl::default())
+        .title("JarvisX Desktop Assistant")
+        .inner_size(1200.0, 800.0)
+        .min_inner_size(800.0, 600.0)
+        .resizable(true)
+        .transparent(true)
+        .build()
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Workflows live in the UI, so running one from the API asks the main window to start it.
+pub fn request_workflow(app: &AppHandle, name: &str) -> Result<serde_json::Value, String> {
+    let window = app.get_window(MAIN_LABEL).ok_or("Workflows run in the UI and are not available in headless mode")?;
+    window.emit("workflow://run-requested", serde_json::json!({ "name": name })).map_err(|e| e.to_string())?;
+
+    println!("🔁 Workflow requested: {}", name);
+    Ok(serde_json::json!({ "workflow": name, "requested": true }))
+}
@@ -0,0 +1,271 @@
+/**
+ * HID module - Macro pads and Stream Decks as hardware buttons for commands, scripts and workflows
+ *
+ * Devices are enumerated through hidapi and diffed for hot-plug (`hid://device-attached`, `hid://device-removed`).
+ * Elgato Stream Decks are recognized by model; any other HID device can be used once it has a mapping, with each
+ * bit of its input report counting as a button. Presses emit `hid://button-pressed` and run the mapped action
+ * (the same actions as voice shortcuts) from `hid_mappings.json`. On Linux the hidraw node must be readable by the
+ * user (a udev rule).
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use crate::voice_shortcuts::ShortcutAction;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+const READ_TIMEOUT_MS: i32 = 500;
+const ELGATO_VENDOR_ID: u16 = 0x0fd9;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HidDeviceInfo {
+    /// "vvvv:pppp" or "vvvv:pppp:serial"; mappings may use either
+    pub id: String,
+    pub name: String,
+    pub vendor_id: u16,
+    pub product_id: u16,
+    pub serial: Option<String>,
+    /// "stream-deck" or "generic"
+    pub kind: String,
+    pub buttons: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HidMapping {
+    /// Device id, or "vvvv:pppp" for every unit of that model
+    pub device: String,
+    /// Zero-based, left to right and top to bottom on a Stream Deck
+    pub button: usize,
+    pub action: ShortcutAction,
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+/// Where a Stream Deck model's key states start in its input report, and how many keys it has.
+fn stream_deck_layout(product_id: u16) -> Option<(&'static str, usize, usize)> {
+    match product_id {
+        0x0060 => Some(("Stream Deck", 1, 15)),
+        0x0063 => Some(("Stream Deck Mini", 1, 6)),
+        0x0090 => Some(("Stream Deck Mini", 4, 6)),
+        0x006c | 0x008f => Some(("Stream Deck XL", 4, 32)),
+        0x006d | 0x0080 => Some(("Stream Deck", 4, 15)),
+        0x0084 => Some(("Stream Deck +", 4, 8)),
+        0x0086 => Some(("Stream Deck Pedal", 4, 3)),
+        _ => None,
+    }
+}
+
+fn mappings_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("hid_mappings.json"))
+}
+
+fn load_mappings(app: &AppHandle) -> Vec<HidMapping> {
+    mappings_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_mappings(app: &AppHandle, mappings: &[HidMapping]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(mappings).map_err(|e| e.to_string())?;
+    std::fs::write(mappings_path(app)?, json).map_err(|e| e.to_string())
+}
+
+fn mapping_applies(mapping: &HidMapping, device: &HidDeviceInfo) -> bool {
+    let model = format!("{:04x}:{:04x}", device.vendor_id, device.product_id);
+    let wanted = mapping.device.to_lowercase();
+    wanted == device.id.to_lowercase() || wanted == model
+}
+
+fn describe(info: &hidapi::DeviceInfo) -> HidDeviceInfo {
+    let (vendor_id, product_id) = (info.vendor_id(), info.product_id());
+    let serial = info.serial_number().filter(|serial| !serial.is_empty()).map(str::to_string);
+    let model = format!("{:04x}:{:04x}", vendor_id, product_id);
+    let stream_deck = if vendor_id == ELGATO_VENDOR_ID { stream_deck_layout(product_id) } else { None };
+
+    let name = match stream_deck {
+        Some((model_name, _, _)) => model_name.to_string(),
+        None => [info.manufacturer_string(), info.product_string()].into_iter().flatten().collect::<Vec<_>>().join(" "),
+    };
+    HidDeviceInfo {
+        id: serial.as_ref().map_or_else(|| model.clone(), |serial| format!("{}:{}", model, serial)),
+        name: if name.trim().is_empty() { model } else { name },
+        vendor_id,
+        product_id,
+        serial,
+        kind: if stream_deck.is_some() { "stream-deck" } else { "generic" }.to_string(),
+        buttons: stream_deck.map(|(_, _, keys)| keys),
+    }
+}
+
+/// Connected devices with the hidapi path to open each, one entry per device.
+fn enumerate(api: &mut hidapi::HidApi) -> BTreeMap<String, (HidDeviceInfo, std::ffi::CString)> {
+    let _ = api.refresh_devices();
+    let mut devices = BTreeMap::new();
+    for info in api.device_list() {
+        let device = describe(info);
+        devices.entry(device.id.clone()).or_insert_with(|| (device, info.path().to_owned()));
+    }
+    devices
+}
+
+/// Indices of the buttons held down in an input report.
+fn pressed_buttons(device: &HidDeviceInfo, report: &[u8]) -> BTreeSet<usize> {
+    match stream_deck_layout(device.product_id).filter(|_| device.kind == "stream-deck") {
+        Some((_, offset, keys)) => {
+            report.iter().skip(offset).take(keys).enumerate().filter(|(_, state)| **state != 0).map(|(key, _)| key).collect()
+        }
+        // Byte 0 is the report id; every other bit is a button
+        None => report
+            .iter()
+            .skip(1)
+            .enumerate()
+            .flat_map(|(byte, value)| (0..8).filter(move |bit| value & (1 << bit) != 0).map(move |bit| byte * 8 + bit))
+            .collect(),
+    }
+}
+
+fn on_press(app: &AppHandle, device: &HidDeviceInfo, button: usize) {
+    let mapping = load_mappings(app).into_iter().find(|mapping| mapping.button == button && mapping_applies(mapping, device));
+    let result = mapping.as_ref().map(|mapping| crate::voice_shortcuts::run_action(app, &mapping.action));
+
+    if let Some(Err(e)) = &result {
+        println!("⚠️  {} button {} failed: {}", device.name, button, e);
+    }
+    let _ = app.emit_all("hid://button-pressed", serde_json::json!({
+        "device": device,
+        "button": button,
+        "label": mapping.as_ref().and_then(|mapping| mapping.label.clone()),
+        "action": mapping.as_ref().map(|mapping| &mapping.action),
+        "result": result.as_ref().and_then(|result| result.as_ref().ok()),
+        "error": result.as_ref().and_then(|result| result.as_ref().err()),
+    }));
+}
+
+/// Reads input reports until the device goes away, acting on button presses (not releases or holds).
+fn read_device(app: AppHandle, device: HidDeviceInfo, handle: hidapi::HidDevice, reading: Arc<Mutex<BTreeSet<String>>>) {
+    let mut held = BTreeSet::new();
+    let mut report = [0u8; 512];
+    loop {
+        match handle.read_timeout(&mut report, READ_TIMEOUT_MS) {
+            Ok(0) => continue,
+            Ok(read) => {
+                let pressed = pressed_buttons(&device, &report[..read]);
+                for button in pressed.difference(&held) {
+                    on_press(&app, &device, *button);
+                }
+                held = pressed;
+            }
+            Err(_) => break,
+        }
+    }
+    reading.lock().unwrap().remove(&device.id);
+}
+
+/// Watches for macro pads coming and going and reads the ones that are Stream Decks or have mappings.
+pub fn spawn_hid_watcher(app: AppHandle) {
+    if crate::mock_platform::is_active() {
+        return;
+    }
+
+    std::thread::spawn(move || {
+        let mut api = match hidapi::HidApi::new() {
+            Ok(api) => api,
+            Err(e) => {
+                println!("⚠️  HID unavailable: {}", e);
+                return;
+            }
+        };
+        let reading: Arc<Mutex<BTreeSet<String>>> = Arc::new(Mutex::new(BTreeSet::new()));
+        let mut known: BTreeMap<String, HidDeviceInfo> = BTreeMap::new();
+        let mut first_pass = true;
+
+        loop {
+            let current = enumerate(&mut api);
+            if !first_pass {
+                for (device, _) in current.values().filter(|(device, _)| !known.contains_key(&device.id)) {
+                    println!("🎛️  HID device attached: {}", device.name);
+                    let _ = app.emit_all("hid://device-attached", device);
+                }
+                for device in known.values().filter(|device| !current.contains_key(&device.id)) {
+                    println!("🎛️  HID device removed: {}", device.name);
+                    let _ = app.emit_all("hid://device-removed", device);
+                }
+            }
+            first_pass = false;
+
+            let mappings = load_mappings(&app);
+            for (device, path) in current.values() {
+                let wanted = device.kind == "stream-deck" || mappings.iter().any(|mapping| mapping_applies(mapping, device));
+                if !wanted || reading.lock().unwrap().contains(&device.id) {
+                    continue;
+                }
+                match api.open_path(path) {
+                    Ok(handle) => {
+                        reading.lock().unwrap().insert(device.id.clone());
+                        let (app, device, reading) = (app.clone(), device.clone(), reading.clone());
+                        std::thread::spawn(move || read_device(app, device, handle, reading));
+                    }
+                    // Retried next poll; keyboards and mice are usually held exclusively by the OS
+                    Err(e) => println!("⚠️  Could not open {}: {}", device.name, e),
+                }
+            }
+
+            known = current.into_iter().map(|(id, (device, _))| (id, device)).collect();
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn list_hid_devices() -> Result<Vec<HidDeviceInfo>, String> {
+    if crate::mock_platform::is_active() {
+        return Ok(Vec::new());
+    }
+    tauri::async_runtime::spawn_blocking(|| {
+        let mut api = hidapi::HidApi::new().map_err(|e| e.to_string())?;
+        Ok(enumerate(&mut api).into_values().map(|(device, _)| device).collect())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Maps `button` on `device` to an action, or removes the mapping when no action is given.
+#[tauri::command]
+pub async fn configure_hid_mapping(
+    app: AppHandle,
+    device: String,
+    button: usize,
+    action: Option<ShortcutAction>,
+    label: Option<String>,
+) -> Result<Vec<HidMapping>, String> {
+    let mut mappings = load_mappings(&app);
+    mappings.retain(|mapping| !(mapping.device.eq_ignore_ascii_case(&device) && mapping.button == button));
+
+    if let Some(action) = action {
+        if !["command", "script", "workflow"].contains(&action.kind.as_str()) {
+            return Err(format!("❌ Unknown shortcut action kind: {}", action.kind));
+        }
+        mappings.push(HidMapping { device: device.to_lowercase(), button, action, label });
+        mappings.sort_by(|a, b| a.device.cmp(&b.device).then(a.button.cmp(&b.button)));
+    }
+    save_mappings(&app, &mappings)?;
+
+    println!("🎛️  HID mapping saved: {} button {}", device, button);
+    Ok(mappings.into_iter().filter(|mapping| mapping.device.eq_ignore_ascii_case(&device)).collect())
+}
+
+#[tauri::command]
+pub async fn get_hid_mappings(app: AppHandle, device: Option<String>) -> Result<Vec<HidMapping>, String> {
+    Ok(load_mappings(&app)
+        .into_iter()
+        .filter(|mapping| device.as_ref().map_or(true, |device| mapping.device.eq_ignore_ascii_case(device)))
+        .collect())
+}
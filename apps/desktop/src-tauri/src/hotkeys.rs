@@ -0,0 +1,273 @@
+/**
+ * Hotkeys module - Global hotkey registration with conflict detection
+ *
+ * Accelerators are checked against well-known OS shortcuts, hotkeys JarvisX already holds and the OS's own
+ * registration result. Conflicts come back as a HotkeyConflict error (JSON in the error string) with free
+ * alternatives, instead of a hotkey that silently never fires.
+ */
+
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Mutex;
+use tauri::{AppHandle, GlobalShortcutManager, Manager};
+
+const MODIFIER_ORDER: [&str; 4] = ["Ctrl", "Alt", "Shift", "Super"];
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Shortcuts the OS or desktop shell reserves, in canonical form
+#[cfg(target_os = "macos")]
+const SYSTEM_SHORTCUTS: &[(&str, &str)] = &[
+    ("Super+Space", "Spotlight"),
+    ("Alt+Super+Space", "Finder search"),
+    ("Super+Tab", "App switcher"),
+    ("Super+Q", "Quit app"),
+    ("Super+W", "Close window"),
+    ("Super+H", "Hide app"),
+    ("Super+M", "Minimize window"),
+    ("Super+C", "Copy"),
+    ("Super+V", "Paste"),
+    ("Super+X", "Cut"),
+    ("Super+Z", "Undo"),
+    ("Super+A", "Select all"),
+    ("Shift+Super+3", "Screenshot"),
+    ("Shift+Super+4", "Screenshot selection"),
+    ("Shift+Super+5", "Screenshot toolbar"),
+    ("Ctrl+Super+Q", "Lock screen"),
+    ("Alt+Super+Escape", "Force quit"),
+    ("Ctrl+Space", "Switch input source"),
+    ("Ctrl+Up", "Mission Control"),
+    ("Ctrl+Down", "App windows"),
+    ("Ctrl+Left", "Previous space"),
+    ("Ctrl+Right", "Next space"),
+];
+
+#[cfg(target_os = "windows")]
+const SYSTEM_SHORTCUTS: &[(&str, &str)] = &[
+    ("Alt+Tab", "App switcher"),
+    ("Alt+F4", "Close window"),
+    ("Ctrl+Alt+Delete", "Security screen"),
+    ("Ctrl+Shift+Escape", "Task Manager"),
+    ("Ctrl+Escape", "Start menu"),
+    ("Ctrl+C", "Copy"),
+    ("Ctrl+V", "Paste"),
+    ("Ctrl+X", "Cut"),
+    ("Ctrl+Z", "Undo"),
+    ("Ctrl+A", "Select all"),
+    ("Super+D", "Show desktop"),
+    ("Super+E", "File Explorer"),
+    ("Super+L", "Lock screen"),
+    ("Super+R", "Run"),
+    ("Super+S", "Search"),
+    ("Super+V", "Clipboard history"),
+    ("Super+Tab", "Task view"),
+    ("Shift+Super+S", "Snipping tool"),
+    ("Super+Space", "Switch input language"),
+    ("Alt+Shift", "Switch input language"),
+    ("Ctrl+Super+Left", "Previous desktop"),
+    ("Ctrl+Super+Right", "Next desktop"),
+];
+
+#[cfg(target_os = "linux")]
+const SYSTEM_SHORTCUTS: &[(&str, &str)] = &[
+    ("Alt+Tab", "App switcher"),
+    ("Alt+F4", "Close window"),
+    ("Alt+F2", "Run command"),
+    ("Ctrl+Alt+Delete", "Log out"),
+    ("Ctrl+Alt+T", "Terminal"),
+    ("Ctrl+Alt+L", "Lock screen"),
+    ("Ctrl+Alt+Left", "Previous workspace"),
+    ("Ctrl+Alt+Right", "Next workspace"),
+    ("Ctrl+C", "Copy"),
+    ("Ctrl+V", "Paste"),
+    ("Ctrl+X", "Cut"),
+    ("Ctrl+Z", "Undo"),
+    ("Ctrl+A", "Select all"),
+    ("Super+L", "Lock screen"),
+    ("Super+Space", "Switch input source"),
+    ("Super+Tab", "App switcher"),
+    ("Super+A", "Show applications"),
+    ("Shift+Print", "Screenshot"),
+];
+
+/// Hotkeys registered through this module, by canonical accelerator
+static REGISTERED: Mutex<BTreeMap<String, String>> = Mutex::new(BTreeMap::new());
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HotkeyConflict {
+    /// "system" (reserved by the OS), "app" (held by JarvisX) or "taken" (the OS refused, usually another app)
+    pub kind: String,
+    pub accelerator: String,
+    /// The system action or JarvisX hotkey id it collides with
+    pub conflicts_with: String,
+}
+
+/// Parses an accelerator into canonical form ("Ctrl+Shift+K"), resolving CmdOrCtrl for this platform.
+pub fn canonical(accelerator: &str) -> Result<String, String> {
+    let mut modifiers = BTreeSet::new();
+    let mut key = None;
+
+    for part in accelerator.split('+').map(str::trim) {
+        let modifier = match part.to_lowercase().as_str() {
+            "ctrl" | "control" => Some("Ctrl"),
+            "alt" | "option" => Some("Alt"),
+            "shift" => Some("Shift"),
+            "super" | "cmd" | "command" | "meta" | "win" => Some("Super"),
+            "cmdorctrl" | "commandorcontrol" => Some(if cfg!(target_os = "macos") { "Super" } else { "Ctrl" }),
+            _ => None,
+        };
+        match modifier {
+            Some(modifier) => {
+                modifiers.insert(MODIFIER_ORDER.iter().position(|m| *m == modifier).unwrap_or(0));
+            }
+            None if part.is_empty() => return Err(format!("❌ Invalid accelerator: {}", accelerator)),
+            None if key.is_some() => return Err(format!("❌ Accelerator has more than one key: {}", accelerator)),
+            None => {
+                key = Some(if part.len() == 1 { part.to_uppercase() } else { part.to_string() });
+            }
+        }
+    }
+
+    let mut parts: Vec<String> = modifiers.into_iter().map(|index| MODIFIER_ORDER[index].to_string()).collect();
+    match key {
+        Some(key) => parts.push(key),
+        // Modifier-only chords like Alt+Shift are valid system shortcuts but not registrable hotkeys
+        None if parts.len() > 1 => {}
+        None => return Err(format!("❌ Accelerator has no key: {}", accelerator)),
+    }
+    Ok(parts.join("+"))
+}
+
+/// Conflicts known before asking the OS: reserved system shortcuts and hotkeys JarvisX already holds.
+pub fn find_conflicts(accelerator: &str, id: Option<&str>) -> Result<Vec<HotkeyConflict>, String> {
+    let accelerator = canonical(accelerator)?;
+    let mut conflicts = Vec::new();
+
+    for (shortcut, action) in SYSTEM_SHORTCUTS {
+        if canonical(shortcut).map_or(false, |shortcut| shortcut == accelerator) {
+            conflicts.push(HotkeyConflict { kind: "system".to_string(), accelerator: accelerator.clone(), conflicts_with: action.to_string() });
+        }
+    }
+    if let Some(owner) = REGISTERED.lock().unwrap().get(&accelerator) {
+        if Some(owner.as_str()) != id {
+            conflicts.push(HotkeyConflict { kind: "app".to_string(), accelerator: accelerator.clone(), conflicts_with: owner.clone() });
+        }
+    }
+    Ok(conflicts)
+}
+
+/// Free variants of a conflicting accelerator: extra modifiers first, then neighbouring function keys.
+fn suggest_alternatives(app: &AppHandle, accelerator: &str) -> Vec<String> {
+    let (modifiers, key) = match accelerator.rsplit_once('+') {
+        Some((modifiers, key)) => (modifiers.to_string(), key.to_string()),
+        None => (String::new(), accelerator.to_string()),
+    };
+
+    let mut candidates: Vec<String> = MODIFIER_ORDER
+        .iter()
+        .filter(|modifier| !modifiers.split('+').any(|m| m == **modifier))
+        .map(|modifier| format!("{}+{}+{}", modifiers, modifier, key))
+        .collect();
+    candidates.extend((1..=12).map(|n| format!("{}+F{}", if modifiers.is_empty() { "Ctrl+Alt" } else { &modifiers }, n)));
+
+    let manager = app.global_shortcut_manager();
+    let mut suggestions = Vec::new();
+    for candidate in candidates {
+        let candidate = match canonical(candidate.trim_start_matches('+')) {
+            Ok(candidate) => candidate,
+            Err(_) => continue,
+        };
+        let free = find_conflicts(&candidate, None).map_or(false, |conflicts| conflicts.is_empty())
+            && !manager.is_registered(&candidate).unwrap_or(true);
+        if free && candidate != accelerator && !suggestions.contains(&candidate) {
+            suggestions.push(candidate);
+        }
+        if suggestions.len() == MAX_SUGGESTIONS {
+            break;
+        }
+    }
+    suggestions
+}
+
+/// The HotkeyConflict error, serialized so the frontend can offer the alternatives.
+fn conflict_error(app: &AppHandle, accelerator: &str, conflicts: &[HotkeyConflict]) -> String {
+    serde_json::json!({
+        "error": "HotkeyConflict",
+        "message": format!("❌ {} is already in use", accelerator),
+        "accelerator": accelerator,
+        "conflicts": conflicts,
+        "suggestions": suggest_alternatives(app, accelerator),
+    })
+    .to_string()
+}
+
+/// Registers a global hotkey under `id` after checking for conflicts; re-registering the same id replaces it.
+pub fn register_hotkey<F>(app: &AppHandle, id: &str, accelerator: &str, handler: F) -> Result<String, String>
+where
+    F: Fn() + Send + 'static,
+{
+    let accelerator = canonical(accelerator)?;
+    let conflicts = find_conflicts(&accelerator, Some(id))?;
+    if !conflicts.is_empty() {
+        return Err(conflict_error(app, &accelerator, &conflicts));
+    }
+
+    unregister_hotkey(app, id);
+    let mut manager = app.global_shortcut_manager();
+    // Registered elsewhere in the app (e.g. from the frontend) or refused by the OS
+    if manager.is_registered(&accelerator).unwrap_or(false) || manager.register(&accelerator, handler).is_err() {
+        let conflict = HotkeyConflict { kind: "taken".to_string(), accelerator: accelerator.clone(), conflicts_with: "another application".to_string() };
+        return Err(conflict_error(app, &accelerator, &[conflict]));
+    }
+
+    REGISTERED.lock().unwrap().insert(accelerator.clone(), id.to_string());
+    println!("⌨️  Registered hotkey {} for {}", accelerator, id);
+    Ok(accelerator)
+}
+
+pub fn unregister_hotkey(app: &AppHandle, id: &str) {
+    let mut registered = REGISTERED.lock().unwrap();
+    let held: Vec<String> = registered.iter().filter(|(_, owner)| *owner == id).map(|(accelerator, _)| accelerator.clone()).collect();
+    for accelerator in held {
+        let _ = app.global_shortcut_manager().unregister(&accelerator);
+        registered.remove(&accelerator);
+    }
+}
+
+/// Checks an accelerator without registering it; returns the conflicts and, if any, free alternatives.
+#[tauri::command]
+pub async fn check_hotkey(app: AppHandle, accelerator: String) -> Result<serde_json::Value, String> {
+    let accelerator = canonical(&accelerator)?;
+    let mut conflicts = find_conflicts(&accelerator, None)?;
+    if conflicts.is_empty() && app.global_shortcut_manager().is_registered(&accelerator).unwrap_or(false) {
+        conflicts.push(HotkeyConflict { kind: "taken".to_string(), accelerator: accelerator.clone(), conflicts_with: "another application".to_string() });
+    }
+
+    Ok(serde_json::json!({
+        "accelerator": accelerator,
+        "available": conflicts.is_empty(),
+        "suggestions": if conflicts.is_empty() { Vec::new() } else { suggest_alternatives(&app, &accelerator) },
+        "conflicts": conflicts,
+    }))
+}
+
+/// Registers a hotkey that emits `hotkey://triggered` with its id.
+#[tauri::command]
+pub async fn register_global_hotkey(app: AppHandle, id: String, accelerator: String) -> Result<String, String> {
+    let handle = app.clone();
+    let trigger_id = id.clone();
+    register_hotkey(&app, &id, &accelerator, move || {
+        let _ = handle.emit_all("hotkey://triggered", serde_json::json!({ "id": trigger_id }));
+    })
+}
+
+#[tauri::command]
+pub async fn unregister_global_hotkey(app: AppHandle, id: String) -> Result<(), String> {
+    unregister_hotkey(&app, &id);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn list_global_hotkeys() -> Result<BTreeMap<String, String>, String> {
+    Ok(REGISTERED.lock().unwrap().clone())
+}
@@ -0,0 +1,93 @@
+/**
+ * HTTP server module - Local HTTP endpoint for inbound webhooks and capability-scoped command invocation
+ *
+ * Binds to 127.0.0.1 by default; set JARVISX_HTTP_BIND (e.g. "0.0.0.0:8765") or put a tunnel in
+ * front of it to receive webhooks from hosted stores. Request plumbing is shared with `jarvisx-headless`.
+ */
+
+use jarvisx_core::api::{bind_address, json_response, lowercase_headers, read_body, HttpResponse};
+use tauri::AppHandle;
+
+/// `POST /invoke/<command>` with `Authorization: Bearer <capability token>` and the arguments as a JSON body.
+fn invoke(app: &AppHandle, request: &mut tiny_http::Request, command: &str) -> HttpResponse {
+    let Some(token) = jarvisx_core::api::bearer_token(&lowercase_headers(request)) else {
+        return json_response(401, serde_json::json!({ "error": "Missing capability token" }));
+    };
+
+    let mut args = match read_body(request).and_then(|body| jarvisx_core::api::invoke_args(&body)) {
+        Ok(args) => args,
+        Err(e) => return json_response(400, serde_json::json!({ "error": e })),
+    };
+
+    if let Err(e) = crate::permissions::authorize(app, &token, command, &args) {
+        return json_response(403, serde_json::json!({ "error": e }));
+    }
+
+    // Lets commands that spawn processes apply the capability's sandbox
+    if let Some(args) = args.as_object_mut() {
+        args.insert(crate::permissions::CAPABILITY_FIELD.to_string(), serde_json::json!(token));
+    }
+
+    println!("🌐 API invoke: {}", command);
+    match tauri::async_runtime::block_on(crate::plans::dispatch_native(app, command, &args)) {
+        Ok(result) => json_response(200, serde_json::json!({ "result": result })),
+        Err(e) => json_response(400, serde_json::json!({ "error": e })),
+    }
+}
+
+fn route(app: &AppHandle, request: &mut tiny_http::Request) -> HttpResponse {
+    let path = request.url().split('?').next().unwrap_or("").to_string();
+    let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+
+    match (request.method(), segments.as_slice()) {
+        (tiny_http::Method::Get, ["health"]) => json_response(200, serde_json::json!({
+            "status": "ok",
+            "headless": crate::headless::is_headless(),
+        })),
+        (tiny_http::Method::Post, ["invoke", command]) => invoke(app, request, command),
+        (tiny_http::Method::Post, ["webhooks", integration_id]) => {
+            let headers = lowercase_headers(request);
+            let body = match read_body(request) {
+                Ok(body) => body,
+                Err(e) => return json_response(400, serde_json::json!({ "error": e })),
+            };
+
+            match crate::orders::handle_webhook(app, integration_id, &headers, &body) {
+                Ok(result) => json_response(200, result),
+                Err(e) => json_response(400, serde_json::json!({ "error": e })),
+            }
+        }
+        _ => json_response(404, serde_json::json!({ "error": "Not found" })),
+    }
+}
+
+/// Serves webhooks and API calls on a dedicated thread for the lifetime of the app.
+pub fn spawn_http_server(app: AppHandle) {
+    let address = bind_address();
+
+    std::thread::spawn(move || {
+        let server = match tiny_http::Server::http(&address) {
+            Ok(server) => server,
+            Err(e) => {
+                println!("⚠️  Local HTTP server could not bind {}: {}", address, e);
+                return;
+            }
+        };
+
+        println!("🌐 Local HTTP server listening on {}", address);
+        for mut request in server.incoming_requests() {
+            let feature = match request.url().trim_start_matches('/').split('/').next() {
+                Some("invoke") => Some(crate::bandwidth::Feature::Plugins),
+                Some("webhooks") => Some(crate::bandwidth::Feature::Webhooks),
+                _ => None,
+            };
+            let received = request.url().len() + request.body_length().unwrap_or(0);
+
+            let response = route(&app, &mut request);
+            if let Some(feature) = feature {
+                crate::bandwidth::record(feature, response.data_length().unwrap_or(0) as u64, received as u64);
+            }
+            let _ = request.respond(response);
+        }
+    });
+}
@@ -0,0 +1,461 @@
+/**
+ * Imaging module - Annotation and processing of captured screenshots
+ */
+
+use image::{DynamicImage, Rgba, RgbaImage};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Annotation {
+    #[serde(rename_all = "camelCase")]
+    Arrow {
+        from_x: i32,
+        from_y: i32,
+        to_x: i32,
+        to_y: i32,
+        color: Option<String>,
+        thickness: Option<u32>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Box {
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        color: Option<String>,
+        thickness: Option<u32>,
+        filled: Option<bool>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Blur {
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        strength: Option<f32>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Text {
+        x: i32,
+        y: i32,
+        text: String,
+        color: Option<String>,
+        size: Option<f32>,
+    },
+}
+
+/// Decodes a base64 image, accepting both raw base64 and `data:` URLs.
+pub fn decode_base64_image(data: &str) -> Result<DynamicImage, String> {
+    use base64::{Engine as _, engine::general_purpose};
+
+    let raw = match data.find("base64,") {
+        Some(index) => &data[index + "base64,".len()..],
+        None => data,
+    };
+
+    let bytes = general_purpose::STANDARD.decode(raw.trim()).map_err(|e| e.to_string())?;
+    image::load_from_memory(&bytes).map_err(|e| e.to_string())
+}
+
+/// Encodes an image as base64 PNG for commands that return images inline.
+pub fn encode_base64_png(image: &DynamicImage) -> Result<String, String> {
+    use base64::{Engine as _, engine::general_purpose};
+    use std::io::Cursor;
+
+    let mut cursor = Cursor::new(Vec::new());
+    image.write_to(&mut cursor, image::ImageOutputFormat::Png).map_err(|e| e.to_string())?;
+    Ok(general_purpose::STANDARD.encode(cursor.into_inner()))
+}
+
+fn parse_color(color: &Option<String>) -> Rgba<u8> {
+    let default = Rgba([255, 59, 48, 255]);
+
+    let hex = match color {
+        Some(hex) => hex.trim_start_matches('#'),
+        None => return default,
+    };
+
+    let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+    match hex.len() {
+        6 => match (channel(0), channel(2), channel(4)) {
+            (Some(r), Some(g), Some(b)) => Rgba([r, g, b, 255]),
+            _ => default,
+        },
+        8 => match (channel(0), channel(2), channel(4), channel(6)) {
+            (Some(r), Some(g), Some(b), Some(a)) => Rgba([r, g, b, a]),
+            _ => default,
+        },
+        _ => default,
+    }
+}
+
+/// Draws a line `thickness` pixels wide as a square of offset one-pixel segments.
+pub fn draw_thick_line(canvas: &mut RgbaImage, from: (f32, f32), to: (f32, f32), color: Rgba<u8>, thickness: u32) {
+    use imageproc::drawing::draw_line_segment_mut;
+
+    let half = thickness as i32 / 2;
+    for offset_x in -half..=half {
+        for offset_y in -half..=half {
+            draw_line_segment_mut(
+                canvas,
+                (from.0 + offset_x as f32, from.1 + offset_y as f32),
+                (to.0 + offset_x as f32, to.1 + offset_y as f32),
+                color,
+            );
+        }
+    }
+}
+
+/// Clamps a region to the image bounds, returning None when nothing is left.
+pub fn clamp_region(x: i32, y: i32, width: u32, height: u32, bounds: (u32, u32)) -> Option<(u32, u32, u32, u32)> {
+    let left = x.max(0) as u32;
+    let top = y.max(0) as u32;
+    let right = ((x as i64 + width as i64).max(0) as u32).min(bounds.0);
+    let bottom = ((y as i64 + height as i64).max(0) as u32).min(bounds.1);
+
+    if left >= right || top >= bottom {
+        None
+    } else {
+        Some((left, top, right - left, bottom - top))
+    }
+}
+
+/// Pixelates a region beyond recovery (unlike a blur, which can sometimes be reversed) for privacy masking.
+pub fn pixelate_region(canvas: &mut RgbaImage, x: i32, y: i32, width: u32, height: u32, block: u32) {
+    use image::imageops::FilterType;
+
+    if let Some((left, top, w, h)) = clamp_region(x, y, width, height, canvas.dimensions()) {
+        let block = block.max(2);
+        let region = image::imageops::crop_imm(canvas, left, top, w, h).to_image();
+        let small = image::imageops::resize(&region, (w / block).max(1), (h / block).max(1), FilterType::Triangle);
+        let blocky = image::imageops::resize(&small, w, h, FilterType::Nearest);
+        image::imageops::replace(canvas, &blocky, left as i64, top as i64);
+    }
+}
+
+fn load_system_font() -> Result<rusttype::Font<'static>, String> {
+    let candidates = [
+        "/System/Library/Fonts/Helvetica.ttc",
+        "/Library/Fonts/Arial.ttf",
+        "C:\\Windows\\Fonts\\arial.ttf",
+        "C:\\Windows\\Fonts\\segoeui.ttf",
+        "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+        "/usr/share/fonts/TTF/DejaVuSans.ttf",
+        "/usr/share/fonts/dejavu/DejaVuSans.ttf",
+    ];
+
+    for path in candidates.iter() {
+        if let Ok(bytes) = std::fs::read(path) {
+            if let Some(font) = rusttype::Font::try_from_vec(bytes) {
+                return Ok(font);
+            }
+        }
+    }
+
+    Err("No usable system font found for text annotations".to_string())
+}
+
+fn apply_annotation(canvas: &mut RgbaImage, annotation: &Annotation) -> Result<(), String> {
+    use imageproc::drawing::{draw_filled_rect_mut, draw_hollow_rect_mut, draw_text_mut};
+    use imageproc::rect::Rect;
+
+    match annotation {
+        Annotation::Arrow { from_x, from_y, to_x, to_y, color, thickness } => {
+            let color = parse_color(color);
+            let thickness = thickness.unwrap_or(4).max(1);
+            let from = (*from_x as f32, *from_y as f32);
+            let to = (*to_x as f32, *to_y as f32);
+
+            draw_thick_line(canvas, from, to, color, thickness);
+
+            // Arrow head: two short strokes at ±30° from the shaft
+            let angle = (to.1 - from.1).atan2(to.0 - from.0);
+            let head_length = 12.0 + thickness as f32 * 3.0;
+            for spread in [0.5236f32, -0.5236f32].iter() {
+                let head = (
+                    to.0 - head_length * (angle + spread).cos(),
+                    to.1 - head_length * (angle + spread).sin(),
+                );
+                draw_thick_line(canvas, to, head, color, thickness);
+            }
+        }
+        Annotation::Box { x, y, width, height, color, thickness, filled } => {
+            let color = parse_color(color);
+
+            if filled.unwrap_or(false) {
+                draw_filled_rect_mut(canvas, Rect::at(*x, *y).of_size((*width).max(1), (*height).max(1)), color);
+            } else {
+                let thickness = thickness.unwrap_or(3).max(1);
+                for inset in 0..thickness {
+                    let w = width.saturating_sub(inset * 2);
+                    let h = height.saturating_sub(inset * 2);
+                    if w == 0 || h == 0 {
+                        break;
+                    }
+                    draw_hollow_rect_mut(canvas, Rect::at(x + inset as i32, y + inset as i32).of_size(w, h), color);
+                }
+            }
+        }
+        Annotation::Blur { x, y, width, height, strength } => {
+            if let Some((left, top, w, h)) = clamp_region(*x, *y, *width, *height, canvas.dimensions()) {
+                let region = image::imageops::crop_imm(canvas, left, top, w, h).to_image();
+                let blurred = image::imageops::blur(&region, strength.unwrap_or(12.0).max(1.0));
+                image::imageops::replace(canvas, &blurred, left as i64, top as i64);
+            }
+        }
+        Annotation::Text { x, y, text, color, size } => {
+            let font = load_system_font()?;
+            let scale = rusttype::Scale::uniform(size.unwrap_or(24.0));
+            draw_text_mut(canvas, parse_color(color), *x, *y, scale, &font, text);
+        }
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn annotate_image(base64_image: String, annotations: Vec<Annotation>) -> Result<String, String> {
+    println!("🖍️  Annotating image with {} annotations", annotations.len());
+
+    let mut canvas = decode_base64_image(&base64_image)?.to_rgba8();
+
+    for annotation in annotations.iter() {
+        apply_annotation(&mut canvas, annotation)?;
+    }
+
+    encode_base64_png(&DynamicImage::ImageRgba8(canvas))
+}
+
+/// Per-channel difference above which a pixel counts as changed.
+const PIXEL_TOLERANCE: u8 = 32;
+/// Changed pixels are bucketed into square cells before being merged into regions.
+const DIFF_CELL_SIZE: u32 = 16;
+
+fn changed_regions(cells: &[bool], columns: u32, rows: u32) -> Vec<serde_json::Value> {
+    let mut visited = vec![false; cells.len()];
+    let mut regions = Vec::new();
+
+    for start in 0..cells.len() {
+        if !cells[start] || visited[start] {
+            continue;
+        }
+
+        // Flood-fill adjacent changed cells into one bounding rectangle
+        let (mut min_col, mut min_row) = (u32::MAX, u32::MAX);
+        let (mut max_col, mut max_row) = (0u32, 0u32);
+        let mut stack = vec![start];
+        visited[start] = true;
+
+        while let Some(index) = stack.pop() {
+            let col = index as u32 % columns;
+            let row = index as u32 / columns;
+            min_col = min_col.min(col);
+            min_row = min_row.min(row);
+            max_col = max_col.max(col);
+            max_row = max_row.max(row);
+
+            let mut neighbours = Vec::with_capacity(4);
+            if col > 0 { neighbours.push(index - 1); }
+            if col + 1 < columns { neighbours.push(index + 1); }
+            if row > 0 { neighbours.push(index - columns as usize); }
+            if row + 1 < rows { neighbours.push(index + columns as usize); }
+
+            for next in neighbours {
+                if cells[next] && !visited[next] {
+                    visited[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+
+        regions.push(serde_json::json!({
+            "x": min_col * DIFF_CELL_SIZE,
+            "y": min_row * DIFF_CELL_SIZE,
+            "width": (max_col - min_col + 1) * DIFF_CELL_SIZE,
+            "height": (max_row - min_row + 1) * DIFF_CELL_SIZE,
+        }));
+    }
+
+    regions
+}
+
+#[tauri::command]
+pub async fn compare_images(image_a: String, image_b: String, threshold: Option<f64>) -> Result<serde_json::Value, String> {
+    let threshold = threshold.unwrap_or(0.98);
+    let before = decode_base64_image(&image_a)?.to_rgba8();
+    let mut after = decode_base64_image(&image_b)?.to_rgba8();
+
+    let (width, height) = before.dimensions();
+    if width == 0 || height == 0 {
+        return Err("Cannot compare empty images".to_string());
+    }
+    if after.dimensions() != (width, height) {
+        after = image::imageops::resize(&after, width, height, image::imageops::FilterType::Triangle);
+    }
+
+    let columns = (width + DIFF_CELL_SIZE - 1) / DIFF_CELL_SIZE;
+    let rows = (height + DIFF_CELL_SIZE - 1) / DIFF_CELL_SIZE;
+    let mut cells = vec![false; (columns * rows) as usize];
+    let mut changed_pixels: u64 = 0;
+
+    for (x, y, pixel) in before.enumerate_pixels() {
+        let other = after.get_pixel(x, y);
+        let changed = pixel.0
+            .iter()
+            .zip(other.0.iter())
+            .any(|(a, b)| a.abs_diff(*b) > PIXEL_TOLERANCE);
+
+        if changed {
+            changed_pixels += 1;
+            cells[((y / DIFF_CELL_SIZE) * columns + x / DIFF_CELL_SIZE) as usize] = true;
+        }
+    }
+
+    let total_pixels = width as u64 * height as u64;
+    let similarity = 1.0 - changed_pixels as f64 / total_pixels as f64;
+    let regions = changed_regions(&cells, columns, rows);
+
+    println!("🔍 Image comparison: {:.2}% similar, {} changed regions", similarity * 100.0, regions.len());
+
+    Ok(serde_json::json!({
+        "similarity": similarity,
+        "matches": similarity >= threshold,
+        "threshold": threshold,
+        "changedPixels": changed_pixels,
+        "changedRegions": regions,
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScreenRegion {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+/// Splits a `WIFI:T:WPA;S:name;P:secret;;` payload into its fields.
+fn parse_wifi_payload(content: &str) -> Option<serde_json::Value> {
+    // Scanners disagree on case, so `wifi:` is accepted too (classify_qr_content matches either)
+    let body = content.get(..5).filter(|prefix| prefix.eq_ignore_ascii_case("WIFI:")).map(|_| &content[5..])?;
+    let mut fields = serde_json::Map::new();
+
+    let mut current = String::new();
+    let mut escaped = false;
+    for ch in body.chars() {
+        if escaped {
+            current.push(ch);
+            escaped = false;
+        } else if ch == '\\' {
+            escaped = true;
+        } else if ch == ';' {
+            if let Some((key, value)) = current.split_once(':') {
+                let name = match key {
+                    "S" => Some("ssid"),
+                    "T" => Some("security"),
+                    "P" => Some("password"),
+                    "H" => Some("hidden"),
+                    _ => None,
+                };
+                if let Some(name) = name {
+                    fields.insert(name.to_string(), serde_json::json!(value));
+                }
+            }
+            current.clear();
+        } else {
+            current.push(ch);
+        }
+    }
+
+    Some(serde_json::Value::Object(fields))
+}
+
+fn classify_qr_content(content: &str) -> (&'static str, Option<serde_json::Value>) {
+    let lower = content.to_lowercase();
+
+    if lower.starts_with("wifi:") {
+        ("wifi", parse_wifi_payload(content))
+    } else if lower.starts_with("http://") || lower.starts_with("https://") {
+        ("url", None)
+    } else if lower.starts_with("mailto:") {
+        ("email", None)
+    } else if lower.starts_with("tel:") {
+        ("phone", None)
+    } else if lower.starts_with("begin:vcard") {
+        ("contact", None)
+    } else if lower.starts_with("otpauth://") {
+        ("otp", None)
+    } else {
+        ("text", None)
+    }
+}
+
+#[tauri::command]
+pub async fn detect_qr_codes(
+    base64_image: Option<String>,
+    region: Option<ScreenRegion>,
+) -> Result<Vec<serde_json::Value>, String> {
+    // Fall back to the screen (or a region of it) when no image is supplied
+    let image = match (base64_image, region) {
+        (Some(image), _) => decode_base64_image(&image)?,
+        (None, Some(region)) => {
+            let png = crate::commands::capture_screen_region_png(region.x, region.y, region.width, region.height).await?;
+            image::load_from_memory(&png).map_err(|e| e.to_string())?
+        }
+        (None, None) => {
+            let png = crate::commands::capture_screen_png().await?;
+            image::load_from_memory(&png).map_err(|e| e.to_string())?
+        }
+    };
+
+    let luma = image.to_luma8();
+    let mut prepared = rqrr::PreparedImage::prepare(luma);
+    let grids = prepared.detect_grids();
+
+    let mut codes = Vec::new();
+    for grid in grids.iter() {
+        let (meta, content) = match grid.decode() {
+            Ok(decoded) => decoded,
+            Err(e) => {
+                println!("⚠️  Failed to decode QR grid: {:?}", e);
+                continue;
+            }
+        };
+
+        let (kind, details) = classify_qr_content(&content);
+        let corners: Vec<serde_json::Value> = grid
+            .bounds
+            .iter()
+            .map(|point| serde_json::json!({ "x": point.x, "y": point.y }))
+            .collect();
+
+        codes.push(serde_json::json!({
+            "content": content,
+            "kind": kind,
+            "details": details,
+            "version": meta.version.0,
+            "corners": corners,
+        }));
+    }
+
+    println!("🔳 Detected {} QR codes", codes.len());
+    Ok(codes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wifi_payloads_parse_regardless_of_prefix_case() {
+        for payload in ["WIFI:T:WPA;S:Home\\;Net;P:secret;;", "wifi:T:WPA;S:Home\\;Net;P:secret;;"] {
+            let fields = parse_wifi_payload(payload).unwrap();
+            assert_eq!(fields["ssid"], "Home;Net");
+            assert_eq!(fields["security"], "WPA");
+            assert_eq!(fields["password"], "secret");
+        }
+        assert_eq!(classify_qr_content("Wifi:S:Cafe;;").1.unwrap()["ssid"], "Cafe");
+        assert!(parse_wifi_payload("MECARD:N:Someone;;").is_none());
+    }
+}
@@ -0,0 +1,350 @@
+/**
+ * Ink module - Recognizes stylus or mouse scribbles as text or simple shapes, entirely on device
+ *
+ * Text: strokes are split into lines, each rendered to a grayscale strip and read by a CRNN handwriting model
+ * (`models/handwriting-crnn.onnx`, charset in `handwriting-crnn.txt`) with greedy CTC decoding. No model ships with
+ * the app; until one is provisioned by hand (see `models/README.md`) text mode reports that it is unavailable.
+ * Shapes: each stroke is classified geometrically as a line, circle, ellipse, triangle or rectangle.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tauri::AppHandle;
+use tract_onnx::prelude::*;
+
+/// Model input: one line of handwriting, scaled to this height and padded to this width
+const MODEL_HEIGHT: usize = 64;
+const MODEL_WIDTH: usize = 512;
+/// Stroke width when rendering, in model pixels
+const INK_THICKNESS: u32 = 3;
+/// Strokes whose vertical extents overlap by this fraction of the shorter one belong to the same line
+const LINE_OVERLAP: f64 = 0.3;
+/// Auto mode reads this many strokes or fewer as shapes when every one of them is a shape
+const MAX_SHAPE_STROKES: usize = 3;
+
+pub type InkModel = SimplePlan<TypedFact, Box<dyn TypedOp>, Graph<TypedFact, Box<dyn TypedOp>>>;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct InkPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Stroke {
+    pub points: Vec<InkPoint>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InkBounds {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecognizedShape {
+    /// "line", "circle", "ellipse", "triangle" or "rectangle"
+    pub kind: String,
+    pub bounds: InkBounds,
+    /// Corners for lines and polygons, in input coordinates
+    pub vertices: Vec<(f64, f64)>,
+    pub confidence: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InkRecognition {
+    /// "text" or "shapes"
+    pub kind: String,
+    pub text: Option<String>,
+    pub shapes: Vec<RecognizedShape>,
+    pub confidence: f64,
+}
+
+struct HandwritingModel {
+    plan: InkModel,
+    /// Class i + 1 of the model output; class 0 is the CTC blank
+    charset: Vec<String>,
+}
+
+static HANDWRITING_MODEL: Mutex<Option<Arc<HandwritingModel>>> = Mutex::new(None);
+
+/// Model files text recognition needs, in app resources.
+const HANDWRITING_FILES: &[&str] = &["handwriting-crnn.onnx", "handwriting-crnn.txt"];
+
+/// Why text recognition can't run, if a handwriting model file isn't installed.
+fn text_unavailable(app: &AppHandle) -> Option<String> {
+    let missing: Vec<&str> = HANDWRITING_FILES.iter().copied().filter(|name| resource(app, name).is_err()).collect();
+    (!missing.is_empty()).then(|| {
+        format!("❌ Handwriting text recognition is unavailable: {} not installed (see models/README.md)", missing.join(" and "))
+    })
+}
+
+fn resource(app: &AppHandle, name: &str) -> Result<PathBuf, String> {
+    app.path_resolver()
+        .resolve_resource(format!("models/{}", name))
+        .filter(|path| path.exists())
+        .ok_or_else(|| format!("❌ Handwriting model file {} not found in app resources; see models/README.md", name))
+}
+
+fn load_model(app: &AppHandle) -> Result<HandwritingModel, String> {
+    let plan = tract_onnx::onnx()
+        .model_for_path(resource(app, "handwriting-crnn.onnx")?)
+        .and_then(|model| model.with_input_fact(0, f32::fact([1, 1, MODEL_HEIGHT, MODEL_WIDTH]).into()))
+        .and_then(|model| model.into_optimized())
+        .and_then(|model| model.into_runnable())
+        .map_err(|e| e.to_string())?;
+    let charset = std::fs::read_to_string(resource(app, "handwriting-crnn.txt")?)
+        .map_err(|e| e.to_string())?
+        .split('\n')
+        .map(|line| line.trim_end_matches('\r').to_string())
+        .collect();
+    Ok(HandwritingModel { plan, charset })
+}
+
+/// The handwriting model, loaded on first use and shared afterwards.
+fn handwriting_model(app: &AppHandle) -> Result<Arc<HandwritingModel>, String> {
+    let mut cached = HANDWRITING_MODEL.lock().unwrap();
+
+    if let Some(model) = cached.as_ref() {
+        return Ok(model.clone());
+    }
+
+    let model = Arc::new(crate::subsystems::track_load("handwriting", || load_model(app))?);
+    *cached = Some(model.clone());
+    Ok(model)
+}
+
+fn bounds_of<'a>(points: impl Iterator<Item = &'a InkPoint>) -> InkBounds {
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (f64::MAX, f64::MAX, f64::MIN, f64::MIN);
+    for point in points {
+        min_x = min_x.min(point.x);
+        min_y = min_y.min(point.y);
+        max_x = max_x.max(point.x);
+        max_y = max_y.max(point.y);
+    }
+    InkBounds { x: min_x, y: min_y, width: (max_x - min_x).max(0.0), height: (max_y - min_y).max(0.0) }
+}
+
+/// Strokes grouped into lines of writing, top to bottom, by overlapping vertical extents.
+fn split_lines(strokes: &[Stroke]) -> Vec<Vec<&Stroke>> {
+    let mut ordered: Vec<(&Stroke, InkBounds)> = strokes.iter().map(|stroke| (stroke, bounds_of(stroke.points.iter()))).collect();
+    ordered.sort_by(|a, b| a.1.y.total_cmp(&b.1.y));
+
+    let mut lines: Vec<(Vec<&Stroke>, f64, f64)> = Vec::new();
+    for (stroke, bounds) in ordered {
+        let (top, bottom) = (bounds.y, bounds.y + bounds.height);
+        let joins = lines.last().map_or(false, |(_, line_top, line_bottom)| {
+            let overlap = bottom.min(*line_bottom) - top.max(*line_top);
+            let shorter = (bottom - top).min(line_bottom - line_top).max(1.0);
+            overlap >= shorter * LINE_OVERLAP
+        });
+        match lines.last_mut() {
+            Some((line, line_top, line_bottom)) if joins => {
+                line.push(stroke);
+                *line_top = line_top.min(top);
+                *line_bottom = line_bottom.max(bottom);
+            }
+            _ => lines.push((vec![stroke], top, bottom)),
+        }
+    }
+    lines.into_iter().map(|(line, _, _)| line).collect()
+}
+
+/// Renders one line of strokes as model input: ink 1.0 on a 0.0 background, left-aligned.
+fn render_line(line: &[&Stroke]) -> Tensor {
+    let bounds = bounds_of(line.iter().flat_map(|stroke| stroke.points.iter()));
+    let margin = INK_THICKNESS as f64 * 2.0;
+    let scale = ((MODEL_HEIGHT as f64 - margin * 2.0) / bounds.height.max(1.0))
+        .min((MODEL_WIDTH as f64 - margin * 2.0) / bounds.width.max(1.0));
+
+    let mut canvas = image::RgbaImage::from_pixel(MODEL_WIDTH as u32, MODEL_HEIGHT as u32, image::Rgba([255, 255, 255, 255]));
+    let to_canvas = |point: &InkPoint| {
+        (
+            ((point.x - bounds.x) * scale + margin) as f32,
+            ((point.y - bounds.y) * scale + (MODEL_HEIGHT as f64 - bounds.height * scale) / 2.0) as f32,
+        )
+    };
+    for stroke in line {
+        let points: Vec<(f32, f32)> = stroke.points.iter().map(to_canvas).collect();
+        match points.as_slice() {
+            [dot] => crate::imaging::draw_thick_line(&mut canvas, *dot, *dot, image::Rgba([0, 0, 0, 255]), INK_THICKNESS),
+            _ => {
+                for pair in points.windows(2) {
+                    crate::imaging::draw_thick_line(&mut canvas, pair[0], pair[1], image::Rgba([0, 0, 0, 255]), INK_THICKNESS);
+                }
+            }
+        }
+    }
+
+    tract_ndarray::Array4::from_shape_fn((1, 1, MODEL_HEIGHT, MODEL_WIDTH), |(_, _, y, x)| {
+        1.0 - canvas.get_pixel(x as u32, y as u32)[0] as f32 / 255.0
+    })
+    .into()
+}
+
+/// Greedy CTC decoding: best class per time step, repeats collapsed, blanks dropped.
+fn decode(model: &HandwritingModel, outputs: &TVec<TValue>) -> Result<(String, f64), String> {
+    let logits = outputs[0].to_array_view::<f32>().map_err(|e| e.to_string())?;
+    let (steps, classes) = match logits.shape() {
+        [1, steps, classes] | [steps, 1, classes] | [steps, classes] => (*steps, *classes),
+        shape => return Err(format!("Unexpected handwriting model output shape {:?}", shape)),
+    };
+    let values: Vec<f32> = logits.iter().copied().collect();
+
+    let mut text = String::new();
+    let (mut previous, mut confidence_sum, mut emitted) = (0usize, 0.0, 0usize);
+    for step in values.chunks(classes).take(steps) {
+        let (best, best_value) = step.iter().enumerate().fold((0, f32::MIN), |best, (i, v)| if *v > best.1 { (i, *v) } else { best });
+        if best != 0 && best != previous {
+            text.push_str(model.charset.get(best - 1).map(String::as_str).unwrap_or(""));
+            // Softmax probability of the chosen class
+            let total: f32 = step.iter().map(|v| (v - best_value).exp()).sum();
+            confidence_sum += 1.0 / total as f64;
+            emitted += 1;
+        }
+        previous = best;
+    }
+    Ok((text.trim().to_string(), if emitted == 0 { 0.0 } else { confidence_sum / emitted as f64 }))
+}
+
+fn recognize_text(app: &AppHandle, strokes: &[Stroke]) -> Result<InkRecognition, String> {
+    let model = handwriting_model(app)?;
+
+    let mut lines = Vec::new();
+    let mut confidences = Vec::new();
+    for line in split_lines(strokes) {
+        let outputs = model.plan.run(tvec!(render_line(&line).into())).map_err(|e| e.to_string())?;
+        let (text, confidence) = decode(&model, &outputs)?;
+        if !text.is_empty() {
+            lines.push(text);
+            confidences.push(confidence);
+        }
+    }
+
+    Ok(InkRecognition {
+        kind: "text".to_string(),
+        text: Some(lines.join("\n")),
+        shapes: Vec::new(),
+        confidence: confidences.iter().sum::<f64>() / confidences.len().max(1) as f64,
+    })
+}
+
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+/// Ramer–Douglas–Peucker: the corners of a path, dropping points within `epsilon` of the line between them.
+fn simplify(points: &[(f64, f64)], epsilon: f64) -> Vec<(f64, f64)> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let length = distance(first, last).max(f64::EPSILON);
+    let offset = |p: &(f64, f64)| ((last.0 - first.0) * (first.1 - p.1) - (first.0 - p.0) * (last.1 - first.1)).abs() / length;
+
+    let (index, furthest) = points[1..points.len() - 1]
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i + 1, offset(p)))
+        .fold((0, 0.0), |best, candidate| if candidate.1 > best.1 { candidate } else { best });
+    if furthest <= epsilon {
+        return vec![first, last];
+    }
+
+    let mut left = simplify(&points[..=index], epsilon);
+    left.pop();
+    left.extend(simplify(&points[index..], epsilon));
+    left
+}
+
+fn classify_stroke(stroke: &Stroke) -> Option<RecognizedShape> {
+    let points: Vec<(f64, f64)> = stroke.points.iter().map(|point| (point.x, point.y)).collect();
+    let bounds = bounds_of(stroke.points.iter());
+    let diagonal = (bounds.width.powi(2) + bounds.height.powi(2)).sqrt();
+    if points.len() < 2 || diagonal < 1.0 {
+        return None;
+    }
+
+    let length: f64 = points.windows(2).map(|pair| distance(pair[0], pair[1])).sum();
+    let ends = distance(points[0], points[points.len() - 1]);
+    let shape = |kind: &str, vertices: Vec<(f64, f64)>, confidence: f64| {
+        Some(RecognizedShape { kind: kind.to_string(), bounds, vertices, confidence: confidence.clamp(0.0, 1.0) })
+    };
+
+    // Open strokes: straight enough to be a line, or nothing
+    if ends > diagonal * 0.2 {
+        let straightness = ends / length.max(f64::EPSILON);
+        return if straightness > 0.95 { shape("line", vec![points[0], points[points.len() - 1]], straightness) } else { None };
+    }
+
+    // Closed strokes: an ellipse keeps a steady radius once squashed into a unit square
+    let center = (bounds.x + bounds.width / 2.0, bounds.y + bounds.height / 2.0);
+    let radii: Vec<f64> = points
+        .iter()
+        .map(|p| distance(((p.0 - center.0) / bounds.width.max(1.0), (p.1 - center.1) / bounds.height.max(1.0)), (0.0, 0.0)))
+        .collect();
+    let mean = radii.iter().sum::<f64>() / radii.len() as f64;
+    let spread = (radii.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / radii.len() as f64).sqrt() / mean.max(f64::EPSILON);
+
+    let mut corners = simplify(&points, diagonal * 0.06);
+    if corners.len() > 1 && distance(corners[0], corners[corners.len() - 1]) < diagonal * 0.2 {
+        corners.pop();
+    }
+    match corners.len() {
+        3 => shape("triangle", corners, 1.0 - spread),
+        4 if spread > 0.08 => shape("rectangle", corners, 1.0 - spread),
+        _ if spread < 0.15 => {
+            let aspect = bounds.width / bounds.height.max(1.0);
+            let kind = if (0.8..=1.25).contains(&aspect) { "circle" } else { "ellipse" };
+            shape(kind, Vec::new(), 1.0 - spread * 4.0)
+        }
+        _ => None,
+    }
+}
+
+/// Reads `strokes` (points in any consistent coordinate space) as handwriting or shapes; `mode` is "text", "shapes"
+/// or "auto" (the default: a few strokes that are all shapes are shapes, anything else is text). Without a
+/// handwriting model, auto mode still reads strokes that are all shapes, and anything read as text fails with why.
+#[tauri::command]
+pub async fn recognize_ink(app: AppHandle, strokes: Vec<Stroke>, mode: Option<String>) -> Result<InkRecognition, String> {
+    let strokes: Vec<Stroke> = strokes.into_iter().filter(|stroke| !stroke.points.is_empty()).collect();
+    if strokes.is_empty() {
+        return Err("❌ No strokes to recognize".to_string());
+    }
+
+    let shapes: Vec<Option<RecognizedShape>> = strokes.iter().map(classify_stroke).collect();
+    let all_shapes = strokes.len() <= MAX_SHAPE_STROKES && shapes.iter().all(Option::is_some);
+    let unavailable = text_unavailable(&app);
+    let as_shapes = match mode.as_deref().unwrap_or("auto") {
+        "shapes" => true,
+        "text" => false,
+        "auto" => all_shapes || (unavailable.is_some() && shapes.iter().all(Option::is_some)),
+        other => return Err(format!("❌ Unknown ink mode: {}", other)),
+    };
+    if let (false, Some(reason)) = (as_shapes, unavailable) {
+        return Err(reason);
+    }
+
+    let recognition = if as_shapes {
+        let shapes: Vec<RecognizedShape> = shapes.into_iter().flatten().collect();
+        let confidence = shapes.iter().map(|shape| shape.confidence).sum::<f64>() / shapes.len().max(1) as f64;
+        InkRecognition { kind: "shapes".to_string(), text: None, shapes, confidence }
+    } else {
+        tauri::async_runtime::spawn_blocking(move || recognize_text(&app, &strokes))
+            .await
+            .map_err(|e| e.to_string())??
+    };
+
+    let summary = match &recognition.text {
+        Some(text) => text.clone(),
+        None => recognition.shapes.iter().map(|shape| shape.kind.as_str()).collect::<Vec<_>>().join(", "),
+    };
+    println!("✍️  Ink recognized as {}: {}", recognition.kind, summary);
+    Ok(recognition)
+}
@@ -0,0 +1,138 @@
+/**
+ * Input verify module - Checks that simulated input actually reached the OS
+ *
+ * enigo reports success even when the OS silently drops injected events (missing accessibility or input monitoring
+ * permission), so callers can opt in to checking the effect: cursor position, a key or wheel event seen by the
+ * global input hook, or a change of frontmost app.
+ */
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// How long an injected event gets to show up before it counts as dropped
+const VERIFY_TIMEOUT: Duration = Duration::from_millis(300);
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+/// Scaled displays can round the reported cursor position by a pixel
+const CURSOR_TOLERANCE: i32 = 1;
+
+static HOOK_ACTIVE: AtomicBool = AtomicBool::new(false);
+static KEY_EVENTS: AtomicU64 = AtomicU64::new(0);
+static WHEEL_EVENTS: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputResult {
+    pub message: String,
+    /// Only present when verification was requested; false means the OS most likely dropped the input
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified: Option<bool>,
+}
+
+impl InputResult {
+    pub fn new(message: String, verified: Option<bool>) -> Self {
+        if verified == Some(false) {
+            println!("⚠️  Input not confirmed by the OS: {} (check accessibility / input monitoring permissions)", message);
+        }
+        Self { message, verified }
+    }
+}
+
+pub fn set_hook_active(active: bool) {
+    HOOK_ACTIVE.store(active, Ordering::Relaxed);
+}
+
+/// Counts key and wheel events seen by the global input hook; called by the shared input listener.
+pub fn observe(event: &rdev::Event) {
+    use rdev::EventType;
+
+    match event.event_type {
+        EventType::KeyPress(_) => {
+            KEY_EVENTS.fetch_add(1, Ordering::Relaxed);
+        }
+        EventType::Wheel { .. } => {
+            WHEEL_EVENTS.fetch_add(1, Ordering::Relaxed);
+        }
+        _ => {}
+    }
+}
+
+/// Snapshot of the hook counters to compare against after injecting input.
+#[derive(Debug, Clone, Copy)]
+pub struct HookMark {
+    keys: u64,
+    wheels: u64,
+}
+
+pub fn mark() -> HookMark {
+    HookMark {
+        keys: KEY_EVENTS.load(Ordering::Relaxed),
+        wheels: WHEEL_EVENTS.load(Ordering::Relaxed),
+    }
+}
+
+async fn wait_for(mut check: impl FnMut() -> bool) -> bool {
+    let started = Instant::now();
+    loop {
+        if check() {
+            return true;
+        }
+        if started.elapsed() >= VERIFY_TIMEOUT {
+            return false;
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// Whether the hook saw a key press since `since`; None when no hook is running to tell.
+pub async fn key_registered(since: HookMark) -> Option<bool> {
+    if !HOOK_ACTIVE.load(Ordering::Relaxed) {
+        return None;
+    }
+    Some(wait_for(|| KEY_EVENTS.load(Ordering::Relaxed) > since.keys).await)
+}
+
+pub async fn wheel_registered(since: HookMark) -> Option<bool> {
+    if !HOOK_ACTIVE.load(Ordering::Relaxed) {
+        return None;
+    }
+    Some(wait_for(|| WHEEL_EVENTS.load(Ordering::Relaxed) > since.wheels).await)
+}
+
+/// Whether the OS cursor ended up at (x, y).
+pub async fn cursor_at(x: i32, y: i32) -> Option<bool> {
+    use enigo::{Enigo, Mouse, Settings};
+
+    // Created per poll: an Enigo held across an await would make the command future non-Send
+    let location = || Enigo::new(&Settings::default()).ok().and_then(|enigo| enigo.location().ok());
+    location()?;
+
+    Some(
+        wait_for(|| {
+            location().map_or(false, |(cx, cy)| (cx - x).abs() <= CURSOR_TOLERANCE && (cy - y).abs() <= CURSOR_TOLERANCE)
+        })
+        .await,
+    )
+}
+
+/// Whether the frontmost app is now `title` or at least changed from `before`.
+pub async fn focus_changed(before: Option<String>, title: &str) -> Option<bool> {
+    let needle = title.to_lowercase();
+    let mut seen = false;
+
+    let changed = wait_for(|| match crate::activity::frontmost_app() {
+        Some(app) => {
+            seen = true;
+            app.to_lowercase().contains(&needle) || before.as_deref().map_or(false, |before| before != app)
+        }
+        None => false,
+    })
+    .await;
+
+    Some(changed).filter(|_| seen)
+}
+
+/// Whether the window with `window_id` became the active one.
+pub async fn window_focused(window_id: &str) -> Option<bool> {
+    Some(wait_for(|| crate::window_ids::active_window().map_or(false, |window| window.window_id == window_id)).await)
+}
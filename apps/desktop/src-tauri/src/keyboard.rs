@@ -0,0 +1,260 @@
+/**
+ * Keyboard module - Active layout detection and layout-aware key translation
+ */
+
+use enigo::{Direction, Enigo, Key, Keyboard, Settings};
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::watchdog::WatchdogCommand;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyboardLayout {
+    /// Short layout code such as "us", "de", "fr" or "gb"
+    pub code: String,
+    /// Raw platform identifier the code was derived from
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Modifier {
+    Shift,
+    AltGr,
+}
+
+/// Layout lookups shell out, so the result is reused for a few seconds.
+static LAYOUT_CACHE: Mutex<Option<(Instant, KeyboardLayout)>> = Mutex::new(None);
+const LAYOUT_CACHE_TTL: Duration = Duration::from_secs(5);
+
+fn normalize_layout(source: &str) -> String {
+    let lower = source.to_lowercase();
+    let known = [
+        ("german", "de"), ("de", "de"),
+        ("french", "fr"), ("fr", "fr"),
+        ("british", "gb"), ("gb", "gb"), ("en-gb", "gb"), ("uk", "gb"),
+        ("sinhala", "si"), ("si", "si"),
+        ("tamil", "ta"), ("ta", "ta"),
+    ];
+
+    // macOS ids look like com.apple.keylayout.German, Windows like de-DE, X11 like "de"
+    let last = lower.rsplit(|c| c == '.' || c == ':').next().unwrap_or(&lower).trim();
+    for (pattern, code) in known.iter() {
+        if last == *pattern || last.starts_with(&format!("{}-", pattern)) || last.contains(pattern) && pattern.len() > 3 {
+            return code.to_string();
+        }
+    }
+    "us".to_string()
+}
+
+fn detect_layout_source() -> Result<String, String> {
+    use std::process::Command;
+
+    #[cfg(target_os = "macos")]
+    let output = Command::new("defaults")
+        .args(&["read", "com.apple.HIToolbox", "AppleCurrentKeyboardLayoutInputSourceID"])
+        .output_with_timeout();
+
+    #[cfg(target_os = "windows")]
+    let output = Command::new("powershell")
+        .args(&["-Command", "Add-Type -AssemblyName System.Windows.Forms; [System.Windows.Forms.InputLanguage]::CurrentInputLanguage.Culture.Name"])
+        .output_with_timeout();
+
+    #[cfg(target_os = "linux")]
+    let output = Command::new("setxkbmap").arg("-query").output_with_timeout();
+
+    let output = output?;
+    if !output.status.success() {
+        return Err("Failed to detect keyboard layout".to_string());
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout).to_string();
+
+    #[cfg(target_os = "linux")]
+    let text = text
+        .lines()
+        .find_map(|line| line.strip_prefix("layout:"))
+        .map(|layout| layout.trim().split(',').next().unwrap_or("us").to_string())
+        .unwrap_or_else(|| "us".to_string());
+
+    Ok(text.trim().to_string())
+}
+
+/// Returns the active keyboard layout, refreshing the cache when it is stale.
+pub fn current_layout() -> KeyboardLayout {
+    let mut cache = LAYOUT_CACHE.lock().unwrap();
+
+    if let Some((fetched, layout)) = cache.as_ref() {
+        if fetched.elapsed() < LAYOUT_CACHE_TTL {
+            return layout.clone();
+        }
+    }
+
+    let source = detect_layout_source().unwrap_or_else(|_| "us".to_string());
+    let layout = KeyboardLayout {
+        code: normalize_layout(&source),
+        source,
+    };
+    *cache = Some((Instant::now(), layout.clone()));
+    layout
+}
+
+/// Maps a character to the base key and modifiers that produce it on the layout.
+/// Returns None when the character can be sent as-is.
+pub fn layout_stroke(layout: &str, ch: char) -> Option<(char, &'static [Modifier])> {
+    const SHIFT: &[Modifier] = &[Modifier::Shift];
+    const ALTGR: &[Modifier] = &[Modifier::AltGr];
+    #[cfg(target_os = "macos")]
+    const ALTGR_SHIFT: &[Modifier] = &[Modifier::AltGr, Modifier::Shift];
+
+    // Mac German keyboards put these on Option positions, not the Windows/Linux AltGr ones below
+    #[cfg(target_os = "macos")]
+    match (layout, ch) {
+        ("de", '@') => return Some(('l', ALTGR)),
+        ("de", '€') => return Some(('e', ALTGR)),
+        ("de", '[') => return Some(('5', ALTGR)),
+        ("de", ']') => return Some(('6', ALTGR)),
+        ("de", '|') => return Some(('7', ALTGR)),
+        ("de", '\\') => return Some(('7', ALTGR_SHIFT)),
+        ("de", '{') => return Some(('8', ALTGR)),
+        ("de", '}') => return Some(('9', ALTGR)),
+        // Option+N is a dead key, so the character is sent as-is
+        ("de", '~') => return None,
+        _ => {}
+    }
+
+    let mapped = match (layout, ch) {
+        ("de", '!') => ('1', SHIFT), ("de", '"') => ('2', SHIFT), ("de", '§') => ('3', SHIFT),
+        ("de", '$') => ('4', SHIFT), ("de", '%') => ('5', SHIFT), ("de", '&') => ('6', SHIFT),
+        ("de", '/') => ('7', SHIFT), ("de", '(') => ('8', SHIFT), ("de", ')') => ('9', SHIFT),
+        ("de", '=') => ('0', SHIFT), ("de", '?') => ('ß', SHIFT), ("de", '*') => ('+', SHIFT),
+        ("de", ':') => ('.', SHIFT), ("de", ';') => (',', SHIFT), ("de", '_') => ('-', SHIFT),
+        ("de", '>') => ('<', SHIFT), ("de", '\'') => ('#', SHIFT),
+        ("de", '@') => ('q', ALTGR), ("de", '€') => ('e', ALTGR), ("de", '{') => ('7', ALTGR),
+        ("de", '[') => ('8', ALTGR), ("de", ']') => ('9', ALTGR), ("de", '}') => ('0', ALTGR),
+        ("de", '\\') => ('ß', ALTGR), ("de", '|') => ('<', ALTGR), ("de", '~') => ('+', ALTGR),
+
+        ("fr", '1') => ('&', SHIFT), ("fr", '2') => ('é', SHIFT), ("fr", '3') => ('"', SHIFT),
+        ("fr", '4') => ('\'', SHIFT), ("fr", '5') => ('(', SHIFT), ("fr", '6') => ('-', SHIFT),
+        ("fr", '7') => ('è', SHIFT), ("fr", '8') => ('_', SHIFT), ("fr", '9') => ('ç', SHIFT),
+        ("fr", '0') => ('à', SHIFT), ("fr", '.') => (';', SHIFT), ("fr", '/') => (':', SHIFT),
+        ("fr", '?') => (',', SHIFT), ("fr", '%') => ('ù', SHIFT),
+        ("fr", '@') => ('à', ALTGR), ("fr", '#') => ('"', ALTGR), ("fr", '{') => ('\'', ALTGR),
+        ("fr", '[') => ('(', ALTGR), ("fr", '|') => ('-', ALTGR), ("fr", '\\') => ('_', ALTGR),
+        ("fr", ']') => (')', ALTGR), ("fr", '}') => ('=', ALTGR), ("fr", '€') => ('e', ALTGR),
+
+        ("gb", '"') => ('2', SHIFT), ("gb", '@') => ('\'', SHIFT), ("gb", '£') => ('3', SHIFT),
+        ("gb", '~') => ('#', SHIFT),
+
+        (_, c) if c.is_uppercase() => return c.to_lowercase().next().map(|lower| (lower, SHIFT)),
+        _ => return None,
+    };
+
+    Some(mapped)
+}
+
+/// Keys to hold for a layout modifier on this platform.
+pub fn modifier_keys(modifier: Modifier) -> Vec<Key> {
+    match modifier {
+        Modifier::Shift => vec![Key::Shift],
+        // Windows treats Ctrl+Alt as AltGr; macOS uses Option
+        #[cfg(target_os = "windows")]
+        Modifier::AltGr => vec![Key::Control, Key::Alt],
+        #[cfg(target_os = "macos")]
+        Modifier::AltGr => vec![Key::Alt],
+        #[cfg(target_os = "linux")]
+        Modifier::AltGr => vec![Key::Other(0xfe03)], // ISO_Level3_Shift
+    }
+}
+
+/// Presses a chord: modifiers down in order, key tap, modifiers up in reverse.
+pub fn press_chord(enigo: &mut Enigo, modifiers: &[Key], key: Key) -> Result<(), String> {
+    if !modifiers.is_empty() {
+        crate::recovery::track("input:chord", "input", serde_json::json!({ "modifiers": format!("{:?}", modifiers) }));
+    }
+    let result = modifiers
+        .iter()
+        .try_for_each(|modifier| enigo.key(*modifier, Direction::Press).map_err(|e| e.to_string()))
+        .and_then(|_| enigo.key(key, Direction::Click).map_err(|e| e.to_string()));
+
+    // Release even after a failed press; anything still held is left to the stuck-key watchdog
+    let released = modifiers
+        .iter()
+        .rev()
+        .try_for_each(|modifier| enigo.key(*modifier, Direction::Release).map_err(|e| e.to_string()));
+    if !modifiers.is_empty() {
+        crate::recovery::untrack("input:chord");
+    }
+
+    result.and(released)
+}
+
+/// Types a single character with the modifiers the active layout requires.
+pub fn type_char(enigo: &mut Enigo, layout: &str, ch: char) -> Result<(), String> {
+    match layout_stroke(layout, ch) {
+        Some((base, modifiers)) => {
+            let keys: Vec<Key> = modifiers.iter().flat_map(|m| modifier_keys(*m)).collect();
+            press_chord(enigo, &keys, Key::Unicode(base))
+        }
+        None => enigo.key(Key::Unicode(ch), Direction::Click).map_err(|e| e.to_string()),
+    }
+}
+
+/// Parses a non-character key name ("enter", "tab", "f5", "left", ...).
+pub fn named_key(name: &str) -> Option<Key> {
+    let key = match name.to_lowercase().as_str() {
+        "enter" | "return" => Key::Return,
+        "space" => Key::Space,
+        "tab" => Key::Tab,
+        "escape" | "esc" => Key::Escape,
+        "backspace" => Key::Backspace,
+        "delete" | "del" => Key::Delete,
+        "up" => Key::UpArrow,
+        "down" => Key::DownArrow,
+        "left" => Key::LeftArrow,
+        "right" => Key::RightArrow,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" | "pgup" => Key::PageUp,
+        "pagedown" | "pgdown" => Key::PageDown,
+        "f1" => Key::F1,
+        "f2" => Key::F2,
+        "f3" => Key::F3,
+        "f4" => Key::F4,
+        "f5" => Key::F5,
+        "f6" => Key::F6,
+        "f7" => Key::F7,
+        "f8" => Key::F8,
+        "f9" => Key::F9,
+        "f10" => Key::F10,
+        "f11" => Key::F11,
+        "f12" => Key::F12,
+        "ctrl" => Key::Control,
+        "alt" => Key::Alt,
+        "shift" => Key::Shift,
+        "cmd" | "command" | "meta" => Key::Meta,
+        _ => return None,
+    };
+    Some(key)
+}
+
+/// Parses a modifier name as used by the frontend ("ctrl", "cmd", ...).
+pub fn parse_modifier(name: &str) -> Result<Key, String> {
+    match name.to_lowercase().as_str() {
+        "ctrl" | "control" => Ok(Key::Control),
+        "alt" | "option" => Ok(Key::Alt),
+        "shift" => Ok(Key::Shift),
+        "cmd" | "command" | "meta" | "super" | "win" => Ok(Key::Meta),
+        _ => Err(format!("Unsupported modifier: {}", name)),
+    }
+}
+
+pub fn new_enigo() -> Result<Enigo, String> {
+    Enigo::new(&Settings::default()).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_keyboard_layout() -> Result<KeyboardLayout, String> {
+    Ok(current_layout())
+}
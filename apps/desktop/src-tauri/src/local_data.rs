@@ -0,0 +1,178 @@
+/**
+ * Local data module - Export of the active profile's data to a zip archive, and confirmed wipes by category
+ *
+ * Categories: settings, memory, transcripts, audit, voice, integrations. Keychain secrets are never exported; wiping
+ * integrations also deletes the broker, store and S3 credentials they keep in the keychain.
+ */
+
+use crate::confirmation::{Confirmations, CONFIRMATION_TTL};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, State};
+
+const CATEGORIES: &[&str] = &["settings", "memory", "transcripts", "audit", "voice", "integrations"];
+
+#[derive(Default)]
+pub struct LocalDataState {
+    pending: Confirmations<Vec<String>>,
+}
+
+/// Files and folders of the profile directory that belong to `category`.
+fn category_paths(dir: &Path, category: &str) -> Result<Vec<PathBuf>, String> {
+    let paths = match category {
+        // Top-level JSON files: profile settings, capabilities and every module's saved configuration
+        "settings" => std::fs::read_dir(dir)
+            .map_err(|e| e.to_string())?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && path.extension().map_or(false, |ext| ext == "json"))
+            .collect(),
+        "memory" => ["memory.db", "memory.db-journal", "memory.db-wal", "memory.db-shm"]
+            .iter()
+            .map(|name| dir.join(name))
+            .collect(),
+        // Recorded sessions hold the transcripts
+        "transcripts" => vec![dir.join("sessions")],
+        "audit" => vec![dir.join("supervised"), dir.join("trading").join("audit.jsonl")],
+        "voice" => vec![dir.join("voice")],
+        "integrations" => vec![
+            dir.join("orders"),
+            dir.join("rss"),
+            dir.join("scripts"),
+            dir.join("vpn"),
+            dir.join("trading").join("broker.json"),
+        ],
+        _ => return Err(format!("❌ Unknown data category: {} (expected one of {})", category, CATEGORIES.join(", "))),
+    };
+
+    Ok(paths.into_iter().filter(|path| path.exists()).collect())
+}
+
+/// Keychain entries (in the profile's secrets namespace) that belong to `category`.
+fn category_secrets(app: &AppHandle, category: &str) -> Vec<String> {
+    match category {
+        "integrations" => {
+            let mut keys = crate::trading::vault_keys();
+            keys.extend(crate::orders::vault_keys(app));
+            keys.extend(crate::share::vault_keys());
+            keys
+        }
+        _ => Vec::new(),
+    }
+}
+
+pub fn size_of(path: &Path) -> u64 {
+    if path.is_dir() {
+        std::fs::read_dir(path)
+            .map(|entries| entries.filter_map(|entry| entry.ok()).map(|entry| size_of(&entry.path())).sum())
+            .unwrap_or(0)
+    } else {
+        std::fs::metadata(path).map(|metadata| metadata.len()).unwrap_or(0)
+    }
+}
+
+fn normalize_categories(categories: Vec<String>) -> Result<Vec<String>, String> {
+    let mut categories: Vec<String> = categories.into_iter().map(|category| category.trim().to_lowercase()).collect();
+    if categories.iter().any(|category| category == "all") {
+        categories = CATEGORIES.iter().map(|category| category.to_string()).collect();
+    }
+    categories.sort();
+    categories.dedup();
+
+    if categories.is_empty() {
+        return Err("No data categories given".to_string());
+    }
+    Ok(categories)
+}
+
+/// Writes everything in the active profile (settings, memory DB, transcripts, audit logs, ...) to a zip at `dest`.
+#[tauri::command]
+pub async fn export_all_data(app: AppHandle, dest: String) -> Result<serde_json::Value, String> {
+    let dir = crate::profiles::active_profile_dir(&app)?;
+    let dest_path = PathBuf::from(&dest);
+    if dest_path.starts_with(&dir) {
+        return Err("Choose a destination outside the profile folder".to_string());
+    }
+
+    println!("📤 Exporting local data to {}", dest);
+
+    let task_app = app.clone();
+    let count = tauri::async_runtime::spawn_blocking(move || {
+        let entries = crate::archive::collect_entries(&[dir.to_string_lossy().to_string()])?;
+        crate::archive::create_zip(&task_app, &entries, &dest_path)?;
+        Ok::<_, String>(entries.len())
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    Ok(serde_json::json!({
+        "archive": dest,
+        "files": count,
+    }))
+}
+
+/// First call (no token): lists what would be deleted and returns a confirmation token.
+/// Second call (with that token, same categories): deletes it.
+#[tauri::command]
+pub async fn wipe_local_data(
+    app: AppHandle,
+    state: State<'_, Mutex<LocalDataState>>,
+    categories: Vec<String>,
+    confirmation_token: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let categories = normalize_categories(categories)?;
+    let dir = crate::profiles::active_profile_dir(&app)?;
+
+    let mut targets = Vec::new();
+    let mut secrets = Vec::new();
+    for category in &categories {
+        targets.extend(category_paths(&dir, category)?);
+        secrets.extend(category_secrets(&app, category));
+    }
+
+    let token = match confirmation_token {
+        None => {
+            let token = state.lock().unwrap().pending.issue(categories.clone());
+            return Ok(serde_json::json!({
+                "status": "confirmation_required",
+                "confirmationToken": token,
+                "expiresInSecs": CONFIRMATION_TTL.as_secs(),
+                "categories": categories,
+                "paths": targets,
+                "secrets": secrets,
+                "bytes": targets.iter().map(|path| size_of(path)).sum::<u64>(),
+            }));
+        }
+        Some(token) => token,
+    };
+
+    state
+        .lock()
+        .unwrap()
+        .pending
+        .redeem(&token, &categories, "Categories do not match the confirmed wipe")
+        .map_err(|reason| format!("❌ {}", reason))?;
+
+    for path in &targets {
+        let result = if path.is_dir() { std::fs::remove_dir_all(path) } else { std::fs::remove_file(path) };
+        result.map_err(|e| format!("Failed to delete {}: {}", path.display(), e))?;
+    }
+    let namespace = crate::profiles::active_secrets_namespace(&app);
+    for key in &secrets {
+        crate::secrets::delete_secret(&namespace, key).map_err(|e| format!("Failed to delete the {} credential: {}", key, e))?;
+    }
+
+    // Drop in-memory copies of what was just deleted
+    if categories.iter().any(|category| category == "settings") {
+        crate::permissions::load_capabilities(&app);
+        crate::egress::load_egress_policy(app.clone());
+        crate::snippets::load_snippets(app.clone());
+    }
+
+    println!("🧹 Wiped local data: {} ({} paths)", categories.join(", "), targets.len());
+    Ok(serde_json::json!({
+        "status": "wiped",
+        "categories": categories,
+        "paths": targets,
+        "secrets": secrets,
+    }))
+}
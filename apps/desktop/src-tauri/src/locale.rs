@@ -0,0 +1,140 @@
+/**
+ * Locale module - Locale-aware formatting for command responses and notifications
+ */
+
+use std::sync::RwLock;
+
+const SUPPORTED_LOCALES: [&str; 4] = ["en-US", "en-GB", "si-LK", "ta-LK"];
+
+static CURRENT_LOCALE: RwLock<String> = RwLock::new(String::new());
+
+pub fn current_locale() -> String {
+    let locale = CURRENT_LOCALE.read().unwrap();
+    if locale.is_empty() { "en-US".to_string() } else { locale.clone() }
+}
+
+fn language() -> String {
+    current_locale().split('-').next().unwrap_or("en").to_string()
+}
+
+/// Looks up a user-facing message, falling back to English for missing entries.
+pub fn t(key: &str) -> String {
+    let message = match (language().as_str(), key) {
+        ("si", "focus.blocked.title") => "අවධානය රඳවා ගන්න",
+        ("si", "focus.complete.title") => "අවධාන සැසිය අවසන්",
+        ("si", "focus.complete.body") => "ඉතා හොඳයි! දැන් විවේකයක් ගන්න.",
+        ("si", "download.complete.title") => "බාගැනීම සම්පූර්ණයි",
+        ("si", "greeting.morning") => "සුභ උදෑසනක්",
+        ("si", "greeting.afternoon") => "සුභ දහවලක්",
+        ("si", "greeting.evening") => "සුභ සන්ධ්‍යාවක්",
+        ("si", "greeting.night") => "සුභ රාත්‍රියක්",
+        ("ta", "focus.blocked.title") => "கவனத்தை தக்கவையுங்கள்",
+        ("ta", "focus.complete.title") => "கவன அமர்வு முடிந்தது",
+        ("ta", "focus.complete.body") => "அருமை! இப்போது ஓய்வெடுங்கள்.",
+        ("ta", "download.complete.title") => "பதிவிறக்கம் முடிந்தது",
+        ("ta", "greeting.morning") => "காலை வணக்கம்",
+        ("ta", "greeting.afternoon") => "மதிய வணக்கம்",
+        ("ta", "greeting.evening") => "மாலை வணக்கம்",
+        ("ta", "greeting.night") => "இனிய இரவு",
+        (_, "focus.blocked.title") => "Stay focused",
+        (_, "focus.complete.title") => "Focus session complete",
+        (_, "focus.complete.body") => "Nice work! Time for a break.",
+        (_, "download.complete.title") => "Download complete",
+        (_, "greeting.morning") => "Good morning",
+        (_, "greeting.afternoon") => "Good afternoon",
+        (_, "greeting.evening") => "Good evening",
+        (_, "greeting.night") => "Good night",
+        (_, other) => other,
+    };
+
+    message.to_string()
+}
+
+/// Formats a duration in seconds, e.g. "3h 12m" or "පැය 3 මිනිත්තු 12".
+pub fn format_duration(total_secs: u64) -> String {
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+
+    match language().as_str() {
+        "si" => format!("පැය {} මිනිත්තු {}", hours, minutes),
+        "ta" => format!("{} மணி {} நிமிடம்", hours, minutes),
+        _ => format!("{}h {}m", hours, minutes),
+    }
+}
+
+/// Formats a number with locale grouping and the given number of decimals.
+pub fn format_number(value: f64, decimals: usize) -> String {
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = match formatted.split_once('.') {
+        Some((integer, fraction)) => (integer.to_string(), Some(fraction.to_string())),
+        None => (formatted, None),
+    };
+
+    // South Asian locales group as 12,34,567 (lakh/crore); others in thousands
+    let south_asian = matches!(language().as_str(), "si" | "ta");
+    let mut groups = Vec::new();
+    let mut rest = integer.as_str();
+    let mut group_size = 3;
+    while rest.len() > group_size {
+        let split = rest.len() - group_size;
+        groups.push(rest[split..].to_string());
+        rest = &rest[..split];
+        if south_asian {
+            group_size = 2;
+        }
+    }
+    groups.push(rest.to_string());
+    groups.reverse();
+
+    let mut result = String::new();
+    if value < 0.0 {
+        result.push('-');
+    }
+    result.push_str(&groups.join(","));
+    if let Some(fraction) = fraction {
+        result.push('.');
+        result.push_str(&fraction);
+    }
+    result
+}
+
+/// Formats a unix timestamp (seconds) as a local date, optionally with time.
+pub fn format_date(timestamp_secs: i64, include_time: bool) -> String {
+    use chrono::{Local, TimeZone};
+
+    let datetime = match Local.timestamp_opt(timestamp_secs, 0).single() {
+        Some(datetime) => datetime,
+        None => return timestamp_secs.to_string(),
+    };
+
+    let pattern = match (current_locale().as_str(), include_time) {
+        ("en-US", false) => "%m/%d/%Y",
+        ("en-US", true) => "%m/%d/%Y %I:%M %p",
+        ("en-GB", false) => "%d/%m/%Y",
+        ("en-GB", true) => "%d/%m/%Y %H:%M",
+        (_, false) => "%Y-%m-%d",
+        (_, true) => "%Y-%m-%d %H:%M",
+    };
+
+    datetime.format(pattern).to_string()
+}
+
+#[tauri::command]
+pub async fn set_locale(locale: String) -> Result<String, String> {
+    let normalized = SUPPORTED_LOCALES
+        .iter()
+        .find(|supported| supported.eq_ignore_ascii_case(&locale.replace('_', "-")))
+        .ok_or_else(|| format!("Unsupported locale: {}", locale))?;
+
+    *CURRENT_LOCALE.write().unwrap() = normalized.to_string();
+    println!("🌐 Locale set to {}", normalized);
+    Ok(normalized.to_string())
+}
+
+#[tauri::command]
+pub async fn get_locale() -> Result<serde_json::Value, String> {
+    Ok(serde_json::json!({
+        "locale": current_locale(),
+        "supported": SUPPORTED_LOCALES,
+    }))
+}
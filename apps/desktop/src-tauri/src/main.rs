@@ -5,25 +5,277 @@ mod commands;
 mod voice;
 mod system;
 mod screen;
+mod focus;
+mod imaging;
+mod documents;
+mod downloads;
+mod archive;
+mod camera;
+mod presence;
+mod secrets;
+mod auth;
+mod profiles;
+mod secure_channel;
+mod recording;
+mod benchmarks;
+mod keyboard;
+mod text_input;
+mod gestures;
+mod dragdrop;
+mod window_registry;
+mod watchdog;
+mod locale;
+mod theme;
+mod bluetooth;
+mod wifi;
+mod vpn;
+mod permissions;
+mod supervised;
+mod scripting;
+mod voice_shortcuts;
+mod context;
+mod plans;
+mod retry;
+mod cache;
+mod scheduler;
+mod subsystems;
+mod binary;
+mod batch;
+mod speech;
+mod narration;
+mod captions;
+mod memory;
+mod meetings;
+mod translation;
+mod calculator;
+mod timers;
+mod rss;
+mod tickers;
+mod trading;
+mod http_server;
+mod orders;
+mod printing;
+mod snippets;
+mod search;
+mod associations;
+mod recents;
+mod placement;
+mod activity;
+mod redaction;
+mod egress;
+mod local_data;
+mod retention;
+mod recovery;
+mod headless;
+mod mock_platform;
+mod input_verify;
+mod stuck_keys;
+mod ducking;
+mod config_watch;
+mod app_windows;
+mod screen_permission;
+mod calibration;
+mod automation_guard;
+mod voice_activity;
+mod spectrum;
+mod environment;
+mod hotkeys;
+mod process_sandbox;
+mod frame_scaler;
+mod window_ids;
+mod audio_sessions;
+mod proxy;
+mod connectivity;
+mod bandwidth;
+mod time_context;
+mod datetime_parse;
+mod reading_overlay;
+mod presentation;
+mod power;
+mod removable;
+mod backups;
+mod share;
+mod tables;
+mod ink;
+mod hid;
+mod confirmation;
 
 use commands::*;
 use voice::*;
 use system::*;
 use screen::*;
+use focus::*;
+use imaging::*;
+use documents::*;
+use downloads::*;
+use archive::*;
+use camera::*;
+use presence::*;
+use auth::*;
+use profiles::*;
+use secure_channel::*;
+use recording::*;
+use benchmarks::*;
+use keyboard::*;
+use gestures::*;
+use dragdrop::*;
+use window_registry::*;
+use watchdog::*;
+use locale::*;
+use theme::*;
+use bluetooth::*;
+use wifi::*;
+use vpn::*;
+use permissions::*;
+use supervised::*;
+use scripting::*;
+use voice_shortcuts::*;
+use context::*;
+use plans::*;
+use retry::*;
+use cache::*;
+use scheduler::*;
+use subsystems::*;
+use binary::*;
+use batch::*;
+use speech::*;
+use narration::*;
+use captions::*;
+use memory::*;
+use meetings::*;
+use translation::*;
+use calculator::*;
+use timers::*;
+use rss::*;
+use tickers::*;
+use trading::*;
+use http_server::*;
+use orders::*;
+use printing::*;
+use snippets::*;
+use search::*;
+use associations::*;
+use recents::*;
+use placement::*;
+use activity::*;
+use redaction::*;
+use egress::*;
+use local_data::*;
+use retention::*;
+use recovery::*;
+use headless::*;
+use mock_platform::*;
+use stuck_keys::*;
+use ducking::*;
+use config_watch::*;
+use app_windows::*;
+use screen_permission::*;
+use calibration::*;
+use automation_guard::*;
+use voice_activity::*;
+use environment::*;
+use hotkeys::*;
+use process_sandbox::*;
+use frame_scaler::*;
+use window_ids::*;
+use audio_sessions::*;
+use proxy::*;
+use connectivity::*;
+use bandwidth::*;
+use time_context::*;
+use datetime_parse::*;
+use reading_overlay::*;
+use presentation::*;
+use power::*;
+use removable::*;
+use backups::*;
+use share::*;
+use tables::*;
+use ink::*;
+use hid::*;
+
+use std::sync::Mutex;
 
 fn main() {
     tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![
+        .manage(Mutex::new(FocusState::default()))
+        .manage(Mutex::new(DownloadsState::default()))
+        .manage(Mutex::new(CameraState::default()))
+        .manage(Mutex::new(VoiceState::default()))
+        .manage(Mutex::new(PresenceState::default()))
+        .manage(Mutex::new(AuthState::default()))
+        .manage(Mutex::new(ProfilesState::default()))
+        .manage(Mutex::new(SecureChannelState::default()))
+        .manage(Mutex::new(RecordingState::default()))
+        .manage(Mutex::new(ScreenState::default()))
+        .manage(Mutex::new(WindowRegistry::default()))
+        .manage(Mutex::new(PermissionsState::default()))
+        .manage(Mutex::new(SupervisedState::default()))
+        .manage(Mutex::new(PlanState::default()))
+        .manage(Mutex::new(SchedulerState::default()))
+        .manage(Mutex::new(NarrationState::default()))
+        .manage(Mutex::new(CaptionState::default()))
+        .manage(Mutex::new(MeetingState::default()))
+        .manage(Mutex::new(TimerState::default()))
+        .manage(Mutex::new(TickerState::default()))
+        .manage(Mutex::new(TradingState::default()))
+        .manage(Mutex::new(SnippetState::default()))
+        .manage(Mutex::new(PlacementState::default()))
+        .manage(Mutex::new(ActivityState::default()))
+        .manage(Mutex::new(RedactionReport::default()))
+        .manage(Mutex::new(LocalDataState::default()))
+        .manage(Mutex::new(AppWindowsState::default()))
+        .setup(|app| {
+            load_mock_platform();
+            recover_runtime_state(app.handle());
+            if !is_headless() {
+                open_main_window(&app.handle())?;
+            }
+            load_profiles(&app.handle());
+            load_capabilities(&app.handle());
+            // Before anything that makes outbound requests
+            load_egress_policy(app.handle());
+            load_proxy_settings(&app.handle());
+            load_bandwidth_policy(&app.handle());
+            spawn_token_refresher(app.handle());
+            spawn_theme_watcher(app.handle());
+            spawn_audio_device_watcher(app.handle());
+            spawn_scheduler(app.handle());
+            register_narration_hotkey(app.handle());
+            load_timers(app.handle());
+            spawn_ticker_watcher(app.handle());
+            spawn_http_server(app.handle());
+            load_snippets(app.handle());
+            load_pointer_calibration(&app.handle());
+            load_environment_profile(&app.handle());
+            watch_active_profile(&app.handle());
+            spawn_input_listener(app.handle());
+            spawn_display_watcher(app.handle());
+            spawn_retention_janitor(app.handle());
+            spawn_stuck_key_watchdog(app.handle());
+            spawn_connectivity_watcher(app.handle());
+            spawn_removable_watcher(app.handle());
+            spawn_backup_scheduler(app.handle());
+            spawn_hid_watcher(app.handle());
+            Ok(())
+        })
+        .register_uri_scheme_protocol(binary::PROTOCOL, handle_protocol)
+        .invoke_handler(scoped(tauri::generate_handler![
             // System control
             open_application,
+            open_with,
+            open_uri,
             execute_command,
             get_system_info,
+            list_installed_applications,
             simulate_keyboard,
             simulate_mouse_click,
             simulate_mouse_drag,
             simulate_mouse_scroll,
             get_mouse_position,
             simulate_mouse_hover,
+            simulate_gesture,
+            drag_file_to_window,
             get_running_processes,
             // App management
             switch_to_application,
@@ -32,16 +284,24 @@ fn main() {
             close_application,
             get_window_list,
             focus_window,
+            subscribe_window_events,
+            unsubscribe_window_events,
             // Voice
             start_microphone,
             stop_microphone,
             get_audio_devices,
+            set_preferred_audio_devices,
+            get_preferred_audio_devices,
             process_audio_chunk,
             // Screen
             capture_screen,
             capture_screen_region,
             start_screen_stream,
             stop_screen_stream,
+            set_adaptive_streaming,
+            annotate_image,
+            compare_images,
+            detect_qr_codes,
             // System
             get_system_uptime,
             // Avatar
@@ -51,7 +311,328 @@ fn main() {
             set_window_always_on_top,
             hide_window,
             show_window,
-        ])
+            // Focus sessions
+            start_focus_session,
+            stop_focus_session,
+            get_focus_status,
+            // Documents
+            extract_document_text,
+            // Downloads
+            start_downloads_watcher,
+            stop_downloads_watcher,
+            set_download_rules,
+            get_download_rules,
+            perform_download_action,
+            // Archives
+            extract_archive,
+            create_archive,
+            // Camera
+            list_cameras,
+            capture_photo,
+            start_camera_stream,
+            stop_camera_stream,
+            revoke_camera_permission,
+            // Presence
+            start_presence_detection,
+            stop_presence_detection,
+            get_presence_status,
+            // Auth
+            login,
+            logout,
+            get_auth_status,
+            get_access_token,
+            // Profiles
+            list_profiles,
+            get_active_profile,
+            create_profile,
+            switch_profile,
+            delete_profile,
+            get_profile_settings,
+            set_profile_settings,
+            // Secure remote sessions
+            begin_secure_session,
+            process_handshake_message,
+            encrypt_message,
+            decrypt_message,
+            close_secure_session,
+            get_session_security_info,
+            unpin_device,
+            // Session recording
+            record_session,
+            record_action,
+            record_transcript,
+            stop_session,
+            list_sessions,
+            replay_session,
+            stop_replay,
+            // Benchmarks
+            run_benchmarks,
+            // Keyboard
+            type_text,
+            press_hotkey,
+            get_keyboard_layout,
+            // Watchdog
+            set_command_timeout,
+            get_command_timeout,
+            // Locale
+            set_locale,
+            get_locale,
+            // Appearance
+            get_system_theme,
+            // Bluetooth
+            list_bluetooth_devices,
+            connect_bluetooth_device,
+            disconnect_bluetooth_device,
+            // Wi-Fi
+            list_wifi_networks,
+            connect_wifi,
+            save_wifi_credential,
+            forget_wifi_credential,
+            // VPN
+            get_vpn_status,
+            set_vpn_connection,
+            toggle_vpn,
+            // Capabilities
+            grant_capability,
+            revoke_capability,
+            list_capabilities,
+            // Supervised mode
+            enter_supervised_mode,
+            exit_supervised_mode,
+            get_supervised_status,
+            shutdown_system,
+            delete_path,
+            // Scripting
+            run_script,
+            list_scripts,
+            save_script,
+            delete_script,
+            // Voice shortcuts
+            create_voice_shortcut,
+            list_voice_shortcuts,
+            delete_voice_shortcut,
+            match_voice_shortcut,
+            // Context
+            get_context_snapshot,
+            // Plans
+            execute_plan,
+            respond_plan_step,
+            // Retry
+            set_retry_policy,
+            get_retry_policy,
+            // Cache
+            clear_response_cache,
+            // Scheduler
+            set_performance_profile,
+            get_scheduler_status,
+            // Subsystems
+            get_subsystem_status,
+            preload_subsystem,
+            // Binary transfer
+            read_file_binary,
+            // Batching
+            invoke_batch,
+            // Speech
+            speak_text,
+            stop_speaking,
+            // Narration
+            toggle_narration,
+            get_narration_status,
+            // Captions
+            start_system_audio_capture,
+            stop_system_audio_capture,
+            get_caption_status,
+            // Memory
+            list_memories,
+            get_memory,
+            delete_memory,
+            // Meetings
+            set_meeting_mode,
+            end_meeting,
+            get_meeting_status,
+            // Translation
+            translate_text,
+            set_translation_settings,
+            get_translation_settings,
+            // Calculator
+            evaluate_expression,
+            // Timers
+            start_timer,
+            list_timers,
+            cancel_timer,
+            start_stopwatch,
+            lap_stopwatch,
+            stop_stopwatch,
+            get_stopwatch,
+            // RSS
+            add_feed,
+            remove_feed,
+            list_feeds,
+            get_briefing,
+            // Tickers
+            watch_ticker,
+            list_watched_tickers,
+            remove_watch,
+            set_ticker_provider,
+            // Trading
+            configure_broker,
+            get_positions,
+            get_quotes,
+            place_order,
+            // Orders
+            register_order_integration,
+            list_order_integrations,
+            remove_order_integration,
+            get_recent_orders,
+            // Printing
+            list_printers,
+            print_file,
+            // Snippets
+            add_snippet,
+            list_snippets,
+            remove_snippet,
+            // Search
+            universal_search,
+            // Associations
+            get_default_app,
+            set_default_app,
+            // Recents
+            get_recent_documents,
+            // Placement
+            list_displays,
+            move_window_to_display,
+            dock_window,
+            undock_window,
+            toggle_mini_mode,
+            // Activity
+            set_activity_observer,
+            get_activity_status,
+            // Redaction
+            redact_text,
+            get_redaction_report,
+            set_redaction_policy,
+            // Egress
+            list_egress_destinations,
+            block_egress_destination,
+            allow_egress_destination,
+            set_egress_allowlist_only,
+            // Local data
+            export_all_data,
+            wipe_local_data,
+            // Retention
+            get_retention_policies,
+            set_retention_policy,
+            get_storage_breakdown,
+            // Recovery
+            get_recovery_report,
+            get_runtime_state,
+            // Mock platform
+            set_mock_platform,
+            get_mock_platform_state,
+            get_mock_input_log,
+            paint_mock_screen,
+            // Stuck keys
+            release_all_keys,
+            // Audio ducking
+            set_audio_ducking,
+            get_audio_ducking,
+            // App windows
+            create_child_window,
+            list_app_windows,
+            close_app_window,
+            // Screen permission
+            check_screen_capture_permission,
+            // Pointer calibration
+            run_pointer_calibration,
+            get_pointer_calibration,
+            reset_pointer_calibration,
+            // Automation guard
+            set_automation_guard,
+            get_automation_guard,
+            // Voice activity
+            get_voice_activity,
+            // Environment profiles
+            set_environment_profile,
+            get_environment_profile,
+            list_environment_profiles,
+            measure_ambient_noise,
+            // Hotkeys
+            check_hotkey,
+            register_global_hotkey,
+            unregister_global_hotkey,
+            list_global_hotkeys,
+            // Process sandbox
+            set_process_sandbox_policy,
+            get_process_sandbox_policy,
+            // Frame scaler
+            get_frame_scaler_info,
+            // Window ids
+            get_active_window_info,
+            minimize_window,
+            move_window,
+            capture_window,
+            get_windows_for_process,
+            get_process_for_window,
+            // Audio sessions
+            get_apps_playing_audio,
+            set_app_volume,
+            set_app_muted,
+            mute_apps_playing_audio,
+            get_app_volumes,
+            // Proxy
+            get_proxy_config,
+            get_proxy_settings,
+            set_proxy_settings,
+            // Connectivity
+            get_network_status,
+            run_network_diagnostics,
+            // Bandwidth
+            get_bandwidth_report,
+            set_bandwidth_policy,
+            get_bandwidth_policy,
+            // Time context
+            get_time_context,
+            set_time_location,
+            list_holidays,
+            // Date/time parsing
+            parse_datetime,
+            // Reading overlay
+            set_reading_overlay,
+            clear_reading_overlay,
+            get_reading_overlay,
+            // Presentation mode
+            enter_presentation_mode,
+            exit_presentation_mode,
+            get_presentation_mode,
+            // Power
+            prevent_sleep,
+            allow_sleep,
+            get_power_assertions,
+            // Removable drives
+            list_removable_volumes,
+            list_usb_devices,
+            safely_eject,
+            set_removable_triggers,
+            get_removable_triggers,
+            // Backups
+            create_backup_job,
+            list_backup_jobs,
+            run_backup_job,
+            cancel_backup_job,
+            delete_backup_job,
+            // Sharing
+            configure_share_provider,
+            get_share_config,
+            capture_and_share,
+            // Tables
+            extract_table,
+            // Ink
+            recognize_ink,
+            // HID
+            list_hid_devices,
+            configure_hid_mapping,
+            get_hid_mappings,
+        ]))
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,380 @@
+/**
+ * Meetings module - Detects Zoom/Meet/Teams calls and, with consent, takes transcribed meeting notes
+ */
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, State};
+
+use crate::captions::Caption;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+/// Consecutive polls without a meeting signal before the meeting is considered over
+const END_AFTER_MISSES: u32 = 2;
+const ACTION_MARKERS: [&str; 9] = [
+    "action item", "follow up", "follow-up", "to do", "todo", "i'll", "we'll", "will send", "deadline",
+];
+
+fn is_zoom_meeting(title: &str) -> bool {
+    title.contains("zoom meeting") || title.contains("zoom webinar")
+}
+
+fn is_google_meet(title: &str) -> bool {
+    title.starts_with("meet - ") || title.contains("meet.google.com")
+}
+
+fn is_teams_meeting(title: &str) -> bool {
+    title.contains("microsoft teams") && (title.contains("meeting") || title.contains("call with"))
+}
+
+struct MeetingApp {
+    name: &'static str,
+    /// Window titles only present while a call is in progress
+    is_meeting_title: fn(&str) -> bool,
+    /// Helper processes only running while a call is in progress
+    meeting_processes: &'static [&'static str],
+    /// The client itself; counts only together with the microphone being in use
+    app_processes: &'static [&'static str],
+}
+
+const MEETING_APPS: [MeetingApp; 3] = [
+    MeetingApp {
+        name: "Zoom",
+        is_meeting_title: is_zoom_meeting,
+        meeting_processes: &["cpthost", "cpthost.exe"],
+        app_processes: &["zoom.us", "zoom", "zoom.exe"],
+    },
+    MeetingApp {
+        name: "Google Meet",
+        is_meeting_title: is_google_meet,
+        meeting_processes: &[],
+        app_processes: &[],
+    },
+    MeetingApp {
+        name: "Microsoft Teams",
+        is_meeting_title: is_teams_meeting,
+        meeting_processes: &[],
+        app_processes: &["teams", "ms-teams", "microsoft teams", "teams.exe", "ms-teams.exe"],
+    },
+];
+
+struct ActiveMeeting {
+    app: String,
+    title: String,
+    started_at: u64,
+    started: Instant,
+    misses: u32,
+}
+
+pub struct MeetingState {
+    pub enabled: bool,
+    pub generation: u64,
+    active: Option<ActiveMeeting>,
+    /// App whose current meeting the user declined to record
+    declined: Option<String>,
+}
+
+impl Default for MeetingState {
+    fn default() -> Self {
+        MeetingState {
+            enabled: false,
+            generation: 0,
+            active: None,
+            declined: None,
+        }
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Whether any application is currently recording from a microphone, where the OS exposes it.
+#[cfg(target_os = "linux")]
+fn mic_in_use() -> Option<bool> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let output = Command::new("pactl")
+        .args(&["list", "short", "source-outputs"])
+        .output_with_timeout()
+        .ok()?;
+    Some(output.status.success() && !output.stdout.is_empty())
+}
+
+#[cfg(target_os = "windows")]
+fn mic_in_use() -> Option<bool> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    // Apps currently holding the microphone have LastUsedTimeStop = 0 in the consent store
+    let script = "Get-ChildItem 'HKCU:\\Software\\Microsoft\\Windows\\CurrentVersion\\CapabilityAccessManager\\ConsentStore\\microphone' -Recurse \
+        | Get-ItemProperty | Where-Object { $_.LastUsedTimeStop -eq 0 } | Measure-Object | Select-Object -ExpandProperty Count";
+    let output = Command::new("powershell")
+        .args(&["-NoProfile", "-Command", script])
+        .output_with_timeout()
+        .ok()?;
+    let count: u32 = String::from_utf8_lossy(&output.stdout).trim().parse().ok()?;
+    Some(count > 0)
+}
+
+#[cfg(target_os = "macos")]
+fn mic_in_use() -> Option<bool> {
+    // macOS only surfaces this through the menu bar indicator
+    None
+}
+
+fn process_names() -> Vec<String> {
+    use sysinfo::{ProcessExt, SystemExt};
+
+    crate::subsystems::with_system(|sys| {
+        sys.refresh_processes();
+        sys.processes().values().map(|process| process.name().to_lowercase()).collect()
+    })
+}
+
+/// Returns (app, window title) for a meeting in progress.
+async fn detect_meeting() -> Option<(String, String)> {
+    let titles: Vec<String> = crate::commands::query_window_list()
+        .await
+        .unwrap_or_default()
+        .iter()
+        .map(crate::window_registry::window_title)
+        .collect();
+    let (processes, mic) = tauri::async_runtime::spawn_blocking(|| (process_names(), mic_in_use()))
+        .await
+        .ok()?;
+
+    for meeting_app in MEETING_APPS.iter() {
+        if let Some(title) = titles.iter().find(|title| (meeting_app.is_meeting_title)(&title.to_lowercase())) {
+            return Some((meeting_app.name.to_string(), title.clone()));
+        }
+
+        let has_process = |names: &[&str]| processes.iter().any(|process| names.contains(&process.as_str()));
+        if has_process(meeting_app.meeting_processes) || (mic == Some(true) && has_process(meeting_app.app_processes)) {
+            return Some((meeting_app.name.to_string(), format!("{} meeting", meeting_app.name)));
+        }
+    }
+
+    None
+}
+
+fn ask_consent(app: &AppHandle, meeting_app: &str) -> bool {
+    let parent = app.get_window("main");
+    tauri::api::dialog::blocking::ask(
+        parent.as_ref(),
+        "Meeting detected",
+        format!(
+            "A {} meeting is in progress. Transcribe it and save notes? Meeting and microphone audio are sent to the local speech service.",
+            meeting_app
+        ),
+    )
+}
+
+fn split_sentences(text: &str) -> Vec<String> {
+    text.split_inclusive(&['.', '?', '!'][..])
+        .map(|sentence| sentence.trim().to_string())
+        .filter(|sentence| !sentence.is_empty())
+        .collect()
+}
+
+/// Extractive summary: talk time per speaker, sentences that read like action items, and the longest statements.
+fn summarize(captions: &[Caption]) -> serde_json::Value {
+    let mut talk_time: BTreeMap<String, f64> = BTreeMap::new();
+    let mut action_items = Vec::new();
+    let mut statements = Vec::new();
+
+    for caption in captions {
+        let speaker = caption.speaker.clone().unwrap_or_else(|| "Unknown".to_string());
+        *talk_time.entry(speaker.clone()).or_default() += caption.end_secs - caption.start_secs;
+
+        for sentence in split_sentences(&caption.text) {
+            let lower = sentence.to_lowercase();
+            if ACTION_MARKERS.iter().any(|marker| lower.contains(marker)) {
+                action_items.push(serde_json::json!({ "speaker": speaker, "text": sentence }));
+            }
+            statements.push((caption.start_secs, speaker.clone(), sentence));
+        }
+    }
+
+    let mut longest: Vec<&(f64, String, String)> = statements.iter().collect();
+    longest.sort_by(|a, b| b.2.len().cmp(&a.2.len()));
+    longest.truncate(5);
+    longest.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    serde_json::json!({
+        "talkTimeSecs": talk_time,
+        "wordCount": captions.iter().map(|c| c.text.split_whitespace().count()).sum::<usize>(),
+        "actionItems": action_items,
+        "keyPoints": longest
+            .iter()
+            .map(|(_, speaker, text)| serde_json::json!({ "speaker": speaker, "text": text }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+async fn begin_meeting(app: &AppHandle, meeting_app: String, title: String) {
+    let _ = app.emit_all("meeting://detected", serde_json::json!({ "app": meeting_app, "title": title }));
+
+    let handle = app.clone();
+    let name = meeting_app.clone();
+    let consented = tauri::async_runtime::spawn_blocking(move || ask_consent(&handle, &name))
+        .await
+        .unwrap_or(false);
+
+    if !consented {
+        app.state::<Mutex<MeetingState>>().lock().unwrap().declined = Some(meeting_app);
+        return;
+    }
+
+    if let Err(e) = crate::captions::start_capture(app, None, Some(8), true).await {
+        println!("⚠️  Meeting capture failed: {}", e);
+        let _ = app.emit_all("meeting://error", e);
+        app.state::<Mutex<MeetingState>>().lock().unwrap().declined = Some(meeting_app);
+        return;
+    }
+
+    println!("📝 Meeting notes started: {} ({})", title, meeting_app);
+    app.state::<Mutex<MeetingState>>().lock().unwrap().active = Some(ActiveMeeting {
+        app: meeting_app.clone(),
+        title: title.clone(),
+        started_at: now_secs(),
+        started: Instant::now(),
+        misses: 0,
+    });
+    let _ = app.emit_all("meeting://started", serde_json::json!({ "app": meeting_app, "title": title }));
+}
+
+/// Stops capture, stores the note in the memory store and emits the summary.
+fn finish_meeting(app: &AppHandle) -> Result<serde_json::Value, String> {
+    let meeting = app
+        .state::<Mutex<MeetingState>>()
+        .lock()
+        .unwrap()
+        .active
+        .take()
+        .ok_or("No meeting in progress")?;
+
+    let captions = crate::captions::stop_capture(app).unwrap_or_default();
+    let summary = summarize(&captions);
+
+    let note = serde_json::json!({
+        "app": meeting.app,
+        "title": meeting.title,
+        "startedAt": meeting.started_at,
+        "endedAt": now_secs(),
+        "durationSecs": meeting.started.elapsed().as_secs(),
+        "summary": summary,
+        "transcript": captions,
+    });
+    let memory_id = crate::memory::save_memory(app, "meeting", &meeting.title, &note)?;
+
+    println!("📝 Meeting notes saved: {} ({} captions)", meeting.title, captions.len());
+    let result = serde_json::json!({
+        "memoryId": memory_id,
+        "app": meeting.app,
+        "title": meeting.title,
+        "durationSecs": note["durationSecs"],
+        "summary": summary,
+    });
+    let _ = app.emit_all("meeting://ended", result.clone());
+    Ok(result)
+}
+
+async fn run_meeting_detector(app: AppHandle, generation: u64) {
+    loop {
+        {
+            let state = app.state::<Mutex<MeetingState>>();
+            let meeting_state = state.lock().unwrap();
+            if !meeting_state.enabled || meeting_state.generation != generation {
+                return;
+            }
+        }
+
+        let detected = detect_meeting().await;
+
+        let (in_meeting, declined) = {
+            let state = app.state::<Mutex<MeetingState>>();
+            let meeting_state = state.lock().unwrap();
+            (meeting_state.active.is_some(), meeting_state.declined.clone())
+        };
+
+        match detected {
+            Some((meeting_app, title)) => {
+                if in_meeting {
+                    if let Some(active) = app.state::<Mutex<MeetingState>>().lock().unwrap().active.as_mut() {
+                        active.misses = 0;
+                    }
+                } else if declined.as_deref() != Some(meeting_app.as_str()) {
+                    begin_meeting(&app, meeting_app, title).await;
+                }
+            }
+            None => {
+                let ended = {
+                    let state = app.state::<Mutex<MeetingState>>();
+                    let mut meeting_state = state.lock().unwrap();
+                    meeting_state.declined = None;
+                    match meeting_state.active.as_mut() {
+                        Some(active) => {
+                            active.misses += 1;
+                            active.misses >= END_AFTER_MISSES
+                        }
+                        None => false,
+                    }
+                };
+
+                if ended {
+                    if let Err(e) = finish_meeting(&app) {
+                        let _ = app.emit_all("meeting://error", e);
+                    }
+                }
+            }
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+#[tauri::command]
+pub async fn set_meeting_mode(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let generation = {
+        let state = app.state::<Mutex<MeetingState>>();
+        let mut meeting_state = state.lock().unwrap();
+        meeting_state.enabled = enabled;
+        meeting_state.generation += 1;
+        meeting_state.declined = None;
+        meeting_state.generation
+    };
+
+    println!("📝 Meeting mode {}", if enabled { "on" } else { "off" });
+
+    if enabled {
+        tauri::async_runtime::spawn(run_meeting_detector(app, generation));
+    } else if app.state::<Mutex<MeetingState>>().lock().unwrap().active.is_some() {
+        finish_meeting(&app)?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn end_meeting(app: AppHandle) -> Result<serde_json::Value, String> {
+    finish_meeting(&app)
+}
+
+#[tauri::command]
+pub async fn get_meeting_status(state: State<'_, Mutex<MeetingState>>) -> Result<serde_json::Value, String> {
+    let meeting_state = state.lock().unwrap();
+
+    Ok(serde_json::json!({
+        "enabled": meeting_state.enabled,
+        "inMeeting": meeting_state.active.is_some(),
+        "app": meeting_state.active.as_ref().map(|active| active.app.clone()),
+        "title": meeting_state.active.as_ref().map(|active| active.title.clone()),
+        "elapsedSecs": meeting_state.active.as_ref().map(|active| active.started.elapsed().as_secs()),
+    }))
+}
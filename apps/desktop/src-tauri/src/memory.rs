@@ -0,0 +1,123 @@
+/**
+ * Memory module - Per-profile memory store (meeting notes, facts) backed by the profile's memory.db
+ */
+
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::AppHandle;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Memory {
+    pub id: i64,
+    pub kind: String,
+    pub title: String,
+    pub content: serde_json::Value,
+    pub created_at: u64,
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn open_store(app: &AppHandle) -> Result<Connection, String> {
+    let path = crate::profiles::active_profile_dir(app)?.join("memory.db");
+    let connection = Connection::open(path).map_err(|e| e.to_string())?;
+
+    connection
+        .execute_batch(
+            "CREATE TABLE IF NOT EXISTS memories (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                kind TEXT NOT NULL,
+                title TEXT NOT NULL,
+                content TEXT NOT NULL,
+                created_at INTEGER NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS memories_kind ON memories(kind);",
+        )
+        .map_err(|e| e.to_string())?;
+
+    Ok(connection)
+}
+
+fn row_to_memory(row: &rusqlite::Row) -> rusqlite::Result<Memory> {
+    let content: String = row.get(3)?;
+    Ok(Memory {
+        id: row.get(0)?,
+        kind: row.get(1)?,
+        title: row.get(2)?,
+        content: serde_json::from_str(&content).unwrap_or(serde_json::Value::String(content)),
+        created_at: row.get::<_, i64>(4)? as u64,
+    })
+}
+
+/// Stores a memory in the active profile and returns its id.
+pub fn save_memory(app: &AppHandle, kind: &str, title: &str, content: &serde_json::Value) -> Result<i64, String> {
+    let connection = open_store(app)?;
+
+    connection
+        .execute(
+            "INSERT INTO memories (kind, title, content, created_at) VALUES (?1, ?2, ?3, ?4)",
+            params![kind, title, content.to_string(), now_secs() as i64],
+        )
+        .map_err(|e| e.to_string())?;
+
+    Ok(connection.last_insert_rowid())
+}
+
+#[tauri::command]
+pub async fn list_memories(
+    app: AppHandle,
+    kind: Option<String>,
+    query: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<Memory>, String> {
+    let connection = open_store(&app)?;
+    let pattern = query.map(|q| format!("%{}%", q));
+
+    let mut statement = connection
+        .prepare(
+            "SELECT id, kind, title, content, created_at FROM memories
+             WHERE (?1 IS NULL OR kind = ?1)
+               AND (?2 IS NULL OR title LIKE ?2 OR content LIKE ?2)
+             ORDER BY created_at DESC, id DESC
+             LIMIT ?3",
+        )
+        .map_err(|e| e.to_string())?;
+
+    let memories = statement
+        .query_map(params![kind, pattern, limit.unwrap_or(50).min(500)], row_to_memory)
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    Ok(memories)
+}
+
+#[tauri::command]
+pub async fn get_memory(app: AppHandle, id: i64) -> Result<Memory, String> {
+    open_store(&app)?
+        .query_row(
+            "SELECT id, kind, title, content, created_at FROM memories WHERE id = ?1",
+            params![id],
+            row_to_memory,
+        )
+        .map_err(|_| format!("❌ Memory not found: {}", id))
+}
+
+#[tauri::command]
+pub async fn delete_memory(app: AppHandle, id: i64) -> Result<(), String> {
+    let removed = open_store(&app)?
+        .execute("DELETE FROM memories WHERE id = ?1", params![id])
+        .map_err(|e| e.to_string())?;
+
+    if removed == 0 {
+        return Err(format!("❌ Memory not found: {}", id));
+    }
+
+    Ok(())
+}
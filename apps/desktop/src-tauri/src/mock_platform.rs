@@ -0,0 +1,293 @@
+/**
+ * Mock platform module - In-memory stand-in for the desktop (fake windows, a virtual screen buffer and an input sink)
+ *
+ * Start with `--mock-platform` (or JARVISX_PLATFORM=mock), or switch at runtime with set_mock_platform. While active,
+ * simulated input, window listing/focus and screen capture never touch the real desktop, so workflows and plugins can
+ * be exercised deterministically in CI.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const INPUT_LOG_LIMIT: usize = 1000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockWindow {
+    pub id: String,
+    pub name: String,
+    pub title: String,
+    /// Fake owning process id, for the process-to-window mapping
+    #[serde(default)]
+    pub pid: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockFixture {
+    #[serde(default)]
+    pub windows: Vec<MockWindow>,
+    #[serde(default = "default_screen_width")]
+    pub screen_width: u32,
+    #[serde(default = "default_screen_height")]
+    pub screen_height: u32,
+    /// RGBA the virtual screen starts filled with
+    #[serde(default = "default_background")]
+    pub background: [u8; 4],
+}
+
+fn default_screen_width() -> u32 {
+    1920
+}
+
+fn default_screen_height() -> u32 {
+    1080
+}
+
+fn default_background() -> [u8; 4] {
+    [255, 255, 255, 255]
+}
+
+impl Default for MockFixture {
+    fn default() -> Self {
+        Self {
+            windows: vec![
+                MockWindow { id: "0x01".to_string(), name: "Finder".to_string(), title: "Finder".to_string(), pid: Some(101) },
+                MockWindow { id: "0x02".to_string(), name: "Editor".to_string(), title: "untitled - Editor".to_string(), pid: Some(102) },
+            ],
+            screen_width: default_screen_width(),
+            screen_height: default_screen_height(),
+            background: default_background(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputEvent {
+    /// "key", "text", "hotkey", "click", "hover", "drag", "scroll", "release" or "window"
+    pub kind: String,
+    pub detail: serde_json::Value,
+    pub at: u64,
+}
+
+struct MockPlatform {
+    windows: Vec<MockWindow>,
+    focused: Option<String>,
+    cursor: (i32, i32),
+    screen: image::RgbaImage,
+    input_log: Vec<InputEvent>,
+}
+
+impl MockPlatform {
+    fn from_fixture(fixture: MockFixture) -> Self {
+        let background = image::Rgba(fixture.background);
+        Self {
+            focused: fixture.windows.first().map(|window| window.id.clone()),
+            windows: fixture.windows,
+            cursor: (0, 0),
+            screen: image::RgbaImage::from_pixel(fixture.screen_width.max(1), fixture.screen_height.max(1), background),
+            input_log: Vec::new(),
+        }
+    }
+}
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static PLATFORM: Mutex<Option<MockPlatform>> = Mutex::new(None);
+
+pub fn is_active() -> bool {
+    ACTIVE.load(Ordering::Relaxed)
+}
+
+fn with_platform<T>(f: impl FnOnce(&mut MockPlatform) -> T) -> T {
+    let mut platform = PLATFORM.lock().unwrap();
+    f(platform.get_or_insert_with(|| MockPlatform::from_fixture(MockFixture::default())))
+}
+
+/// Switches to the mock backend at startup when asked to on the command line or in the environment.
+pub fn load_mock_platform() {
+    let requested = std::env::args().any(|arg| arg == "--mock-platform")
+        || std::env::var("JARVISX_PLATFORM").map_or(false, |value| value == "mock");
+
+    if requested {
+        ACTIVE.store(true, Ordering::Relaxed);
+        println!("🧪 Mock platform active: input, windows and capture are simulated");
+    }
+}
+
+/// Appends to the input sink; pointer events also move the virtual cursor.
+pub fn record_input(kind: &str, detail: serde_json::Value) {
+    with_platform(|platform| {
+        if let (Some(x), Some(y)) = (detail["x"].as_i64(), detail["y"].as_i64()) {
+            platform.cursor = (x as i32, y as i32);
+        }
+        platform.input_log.push(InputEvent {
+            kind: kind.to_string(),
+            detail,
+            at: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0),
+        });
+        if platform.input_log.len() > INPUT_LOG_LIMIT {
+            platform.input_log.remove(0);
+        }
+    });
+}
+
+pub fn cursor() -> (i32, i32) {
+    with_platform(|platform| platform.cursor)
+}
+
+/// Whether the newest event in the input sink is `kind` with `detail`, i.e. the input landed.
+pub fn last_input_is(kind: &str, detail: &serde_json::Value) -> bool {
+    with_platform(|platform| platform.input_log.last().map_or(false, |event| event.kind == kind && event.detail == *detail))
+}
+
+/// Whether the focused fake window's name or title contains `title`, matching how `focus` picks one.
+pub fn is_focused(title: &str) -> bool {
+    with_platform(|platform| {
+        let needle = title.to_lowercase();
+        platform
+            .focused
+            .as_ref()
+            .and_then(|id| platform.windows.iter().find(|window| window.id == *id))
+            .map_or(false, |window| window.title.to_lowercase().contains(&needle) || window.name.to_lowercase().contains(&needle))
+    })
+}
+
+/// Fake windows in the same shape as the platform window list, with the focused one flagged.
+pub fn window_list() -> Vec<serde_json::Value> {
+    with_platform(|platform| {
+        platform
+            .windows
+            .iter()
+            .map(|window| serde_json::json!({
+                "id": window.id,
+                "windowId": format!("mock:{}", window.id),
+                "name": window.name,
+                "title": window.title,
+                "pid": window.pid,
+                "focused": platform.focused.as_deref() == Some(window.id.as_str()),
+            }))
+            .collect()
+    })
+}
+
+pub fn active_window() -> String {
+    with_platform(|platform| {
+        platform
+            .focused
+            .as_ref()
+            .and_then(|id| platform.windows.iter().find(|window| window.id == *id))
+            .map(|window| window.title.clone())
+            .unwrap_or_default()
+    })
+}
+
+/// Focuses the first fake window whose name or title contains `title`, like the platform implementations.
+pub fn focus(title: &str) -> Result<String, String> {
+    with_platform(|platform| {
+        let needle = title.to_lowercase();
+        let window = platform
+            .windows
+            .iter()
+            .find(|window| window.title.to_lowercase().contains(&needle) || window.name.to_lowercase().contains(&needle))
+            .ok_or_else(|| format!("❌ Failed to focus window: {}", title))?;
+
+        platform.focused = Some(window.id.clone());
+        Ok(format!("✅ Focused window: {}", title))
+    })
+}
+
+/// Focuses a fake window by its "mock:<id>" WindowId.
+pub fn focus_id(window_id: &str) -> Result<String, String> {
+    with_platform(|platform| {
+        let window = platform
+            .windows
+            .iter()
+            .find(|window| window_id.strip_prefix("mock:") == Some(window.id.as_str()))
+            .ok_or_else(|| format!("❌ Window not found: {}", window_id))?;
+
+        platform.focused = Some(window.id.clone());
+        Ok(format!("✅ Focused window: {}", window.title))
+    })
+}
+
+/// PNG of the virtual screen, or of a rectangle of it. Only display 0 exists.
+pub fn capture(index: usize, region: Option<(i32, i32, u32, u32)>) -> Result<Vec<u8>, String> {
+    use std::io::Cursor;
+
+    if index != 0 {
+        return Err(format!("❌ Display not found: {}", index + 1));
+    }
+
+    let image = with_platform(|platform| match region {
+        Some((x, y, width, height)) => {
+            let x = x.clamp(0, platform.screen.width() as i32) as u32;
+            let y = y.clamp(0, platform.screen.height() as i32) as u32;
+            let width = width.min(platform.screen.width() - x);
+            let height = height.min(platform.screen.height() - y);
+            image::imageops::crop_imm(&platform.screen, x, y, width, height).to_image()
+        }
+        None => platform.screen.clone(),
+    });
+
+    let mut cursor = Cursor::new(Vec::new());
+    image::DynamicImage::ImageRgba8(image)
+        .write_to(&mut cursor, image::ImageOutputFormat::Png)
+        .map_err(|e| e.to_string())?;
+    Ok(cursor.into_inner())
+}
+
+/// Turns the mock backend on (optionally with a fresh fixture) or off.
+#[tauri::command]
+pub async fn set_mock_platform(enabled: bool, fixture: Option<MockFixture>) -> Result<serde_json::Value, String> {
+    if let Some(fixture) = fixture {
+        *PLATFORM.lock().unwrap() = Some(MockPlatform::from_fixture(fixture));
+    }
+    ACTIVE.store(enabled, Ordering::Relaxed);
+
+    println!("🧪 Mock platform {}", if enabled { "enabled" } else { "disabled" });
+    get_mock_platform_state().await
+}
+
+#[tauri::command]
+pub async fn get_mock_platform_state() -> Result<serde_json::Value, String> {
+    let (width, height, cursor, events) = with_platform(|platform| {
+        (platform.screen.width(), platform.screen.height(), platform.cursor, platform.input_log.len())
+    });
+
+    Ok(serde_json::json!({
+        "active": is_active(),
+        "windows": window_list(),
+        "screen": { "width": width, "height": height },
+        "cursor": { "x": cursor.0, "y": cursor.1 },
+        "inputEvents": events,
+    }))
+}
+
+/// Everything simulated input sent to the mock sink, oldest first.
+#[tauri::command]
+pub async fn get_mock_input_log(clear: Option<bool>) -> Result<Vec<InputEvent>, String> {
+    Ok(with_platform(|platform| {
+        if clear.unwrap_or(false) {
+            std::mem::take(&mut platform.input_log)
+        } else {
+            platform.input_log.clone()
+        }
+    }))
+}
+
+/// Fills a rectangle of the virtual screen, so capture and vision steps have something to find.
+#[tauri::command]
+pub async fn paint_mock_screen(x: u32, y: u32, width: u32, height: u32, color: [u8; 4]) -> Result<(), String> {
+    with_platform(|platform| {
+        let (screen_width, screen_height) = platform.screen.dimensions();
+        for py in y.min(screen_height)..y.saturating_add(height).min(screen_height) {
+            for px in x.min(screen_width)..x.saturating_add(width).min(screen_width) {
+                platform.screen.put_pixel(px, py, image::Rgba(color));
+            }
+        }
+    });
+    Ok(())
+}
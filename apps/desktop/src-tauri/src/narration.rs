@@ -0,0 +1,190 @@
+/**
+ * Narration module - Lightweight screen reader that speaks focused UI elements and notifications
+ */
+
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+
+use crate::watchdog::WatchdogCommand;
+
+pub const NARRATION_HOTKEY: &str = "CmdOrCtrl+Alt+N";
+const POLL_INTERVAL: Duration = Duration::from_millis(700);
+
+pub struct NarrationState {
+    pub enabled: bool,
+    pub generation: u64,
+    pub last_spoken: Option<String>,
+}
+
+impl Default for NarrationState {
+    fn default() -> Self {
+        NarrationState {
+            enabled: false,
+            generation: 0,
+            last_spoken: None,
+        }
+    }
+}
+
+fn query(command: &mut Command) -> Option<String> {
+    let output = command.output_within(Duration::from_secs(2)).ok()?;
+    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if output.status.success() && !text.is_empty() {
+        Some(text)
+    } else {
+        None
+    }
+}
+
+/// "role, name, value" for the focused accessibility element.
+#[cfg(target_os = "macos")]
+fn focused_element() -> Option<String> {
+    let script = r#"
+        tell application "System Events"
+            set frontApp to first process whose frontmost is true
+            set el to value of attribute "AXFocusedUIElement" of frontApp
+            set label to ""
+            try
+                set label to (role description of el) & ", " & (name of el)
+            end try
+            try
+                set label to label & ", " & (value of el as text)
+            end try
+            return label
+        end tell
+    "#;
+    query(Command::new("osascript").args(&["-e", script]))
+}
+
+#[cfg(target_os = "windows")]
+fn focused_element() -> Option<String> {
+    let script = "Add-Type -AssemblyName UIAutomationClient; \
+        $e = [System.Windows.Automation.AutomationElement]::FocusedElement; \
+        if ($e) { \"$($e.Current.LocalizedControlType), $($e.Current.Name)\" }";
+    query(Command::new("powershell").args(&["-NoProfile", "-Command", script]))
+}
+
+#[cfg(target_os = "linux")]
+fn focused_element() -> Option<String> {
+    // No generic AT-SPI CLI; the focused window title is the closest portable signal
+    query(Command::new("xdotool").args(&["getactivewindow", "getwindowname"])).map(|title| format!("window, {}", title))
+}
+
+fn clean_label(label: &str) -> String {
+    label
+        .split(", ")
+        .map(str::trim)
+        .filter(|part| !part.is_empty() && *part != "missing value")
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+async fn run_narration(app: AppHandle, generation: u64) {
+    loop {
+        {
+            let state = app.state::<Mutex<NarrationState>>();
+            let narration = state.lock().unwrap();
+            if !narration.enabled || narration.generation != generation {
+                return;
+            }
+        }
+
+        let label = tauri::async_runtime::spawn_blocking(focused_element)
+            .await
+            .ok()
+            .flatten()
+            .map(|label| clean_label(&label))
+            .filter(|label| !label.is_empty());
+
+        if let Some(label) = label {
+            let changed = {
+                let state = app.state::<Mutex<NarrationState>>();
+                let mut narration = state.lock().unwrap();
+                if narration.last_spoken.as_deref() != Some(label.as_str()) {
+                    narration.last_spoken = Some(label.clone());
+                    true
+                } else {
+                    false
+                }
+            };
+
+            if changed {
+                let _ = app.emit_all("narration://spoken", serde_json::json!({ "text": label }));
+                // Speak in the background so a focus change can interrupt it
+                tauri::async_runtime::spawn(async move {
+                    let _ = crate::speech::speak(&label, None).await;
+                });
+            }
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+fn set_enabled(app: &AppHandle, enabled: bool) -> bool {
+    let generation = {
+        let state = app.state::<Mutex<NarrationState>>();
+        let mut narration = state.lock().unwrap();
+        narration.enabled = enabled;
+        narration.generation += 1;
+        narration.last_spoken = None;
+        narration.generation
+    };
+
+    println!("🦻 Narration {}", if enabled { "on" } else { "off" });
+    let _ = app.emit_all("narration://toggled", serde_json::json!({ "enabled": enabled }));
+
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let _ = crate::speech::speak(if enabled { "Narration on" } else { "Narration off" }, None).await;
+        if enabled {
+            run_narration(handle, generation).await;
+        }
+    });
+
+    enabled
+}
+
+/// Speaks a notification when narration is on.
+pub fn narrate_notification(app: &AppHandle, title: &str, body: &str) {
+    if !app.state::<Mutex<NarrationState>>().lock().unwrap().enabled {
+        return;
+    }
+
+    let text = format!("Notification: {}. {}", title, body);
+    tauri::async_runtime::spawn(async move {
+        let _ = crate::speech::speak(&text, None).await;
+    });
+}
+
+/// Registers the global toggle hotkey.
+pub fn register_narration_hotkey(app: AppHandle) {
+    let handle = app.clone();
+    let result = crate::hotkeys::register_hotkey(&app, "narration", NARRATION_HOTKEY, move || {
+        let enabled = handle.state::<Mutex<NarrationState>>().lock().unwrap().enabled;
+        set_enabled(&handle, !enabled);
+    });
+
+    if let Err(e) = result {
+        println!("⚠️  Could not register narration hotkey {}: {}", NARRATION_HOTKEY, e);
+    }
+}
+
+#[tauri::command]
+pub async fn toggle_narration(app: AppHandle, enabled: Option<bool>) -> Result<bool, String> {
+    let enabled = enabled.unwrap_or_else(|| !app.state::<Mutex<NarrationState>>().lock().unwrap().enabled);
+    Ok(set_enabled(&app, enabled))
+}
+
+#[tauri::command]
+pub async fn get_narration_status(state: State<'_, Mutex<NarrationState>>) -> Result<serde_json::Value, String> {
+    let narration = state.lock().unwrap();
+
+    Ok(serde_json::json!({
+        "enabled": narration.enabled,
+        "hotkey": NARRATION_HOTKEY,
+        "lastSpoken": narration.last_spoken,
+    }))
+}
@@ -0,0 +1,288 @@
+/**
+ * Orders module - Shopify/WooCommerce order webhooks that raise notifications and trigger scripts
+ */
+
+use base64::{Engine as _, engine::general_purpose};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StoreKind {
+    Shopify,
+    WooCommerce,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderIntegration {
+    pub id: String,
+    pub kind: StoreKind,
+    pub name: String,
+    pub store_url: String,
+    /// Saved script run with the normalized order as `event`
+    pub script: Option<String>,
+    pub created_at: u64,
+}
+
+/// Held while `orders.jsonl` is read or written, here and by the retention janitor that rewrites it, so an order
+/// appended mid-rewrite isn't lost.
+pub static ORDER_LOG: Mutex<()> = Mutex::new(());
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn orders_dir(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = crate::profiles::active_profile_dir(app)?.join("orders");
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+fn load_integrations(app: &AppHandle) -> Vec<OrderIntegration> {
+    orders_dir(app)
+        .ok()
+        .and_then(|dir| std::fs::read_to_string(dir.join("integrations.json")).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_integrations(app: &AppHandle, integrations: &[OrderIntegration]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(integrations).map_err(|e| e.to_string())?;
+    std::fs::write(orders_dir(app)?.join("integrations.json"), json).map_err(|e| e.to_string())
+}
+
+fn secret_key(id: &str, name: &str) -> String {
+    format!("orders:{}:{}", id, name)
+}
+
+/// Vault keys holding the webhook secrets and API tokens of the registered integrations.
+pub fn vault_keys(app: &AppHandle) -> Vec<String> {
+    load_integrations(app)
+        .iter()
+        .flat_map(|integration| ["webhook_secret", "api_token"].map(|name| secret_key(&integration.id, name)))
+        .collect()
+}
+
+/// Both Shopify and WooCommerce sign the raw body as base64(HMAC-SHA256(secret, body)).
+fn verify_signature(secret: &str, body: &[u8], signature: &str) -> Result<(), String> {
+    use hmac::{Hmac, Mac};
+
+    let expected = general_purpose::STANDARD.decode(signature.trim()).map_err(|_| "Malformed webhook signature")?;
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret.as_bytes()).map_err(|e| e.to_string())?;
+    mac.update(body);
+    mac.verify_slice(&expected).map_err(|_| "Webhook signature mismatch".to_string())
+}
+
+fn normalize_order(kind: StoreKind, payload: &serde_json::Value) -> serde_json::Value {
+    let (number, customer, email, total) = match kind {
+        StoreKind::Shopify => (
+            payload["name"].clone(),
+            format!(
+                "{} {}",
+                payload["customer"]["first_name"].as_str().unwrap_or(""),
+                payload["customer"]["last_name"].as_str().unwrap_or("")
+            ),
+            payload["email"].clone(),
+            payload["total_price"].clone(),
+        ),
+        StoreKind::WooCommerce => (
+            payload["number"].clone(),
+            format!(
+                "{} {}",
+                payload["billing"]["first_name"].as_str().unwrap_or(""),
+                payload["billing"]["last_name"].as_str().unwrap_or("")
+            ),
+            payload["billing"]["email"].clone(),
+            payload["total"].clone(),
+        ),
+    };
+
+    let items: Vec<serde_json::Value> = payload["line_items"]
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .map(|item| serde_json::json!({
+                    "name": item.get("title").or_else(|| item.get("name")),
+                    "quantity": item["quantity"],
+                    "sku": item["sku"],
+                }))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    serde_json::json!({
+        "id": payload["id"],
+        "number": number,
+        "customer": customer.trim(),
+        "email": email,
+        "total": total,
+        "currency": payload["currency"],
+        "items": items,
+    })
+}
+
+/// Handles `POST /webhooks/<integration id>` from the local HTTP server.
+pub fn handle_webhook(
+    app: &AppHandle,
+    integration_id: &str,
+    headers: &HashMap<String, String>,
+    body: &[u8],
+) -> Result<serde_json::Value, String> {
+    let integration = load_integrations(app)
+        .into_iter()
+        .find(|integration| integration.id == integration_id)
+        .ok_or("Unknown integration")?;
+
+    let (signature_header, topic_header, order_topics) = match integration.kind {
+        StoreKind::Shopify => ("x-shopify-hmac-sha256", "x-shopify-topic", ["orders/create", "orders/paid"]),
+        StoreKind::WooCommerce => ("x-wc-webhook-signature", "x-wc-webhook-topic", ["order.created", "order.updated"]),
+    };
+
+    // WooCommerce pings a new webhook with an unsigned form body; acknowledge it
+    let signature = match headers.get(signature_header) {
+        Some(signature) => signature,
+        None if integration.kind == StoreKind::WooCommerce && !body.starts_with(b"{") => {
+            return Ok(serde_json::json!({ "status": "pong" }));
+        }
+        None => return Err("Missing webhook signature".to_string()),
+    };
+
+    let secret = crate::secrets::load_secret(
+        &crate::profiles::active_secrets_namespace(app),
+        &secret_key(&integration.id, "webhook_secret"),
+    )?
+    .ok_or("Webhook secret not in vault")?;
+    verify_signature(&secret, body, signature)?;
+
+    let topic = headers.get(topic_header).map(String::as_str).unwrap_or("");
+    if !order_topics.contains(&topic) {
+        return Ok(serde_json::json!({ "status": "ignored", "topic": topic }));
+    }
+
+    let payload: serde_json::Value = serde_json::from_slice(body).map_err(|e| e.to_string())?;
+    let order = normalize_order(integration.kind, &payload);
+    let event = serde_json::json!({
+        "integrationId": integration.id,
+        "integration": integration.name,
+        "topic": topic,
+        "receivedAt": now_secs(),
+        "order": order,
+    });
+
+    {
+        let _log = ORDER_LOG.lock().unwrap();
+        let mut log = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(orders_dir(app)?.join("orders.jsonl"))
+            .map_err(|e| e.to_string())?;
+        writeln!(log, "{}", event).map_err(|e| e.to_string())?;
+    }
+
+    println!("🛒 Order {} from {}", order["number"], integration.name);
+    let summary = format!(
+        "{} · {} {} · {}",
+        order["number"],
+        order["total"].as_str().unwrap_or(""),
+        order["currency"].as_str().unwrap_or(""),
+        order["customer"].as_str().unwrap_or("")
+    );
+    crate::system::show_notification(app, &format!("New order from {}", integration.name), &summary);
+    let _ = app.emit_all("orders://received", event.clone());
+
+    if let Some(script) = &integration.script {
+        let result = crate::scripting::load_script(app, script)
+            .and_then(|source| crate::scripting::execute(app, &source, event.clone(), crate::scripting::DEFAULT_TIME_LIMIT, None));
+        if let Err(e) = result {
+            println!("⚠️  Order script {} failed: {}", script, e);
+            let _ = app.emit_all("orders://script-failed", serde_json::json!({ "script": script, "error": e }));
+        }
+    }
+
+    Ok(serde_json::json!({ "status": "received" }))
+}
+
+#[tauri::command]
+pub async fn register_order_integration(
+    app: AppHandle,
+    kind: StoreKind,
+    name: String,
+    store_url: String,
+    webhook_secret: String,
+    api_token: Option<String>,
+    script: Option<String>,
+) -> Result<serde_json::Value, String> {
+    if let Some(script) = &script {
+        crate::scripting::load_script(&app, script)?;
+    }
+
+    let integration = OrderIntegration {
+        id: format!("{:016x}", rand::random::<u64>()),
+        kind,
+        name,
+        store_url,
+        script,
+        created_at: now_secs(),
+    };
+
+    let namespace = crate::profiles::active_secrets_namespace(&app);
+    crate::secrets::store_secret(&namespace, &secret_key(&integration.id, "webhook_secret"), &webhook_secret)?;
+    if let Some(api_token) = api_token {
+        crate::secrets::store_secret(&namespace, &secret_key(&integration.id, "api_token"), &api_token)?;
+    }
+
+    let mut integrations = load_integrations(&app);
+    integrations.push(integration.clone());
+    save_integrations(&app, &integrations)?;
+
+    println!("🛒 Registered {:?} integration {}", integration.kind, integration.name);
+    Ok(serde_json::json!({
+        "integration": integration,
+        "webhookUrl": format!("http://{}/webhooks/{}", jarvisx_core::api::bind_address(), integration.id),
+    }))
+}
+
+#[tauri::command]
+pub async fn list_order_integrations(app: AppHandle) -> Result<Vec<OrderIntegration>, String> {
+    Ok(load_integrations(&app))
+}
+
+#[tauri::command]
+pub async fn remove_order_integration(app: AppHandle, id: String) -> Result<(), String> {
+    let mut integrations = load_integrations(&app);
+    let before = integrations.len();
+    integrations.retain(|integration| integration.id != id);
+
+    if integrations.len() == before {
+        return Err(format!("❌ Integration not found: {}", id));
+    }
+
+    let namespace = crate::profiles::active_secrets_namespace(&app);
+    let _ = crate::secrets::delete_secret(&namespace, &secret_key(&id, "webhook_secret"));
+    let _ = crate::secrets::delete_secret(&namespace, &secret_key(&id, "api_token"));
+    save_integrations(&app, &integrations)
+}
+
+#[tauri::command]
+pub async fn get_recent_orders(app: AppHandle, limit: Option<usize>) -> Result<Vec<serde_json::Value>, String> {
+    let log = {
+        let _log = ORDER_LOG.lock().unwrap();
+        std::fs::read_to_string(orders_dir(&app)?.join("orders.jsonl")).unwrap_or_default()
+    };
+
+    Ok(log
+        .lines()
+        .rev()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .take(limit.unwrap_or(20))
+        .collect())
+}
@@ -0,0 +1,226 @@
+/**
+ * Permissions module - Capability tokens scoping what plugins, paired devices and orchestrators may invoke
+ *
+ * Bridges for non-local callers attach `capabilityToken` to every invoke payload; `scoped` wraps the
+ * command handler and rejects calls outside the token's commands, path prefixes and app names. Only the app's
+ * own bundled pages may invoke without a token; anything else (remote origins, plugin pages) is refused.
+ */
+
+use jarvisx_core::capabilities::{now_secs, path_allowed, resolve};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Invoke, Manager, Runtime, State};
+
+pub use jarvisx_core::capabilities::{CapabilityToken, CAPABILITY_FIELD};
+
+pub struct PermissionsState {
+    pub tokens: HashMap<String, CapabilityToken>,
+}
+
+impl Default for PermissionsState {
+    fn default() -> Self {
+        PermissionsState {
+            tokens: HashMap::new(),
+        }
+    }
+}
+
+fn capabilities_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("capabilities.json"))
+}
+
+fn save_capabilities(app: &AppHandle, permissions: &PermissionsState) -> Result<(), String> {
+    let tokens: Vec<&CapabilityToken> = permissions.tokens.values().collect();
+    let json = serde_json::to_string_pretty(&tokens).map_err(|e| e.to_string())?;
+    std::fs::write(capabilities_path(app)?, json).map_err(|e| e.to_string())
+}
+
+/// Loads the active profile's capability tokens into managed state.
+pub fn load_capabilities(app: &AppHandle) {
+    let tokens = capabilities_path(app)
+        .map(|path| jarvisx_core::capabilities::load_tokens(&path))
+        .unwrap_or_default();
+
+    app.state::<Mutex<PermissionsState>>().lock().unwrap().tokens = tokens;
+}
+
+/// Checks a path a command reads itself, returning it resolved: against the token's prefixes, or for the local UI
+/// (no token) against the user's home folder.
+pub fn authorize_path(app: &AppHandle, token: Option<&str>, path: &str) -> Result<PathBuf, String> {
+    let resolved = Some(Path::new(path))
+        .filter(|path| path.is_absolute())
+        .and_then(resolve)
+        .ok_or_else(|| format!("❌ Not an absolute path: {}", path))?;
+
+    match token {
+        Some(token) => {
+            let state = app.state::<Mutex<PermissionsState>>();
+            let permissions = state.lock().unwrap();
+            let capability = permissions.tokens.get(token).ok_or("❌ Unknown capability token")?;
+            if !path_allowed(capability, path) {
+                return Err(format!("❌ {} may not access {}", capability.principal, path));
+            }
+        }
+        None => {
+            let home = tauri::api::path::home_dir().and_then(|home| home.canonicalize().ok());
+            if !home.map_or(false, |home| resolved.starts_with(home)) {
+                return Err(format!("❌ {} is outside the home folder", path));
+            }
+        }
+    }
+    Ok(resolved)
+}
+
+/// Sandbox level for processes spawned on behalf of a token; the local user (no token) gets the workflow level,
+/// so nothing runs unrestricted unless the policy says so.
+pub fn sandbox_level(app: &AppHandle, token: Option<&str>) -> crate::process_sandbox::SandboxLevel {
+    match token {
+        None => crate::process_sandbox::current_policy().workflows,
+        Some(token) => app
+            .state::<Mutex<PermissionsState>>()
+            .lock()
+            .unwrap()
+            .tokens
+            .get(token)
+            .map_or(crate::process_sandbox::SandboxLevel::Strict, |capability| capability.sandbox),
+    }
+}
+
+/// Checks one invocation against a capability token.
+pub fn authorize<R: Runtime>(app: &AppHandle<R>, token: &str, command: &str, payload: &serde_json::Value) -> Result<(), String> {
+    let state = app.state::<Mutex<PermissionsState>>();
+    let permissions = state.lock().unwrap();
+    let capability = permissions.tokens.get(token).ok_or("❌ Unknown capability token")?;
+    jarvisx_core::capabilities::check(capability, command, payload)
+}
+
+/// Authorizes one command run on behalf of a caller (a batch item, plan step or script call) and returns its
+/// args with the caller's token added, so the command applies that capability's sandbox and path scope too.
+/// Callers without a token get the args back unchanged.
+pub fn authorize_nested<R: Runtime>(
+    app: &AppHandle<R>,
+    token: Option<&str>,
+    command: &str,
+    args: &serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    let Some(token) = token else {
+        return Ok(args.clone());
+    };
+    authorize(app, token, command, args)?;
+
+    let mut args = args.clone();
+    if let Some(args) = args.as_object_mut() {
+        args.insert(CAPABILITY_FIELD.to_string(), serde_json::json!(token));
+    }
+    Ok(args)
+}
+
+/// Whether a webview URL is one of the app's own bundled pages (or the dev server in debug builds).
+pub fn is_local_origin(url: &tauri::Url) -> bool {
+    match (url.scheme(), url.host_str()) {
+        ("tauri", Some("localhost")) | ("https", Some("tauri.localhost")) => true,
+        ("http", Some("localhost")) => cfg!(debug_assertions) && url.port() == Some(1420),
+        _ => false,
+    }
+}
+
+/// Wraps the command handler so every invocation is checked before dispatch: token-carrying calls against
+/// their capability, and calls without a token must come from a local page.
+pub fn scoped<R, F>(handler: F) -> impl Fn(Invoke<R>) + Send + Sync + 'static
+where
+    R: Runtime,
+    F: Fn(Invoke<R>) + Send + Sync + 'static,
+{
+    move |invoke: Invoke<R>| {
+        let token = invoke
+            .message
+            .payload()
+            .get(CAPABILITY_FIELD)
+            .and_then(|value| value.as_str())
+            .map(|token| token.to_string());
+        let app = invoke.message.window().app_handle();
+        let command = invoke.message.command().to_string();
+
+        let outcome = match token {
+            Some(token) => authorize(&app, &token, &command, invoke.message.payload()),
+            None if is_local_origin(&invoke.message.window().url()) => Ok(()),
+            None => Err(format!("❌ {} needs a capability token from {}", command, invoke.message.window().url())),
+        };
+
+        if let Err(e) = outcome {
+            println!("🚫 {}", e);
+            let _ = app.emit_all("capability-denied", serde_json::json!({ "command": command, "reason": e }));
+            invoke.resolver.reject(e);
+            return;
+        }
+
+        handler(invoke)
+    }
+}
+
+#[tauri::command]
+pub async fn grant_capability(
+    app: AppHandle,
+    state: State<'_, Mutex<PermissionsState>>,
+    principal: String,
+    commands: Vec<String>,
+    path_prefixes: Option<Vec<String>>,
+    app_names: Option<Vec<String>>,
+    expires_in_secs: Option<u64>,
+    sandbox: Option<crate::process_sandbox::SandboxLevel>,
+) -> Result<CapabilityToken, String> {
+    if !["plugin:", "device:", "orchestrator:"].iter().any(|kind| principal.starts_with(kind)) {
+        return Err("Principal must start with plugin:, device: or orchestrator:".to_string());
+    }
+    if commands.is_empty() {
+        return Err("A capability must allow at least one command".to_string());
+    }
+
+    let created_at = now_secs();
+    let capability = CapabilityToken {
+        token: format!("cap_{:032x}", rand::random::<u128>()),
+        principal,
+        commands,
+        path_prefixes: path_prefixes.unwrap_or_default(),
+        app_names: app_names.unwrap_or_default(),
+        created_at,
+        expires_at: expires_in_secs.map(|secs| created_at + secs),
+        sandbox: sandbox.unwrap_or(crate::process_sandbox::current_policy().plugins),
+    };
+
+    let mut permissions = state.lock().unwrap();
+    permissions.tokens.insert(capability.token.clone(), capability.clone());
+    save_capabilities(&app, &permissions)?;
+
+    println!("🔏 Granted capability to {}: {:?}", capability.principal, capability.commands);
+    Ok(capability)
+}
+
+#[tauri::command]
+pub async fn revoke_capability(app: AppHandle, state: State<'_, Mutex<PermissionsState>>, token: String) -> Result<(), String> {
+    let mut permissions = state.lock().unwrap();
+
+    if permissions.tokens.remove(&token).is_none() {
+        return Err("❌ Unknown capability token".to_string());
+    }
+
+    save_capabilities(&app, &permissions)
+}
+
+#[tauri::command]
+pub async fn list_capabilities(
+    state: State<'_, Mutex<PermissionsState>>,
+    principal: Option<String>,
+) -> Result<Vec<CapabilityToken>, String> {
+    let permissions = state.lock().unwrap();
+
+    let mut tokens: Vec<CapabilityToken> = permissions
+        .tokens
+        .values()
+        .filter(|token| principal.as_ref().map_or(true, |principal| token.principal == *principal))
+        .cloned()
+        .collect();
+    tokens.sort_by_key(|token| token.created_at);
+    Ok(tokens)
+}
@@ -0,0 +1,533 @@
+/**
+ * Placement module - Moving JarvisX windows between displays, edge docking with auto-hide,
+ * per-display positions that are restored when monitors are plugged back in, and mini mode
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, LogicalSize, Manager, Monitor, PhysicalPosition, PhysicalSize, State, Window};
+
+const MAIN_LABEL: &str = "main";
+/// Position key for the main window while it is in mini mode
+const MINI_LABEL: &str = "main-mini";
+const MINI_SIZE: (f64, f64) = (360.0, 200.0);
+const MINI_MARGIN: i32 = 24;
+/// Pixels of an auto-hidden window left on screen as a hover target
+const AUTO_HIDE_STRIP: i32 = 4;
+const AUTO_HIDE_DELAY: Duration = Duration::from_millis(700);
+const AUTO_HIDE_POLL: Duration = Duration::from_millis(150);
+const DISPLAY_POLL: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayInfo {
+    pub index: usize,
+    pub name: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f64,
+    pub primary: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DockEdge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct SavedPlacement {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+}
+
+#[derive(Debug, Clone)]
+struct Dock {
+    edge: DockEdge,
+    auto_hide: bool,
+    display: String,
+    generation: u64,
+}
+
+/// Main window state to return to when leaving mini mode
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MiniRestore {
+    normal: SavedPlacement,
+    maximized: bool,
+    decorated: bool,
+}
+
+#[derive(Default)]
+pub struct PlacementState {
+    /// Display each window was last sent to, by window label
+    preferred: HashMap<String, String>,
+    docks: HashMap<String, Dock>,
+    next_generation: u64,
+    displays: Vec<String>,
+    mini: Option<MiniRestore>,
+}
+
+pub fn display_name(monitor: &Monitor) -> String {
+    monitor.name().cloned().unwrap_or_else(|| {
+        format!("{}x{}@{},{}", monitor.size().width, monitor.size().height, monitor.position().x, monitor.position().y)
+    })
+}
+
+fn placement_key(label: &str, display: &str) -> String {
+    format!("{}@{}", label, display)
+}
+
+fn positions_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("window_positions.json"))
+}
+
+fn load_positions(app: &AppHandle) -> HashMap<String, SavedPlacement> {
+    positions_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_positions(app: &AppHandle, positions: &HashMap<String, SavedPlacement>) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(positions).map_err(|e| e.to_string())?;
+    std::fs::write(positions_path(app)?, json).map_err(|e| e.to_string())
+}
+
+fn mini_mode_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("mini_mode.json"))
+}
+
+fn window_for(app: &AppHandle, label: Option<String>) -> Result<Window, String> {
+    let label = label.unwrap_or_else(|| MAIN_LABEL.to_string());
+    app.get_window(&label).ok_or_else(|| format!("❌ Window not found: {}", label))
+}
+
+/// Matches a display by name (case-insensitive) or by its index in `list_displays`.
+fn find_display(window: &Window, display: &str) -> Result<Monitor, String> {
+    let monitors = window.available_monitors().map_err(|e| e.to_string())?;
+
+    if let Ok(index) = display.parse::<usize>() {
+        if let Some(monitor) = monitors.get(index) {
+            return Ok(monitor.clone());
+        }
+    }
+
+    monitors
+        .into_iter()
+        .find(|monitor| display_name(monitor).eq_ignore_ascii_case(display))
+        .ok_or_else(|| format!("❌ Display not found: {}", display))
+}
+
+fn fits_on(monitor: &Monitor, placement: &SavedPlacement) -> bool {
+    let origin = monitor.position();
+    let size = monitor.size();
+    placement.x >= origin.x
+        && placement.y >= origin.y
+        && placement.x + placement.width as i32 <= origin.x + size.width as i32
+        && placement.y + placement.height as i32 <= origin.y + size.height as i32
+}
+
+/// Restores the window's saved spot on `monitor`, or centres it there.
+fn place_on_display(app: &AppHandle, window: &Window, monitor: &Monitor) -> Result<(), String> {
+    let saved = load_positions(app).get(&placement_key(window.label(), &display_name(monitor))).copied();
+
+    match saved.filter(|placement| fits_on(monitor, placement)) {
+        Some(placement) => {
+            window.set_size(PhysicalSize::new(placement.width, placement.height)).map_err(|e| e.to_string())?;
+            window.set_position(PhysicalPosition::new(placement.x, placement.y)).map_err(|e| e.to_string())
+        }
+        None => {
+            let size = window.outer_size().map_err(|e| e.to_string())?;
+            let width = size.width.min(monitor.size().width);
+            let height = size.height.min(monitor.size().height);
+            let x = monitor.position().x + (monitor.size().width - width) as i32 / 2;
+            let y = monitor.position().y + (monitor.size().height - height) as i32 / 2;
+            window.set_size(PhysicalSize::new(width, height)).map_err(|e| e.to_string())?;
+            window.set_position(PhysicalPosition::new(x, y)).map_err(|e| e.to_string())
+        }
+    }
+}
+
+fn dock_position(monitor: &Monitor, size: PhysicalSize<u32>, edge: DockEdge, hidden: bool) -> PhysicalPosition<i32> {
+    let (mx, my) = (monitor.position().x, monitor.position().y);
+    let (mw, mh) = (monitor.size().width as i32, monitor.size().height as i32);
+    let (w, h) = (size.width as i32, size.height as i32);
+
+    match (edge, hidden) {
+        (DockEdge::Left, false) => PhysicalPosition::new(mx, my + (mh - h) / 2),
+        (DockEdge::Left, true) => PhysicalPosition::new(mx - w + AUTO_HIDE_STRIP, my + (mh - h) / 2),
+        (DockEdge::Right, false) => PhysicalPosition::new(mx + mw - w, my + (mh - h) / 2),
+        (DockEdge::Right, true) => PhysicalPosition::new(mx + mw - AUTO_HIDE_STRIP, my + (mh - h) / 2),
+        (DockEdge::Top, false) => PhysicalPosition::new(mx + (mw - w) / 2, my),
+        (DockEdge::Top, true) => PhysicalPosition::new(mx + (mw - w) / 2, my - h + AUTO_HIDE_STRIP),
+        (DockEdge::Bottom, false) => PhysicalPosition::new(mx + (mw - w) / 2, my + mh - h),
+        (DockEdge::Bottom, true) => PhysicalPosition::new(mx + (mw - w) / 2, my + mh - AUTO_HIDE_STRIP),
+    }
+}
+
+fn cursor_position() -> Option<(i32, i32)> {
+    use enigo::Mouse;
+
+    crate::keyboard::new_enigo().ok()?.location().ok()
+}
+
+fn contains(position: PhysicalPosition<i32>, size: PhysicalSize<u32>, point: (i32, i32)) -> bool {
+    point.0 >= position.x
+        && point.0 < position.x + size.width as i32
+        && point.1 >= position.y
+        && point.1 < position.y + size.height as i32
+}
+
+/// Slides a docked window out while the cursor is over it (or its strip) and back after it leaves.
+fn spawn_auto_hide(app: AppHandle, label: String, generation: u64) {
+    tauri::async_runtime::spawn(async move {
+        // Auto-hiding docks start tucked away
+        let mut hidden = true;
+        let mut left_at: Option<Instant> = None;
+
+        loop {
+            tokio::time::sleep(AUTO_HIDE_POLL).await;
+
+            let dock = match app.state::<Mutex<PlacementState>>().lock().unwrap().docks.get(&label) {
+                Some(dock) if dock.generation == generation => dock.clone(),
+                _ => break,
+            };
+            let (window, cursor) = match (app.get_window(&label), cursor_position()) {
+                (Some(window), Some(cursor)) => (window, cursor),
+                (None, _) => break,
+                _ => continue,
+            };
+            let (monitor, size) = match (find_display(&window, &dock.display), window.outer_size()) {
+                (Ok(monitor), Ok(size)) => (monitor, size),
+                _ => continue,
+            };
+
+            let current = dock_position(&monitor, size, dock.edge, hidden);
+            if contains(current, size, cursor) {
+                left_at = None;
+                if hidden {
+                    hidden = false;
+                    let _ = window.set_position(dock_position(&monitor, size, dock.edge, false));
+                }
+            } else if !hidden {
+                let since = *left_at.get_or_insert_with(Instant::now);
+                if since.elapsed() >= AUTO_HIDE_DELAY {
+                    hidden = true;
+                    left_at = None;
+                    let _ = window.set_position(dock_position(&monitor, size, dock.edge, true));
+                }
+            }
+        }
+    });
+}
+
+fn position_dock(app: &AppHandle, label: &str, dock: &Dock) -> Result<(), String> {
+    let window = window_for(app, Some(label.to_string()))?;
+    let monitor = find_display(&window, &dock.display)?;
+    let size = window.outer_size().map_err(|e| e.to_string())?;
+
+    window.set_always_on_top(true).map_err(|e| e.to_string())?;
+    window
+        .set_position(dock_position(&monitor, size, dock.edge, dock.auto_hide))
+        .map_err(|e| e.to_string())
+}
+
+/// Shrinks the main window to a frameless always-on-top widget at its last mini spot (or the bottom-right corner).
+fn apply_mini_layout(app: &AppHandle, window: &Window) -> Result<(), String> {
+    window.unmaximize().map_err(|e| e.to_string())?;
+    window.set_decorations(false).map_err(|e| e.to_string())?;
+    window.set_always_on_top(true).map_err(|e| e.to_string())?;
+    window.set_skip_taskbar(true).map_err(|e| e.to_string())?;
+    window.set_size(LogicalSize::new(MINI_SIZE.0, MINI_SIZE.1)).map_err(|e| e.to_string())?;
+
+    let monitor = window.current_monitor().map_err(|e| e.to_string())?.ok_or("Window is not on any display")?;
+    let saved = load_positions(app).get(&placement_key(MINI_LABEL, &display_name(&monitor))).copied();
+
+    let position = match saved.filter(|placement| fits_on(&monitor, placement)) {
+        Some(placement) => PhysicalPosition::new(placement.x, placement.y),
+        None => {
+            let size = window.outer_size().map_err(|e| e.to_string())?;
+            PhysicalPosition::new(
+                monitor.position().x + monitor.size().width as i32 - size.width as i32 - MINI_MARGIN,
+                monitor.position().y + monitor.size().height as i32 - size.height as i32 - MINI_MARGIN,
+            )
+        }
+    };
+    window.set_position(position).map_err(|e| e.to_string())
+}
+
+fn enter_mini_mode(app: &AppHandle, window: &Window) -> Result<MiniRestore, String> {
+    let position = window.outer_position().map_err(|e| e.to_string())?;
+    let size = window.outer_size().map_err(|e| e.to_string())?;
+    let restore = MiniRestore {
+        normal: SavedPlacement { x: position.x, y: position.y, width: size.width, height: size.height },
+        maximized: window.is_maximized().unwrap_or(false),
+        decorated: window.is_decorated().unwrap_or(true),
+    };
+
+    apply_mini_layout(app, window)?;
+
+    // Persisted so the app reopens in mini mode and can still find its way back
+    let json = serde_json::to_string_pretty(&restore).map_err(|e| e.to_string())?;
+    std::fs::write(mini_mode_path(app)?, json).map_err(|e| e.to_string())?;
+    Ok(restore)
+}
+
+fn exit_mini_mode(app: &AppHandle, window: &Window, restore: &MiniRestore) -> Result<(), String> {
+    // Remember where the widget was dragged to on this display
+    if let (Ok(Some(monitor)), Ok(position), Ok(size)) = (window.current_monitor(), window.outer_position(), window.outer_size()) {
+        let mut positions = load_positions(app);
+        positions.insert(
+            placement_key(MINI_LABEL, &display_name(&monitor)),
+            SavedPlacement { x: position.x, y: position.y, width: size.width, height: size.height },
+        );
+        let _ = save_positions(app, &positions);
+    }
+
+    window.set_skip_taskbar(false).map_err(|e| e.to_string())?;
+    window.set_always_on_top(false).map_err(|e| e.to_string())?;
+    window.set_decorations(restore.decorated).map_err(|e| e.to_string())?;
+    window
+        .set_size(PhysicalSize::new(restore.normal.width, restore.normal.height))
+        .map_err(|e| e.to_string())?;
+    window
+        .set_position(PhysicalPosition::new(restore.normal.x, restore.normal.y))
+        .map_err(|e| e.to_string())?;
+    if restore.maximized {
+        window.maximize().map_err(|e| e.to_string())?;
+    }
+
+    let _ = std::fs::remove_file(mini_mode_path(app)?);
+    Ok(())
+}
+
+/// Records where each window (other than `skipped`) sits on its current display.
+fn record_positions(app: &AppHandle, skipped: &[String]) {
+    let mut positions = load_positions(app);
+    let mut changed = false;
+
+    for (label, window) in app.windows() {
+        if skipped.contains(&label) || !window.is_visible().unwrap_or(false) {
+            continue;
+        }
+        let (monitor, position, size) = match (window.current_monitor(), window.outer_position(), window.outer_size()) {
+            (Ok(Some(monitor)), Ok(position), Ok(size)) => (monitor, position, size),
+            _ => continue,
+        };
+
+        let placement = SavedPlacement { x: position.x, y: position.y, width: size.width, height: size.height };
+        let key = placement_key(&label, &display_name(&monitor));
+        changed |= positions.insert(key, placement) != Some(placement);
+    }
+
+    if changed {
+        let _ = save_positions(app, &positions);
+    }
+}
+
+/// Watches for monitor hot-plug, moving windows back to their preferred display when it returns.
+pub fn spawn_display_watcher(app: AppHandle) {
+    let restore: Option<MiniRestore> = mini_mode_path(&app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok());
+    if let (Some(restore), Some(window)) = (restore, app.get_window(MAIN_LABEL)) {
+        if apply_mini_layout(&app, &window).is_ok() {
+            app.state::<Mutex<PlacementState>>().lock().unwrap().mini = Some(restore);
+        }
+    }
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(DISPLAY_POLL).await;
+
+            let window = match app.get_window(MAIN_LABEL) {
+                Some(window) => window,
+                None => continue,
+            };
+            let mut displays: Vec<String> = match window.available_monitors() {
+                Ok(monitors) => monitors.iter().map(display_name).collect(),
+                Err(_) => continue,
+            };
+            displays.sort();
+
+            let (changed, preferred, docks, mini) = {
+                let state = app.state::<Mutex<PlacementState>>();
+                let mut placement_state = state.lock().unwrap();
+                let changed = placement_state.displays != displays && !placement_state.displays.is_empty();
+                placement_state.displays = displays.clone();
+                (changed, placement_state.preferred.clone(), placement_state.docks.clone(), placement_state.mini.is_some())
+            };
+
+            if !changed {
+                // Only record while the layout is stable, so OS-forced moves during hot-plug aren't remembered
+                let mut skipped: Vec<String> = docks.keys().cloned().collect();
+                if mini {
+                    skipped.push(MAIN_LABEL.to_string());
+                }
+                record_positions(&app, &skipped);
+                continue;
+            }
+
+            println!("🖥️  Displays changed: {:?}", displays);
+            let _ = app.emit_all("displays://changed", &displays);
+            crate::reading_overlay::reapply(&app);
+
+            for (label, display) in preferred.iter().filter(|(_, display)| displays.contains(display)) {
+                if docks.contains_key(label) {
+                    continue;
+                }
+                if let (Some(window), Ok(monitor)) = (app.get_window(label), find_display(&window, display)) {
+                    let _ = place_on_display(&app, &window, &monitor);
+                }
+            }
+            for (label, dock) in docks.iter().filter(|(_, dock)| displays.contains(&dock.display)) {
+                let _ = position_dock(&app, label, dock);
+            }
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn list_displays(app: AppHandle) -> Result<Vec<DisplayInfo>, String> {
+    let window = window_for(&app, None)?;
+    let primary = window.primary_monitor().map_err(|e| e.to_string())?.map(|monitor| display_name(&monitor));
+
+    Ok(window
+        .available_monitors()
+        .map_err(|e| e.to_string())?
+        .iter()
+        .enumerate()
+        .map(|(index, monitor)| DisplayInfo {
+            index,
+            name: display_name(monitor),
+            x: monitor.position().x,
+            y: monitor.position().y,
+            width: monitor.size().width,
+            height: monitor.size().height,
+            scale_factor: monitor.scale_factor(),
+            primary: primary.as_deref() == Some(display_name(monitor).as_str()),
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn move_window_to_display(
+    app: AppHandle,
+    state: State<'_, Mutex<PlacementState>>,
+    label: Option<String>,
+    display: String,
+) -> Result<(), String> {
+    let window = window_for(&app, label)?;
+    let monitor = find_display(&window, &display)?;
+    let name = display_name(&monitor);
+
+    {
+        let mut placement_state = state.lock().unwrap();
+        placement_state.docks.remove(window.label());
+        placement_state.preferred.insert(window.label().to_string(), name.clone());
+    }
+
+    println!("🖥️  Moving {} to {}", window.label(), name);
+    place_on_display(&app, &window, &monitor)
+}
+
+#[tauri::command]
+pub async fn dock_window(
+    app: AppHandle,
+    state: State<'_, Mutex<PlacementState>>,
+    label: Option<String>,
+    edge: DockEdge,
+    auto_hide: Option<bool>,
+    display: Option<String>,
+) -> Result<(), String> {
+    let window = window_for(&app, label)?;
+    let monitor = match display {
+        Some(display) => find_display(&window, &display)?,
+        None => window
+            .current_monitor()
+            .map_err(|e| e.to_string())?
+            .ok_or("Window is not on any display")?,
+    };
+
+    let dock = {
+        let mut placement_state = state.lock().unwrap();
+        placement_state.next_generation += 1;
+        let dock = Dock {
+            edge,
+            auto_hide: auto_hide.unwrap_or(false),
+            display: display_name(&monitor),
+            generation: placement_state.next_generation,
+        };
+        placement_state.docks.insert(window.label().to_string(), dock.clone());
+        placement_state.preferred.insert(window.label().to_string(), dock.display.clone());
+        dock
+    };
+
+    println!("🖥️  Docking {} to the {:?} edge of {}", window.label(), edge, dock.display);
+    position_dock(&app, window.label(), &dock)?;
+
+    if dock.auto_hide {
+        spawn_auto_hide(app.clone(), window.label().to_string(), dock.generation);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn undock_window(
+    app: AppHandle,
+    state: State<'_, Mutex<PlacementState>>,
+    label: Option<String>,
+) -> Result<(), String> {
+    let window = window_for(&app, label)?;
+    let dock = state
+        .lock()
+        .unwrap()
+        .docks
+        .remove(window.label())
+        .ok_or_else(|| format!("❌ Window is not docked: {}", window.label()))?;
+
+    window.set_always_on_top(false).map_err(|e| e.to_string())?;
+    let monitor = find_display(&window, &dock.display)?;
+    place_on_display(&app, &window, &monitor)
+}
+
+/// Swaps the main window between its normal layout and a small draggable always-on-top widget.
+#[tauri::command]
+pub async fn toggle_mini_mode(
+    app: AppHandle,
+    state: State<'_, Mutex<PlacementState>>,
+    enabled: Option<bool>,
+) -> Result<bool, String> {
+    let window = window_for(&app, None)?;
+    let current = state.lock().unwrap().mini;
+    let enable = enabled.unwrap_or(current.is_none());
+
+    match (enable, current) {
+        (true, None) => {
+            state.lock().unwrap().docks.remove(MAIN_LABEL);
+            let restore = enter_mini_mode(&app, &window)?;
+            state.lock().unwrap().mini = Some(restore);
+        }
+        (false, Some(restore)) => {
+            exit_mini_mode(&app, &window, &restore)?;
+            state.lock().unwrap().mini = None;
+        }
+        _ => {}
+    }
+
+    println!("🖥️  Mini mode {}", if enable { "on" } else { "off" });
+    let _ = app.emit_all("window://mini-mode", serde_json::json!({ "enabled": enable }));
+    Ok(enable)
+}
@@ -0,0 +1,402 @@
+/**
+ * Plans module - Executes LLM-produced action plans step by step with optional user approval
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::oneshot;
+
+/// Unanswered approval requests count as a rejection after this long.
+const APPROVAL_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Commands that change or destroy state outside JarvisX.
+const RISKY_COMMANDS: &[&str] = &[
+    "execute_command",
+    "delete_path",
+    "shutdown_system",
+    "close_application",
+    "type_text",
+    "press_hotkey",
+    "simulate_keyboard",
+    "connect_wifi",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanStep {
+    pub id: String,
+    pub command: String,
+    #[serde(default)]
+    pub args: serde_json::Value,
+    #[serde(default)]
+    pub description: String,
+    /// Overrides the built-in risk classification
+    pub risky: Option<bool>,
+    #[serde(default)]
+    pub retries: u32,
+    #[serde(default)]
+    pub continue_on_error: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Plan {
+    #[serde(default)]
+    pub goal: String,
+    pub steps: Vec<PlanStep>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StepResult {
+    pub id: String,
+    pub command: String,
+    /// "succeeded", "failed", "rejected" or "skipped"
+    pub status: String,
+    pub attempts: u32,
+    pub output: Option<serde_json::Value>,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+pub struct PlanState {
+    pub next_plan: u64,
+    pending_approvals: HashMap<String, oneshot::Sender<bool>>,
+}
+
+impl Default for PlanState {
+    fn default() -> Self {
+        PlanState {
+            next_plan: 1,
+            pending_approvals: HashMap::new(),
+        }
+    }
+}
+
+fn approval_key(plan_id: &str, step_id: &str) -> String {
+    format!("{}/{}", plan_id, step_id)
+}
+
+fn str_arg(args: &serde_json::Value, key: &str) -> Result<String, String> {
+    args.get(key)
+        .and_then(|value| value.as_str())
+        .map(|value| value.to_string())
+        .ok_or_else(|| format!("Missing string argument: {}", key))
+}
+
+fn int_arg(args: &serde_json::Value, key: &str) -> Result<i32, String> {
+    args.get(key)
+        .and_then(|value| value.as_i64())
+        .map(|value| value as i32)
+        .ok_or_else(|| format!("Missing integer argument: {}", key))
+}
+
+fn to_json<T: Serialize>(value: T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap_or(serde_json::Value::Null)
+}
+
+fn is_risky(step: &PlanStep) -> bool {
+    step.risky.unwrap_or_else(|| RISKY_COMMANDS.contains(&step.command.as_str()))
+}
+
+/// Runs one native command by name; anything the script whitelist covers is delegated to it.
+pub async fn dispatch_native(app: &AppHandle, command: &str, args: &serde_json::Value) -> Result<serde_json::Value, String> {
+    use crate::commands;
+
+    let refresh = args.get("refresh").and_then(|value| value.as_bool());
+    let verify = args.get("verify").and_then(|value| value.as_bool());
+
+    match command {
+        "get_system_info" => commands::get_system_info(refresh).await,
+        "list_installed_applications" => commands::list_installed_applications(refresh).await,
+        "get_window_list" => {
+            let registry = app.state::<Mutex<crate::window_registry::WindowRegistry>>();
+            crate::window_registry::cached_window_list(&registry, refresh.unwrap_or(false)).await.map(to_json)
+        }
+        "get_running_processes" => commands::get_running_processes().await.map(to_json),
+        "get_mouse_position" => commands::get_mouse_position().await,
+        "focus_window" => {
+            let title = args["title"].as_str().map(str::to_string);
+            let window_id = args["windowId"].as_str().map(str::to_string);
+            commands::focus_window(title, window_id, verify).await.map(to_json)
+        }
+        "minimize_window" => crate::window_ids::minimize_window(str_arg(args, "windowId")?).await.map(to_json),
+        "get_active_window_info" => crate::window_ids::get_active_window_info().await.map(to_json),
+        "get_windows_for_process" => crate::window_ids::get_windows_for_process(int_arg(args, "pid")? as u32).await.map(to_json),
+        "get_apps_playing_audio" => {
+            let include_idle = args.get("includeIdle").and_then(|value| value.as_bool());
+            crate::audio_sessions::get_apps_playing_audio(include_idle).await.map(to_json)
+        }
+        "get_app_volumes" => crate::audio_sessions::get_app_volumes().await.map(to_json),
+        "set_app_volume" => {
+            let level = args["level"].as_f64().ok_or("Missing number argument: level")? as f32;
+            crate::audio_sessions::set_app_volume(str_arg(args, "app")?, level).await.map(to_json)
+        }
+        "mute_apps_playing_audio" => crate::audio_sessions::mute_apps_playing_audio().await.map(to_json),
+        "enter_presentation_mode" => crate::presentation::enter_presentation_mode(app.clone()).await,
+        "exit_presentation_mode" => crate::presentation::exit_presentation_mode(app.clone()).await,
+        "prevent_sleep" => {
+            let duration = args["duration"].as_str().map(str::to_string);
+            crate::power::prevent_sleep(str_arg(args, "reason")?, duration, args["display"].as_bool()).await.map(to_json)
+        }
+        "safely_eject" => crate::removable::safely_eject(str_arg(args, "volume")?).await.map(to_json),
+        "capture_and_share" => {
+            let target = serde_json::from_value(args["target"].clone()).map_err(|e| format!("Invalid target: {}", e))?;
+            crate::share::capture_and_share(app.clone(), target, None).await.map(to_json)
+        }
+        "extract_table" => {
+            let region = serde_json::from_value(args["region"].clone()).ok();
+            let image = args["image"].as_str().map(str::to_string);
+            crate::tables::extract_table(app.clone(), image, region, None, args["copy"].as_bool()).await.map(to_json)
+        }
+        "recognize_ink" => {
+            let strokes = serde_json::from_value(args["strokes"].clone()).map_err(|e| format!("Invalid strokes: {}", e))?;
+            let mode = args["mode"].as_str().map(str::to_string);
+            crate::ink::recognize_ink(app.clone(), strokes, mode).await.map(to_json)
+        }
+        "run_backup_job" => crate::backups::run_backup_job(app.clone(), str_arg(args, "id")?, args["verify"].as_bool()).await,
+        "allow_sleep" => crate::power::allow_sleep(args["id"].as_u64()).await.map(to_json),
+        "parse_datetime" => {
+            let reference = args["reference"].as_str().map(str::to_string);
+            crate::datetime_parse::parse_datetime(app.clone(), str_arg(args, "text")?, reference).await.map(to_json)
+        }
+        "get_process_for_window" => crate::window_ids::get_process_for_window(str_arg(args, "windowId")?).await.map(to_json),
+        "switch_to_application" => commands::switch_to_application(str_arg(args, "appName")?).await.map(to_json),
+        "minimize_application" => commands::minimize_application(str_arg(args, "appName")?).await.map(to_json),
+        "maximize_application" => commands::maximize_application(str_arg(args, "appName")?).await.map(to_json),
+        "close_application" => commands::close_application(str_arg(args, "appName")?).await.map(to_json),
+        "simulate_keyboard" => commands::simulate_keyboard(str_arg(args, "key")?, verify).await.map(to_json),
+        "simulate_mouse_click" => commands::simulate_mouse_click(int_arg(args, "x")?, int_arg(args, "y")?, verify).await.map(to_json),
+        "simulate_mouse_scroll" => {
+            commands::simulate_mouse_scroll(str_arg(args, "direction")?, int_arg(args, "amount")?, verify).await.map(to_json)
+        }
+        "capture_screen" => commands::capture_screen().await.map(to_json),
+        "execute_command" => {
+            let command_args = serde_json::from_value(args["args"].clone()).unwrap_or_default();
+            // Callers holding a capability get its sandbox; everything else here is a workflow
+            let level = match args[crate::permissions::CAPABILITY_FIELD].as_str() {
+                Some(token) => crate::permissions::sandbox_level(app, Some(token)),
+                None => crate::process_sandbox::current_policy().workflows,
+            };
+            commands::run_command(app, str_arg(args, "command")?, command_args, level).await.map(to_json)
+        }
+        "delete_path" => crate::supervised::delete_path(app.clone(), str_arg(args, "path")?).await.map(to_json),
+        "get_context_snapshot" => crate::context::get_context_snapshot(app.clone()).await,
+        "speak_text" => {
+            let language = args.get("language").and_then(|value| value.as_str()).map(String::from);
+            crate::speech::speak_text(str_arg(args, "text")?, language).await.map(to_json)
+        }
+        "capture_display" => {
+            use base64::{Engine as _, engine::general_purpose};
+
+            // Displays are numbered from 1, matching what users see in system settings
+            let display = args.get("display").and_then(|value| value.as_u64()).unwrap_or(1).max(1);
+            let png = match commands::capture_display_png(display as usize - 1).await {
+                Ok(png) => png,
+                // Callers that opt in get window titles instead of pixels
+                Err(e) if crate::screen_permission::is_permission_denied(&e) && args["fallback"].as_bool() == Some(true) => {
+                    return Ok(crate::screen_permission::title_only_context(app).await);
+                }
+                Err(e) => return Err(e),
+            };
+            Ok(serde_json::json!({
+                "display": display,
+                "contentType": "image/png",
+                "data": general_purpose::STANDARD.encode(png),
+            }))
+        }
+        "run_script" => {
+            let token = args[crate::permissions::CAPABILITY_FIELD].as_str().map(str::to_string);
+            crate::scripting::run_script(app.clone(), Some(str_arg(args, "name")?), None, args.get("event").cloned(), None, token).await
+        }
+        "run_workflow" => crate::headless::request_workflow(app, &str_arg(args, "name")?),
+        "release_all_keys" => crate::stuck_keys::release_all_keys(app.clone()).await.map(to_json),
+        "set_mock_platform" => {
+            let enabled = args.get("enabled").and_then(|value| value.as_bool()).unwrap_or(true);
+            let fixture = args.get("fixture").cloned().map(serde_json::from_value).transpose().map_err(|e| e.to_string())?;
+            crate::mock_platform::set_mock_platform(enabled, fixture).await
+        }
+        "get_mock_platform_state" => crate::mock_platform::get_mock_platform_state().await,
+        "get_mock_input_log" => {
+            let clear = args.get("clear").and_then(|value| value.as_bool());
+            crate::mock_platform::get_mock_input_log(clear).await.map(to_json)
+        }
+        _ => {
+            let (app, command, args) = (app.clone(), command.to_string(), args.clone());
+            let token = args[crate::permissions::CAPABILITY_FIELD].as_str().map(str::to_string);
+            tauri::async_runtime::spawn_blocking(move || crate::scripting::dispatch(&app, &command, args, token.as_deref()))
+                .await
+                .map_err(|e| e.to_string())?
+        }
+    }
+}
+
+/// Emits an approval request and waits for respond_plan_step (or the timeout).
+async fn request_approval(app: &AppHandle, plan_id: &str, step: &PlanStep) -> bool {
+    let (sender, receiver) = oneshot::channel();
+    let key = approval_key(plan_id, &step.id);
+    app.state::<Mutex<PlanState>>().lock().unwrap().pending_approvals.insert(key.clone(), sender);
+
+    let _ = app.emit_all("plan://approval-requested", serde_json::json!({
+        "planId": plan_id,
+        "step": step,
+        "risky": is_risky(step),
+    }));
+
+    let approved = matches!(tokio::time::timeout(APPROVAL_TIMEOUT, receiver).await, Ok(Ok(true)));
+    app.state::<Mutex<PlanState>>().lock().unwrap().pending_approvals.remove(&key);
+    approved
+}
+
+async fn run_step(app: &AppHandle, step: &PlanStep, capability_token: Option<&str>) -> StepResult {
+    let started = Instant::now();
+    // The handler wrapper only saw "execute_plan", so each step is scoped to the caller's capability
+    let args = match crate::permissions::authorize_nested(app, capability_token, &step.command, &step.args) {
+        Ok(args) => args,
+        Err(e) => return skipped(step, "rejected", Some(e)),
+    };
+    let policy = crate::retry::RetryPolicy {
+        max_attempts: step.retries + 1,
+        ..crate::retry::current_policy()
+    };
+
+    let mut attempts = 0;
+    let outcome = crate::retry::retry(&policy, &step.command, || {
+        attempts += 1;
+        dispatch_native(app, &step.command, &args)
+    })
+    .await
+    .map(|retried| retried.value);
+
+    StepResult {
+        id: step.id.clone(),
+        command: step.command.clone(),
+        status: if outcome.is_ok() { "succeeded" } else { "failed" }.to_string(),
+        attempts,
+        output: outcome.as_ref().ok().cloned(),
+        error: outcome.err(),
+        duration_ms: started.elapsed().as_millis() as u64,
+    }
+}
+
+fn skipped(step: &PlanStep, status: &str, error: Option<String>) -> StepResult {
+    StepResult {
+        id: step.id.clone(),
+        command: step.command.clone(),
+        status: status.to_string(),
+        attempts: 0,
+        output: None,
+        error,
+        duration_ms: 0,
+    }
+}
+
+/// Runs a plan. `approval_mode` is "none", "risky" (default) or "all".
+/// With a capability token, every step must be a command (and touch paths and apps) the token allows.
+#[tauri::command]
+pub async fn execute_plan(
+    app: AppHandle,
+    plan_json: String,
+    approval_mode: Option<String>,
+    capability_token: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let plan: Plan = serde_json::from_str(&plan_json).map_err(|e| format!("❌ Invalid plan: {}", e))?;
+    let approval_mode = approval_mode.unwrap_or_else(|| "risky".to_string());
+    if !["none", "risky", "all"].contains(&approval_mode.as_str()) {
+        return Err(format!("❌ Unknown approval mode: {}", approval_mode));
+    }
+    // Checked up front so a plan the token doesn't fully cover never starts
+    if let Some(token) = &capability_token {
+        for step in &plan.steps {
+            crate::permissions::authorize(&app, token, &step.command, &step.args)
+                .map_err(|e| format!("❌ Plan step {} rejected: {}", step.id, e))?;
+        }
+    }
+
+    let plan_id = {
+        let state = app.state::<Mutex<PlanState>>();
+        let mut plan_state = state.lock().unwrap();
+        plan_state.next_plan += 1;
+        format!("plan-{}", plan_state.next_plan - 1)
+    };
+
+    println!("🧭 Executing {} ({} steps): {}", plan_id, plan.steps.len(), plan.goal);
+    crate::recovery::track(&plan_id, "plan", serde_json::json!({ "planId": plan_id, "goal": plan.goal }));
+    let _ = app.emit_all("plan://started", serde_json::json!({ "planId": plan_id, "goal": plan.goal, "steps": plan.steps }));
+
+    let mut results: Vec<StepResult> = Vec::new();
+    let mut halted = false;
+    let user_snapshot = crate::automation_guard::snapshot();
+    let mut input_mark = crate::automation_guard::input_mark();
+
+    for step in &plan.steps {
+        if halted {
+            results.push(skipped(step, "skipped", None));
+            continue;
+        }
+
+        // Don't fight the user for the mouse: anything they did since the last step pauses or aborts the plan
+        if let Err(e) = crate::automation_guard::wait_if_user_active(&app, &plan_id, input_mark).await {
+            results.push(skipped(step, "skipped", Some(e)));
+            halted = true;
+            continue;
+        }
+
+        let needs_approval = approval_mode == "all" || (approval_mode == "risky" && is_risky(step));
+        if needs_approval && !request_approval(&app, &plan_id, step).await {
+            results.push(skipped(step, "rejected", Some("Step was not approved".to_string())));
+            halted = true;
+            continue;
+        }
+
+        // Input from here on (but not the approval click itself) is checked before the next step
+        input_mark = crate::automation_guard::input_mark();
+        let _ = app.emit_all("plan://step-started", serde_json::json!({ "planId": plan_id, "stepId": step.id }));
+        let result = match crate::automation_guard::guard_step(&app, &plan_id, &step.id, run_step(&app, step, capability_token.as_deref())).await {
+            Ok(result) => result,
+            Err(e) => {
+                halted = true;
+                skipped(step, "interrupted", Some(e))
+            }
+        };
+        let _ = app.emit_all("plan://step-finished", serde_json::json!({ "planId": plan_id, "result": result }));
+
+        if result.status == "failed" && !step.continue_on_error {
+            halted = true;
+        }
+        results.push(result);
+    }
+
+    let succeeded = results.iter().all(|result| result.status == "succeeded");
+    let summary = serde_json::json!({
+        "planId": plan_id,
+        "goal": plan.goal,
+        "succeeded": succeeded,
+        "steps": results,
+    });
+
+    crate::automation_guard::restore(user_snapshot).await;
+    crate::recovery::untrack(&plan_id);
+    let _ = app.emit_all("plan://finished", summary.clone());
+    Ok(summary)
+}
+
+#[tauri::command]
+pub async fn respond_plan_step(
+    state: State<'_, Mutex<PlanState>>,
+    plan_id: String,
+    step_id: String,
+    approved: bool,
+) -> Result<(), String> {
+    let sender = state
+        .lock()
+        .unwrap()
+        .pending_approvals
+        .remove(&approval_key(&plan_id, &step_id))
+        .ok_or("No approval pending for this step")?;
+
+    sender.send(approved).map_err(|_| "Plan is no longer waiting".to_string())
+}
@@ -0,0 +1,177 @@
+/**
+ * Power module - Keep-awake assertions so long jobs (recordings, presentations, model downloads) survive idle sleep
+ *
+ * Each assertion is held independently and released on its own: a Windows execution-state thread, or a
+ * `caffeinate` (macOS) or `systemd-inhibit` (Linux) child tied to its lifetime. Timed assertions release
+ * themselves when they expire.
+ */
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::process::Child;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PowerAssertion {
+    pub id: u64,
+    pub reason: String,
+    /// Keeps the display on too, not just the system
+    pub display: bool,
+    pub created_at_ms: u64,
+    pub expires_at_ms: Option<u64>,
+}
+
+enum Hold {
+    /// caffeinate or systemd-inhibit, asserting while it runs
+    Child(Child),
+    /// Thread keeping the Windows execution state set until the flag clears
+    Thread(Arc<AtomicBool>),
+}
+
+static ASSERTIONS: Mutex<BTreeMap<u64, (PowerAssertion, Hold)>> = Mutex::new(BTreeMap::new());
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(target_os = "windows")]
+fn hold(_reason: &str, display: bool) -> Result<Hold, String> {
+    use windows::Win32::System::Power::{SetThreadExecutionState, ES_CONTINUOUS, ES_DISPLAY_REQUIRED, ES_SYSTEM_REQUIRED};
+
+    // The execution state belongs to the thread that set it, so a dedicated thread holds it
+    let running = Arc::new(AtomicBool::new(true));
+    let flag = running.clone();
+    std::thread::spawn(move || unsafe {
+        let mut state = ES_CONTINUOUS | ES_SYSTEM_REQUIRED;
+        if display {
+            state = state | ES_DISPLAY_REQUIRED;
+        }
+        SetThreadExecutionState(state);
+        while flag.load(Ordering::SeqCst) {
+            std::thread::sleep(Duration::from_millis(500));
+        }
+        SetThreadExecutionState(ES_CONTINUOUS);
+    });
+    Ok(Hold::Thread(running))
+}
+
+#[cfg(target_os = "macos")]
+fn hold(_reason: &str, display: bool) -> Result<Hold, String> {
+    use std::process::Command;
+
+    // -w ties the assertion to our process, so a crash can't leave the Mac awake forever
+    let pid = std::process::id().to_string();
+    let mut args = vec!["-i", "-w", pid.as_str()];
+    if display {
+        args.insert(0, "-d");
+    }
+    Command::new("caffeinate")
+        .args(&args)
+        .spawn()
+        .map(Hold::Child)
+        .map_err(|e| format!("Could not start caffeinate: {}", e))
+}
+
+#[cfg(target_os = "linux")]
+fn hold(reason: &str, display: bool) -> Result<Hold, String> {
+    use std::process::Command;
+
+    let what = if display { "--what=idle:sleep" } else { "--what=sleep" };
+    Command::new("systemd-inhibit")
+        .args(&[what, "--who=JarvisX", &format!("--why={}", reason), "--mode=block", "sleep", "infinity"])
+        .spawn()
+        .map(Hold::Child)
+        .map_err(|e| format!("Could not start systemd-inhibit: {}", e))
+}
+
+fn let_go(hold: Hold) {
+    match hold {
+        Hold::Child(mut child) => {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+        Hold::Thread(running) => running.store(false, Ordering::SeqCst),
+    }
+}
+
+/// Keeps the system (and with `display`, the screen) awake until `release` or `duration` elapses.
+pub fn acquire(reason: &str, display: bool, duration: Option<Duration>) -> Result<u64, String> {
+    let id = NEXT_ID.fetch_add(1, Ordering::SeqCst);
+    let created_at_ms = now_ms();
+    let assertion = PowerAssertion {
+        id,
+        reason: reason.to_string(),
+        display,
+        created_at_ms,
+        expires_at_ms: duration.map(|duration| created_at_ms + duration.as_millis() as u64),
+    };
+
+    // The mock platform never sleeps
+    let hold = if crate::mock_platform::is_active() { Hold::Thread(Arc::new(AtomicBool::new(false))) } else { hold(reason, display)? };
+    ASSERTIONS.lock().unwrap().insert(id, (assertion, hold));
+    println!("☕ Keeping awake (#{}): {}", id, reason);
+
+    if let Some(duration) = duration {
+        tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(duration).await;
+            release(id);
+        });
+    }
+    Ok(id)
+}
+
+/// Ends an assertion; false when it was already released or expired.
+pub fn release(id: u64) -> bool {
+    let removed = ASSERTIONS.lock().unwrap().remove(&id);
+    match removed {
+        Some((assertion, hold)) => {
+            let_go(hold);
+            println!("💤 Sleep allowed again (#{}): {}", id, assertion.reason);
+            true
+        }
+        None => false,
+    }
+}
+
+pub fn assertions() -> Vec<PowerAssertion> {
+    ASSERTIONS.lock().unwrap().values().map(|(assertion, _)| assertion.clone()).collect()
+}
+
+/// Keeps the machine awake for `reason`, for `duration` ("90m", "2h") or until allow_sleep.
+#[tauri::command]
+pub async fn prevent_sleep(reason: String, duration: Option<String>, display: Option<bool>) -> Result<PowerAssertion, String> {
+    let duration = duration.map(|duration| crate::timers::parse_duration(&duration)).transpose()?.map(Duration::from_secs);
+    let id = acquire(&reason, display.unwrap_or(false), duration)?;
+    assertions()
+        .into_iter()
+        .find(|assertion| assertion.id == id)
+        .ok_or_else(|| "Assertion ended immediately".to_string())
+}
+
+/// Releases assertion `id`, or every active assertion when none is given.
+#[tauri::command]
+pub async fn allow_sleep(id: Option<u64>) -> Result<Vec<PowerAssertion>, String> {
+    match id {
+        Some(id) if !release(id) => return Err(format!("❌ Power assertion not found: {}", id)),
+        Some(_) => {}
+        None => {
+            let ids: Vec<u64> = assertions().iter().map(|assertion| assertion.id).collect();
+            for id in ids {
+                release(id);
+            }
+        }
+    }
+    Ok(assertions())
+}
+
+#[tauri::command]
+pub async fn get_power_assertions() -> Result<Vec<PowerAssertion>, String> {
+    Ok(assertions())
+}
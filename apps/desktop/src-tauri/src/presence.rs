@@ -0,0 +1,259 @@
+/**
+ * Presence module - Opt-in local face detection that pauses listening when nobody is there
+ */
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+use tract_onnx::prelude::*;
+
+use crate::voice::VoiceState;
+
+/// UltraFace RFB-320 input size; the model is fetched into `models/` at build time and bundled as a resource.
+const MODEL_WIDTH: usize = 320;
+const MODEL_HEIGHT: usize = 240;
+const FACE_SCORE_THRESHOLD: f32 = 0.7;
+
+pub type FaceModel = SimplePlan<TypedFact, Box<dyn TypedOp>, Graph<TypedFact, Box<dyn TypedOp>>>;
+
+pub struct PresenceState {
+    pub stop: Option<Arc<AtomicBool>>,
+    pub is_present: bool,
+    pub paused_microphone: bool,
+}
+
+impl Default for PresenceState {
+    fn default() -> Self {
+        PresenceState {
+            stop: None,
+            is_present: true,
+            paused_microphone: false,
+        }
+    }
+}
+
+fn model_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path_resolver()
+        .resolve_resource("models/face-detection-rfb-320.onnx")
+        .filter(|path| path.exists())
+        .ok_or_else(|| "Face detection model not found in app resources; run `npm run fetch-models`".to_string())
+}
+
+fn load_model(path: &PathBuf) -> Result<FaceModel, String> {
+    tract_onnx::onnx()
+        .model_for_path(path)
+        .and_then(|model| model.with_input_fact(0, f32::fact([1, 3, MODEL_HEIGHT, MODEL_WIDTH]).into()))
+        .and_then(|model| model.into_optimized())
+        .and_then(|model| model.into_runnable())
+        .map_err(|e| e.to_string())
+}
+
+static FACE_MODEL: Mutex<Option<Arc<FaceModel>>> = Mutex::new(None);
+
+/// The optimized face model, loaded on first use and shared afterwards.
+pub fn face_model(app: &AppHandle) -> Result<Arc<FaceModel>, String> {
+    let mut cached = FACE_MODEL.lock().unwrap();
+
+    if let Some(model) = cached.as_ref() {
+        return Ok(model.clone());
+    }
+
+    let path = model_path(app)?;
+    let model = Arc::new(crate::subsystems::track_load("face-detection", || load_model(&path))?);
+    *cached = Some(model.clone());
+    Ok(model)
+}
+
+fn detect_face(model: &FaceModel, frame: &image::RgbImage) -> Result<bool, String> {
+    let resized = image::imageops::resize(
+        frame,
+        MODEL_WIDTH as u32,
+        MODEL_HEIGHT as u32,
+        image::imageops::FilterType::Triangle,
+    );
+
+    let input: Tensor = tract_ndarray::Array4::from_shape_fn((1, 3, MODEL_HEIGHT, MODEL_WIDTH), |(_, c, y, x)| {
+        (resized.get_pixel(x as u32, y as u32)[c] as f32 - 127.0) / 128.0
+    })
+    .into();
+
+    let outputs = model.run(tvec!(input.into())).map_err(|e| e.to_string())?;
+    let scores = outputs[0].to_array_view::<f32>().map_err(|e| e.to_string())?;
+
+    // scores is [1, anchors, 2] with (background, face) per anchor
+    Ok(scores
+        .outer_iter()
+        .flat_map(|batch| batch.outer_iter().map(|anchor| anchor[1]).collect::<Vec<f32>>())
+        .any(|score| score > FACE_SCORE_THRESHOLD))
+}
+
+fn set_overlays_visible(app: &AppHandle, visible: bool) {
+    // Presentation mode keeps them hidden until it ends
+    if crate::presentation::is_active() {
+        return;
+    }
+    for (label, window) in app.windows() {
+        if label.starts_with("overlay") {
+            let _ = if visible { window.show() } else { window.hide() };
+        }
+    }
+}
+
+/// Releases the microphone while nobody is there: the capture stream is closed, not just flagged.
+fn pause_microphone(app: &AppHandle) -> bool {
+    let voice = app.state::<Mutex<VoiceState>>();
+    let mut voice_state = voice.lock().unwrap();
+    if !voice_state.is_recording {
+        return false;
+    }
+
+    voice_state.is_recording = false;
+    crate::voice_activity::stop();
+    crate::ducking::restore("listening");
+    println!("🎤 Microphone paused: no one present");
+    true
+}
+
+fn resume_microphone(app: &AppHandle) {
+    app.state::<Mutex<VoiceState>>().lock().unwrap().is_recording = true;
+    crate::ducking::duck("listening");
+
+    match tauri::async_runtime::block_on(crate::voice_activity::start(app)) {
+        Ok(()) => println!("🎤 Microphone resumed: user returned"),
+        Err(e) => println!("⚠️  Microphone could not be reopened: {}", e),
+    }
+}
+
+fn on_presence_changed(app: &AppHandle, present: bool) {
+    let resume = {
+        let state = app.state::<Mutex<PresenceState>>();
+        let mut presence_state = state.lock().unwrap();
+        presence_state.is_present = present;
+
+        if present {
+            std::mem::replace(&mut presence_state.paused_microphone, false)
+        } else {
+            if pause_microphone(app) {
+                presence_state.paused_microphone = true;
+            }
+            false
+        }
+    };
+
+    if resume {
+        resume_microphone(app);
+    }
+
+    set_overlays_visible(app, present);
+    let _ = app.emit_all("presence-changed", serde_json::json!({ "present": present }));
+}
+
+#[tauri::command]
+pub async fn start_presence_detection(
+    app: AppHandle,
+    camera_id: Option<u32>,
+    interval_ms: Option<u64>,
+    absence_secs: Option<u64>,
+) -> Result<String, String> {
+    crate::camera::ensure_camera_permission(&app)?;
+
+    let model = face_model(&app)?;
+    let camera_id = camera_id.unwrap_or(0);
+    let interval = Duration::from_millis(interval_ms.unwrap_or(1000).max(200));
+    let absence_timeout = Duration::from_secs(absence_secs.unwrap_or(10));
+    let stop = Arc::new(AtomicBool::new(false));
+
+    {
+        let state = app.state::<Mutex<PresenceState>>();
+        let mut presence_state = state.lock().unwrap();
+
+        if presence_state.stop.is_some() {
+            return Err("Presence detection already active".to_string());
+        }
+
+        presence_state.stop = Some(stop.clone());
+        presence_state.is_present = true;
+    }
+
+    crate::camera::set_camera_indicator(&app, true);
+    crate::scheduler::register_task(&app, "presence-detection", crate::scheduler::TaskPriority::High);
+    println!("👤 Presence detection started (camera {})", camera_id);
+
+    let handle = app.clone();
+    std::thread::spawn(move || {
+        let mut camera = match crate::camera::open_camera(camera_id) {
+            Ok(camera) => camera,
+            Err(e) => {
+                let _ = handle.emit_all("presence-error", e);
+                handle.state::<Mutex<PresenceState>>().lock().unwrap().stop = None;
+                crate::camera::set_camera_indicator(&handle, false);
+                crate::scheduler::unregister_task(&handle, "presence-detection");
+                return;
+            }
+        };
+
+        let mut present = true;
+        let mut last_seen = Instant::now();
+
+        while !stop.load(Ordering::SeqCst) {
+            // Over budget: hold the last known presence instead of running the model
+            if crate::scheduler::is_paused(&handle, "presence-detection") {
+                std::thread::sleep(interval);
+                continue;
+            }
+
+            let face = crate::camera::grab_frame(&mut camera)
+                .and_then(|frame| detect_face(&model, &frame))
+                .unwrap_or(present);
+
+            if face {
+                last_seen = Instant::now();
+                if !present {
+                    present = true;
+                    on_presence_changed(&handle, true);
+                }
+            } else if present && last_seen.elapsed() >= absence_timeout {
+                present = false;
+                on_presence_changed(&handle, false);
+            }
+
+            std::thread::sleep(interval);
+        }
+
+        let _ = camera.stop_stream();
+        if !present {
+            on_presence_changed(&handle, true);
+        }
+    });
+
+    Ok("Presence detection started".to_string())
+}
+
+#[tauri::command]
+pub async fn stop_presence_detection(app: AppHandle, state: State<'_, Mutex<PresenceState>>) -> Result<String, String> {
+    let stop = state.lock().unwrap().stop.take();
+
+    match stop {
+        Some(stop) => {
+            stop.store(true, Ordering::SeqCst);
+            crate::camera::set_camera_indicator(&app, false);
+            crate::scheduler::unregister_task(&app, "presence-detection");
+            println!("👤 Presence detection stopped");
+            Ok("Presence detection stopped".to_string())
+        }
+        None => Err("Presence detection not active".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn get_presence_status(state: State<'_, Mutex<PresenceState>>) -> Result<serde_json::Value, String> {
+    let presence_state = state.lock().unwrap();
+
+    Ok(serde_json::json!({
+        "isActive": presence_state.stop.is_some(),
+        "isPresent": presence_state.is_present,
+        "microphonePaused": presence_state.paused_microphone,
+    }))
+}
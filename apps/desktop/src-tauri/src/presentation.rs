@@ -0,0 +1,144 @@
+/**
+ * Presentation module - One switch for screen sharing: Do Not Disturb on, display kept awake, overlays hidden,
+ * notifications held back and spoken cues muted, all restored on exit
+ *
+ * Only what presentation mode itself changed is undone: overlays that were already hidden stay hidden, and Do Not
+ * Disturb stays on when a focus session still wants it. Notifications raised meanwhile are shown once it ends.
+ */
+
+use std::sync::Mutex;
+use std::time::Instant;
+use tauri::{AppHandle, Manager};
+
+/// More held notifications than this are summarised in one on exit
+const HELD_NOTIFICATIONS_SHOWN: usize = 3;
+
+struct Presentation {
+    started_at: Instant,
+    do_not_disturb: bool,
+    /// Power assertion keeping the display on
+    keep_awake: Option<u64>,
+    hidden_windows: Vec<String>,
+}
+
+static PRESENTATION: Mutex<Option<Presentation>> = Mutex::new(None);
+static HELD_NOTIFICATIONS: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+pub fn is_active() -> bool {
+    PRESENTATION.lock().unwrap().is_some()
+}
+
+/// Keeps a notification for later while presenting; true when held.
+pub fn hold_notification(title: &str, body: &str) -> bool {
+    if !is_active() {
+        return false;
+    }
+    HELD_NOTIFICATIONS.lock().unwrap().push((title.to_string(), body.to_string()));
+    true
+}
+
+fn focus_session_active(app: &AppHandle) -> bool {
+    app.state::<Mutex<crate::focus::FocusState>>().lock().unwrap().is_active
+}
+
+fn status(presentation: Option<&Presentation>) -> serde_json::Value {
+    match presentation {
+        Some(presentation) => serde_json::json!({
+            "active": true,
+            "elapsedSecs": presentation.started_at.elapsed().as_secs(),
+            "doNotDisturb": presentation.do_not_disturb,
+            "keepAwake": presentation.keep_awake.is_some(),
+            "hiddenWindows": presentation.hidden_windows,
+            "heldNotifications": HELD_NOTIFICATIONS.lock().unwrap().len(),
+        }),
+        None => serde_json::json!({ "active": false }),
+    }
+}
+
+/// Enables Do Not Disturb, keeps the display awake, hides overlays, holds notifications and mutes spoken cues.
+#[tauri::command]
+pub async fn enter_presentation_mode(app: AppHandle) -> Result<serde_json::Value, String> {
+    if is_active() {
+        return Err("Presentation mode already active".to_string());
+    }
+
+    let mut warnings = Vec::new();
+    let do_not_disturb = match tauri::async_runtime::spawn_blocking(|| crate::focus::set_do_not_disturb(true)).await.map_err(|e| e.to_string()) {
+        Ok(Ok(())) => true,
+        Ok(Err(e)) | Err(e) => {
+            warnings.push(e);
+            false
+        }
+    };
+    let keep_awake = match crate::power::acquire("Presenting", true, None) {
+        Ok(keep_awake) => Some(keep_awake),
+        Err(e) => {
+            warnings.push(e);
+            None
+        }
+    };
+
+    let mut hidden_windows = Vec::new();
+    for (label, window) in app.windows() {
+        if label.starts_with("overlay") && window.is_visible().unwrap_or(false) && window.hide().is_ok() {
+            hidden_windows.push(label);
+        }
+    }
+    crate::speech::stop();
+
+    let presentation = Presentation { started_at: Instant::now(), do_not_disturb, keep_awake, hidden_windows };
+    let mut report = status(Some(&presentation));
+    report["warnings"] = serde_json::json!(warnings);
+    *PRESENTATION.lock().unwrap() = Some(presentation);
+
+    for warning in &warnings {
+        println!("⚠️  Presentation mode: {}", warning);
+    }
+    println!("📽️  Presentation mode on");
+    let _ = app.emit_all("presentation://changed", &report);
+    Ok(report)
+}
+
+/// Restores what enter_presentation_mode changed and shows the notifications held meanwhile.
+#[tauri::command]
+pub async fn exit_presentation_mode(app: AppHandle) -> Result<serde_json::Value, String> {
+    let presentation = PRESENTATION.lock().unwrap().take().ok_or("Presentation mode not active")?;
+
+    if presentation.do_not_disturb && !focus_session_active(&app) {
+        if let Err(e) = tauri::async_runtime::spawn_blocking(|| crate::focus::set_do_not_disturb(false)).await.map_err(|e| e.to_string())? {
+            println!("⚠️  {}", e);
+        }
+    }
+    if let Some(id) = presentation.keep_awake {
+        crate::power::release(id);
+    }
+    for label in &presentation.hidden_windows {
+        if let Some(window) = app.get_window(label) {
+            let _ = window.show();
+        }
+    }
+
+    let held = std::mem::take(&mut *HELD_NOTIFICATIONS.lock().unwrap());
+    if held.len() > HELD_NOTIFICATIONS_SHOWN {
+        let titles: Vec<&str> = held.iter().map(|(title, _)| title.as_str()).collect();
+        crate::system::show_notification(&app, &format!("{} notifications while presenting", held.len()), &titles.join(", "));
+    } else {
+        for (title, body) in &held {
+            crate::system::show_notification(&app, title, body);
+        }
+    }
+
+    let summary = serde_json::json!({
+        "active": false,
+        "presentedSecs": presentation.started_at.elapsed().as_secs(),
+        "heldNotifications": held.len(),
+    });
+    println!("📽️  Presentation mode off after {}s", presentation.started_at.elapsed().as_secs());
+    let _ = app.emit_all("presentation://changed", &summary);
+    Ok(summary)
+}
+
+#[tauri::command]
+pub async fn get_presentation_mode() -> Result<serde_json::Value, String> {
+    Ok(status(PRESENTATION.lock().unwrap().as_ref()))
+}
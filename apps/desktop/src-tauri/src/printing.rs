@@ -0,0 +1,212 @@
+/**
+ * Printing module - Printer discovery and file printing through CUPS (Linux, macOS) and the Windows spooler
+ */
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::process::Command;
+
+use crate::watchdog::WatchdogCommand;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Printer {
+    pub name: String,
+    pub is_default: bool,
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintOptions {
+    pub copies: Option<u32>,
+    pub duplex: Option<bool>,
+    pub landscape: Option<bool>,
+    /// Page ranges in CUPS syntax, e.g. "1-3,5"
+    pub pages: Option<String>,
+}
+
+fn run(command: &mut Command) -> Result<String, String> {
+    let output = command.output_with_timeout()?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn query_printers() -> Result<Vec<Printer>, String> {
+    // lpstat output is localized; force the C locale so it can be parsed
+    let listing = run(Command::new("lpstat").args(&["-p", "-d"]).env("LC_ALL", "C"))?;
+
+    let default = listing
+        .lines()
+        .find_map(|line| line.strip_prefix("system default destination: "))
+        .map(|name| name.trim().to_string());
+
+    Ok(listing
+        .lines()
+        .filter_map(|line| line.strip_prefix("printer "))
+        .filter_map(|rest| {
+            let (name, status) = rest.split_once(' ')?;
+            Some(Printer {
+                name: name.to_string(),
+                is_default: default.as_deref() == Some(name),
+                status: Some(status.split('.').next().unwrap_or(status).trim().to_string()),
+            })
+        })
+        .collect())
+}
+
+#[cfg(target_os = "windows")]
+fn query_printers() -> Result<Vec<Printer>, String> {
+    let listing = run(Command::new("powershell").args(&[
+        "-NoProfile",
+        "-Command",
+        "Get-CimInstance Win32_Printer | Select-Object Name,Default,PrinterStatus | ConvertTo-Json -Compress",
+    ]))?;
+
+    // ConvertTo-Json emits a bare object when there is a single printer
+    let parsed: serde_json::Value = serde_json::from_str(listing.trim()).unwrap_or(serde_json::Value::Null);
+    let entries = match parsed {
+        serde_json::Value::Array(entries) => entries,
+        serde_json::Value::Null => Vec::new(),
+        entry => vec![entry],
+    };
+
+    Ok(entries
+        .iter()
+        .filter_map(|entry| {
+            Some(Printer {
+                name: entry["Name"].as_str()?.to_string(),
+                is_default: entry["Default"].as_bool().unwrap_or(false),
+                status: entry["PrinterStatus"].as_u64().map(|status| match status {
+                    3 => "idle".to_string(),
+                    4 => "printing".to_string(),
+                    7 => "offline".to_string(),
+                    other => format!("status {}", other),
+                }),
+            })
+        })
+        .collect())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn submit_job(path: &Path, printer: &str, options: &PrintOptions) -> Result<Option<String>, String> {
+    let mut command = Command::new("lp");
+    command.env("LC_ALL", "C").args(&["-d", printer]);
+    if let Some(copies) = options.copies {
+        command.args(&["-n", &copies.to_string()]);
+    }
+    if let Some(duplex) = options.duplex {
+        command.args(&["-o", if duplex { "sides=two-sided-long-edge" } else { "sides=one-sided" }]);
+    }
+    if options.landscape == Some(true) {
+        command.args(&["-o", "landscape"]);
+    }
+    if let Some(pages) = &options.pages {
+        command.args(&["-o", &format!("page-ranges={}", pages)]);
+    }
+
+    // "--" keeps file names starting with '-' from being read as options
+    let output = run(command.arg("--").arg(path))?;
+
+    // "request id is HP_LaserJet-42 (1 file(s))"
+    Ok(output
+        .split_whitespace()
+        .skip_while(|word| *word != "is")
+        .nth(1)
+        .map(|id| id.to_string()))
+}
+
+#[cfg(target_os = "windows")]
+fn submit_job(path: &Path, printer: &str, options: &PrintOptions) -> Result<Option<String>, String> {
+    if options.duplex.is_some() || options.landscape.is_some() || options.pages.is_some() {
+        println!("⚠️  Duplex, orientation and page ranges follow the printer defaults on Windows");
+    }
+
+    let is_text = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| matches!(ext.to_lowercase().as_str(), "txt" | "log" | "csv" | "md"))
+        .unwrap_or(false);
+
+    // Text goes straight to the spooler; other files print through their associated application
+    // The path and printer name reach the script as data, never as script text
+    let script = if is_text {
+        "Get-Content -LiteralPath $env:JARVISX_PRINT_FILE | Out-Printer -Name $env:JARVISX_PRINTER"
+    } else {
+        if printer.contains('"') {
+            return Err(format!("❌ Invalid printer name: {}", printer));
+        }
+        "Start-Process -FilePath $env:JARVISX_PRINT_FILE -Verb PrintTo -ArgumentList ('\"' + $env:JARVISX_PRINTER + '\"') -Wait"
+    };
+
+    let path = path.to_string_lossy().to_string();
+    for _ in 0..options.copies.unwrap_or(1).max(1) {
+        run(&mut crate::commands::powershell(script, &[("JARVISX_PRINT_FILE", &path), ("JARVISX_PRINTER", printer)]))?;
+    }
+
+    Ok(None)
+}
+
+#[tauri::command]
+pub async fn list_printers() -> Result<Vec<Printer>, String> {
+    tauri::async_runtime::spawn_blocking(query_printers)
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+pub async fn print_file(
+    path: String,
+    printer: Option<String>,
+    options: Option<PrintOptions>,
+) -> Result<serde_json::Value, String> {
+    let file_path = Path::new(&path);
+    if !file_path.is_file() {
+        return Err(format!("❌ File not found: {}", path));
+    }
+
+    let options = options.unwrap_or_default();
+    if options.copies == Some(0) {
+        return Err("Copies must be at least 1".to_string());
+    }
+    if let Some(pages) = &options.pages {
+        if pages.is_empty() || !pages.chars().all(|c| c.is_ascii_digit() || c == '-' || c == ',') {
+            return Err(format!("Invalid page range: {}", pages));
+        }
+    }
+
+    let printers = tauri::async_runtime::spawn_blocking(query_printers)
+        .await
+        .map_err(|e| e.to_string())??;
+
+    let printer = match printer {
+        Some(name) => printers
+            .into_iter()
+            .find(|candidate| candidate.name.eq_ignore_ascii_case(&name))
+            .map(|candidate| candidate.name)
+            .ok_or_else(|| format!("❌ Printer not found: {}", name))?,
+        None => printers
+            .into_iter()
+            .find(|candidate| candidate.is_default)
+            .map(|candidate| candidate.name)
+            .ok_or("No default printer configured")?,
+    };
+
+    println!("🖨️  Printing {} on {}", path, printer);
+
+    let job_path = file_path.to_path_buf();
+    let job_printer = printer.clone();
+    let job_id = tauri::async_runtime::spawn_blocking(move || submit_job(&job_path, &job_printer, &options))
+        .await
+        .map_err(|e| e.to_string())??;
+
+    Ok(serde_json::json!({
+        "printer": printer,
+        "jobId": job_id,
+    }))
+}
@@ -0,0 +1,393 @@
+/**
+ * Process sandbox module - Restricted privileges and resource limits for processes spawned by plugins and workflows
+ *
+ * Each capability token carries a sandbox level; plans use the workflow level from the policy. Enforcement is
+ * per platform: a job object on Windows, sandbox-exec on macOS, user/network namespaces plus a seccomp filter on
+ * Linux, and rlimits on both unix platforms. A level that can't be enforced fails the spawn rather than running
+ * the process unrestricted.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::process::{Command, Output, Stdio};
+use std::sync::RwLock;
+use std::time::Duration;
+
+pub use jarvisx_core::capabilities::SandboxLevel;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessSandboxPolicy {
+    /// Level for commands run by plans
+    pub workflows: SandboxLevel,
+    /// Level for capabilities granted without an explicit one
+    pub plugins: SandboxLevel,
+}
+
+impl Default for ProcessSandboxPolicy {
+    fn default() -> Self {
+        DEFAULT_POLICY
+    }
+}
+
+const DEFAULT_POLICY: ProcessSandboxPolicy = ProcessSandboxPolicy {
+    workflows: SandboxLevel::Standard,
+    plugins: SandboxLevel::Standard,
+};
+
+static POLICY: RwLock<ProcessSandboxPolicy> = RwLock::new(DEFAULT_POLICY);
+
+pub fn current_policy() -> ProcessSandboxPolicy {
+    *POLICY.read().unwrap()
+}
+
+struct Limits {
+    cpu_secs: u64,
+    memory_bytes: u64,
+    file_bytes: u64,
+    /// Processes in the job, including the first (Windows only; unix process limits are per user)
+    #[cfg_attr(unix, allow(dead_code))]
+    processes: u32,
+}
+
+fn limits(level: SandboxLevel) -> Option<Limits> {
+    match level {
+        SandboxLevel::Unrestricted => None,
+        SandboxLevel::Standard => Some(Limits { cpu_secs: 120, memory_bytes: 2 << 30, file_bytes: 1 << 30, processes: 32 }),
+        SandboxLevel::Strict => Some(Limits { cpu_secs: 20, memory_bytes: 512 << 20, file_bytes: 64 << 20, processes: 1 }),
+    }
+}
+
+#[cfg(unix)]
+fn apply_rlimits(limits: &Limits, cap_memory: bool) -> std::io::Result<()> {
+    let set = |resource, value: u64| {
+        let limit = libc::rlimit { rlim_cur: value as libc::rlim_t, rlim_max: value as libc::rlim_t };
+        if unsafe { libc::setrlimit(resource, &limit) } == 0 {
+            Ok(())
+        } else {
+            Err(std::io::Error::last_os_error())
+        }
+    };
+
+    set(libc::RLIMIT_CPU, limits.cpu_secs)?;
+    set(libc::RLIMIT_FSIZE, limits.file_bytes)?;
+    set(libc::RLIMIT_CORE, 0)?;
+    // Address-space caps break runtimes that reserve large virtual ranges up front, so only Strict uses one
+    if cap_memory {
+        set(libc::RLIMIT_AS, limits.memory_bytes)?;
+    }
+    Ok(())
+}
+
+/// Seccomp filter returning EPERM for syscalls no sandboxed helper needs (tracing, mounts, modules, reboot...);
+/// `strict` also refuses new processes (fork, vfork, and clone without CLONE_THREAD) while still allowing threads.
+#[cfg(target_os = "linux")]
+fn seccomp_filter(strict: bool) -> Vec<libc::sock_filter> {
+    const BPF_LD_W_ABS: u16 = 0x20;
+    const BPF_JMP_JEQ_K: u16 = 0x15;
+    #[cfg(target_arch = "x86_64")]
+    const BPF_JMP_JGE_K: u16 = 0x35;
+    const BPF_JMP_JSET_K: u16 = 0x45;
+    const BPF_RET_K: u16 = 0x06;
+    const RET_ALLOW: u32 = 0x7fff_0000;
+    const RET_ERRNO: u32 = 0x0005_0000;
+    const RET_KILL_PROCESS: u32 = 0x8000_0000;
+    #[cfg(target_arch = "x86_64")]
+    const AUDIT_ARCH: u32 = 0xc000_003e;
+    #[cfg(target_arch = "aarch64")]
+    const AUDIT_ARCH: u32 = 0xc000_00b7;
+    /// x32 syscalls share the x86_64 audit arch and are told apart by this bit in the number
+    #[cfg(target_arch = "x86_64")]
+    const X32_SYSCALL_BIT: u32 = 0x4000_0000;
+
+    let mut denied = vec![
+        libc::SYS_ptrace,
+        libc::SYS_process_vm_writev,
+        libc::SYS_mount,
+        libc::SYS_umount2,
+        libc::SYS_pivot_root,
+        libc::SYS_swapon,
+        libc::SYS_swapoff,
+        libc::SYS_reboot,
+        libc::SYS_kexec_load,
+        libc::SYS_init_module,
+        libc::SYS_finit_module,
+        libc::SYS_delete_module,
+        libc::SYS_bpf,
+        libc::SYS_perf_event_open,
+        libc::SYS_keyctl,
+    ];
+    if strict {
+        #[cfg(target_arch = "x86_64")]
+        denied.extend([libc::SYS_fork, libc::SYS_vfork]);
+        // libc falls back to clone, which is checked below
+        denied.push(libc::SYS_clone3);
+    }
+    let instruction = |code, jt, jf, k| libc::sock_filter { code, jt, jf, k };
+    let errno = RET_ERRNO | libc::EPERM as u32;
+
+    // seccomp_data: nr at offset 0, arch at offset 4, args from offset 16
+    let mut program = vec![
+        instruction(BPF_LD_W_ABS, 0, 0, 4),
+        instruction(BPF_JMP_JEQ_K, 1, 0, AUDIT_ARCH),
+        instruction(BPF_RET_K, 0, 0, RET_KILL_PROCESS),
+        instruction(BPF_LD_W_ABS, 0, 0, 0),
+    ];
+    #[cfg(target_arch = "x86_64")]
+    program.extend([instruction(BPF_JMP_JGE_K, 0, 1, X32_SYSCALL_BIT), instruction(BPF_RET_K, 0, 0, RET_KILL_PROCESS)]);
+    for syscall in denied {
+        program.push(instruction(BPF_JMP_JEQ_K, 0, 1, syscall as u32));
+        program.push(instruction(BPF_RET_K, 0, 0, errno));
+    }
+    if strict {
+        // clone(flags, ...): threads carry CLONE_THREAD, new processes don't
+        program.extend([
+            instruction(BPF_JMP_JEQ_K, 0, 4, libc::SYS_clone as u32),
+            instruction(BPF_LD_W_ABS, 0, 0, 16),
+            instruction(BPF_JMP_JSET_K, 0, 1, libc::CLONE_THREAD as u32),
+            instruction(BPF_RET_K, 0, 0, RET_ALLOW),
+            instruction(BPF_RET_K, 0, 0, errno),
+        ]);
+    }
+    program.push(instruction(BPF_RET_K, 0, 0, RET_ALLOW));
+    program
+}
+
+#[cfg(target_os = "linux")]
+fn sandboxed_command(program: &str, args: &[String], level: SandboxLevel) -> Result<Command, String> {
+    use std::os::unix::process::CommandExt;
+
+    let mut command = Command::new(program);
+    command.args(args);
+    let limits = match limits(level) {
+        Some(limits) => limits,
+        None => return Ok(command),
+    };
+
+    let strict = level == SandboxLevel::Strict;
+    let filter = seccomp_filter(strict);
+    // Runs in the child between fork and exec: only async-signal-safe syscalls, nothing allocated
+    unsafe {
+        command.pre_exec(move || {
+            apply_rlimits(&limits, strict)?;
+            if strict && libc::unshare(libc::CLONE_NEWUSER | libc::CLONE_NEWNET) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            if libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            let program = libc::sock_fprog { len: filter.len() as u16, filter: filter.as_ptr() as *mut libc::sock_filter };
+            if libc::prctl(libc::PR_SET_SECCOMP, libc::SECCOMP_MODE_FILTER, &program as *const libc::sock_fprog) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+    Ok(command)
+}
+
+#[cfg(target_os = "macos")]
+fn sandbox_profile(level: SandboxLevel) -> String {
+    let home = std::env::var("HOME").unwrap_or_default();
+    let mut profile = String::from("(version 1)\n(allow default)\n");
+    // Writes only to temp folders and the user's home, never to system locations or the user's login items
+    profile.push_str("(deny file-write* (require-not (require-any (subpath \"/private/tmp\") (subpath \"/private/var/folders\") (subpath \"/dev\")");
+    profile.push_str(&format!(" (subpath \"{}\"))))\n", home.replace('"', "")));
+    profile.push_str(&format!("(deny file-write* (subpath \"{}/Library/LaunchAgents\"))\n", home.replace('"', "")));
+    profile.push_str("(deny process-exec (literal \"/usr/bin/sudo\") (literal \"/usr/bin/osascript\"))\n");
+    if level == SandboxLevel::Strict {
+        profile.push_str("(deny network*)\n(deny process-fork)\n");
+    }
+    profile
+}
+
+#[cfg(target_os = "macos")]
+fn sandboxed_command(program: &str, args: &[String], level: SandboxLevel) -> Result<Command, String> {
+    use std::os::unix::process::CommandExt;
+
+    let limits = match limits(level) {
+        Some(limits) => limits,
+        None => {
+            let mut command = Command::new(program);
+            command.args(args);
+            return Ok(command);
+        }
+    };
+
+    let mut command = Command::new("/usr/bin/sandbox-exec");
+    command.arg("-p").arg(sandbox_profile(level)).arg(program).args(args);
+    let strict = level == SandboxLevel::Strict;
+    unsafe {
+        command.pre_exec(move || apply_rlimits(&limits, strict));
+    }
+    Ok(command)
+}
+
+#[cfg(target_os = "windows")]
+fn sandboxed_command(program: &str, args: &[String], level: SandboxLevel) -> Result<Command, String> {
+    use std::os::windows::process::CommandExt;
+    const CREATE_SUSPENDED: u32 = 0x0000_0004;
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    let mut command = Command::new(program);
+    command.args(args);
+    // Limits come from a job object, so the process must not run a single instruction before it joins one
+    match level {
+        SandboxLevel::Unrestricted => {}
+        SandboxLevel::Standard => {
+            command.creation_flags(CREATE_SUSPENDED);
+        }
+        SandboxLevel::Strict => {
+            command.creation_flags(CREATE_SUSPENDED | CREATE_NO_WINDOW);
+        }
+    }
+    Ok(command)
+}
+
+/// Job object owning a sandboxed process; closing it kills whatever is left in the job.
+#[cfg(target_os = "windows")]
+struct Job(windows::Win32::Foundation::HANDLE);
+
+#[cfg(target_os = "windows")]
+impl Drop for Job {
+    fn drop(&mut self) {
+        let _ = unsafe { windows::Win32::Foundation::CloseHandle(self.0) };
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn confine(child: &std::process::Child, level: SandboxLevel) -> Result<Option<Job>, String> {
+    use std::os::windows::io::AsRawHandle;
+    use windows::core::PCWSTR;
+    use windows::Win32::Foundation::HANDLE;
+    use windows::Win32::System::JobObjects::*;
+
+    let limits = match limits(level) {
+        Some(limits) => limits,
+        None => return Ok(None),
+    };
+
+    unsafe {
+        let job = Job(CreateJobObjectW(None, PCWSTR::null()).map_err(|e| e.to_string())?);
+
+        let mut info = JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+        info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE
+            | JOB_OBJECT_LIMIT_DIE_ON_UNHANDLED_EXCEPTION
+            | JOB_OBJECT_LIMIT_ACTIVE_PROCESS
+            | JOB_OBJECT_LIMIT_PROCESS_TIME
+            | JOB_OBJECT_LIMIT_PROCESS_MEMORY;
+        info.BasicLimitInformation.ActiveProcessLimit = limits.processes;
+        // 100ns units
+        info.BasicLimitInformation.PerProcessUserTimeLimit = limits.cpu_secs as i64 * 10_000_000;
+        info.ProcessMemoryLimit = limits.memory_bytes as usize;
+        SetInformationJobObject(
+            job.0,
+            JobObjectExtendedLimitInformation,
+            &info as *const _ as *const std::ffi::c_void,
+            std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+        )
+        .map_err(|e| e.to_string())?;
+
+        // Keep sandboxed processes away from system settings; Strict also cuts off the clipboard and other apps' windows
+        let mut restrictions = JOB_OBJECT_UILIMIT_DESKTOP
+            | JOB_OBJECT_UILIMIT_DISPLAYSETTINGS
+            | JOB_OBJECT_UILIMIT_EXITWINDOWS
+            | JOB_OBJECT_UILIMIT_SYSTEMPARAMETERS;
+        if level == SandboxLevel::Strict {
+            restrictions |= JOB_OBJECT_UILIMIT_GLOBALATOMS
+                | JOB_OBJECT_UILIMIT_HANDLES
+                | JOB_OBJECT_UILIMIT_READCLIPBOARD
+                | JOB_OBJECT_UILIMIT_WRITECLIPBOARD;
+        }
+        let ui = JOBOBJECT_BASIC_UI_RESTRICTIONS { UIRestrictionsClass: restrictions };
+        SetInformationJobObject(
+            job.0,
+            JobObjectBasicUIRestrictions,
+            &ui as *const _ as *const std::ffi::c_void,
+            std::mem::size_of::<JOBOBJECT_BASIC_UI_RESTRICTIONS>() as u32,
+        )
+        .map_err(|e| e.to_string())?;
+
+        AssignProcessToJobObject(job.0, HANDLE(child.as_raw_handle() as isize)).map_err(|e| e.to_string())?;
+        Ok(Some(job))
+    }
+}
+
+/// Resumes the threads of a process started with CREATE_SUSPENDED (just its main thread at that point).
+#[cfg(target_os = "windows")]
+fn resume(child: &std::process::Child) -> Result<(), String> {
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Diagnostics::ToolHelp::{CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32};
+    use windows::Win32::System::Threading::{OpenThread, ResumeThread, THREAD_SUSPEND_RESUME};
+
+    unsafe {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0).map_err(|e| e.to_string())?;
+        let mut entry = THREADENTRY32 { dwSize: std::mem::size_of::<THREADENTRY32>() as u32, ..Default::default() };
+        let mut resumed = 0;
+        let mut next = Thread32First(snapshot, &mut entry);
+        while next.is_ok() {
+            if entry.th32OwnerProcessID == child.id() {
+                if let Ok(thread) = OpenThread(THREAD_SUSPEND_RESUME, false, entry.th32ThreadID) {
+                    if ResumeThread(thread) != u32::MAX {
+                        resumed += 1;
+                    }
+                    let _ = CloseHandle(thread);
+                }
+            }
+            next = Thread32Next(snapshot, &mut entry);
+        }
+        let _ = CloseHandle(snapshot);
+
+        if resumed == 0 {
+            return Err("the main thread could not be resumed".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Runs `program` under `level`, killing it after `timeout` like the watchdog does for helpers.
+pub fn output_sandboxed(program: &str, args: &[String], level: SandboxLevel, timeout: Duration) -> Result<Output, String> {
+    let mut command = sandboxed_command(program, args, level)?;
+    let child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("❌ Could not start {} in the {:?} sandbox: {}", program, level, e))?;
+
+    #[cfg(target_os = "windows")]
+    {
+        // The child is created suspended and only resumed once it is in the job; it is killed if either step fails
+        let mut child = child;
+        let confined = confine(&child, level).and_then(|job| {
+            if job.is_some() {
+                resume(&child)?;
+            }
+            Ok(job)
+        });
+        let job = match confined {
+            Ok(job) => job,
+            Err(e) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("❌ Could not sandbox {}: {}", program, e));
+            }
+        };
+        let output = crate::watchdog::collect_output(child, program, timeout);
+        drop(job);
+        output
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    crate::watchdog::collect_output(child, program, timeout)
+}
+
+#[tauri::command]
+pub async fn set_process_sandbox_policy(policy: ProcessSandboxPolicy) -> Result<ProcessSandboxPolicy, String> {
+    *POLICY.write().unwrap() = policy;
+    Ok(policy)
+}
+
+#[tauri::command]
+pub async fn get_process_sandbox_policy() -> Result<ProcessSandboxPolicy, String> {
+    Ok(current_policy())
+}
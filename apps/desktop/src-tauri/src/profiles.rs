@@ -0,0 +1,246 @@
+/**
+ * Profiles module - Multi-user profiles with isolated settings, memory and secrets
+ */
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, State};
+
+pub const DEFAULT_PROFILE_ID: &str = "default";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    pub id: String,
+    pub name: String,
+    pub created_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfilesState {
+    pub active_profile: String,
+    pub profiles: Vec<Profile>,
+}
+
+impl Default for ProfilesState {
+    fn default() -> Self {
+        ProfilesState {
+            active_profile: DEFAULT_PROFILE_ID.to_string(),
+            profiles: vec![Profile {
+                id: DEFAULT_PROFILE_ID.to_string(),
+                name: "Default".to_string(),
+                created_at: 0,
+            }],
+        }
+    }
+}
+
+fn profiles_root(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path_resolver()
+        .app_data_dir()
+        .map(|dir| dir.join("profiles"))
+        .ok_or_else(|| "App data directory not available".to_string())
+}
+
+fn profile_dir_for(app: &AppHandle, profile_id: &str) -> Result<PathBuf, String> {
+    Ok(profiles_root(app)?.join(profile_id))
+}
+
+/// Directory holding the active profile's settings, memory DB and voice enrollment.
+pub fn active_profile_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let active = app.state::<Mutex<ProfilesState>>().lock().unwrap().active_profile.clone();
+    let dir = profile_dir_for(app, &active)?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+/// Keychain namespace for the active profile's secrets.
+pub fn active_secrets_namespace(app: &AppHandle) -> String {
+    let active = app.state::<Mutex<ProfilesState>>().lock().unwrap().active_profile.clone();
+    format!("profile:{}", active)
+}
+
+fn save_registry(app: &AppHandle, profiles_state: &ProfilesState) -> Result<(), String> {
+    let root = profiles_root(app)?;
+    std::fs::create_dir_all(&root).map_err(|e| e.to_string())?;
+    let json = serde_json::to_string_pretty(profiles_state).map_err(|e| e.to_string())?;
+    std::fs::write(root.join("profiles.json"), json).map_err(|e| e.to_string())
+}
+
+/// Loads the profile registry from disk into managed state at startup.
+pub fn load_profiles(app: &AppHandle) {
+    let loaded = profiles_root(app)
+        .ok()
+        .and_then(|root| std::fs::read_to_string(root.join("profiles.json")).ok())
+        .and_then(|json| serde_json::from_str::<ProfilesState>(&json).ok());
+
+    if let Some(loaded) = loaded {
+        println!("👥 Loaded {} profiles, active: {}", loaded.profiles.len(), loaded.active_profile);
+        *app.state::<Mutex<ProfilesState>>().lock().unwrap() = loaded;
+    }
+}
+
+fn profile_info(app: &AppHandle, profile: &Profile, active: &str) -> serde_json::Value {
+    let dir = profile_dir_for(app, &profile.id).unwrap_or_default();
+
+    serde_json::json!({
+        "id": profile.id,
+        "name": profile.name,
+        "createdAt": profile.created_at,
+        "isActive": profile.id == active,
+        "settingsPath": dir.join("settings.json"),
+        "memoryDbPath": dir.join("memory.db"),
+        "voiceEnrollmentDir": dir.join("voice"),
+    })
+}
+
+fn slugify(name: &str) -> String {
+    let slug: String = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    slug.split('-').filter(|part| !part.is_empty()).collect::<Vec<_>>().join("-")
+}
+
+#[tauri::command]
+pub async fn list_profiles(app: AppHandle, state: State<'_, Mutex<ProfilesState>>) -> Result<Vec<serde_json::Value>, String> {
+    let profiles_state = state.lock().unwrap();
+
+    Ok(profiles_state
+        .profiles
+        .iter()
+        .map(|profile| profile_info(&app, profile, &profiles_state.active_profile))
+        .collect())
+}
+
+#[tauri::command]
+pub async fn get_active_profile(app: AppHandle, state: State<'_, Mutex<ProfilesState>>) -> Result<serde_json::Value, String> {
+    let profiles_state = state.lock().unwrap();
+
+    profiles_state
+        .profiles
+        .iter()
+        .find(|profile| profile.id == profiles_state.active_profile)
+        .map(|profile| profile_info(&app, profile, &profiles_state.active_profile))
+        .ok_or_else(|| "Active profile missing".to_string())
+}
+
+#[tauri::command]
+pub async fn create_profile(
+    app: AppHandle,
+    state: State<'_, Mutex<ProfilesState>>,
+    name: String,
+) -> Result<serde_json::Value, String> {
+    let id = slugify(&name);
+    if id.is_empty() {
+        return Err("Profile name must contain letters or digits".to_string());
+    }
+
+    let mut profiles_state = state.lock().unwrap();
+    if profiles_state.profiles.iter().any(|profile| profile.id == id) {
+        return Err(format!("❌ Profile already exists: {}", name));
+    }
+
+    let dir = profile_dir_for(&app, &id)?;
+    std::fs::create_dir_all(dir.join("voice")).map_err(|e| e.to_string())?;
+    std::fs::write(dir.join("settings.json"), "{}").map_err(|e| e.to_string())?;
+
+    let profile = Profile {
+        id,
+        name: name.trim().to_string(),
+        created_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    };
+
+    profiles_state.profiles.push(profile.clone());
+    save_registry(&app, &profiles_state)?;
+
+    println!("👥 Created profile: {}", profile.name);
+    Ok(profile_info(&app, &profile, &profiles_state.active_profile))
+}
+
+#[tauri::command]
+pub async fn switch_profile(app: AppHandle, profile_id: String) -> Result<serde_json::Value, String> {
+    let info = {
+        let state = app.state::<Mutex<ProfilesState>>();
+        let mut profiles_state = state.lock().unwrap();
+
+        let profile = profiles_state
+            .profiles
+            .iter()
+            .find(|profile| profile.id == profile_id)
+            .cloned()
+            .ok_or_else(|| format!("❌ Profile not found: {}", profile_id))?;
+
+        profiles_state.active_profile = profile.id.clone();
+        save_registry(&app, &profiles_state)?;
+        profile_info(&app, &profile, &profile.id)
+    };
+
+    // Drop the previous user's session; the refresher restores the new profile's token
+    *app.state::<Mutex<crate::auth::AuthState>>().lock().unwrap() = crate::auth::AuthState::default();
+    crate::permissions::load_capabilities(&app);
+    crate::egress::load_egress_policy(app.clone());
+    crate::calibration::load_pointer_calibration(&app);
+    crate::environment::load_environment_profile(&app);
+    crate::proxy::load_proxy_settings(&app);
+    crate::bandwidth::load_bandwidth_policy(&app);
+    crate::config_watch::watch_active_profile(&app);
+    crate::timers::load_timers(app.clone());
+    crate::snippets::load_snippets(app.clone());
+
+    println!("👥 Switched to profile: {}", profile_id);
+    let _ = app.emit_all("profile-changed", info.clone());
+    let _ = app.emit_all("auth-changed", serde_json::json!({ "authenticated": false }));
+
+    Ok(info)
+}
+
+#[tauri::command]
+pub async fn delete_profile(app: AppHandle, state: State<'_, Mutex<ProfilesState>>, profile_id: String) -> Result<(), String> {
+    let mut profiles_state = state.lock().unwrap();
+
+    if profile_id == DEFAULT_PROFILE_ID {
+        return Err("The default profile cannot be deleted".to_string());
+    }
+    if profile_id == profiles_state.active_profile {
+        return Err("Switch to another profile before deleting this one".to_string());
+    }
+
+    let before = profiles_state.profiles.len();
+    profiles_state.profiles.retain(|profile| profile.id != profile_id);
+    if profiles_state.profiles.len() == before {
+        return Err(format!("❌ Profile not found: {}", profile_id));
+    }
+
+    let _ = crate::secrets::delete_secret(&format!("profile:{}", profile_id), crate::auth::REFRESH_TOKEN_KEY);
+    let dir = profile_dir_for(&app, &profile_id)?;
+    if dir.exists() {
+        std::fs::remove_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+
+    save_registry(&app, &profiles_state)
+}
+
+#[tauri::command]
+pub async fn get_profile_settings(app: AppHandle) -> Result<serde_json::Value, String> {
+    let path = active_profile_dir(&app)?.join("settings.json");
+
+    match std::fs::read_to_string(path) {
+        Ok(json) => serde_json::from_str(&json).map_err(|e| e.to_string()),
+        Err(_) => Ok(serde_json::json!({})),
+    }
+}
+
+#[tauri::command]
+pub async fn set_profile_settings(app: AppHandle, settings: serde_json::Value) -> Result<(), String> {
+    let path = active_profile_dir(&app)?.join("settings.json");
+    let json = serde_json::to_string_pretty(&settings).map_err(|e| e.to_string())?;
+    std::fs::write(path, json).map_err(|e| e.to_string())
+}
@@ -0,0 +1,369 @@
+/**
+ * Proxy module - OS proxy settings (or a manual override) applied to every outbound request from the native layer
+ *
+ * "system" mode reads the Windows Internet Settings, macOS network configuration or GNOME proxy settings, falling back
+ * to the HTTP(S)_PROXY / NO_PROXY environment variables. PAC scripts aren't evaluated: they are reported, and requests
+ * then go direct. Loopback hosts never use a proxy. All Rust-side HTTP clients come from `client`.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+/// How long detected OS settings are reused before being read again
+const SYSTEM_REFRESH: Duration = Duration::from_secs(60);
+const LOOPBACK: [&str; 3] = ["localhost", "127.0.0.1", "::1"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProxyMode {
+    System,
+    Manual,
+    /// Never use a proxy, whatever the OS says
+    Direct,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxySettings {
+    pub mode: ProxyMode,
+    /// Manual mode: "http://host:port", "socks5://host:port", optionally with "user:password@"
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Manual mode: hosts or domains (".corp.example") that bypass the proxy
+    #[serde(default)]
+    pub no_proxy: Vec<String>,
+}
+
+impl Default for ProxySettings {
+    fn default() -> Self {
+        DEFAULT_SETTINGS
+    }
+}
+
+const DEFAULT_SETTINGS: ProxySettings = ProxySettings { mode: ProxyMode::System, url: None, no_proxy: Vec::new() };
+
+/// The proxy outbound requests actually use.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyConfig {
+    /// "manual", "system", "environment" or "none"
+    pub source: String,
+    pub http: Option<String>,
+    pub https: Option<String>,
+    pub no_proxy: Vec<String>,
+    /// Set when the OS uses a PAC script, which isn't evaluated
+    pub pac_url: Option<String>,
+}
+
+struct CachedClient {
+    config: ProxyConfig,
+    client: reqwest::Client,
+    detected_at: Instant,
+}
+
+static SETTINGS: RwLock<ProxySettings> = RwLock::new(DEFAULT_SETTINGS);
+static CLIENT: Mutex<Option<CachedClient>> = Mutex::new(None);
+
+fn settings_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("proxy.json"))
+}
+
+/// Loads the active profile's proxy settings; called at startup, on profile switches and when proxy.json changes.
+pub fn load_proxy_settings(app: &AppHandle) {
+    *SETTINGS.write().unwrap() = settings_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    *CLIENT.lock().unwrap() = None;
+}
+
+/// Adds a scheme to bare "host:port" values from OS settings.
+fn with_scheme(value: &str, scheme: &str) -> String {
+    if value.contains("://") {
+        value.to_string()
+    } else {
+        format!("{}://{}", scheme, value)
+    }
+}
+
+fn split_list(value: &str, separators: &[char]) -> Vec<String> {
+    value
+        .split(|c| separators.contains(&c))
+        .map(|entry| entry.trim().trim_matches('\'').to_string())
+        .filter(|entry| !entry.is_empty() && entry != "<local>")
+        .collect()
+}
+
+fn from_environment() -> Option<ProxyConfig> {
+    let var = |names: &[&str]| names.iter().find_map(|name| std::env::var(name).ok().filter(|value| !value.trim().is_empty()));
+    let all = var(&["ALL_PROXY", "all_proxy"]);
+    let http = var(&["HTTP_PROXY", "http_proxy"]).or_else(|| all.clone());
+    let https = var(&["HTTPS_PROXY", "https_proxy"]).or(all);
+    if http.is_none() && https.is_none() {
+        return None;
+    }
+
+    Some(ProxyConfig {
+        source: "environment".to_string(),
+        http: http.map(|value| with_scheme(&value, "http")),
+        https: https.map(|value| with_scheme(&value, "http")),
+        no_proxy: var(&["NO_PROXY", "no_proxy"]).map(|value| split_list(&value, &[','])).unwrap_or_default(),
+        pac_url: None,
+    })
+}
+
+/// HKCU Internet Settings: ProxyServer is "host:port" or "http=host:port;https=host:port;socks=host:port".
+#[cfg(target_os = "windows")]
+fn from_os() -> Option<ProxyConfig> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let output = Command::new("reg")
+        .args(&["query", r"HKCU\Software\Microsoft\Windows\CurrentVersion\Internet Settings"])
+        .output_with_timeout()
+        .ok()?;
+    let text = String::from_utf8_lossy(&output.stdout).to_string();
+    // "    ProxyServer    REG_SZ    proxy.corp:8080"
+    let value = |name: &str| {
+        text.lines().find_map(|line| {
+            let mut parts = line.split_whitespace();
+            if parts.next()? != name {
+                return None;
+            }
+            parts.next()?;
+            Some(parts.collect::<Vec<_>>().join(" "))
+        })
+    };
+
+    let pac_url = value("AutoConfigURL");
+    if value("ProxyEnable").as_deref() != Some("0x1") {
+        return pac_url.map(|pac_url| ProxyConfig { source: "system".to_string(), pac_url: Some(pac_url), ..Default::default() });
+    }
+
+    let server = value("ProxyServer")?;
+    let (http, https) = if server.contains('=') {
+        let entry = |scheme: &str| {
+            server.split(';').find_map(|part| part.trim().strip_prefix(scheme)?.strip_prefix('=').map(str::to_string))
+        };
+        let socks = entry("socks").map(|value| with_scheme(&value, "socks5"));
+        (
+            entry("http").map(|value| with_scheme(&value, "http")).or_else(|| socks.clone()),
+            entry("https").map(|value| with_scheme(&value, "http")).or(socks),
+        )
+    } else {
+        (Some(with_scheme(&server, "http")), Some(with_scheme(&server, "http")))
+    };
+
+    Some(ProxyConfig {
+        source: "system".to_string(),
+        http,
+        https,
+        no_proxy: value("ProxyOverride").map(|value| split_list(&value, &[';'])).unwrap_or_default(),
+        pac_url,
+    })
+}
+
+/// `scutil --proxy` prints the active network service's proxy dictionary.
+#[cfg(target_os = "macos")]
+fn from_os() -> Option<ProxyConfig> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let output = Command::new("scutil").arg("--proxy").output_with_timeout().ok()?;
+    let text = String::from_utf8_lossy(&output.stdout).to_string();
+    let value = |key: &str| {
+        text.lines().find_map(|line| {
+            let (name, value) = line.trim().split_once(" : ")?;
+            (name == key).then(|| value.trim().to_string())
+        })
+    };
+    let proxy = |prefix: &str, scheme: &str| {
+        if value(&format!("{}Enable", prefix)).as_deref() != Some("1") {
+            return None;
+        }
+        let host = value(&format!("{}Proxy", prefix))?;
+        let port = value(&format!("{}Port", prefix)).map(|port| format!(":{}", port)).unwrap_or_default();
+        Some(format!("{}://{}{}", scheme, host, port))
+    };
+
+    let socks = proxy("SOCKS", "socks5");
+    let http = proxy("HTTP", "http").or_else(|| socks.clone());
+    let https = proxy("HTTPS", "http").or(socks);
+    let pac_url = value("ProxyAutoConfigURLString").filter(|_| value("ProxyAutoConfigEnable").as_deref() == Some("1"));
+    if http.is_none() && https.is_none() && pac_url.is_none() {
+        return None;
+    }
+
+    // "ExceptionsList : <array> {" followed by "0 : *.local" lines
+    let no_proxy = text
+        .split("ExceptionsList : <array> {")
+        .nth(1)
+        .and_then(|rest| rest.split('}').next())
+        .map(|block| block.lines().filter_map(|line| line.split_once(" : ").map(|(_, host)| host.trim().to_string())).collect())
+        .unwrap_or_default();
+
+    Some(ProxyConfig { source: "system".to_string(), http, https, no_proxy, pac_url })
+}
+
+/// GNOME proxy settings, which most desktops (and Flatpak portals) mirror.
+#[cfg(target_os = "linux")]
+fn from_os() -> Option<ProxyConfig> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    let get = |schema: &str, key: &str| {
+        let output = Command::new("gsettings").args(&["get", schema, key]).output_with_timeout().ok()?;
+        let value = String::from_utf8_lossy(&output.stdout).trim().trim_matches('\'').to_string();
+        (output.status.success() && !value.is_empty()).then(|| value)
+    };
+
+    match get("org.gnome.system.proxy", "mode")?.as_str() {
+        "manual" => {}
+        "auto" => {
+            let pac_url = get("org.gnome.system.proxy", "autoconfig-url")?;
+            return Some(ProxyConfig { source: "system".to_string(), pac_url: Some(pac_url), ..Default::default() });
+        }
+        _ => return None,
+    }
+
+    let proxy = |kind: &str, scheme: &str| {
+        let schema = format!("org.gnome.system.proxy.{}", kind);
+        let host = get(&schema, "host")?;
+        let port = get(&schema, "port").filter(|port| port != "0").map(|port| format!(":{}", port)).unwrap_or_default();
+        Some(format!("{}://{}{}", scheme, host, port))
+    };
+
+    let socks = proxy("socks", "socks5");
+    let http = proxy("http", "http").or_else(|| socks.clone());
+    let https = proxy("https", "http").or(socks);
+    if http.is_none() && https.is_none() {
+        return None;
+    }
+
+    // "['localhost', '127.0.0.0/8', '::1']"
+    let no_proxy = get("org.gnome.system.proxy", "ignore-hosts")
+        .map(|list| split_list(list.trim_start_matches('[').trim_end_matches(']'), &[',']))
+        .unwrap_or_default();
+
+    Some(ProxyConfig { source: "system".to_string(), http, https, no_proxy, pac_url: None })
+}
+
+fn resolve_config(settings: &ProxySettings) -> ProxyConfig {
+    let direct = ProxyConfig { source: "none".to_string(), ..Default::default() };
+    match settings.mode {
+        ProxyMode::Direct => direct,
+        ProxyMode::Manual => ProxyConfig {
+            source: "manual".to_string(),
+            http: settings.url.clone(),
+            https: settings.url.clone(),
+            no_proxy: settings.no_proxy.clone(),
+            pac_url: None,
+        },
+        ProxyMode::System => from_os().or_else(from_environment).unwrap_or(direct),
+    }
+}
+
+fn with_proxy(mut builder: reqwest::ClientBuilder, config: &ProxyConfig) -> Result<reqwest::ClientBuilder, String> {
+    // Environment variables are already folded into `config`, so reqwest mustn't apply them a second time
+    builder = builder.no_proxy();
+
+    let no_proxy: Vec<String> = LOOPBACK.iter().map(|host| host.to_string()).chain(config.no_proxy.iter().cloned()).collect();
+    let bypass = || reqwest::NoProxy::from_string(&no_proxy.join(","));
+
+    if let Some(http) = &config.http {
+        builder = builder.proxy(reqwest::Proxy::http(http).map_err(|e| format!("❌ Invalid proxy URL {}: {}", http, e))?.no_proxy(bypass()));
+    }
+    if let Some(https) = &config.https {
+        builder = builder.proxy(reqwest::Proxy::https(https).map_err(|e| format!("❌ Invalid proxy URL {}: {}", https, e))?.no_proxy(bypass()));
+    }
+    Ok(builder)
+}
+
+fn build_client(config: &ProxyConfig) -> Result<reqwest::Client, String> {
+    with_proxy(reqwest::Client::builder(), config)?.build().map_err(|e| e.to_string())
+}
+
+/// Effective proxy configuration, re-reading OS settings at most once a minute.
+fn cached() -> (ProxyConfig, reqwest::Client) {
+    let mut cache = CLIENT.lock().unwrap();
+    if let Some(cached) = cache.as_ref().filter(|cached| cached.detected_at.elapsed() < SYSTEM_REFRESH) {
+        return (cached.config.clone(), cached.client.clone());
+    }
+
+    let config = resolve_config(&SETTINGS.read().unwrap());
+    // Keep the existing client (and its connection pool) when nothing changed
+    let client = match cache.take() {
+        Some(previous) if previous.config == config => previous.client,
+        _ => {
+            if let Some(pac_url) = &config.pac_url {
+                println!("⚠️  Proxy auto-config ({}) isn't supported; connecting directly", pac_url);
+            }
+            match build_client(&config) {
+                Ok(client) => {
+                    if let Some(proxy) = config.https.as_ref().or(config.http.as_ref()) {
+                        println!("🌐 Using {} proxy {}", config.source, proxy);
+                    }
+                    client
+                }
+                Err(e) => {
+                    println!("⚠️  Ignoring proxy configuration: {}", e);
+                    reqwest::Client::builder().no_proxy().build().unwrap_or_default()
+                }
+            }
+        }
+    };
+
+    *cache = Some(CachedClient { config: config.clone(), client: client.clone(), detected_at: Instant::now() });
+    (config, client)
+}
+
+/// HTTP client honouring the proxy configuration; use this instead of `reqwest::Client::new()`.
+pub fn client() -> reqwest::Client {
+    cached().1
+}
+
+/// Client builder with the proxy configuration applied, for callers that need other options (redirects, timeouts).
+pub fn builder() -> reqwest::ClientBuilder {
+    let config = cached().0;
+    with_proxy(reqwest::Client::builder(), &config).unwrap_or_else(|_| reqwest::Client::builder().no_proxy())
+}
+
+/// Whether requests to `host` go through a proxy.
+pub fn proxied(host: &str) -> bool {
+    let config = cached().0;
+    let bypassed = LOOPBACK.contains(&host)
+        || config.no_proxy.iter().any(|entry| {
+            let entry = entry.trim_start_matches('*').trim_start_matches('.');
+            host == entry || host.ends_with(&format!(".{}", entry))
+        });
+    !bypassed && (config.http.is_some() || config.https.is_some())
+}
+
+#[tauri::command]
+pub async fn get_proxy_config() -> Result<ProxyConfig, String> {
+    tauri::async_runtime::spawn_blocking(|| cached().0).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_proxy_settings() -> Result<ProxySettings, String> {
+    Ok(SETTINGS.read().unwrap().clone())
+}
+
+#[tauri::command]
+pub async fn set_proxy_settings(app: AppHandle, settings: ProxySettings) -> Result<ProxyConfig, String> {
+    if settings.mode == ProxyMode::Manual {
+        let url = settings.url.as_deref().ok_or("❌ Manual proxy mode needs a URL")?;
+        reqwest::Proxy::all(url).map_err(|e| format!("❌ Invalid proxy URL {}: {}", url, e))?;
+    }
+
+    let json = serde_json::to_string_pretty(&settings).map_err(|e| e.to_string())?;
+    std::fs::write(settings_path(&app)?, json).map_err(|e| e.to_string())?;
+    println!("🌐 Proxy mode: {:?}", settings.mode);
+    *SETTINGS.write().unwrap() = settings;
+    *CLIENT.lock().unwrap() = None;
+
+    get_proxy_config().await
+}
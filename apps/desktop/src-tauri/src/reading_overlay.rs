@@ -0,0 +1,189 @@
+/**
+ * Reading overlay module - Click-through, full-screen warm tint and dim per display for late-night reading
+ *
+ * Not every platform lets apps drive its night mode (or dim below the brightness slider's minimum), so this
+ * covers each display with a transparent always-on-top window whose background carries the tint. Settings last
+ * for the session and follow displays across hot-plugs.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, Monitor, PhysicalPosition, PhysicalSize};
+
+const LABEL_PREFIX: &str = "reading-overlay-";
+/// Candle-light amber the warmth fades towards
+const WARM_TINT: (f32, f32, f32) = (255.0, 138.0, 18.0);
+/// Strongest tint and dim at 1.0, kept below opaque so the screen stays readable
+const MAX_WARMTH_ALPHA: f32 = 0.45;
+const MAX_DIM_ALPHA: f32 = 0.85;
+
+/// Hides the app page the window loads and exposes a setter for the tint colour.
+const OVERLAY_SCRIPT: &str = r#"
+window.__setReadingTint = (color) => document.documentElement.style.setProperty('background', color, 'important');
+document.addEventListener('DOMContentLoaded', () => {
+  const style = document.createElement('style');
+  style.textContent = 'body > * { display: none !important; } body { background: transparent !important; }';
+  document.head.appendChild(style);
+  window.__setReadingTint(window.__READING_TINT__);
+});
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingTint {
+    /// 0 (none) to 1 (strongest amber)
+    pub warmth: f32,
+    /// 0 (none) to 1 (darkest)
+    pub dim: f32,
+}
+
+impl ReadingTint {
+    /// The amber layer under the black dim layer, flattened into one CSS colour.
+    fn css_color(&self) -> String {
+        let warm_alpha = self.warmth * MAX_WARMTH_ALPHA;
+        let dim_alpha = self.dim * MAX_DIM_ALPHA;
+        let alpha = dim_alpha + warm_alpha * (1.0 - dim_alpha);
+        if alpha <= 0.0 {
+            return "transparent".to_string();
+        }
+
+        let channel = |tint: f32| (tint * warm_alpha * (1.0 - dim_alpha) / alpha).round() as u8;
+        format!("rgba({}, {}, {}, {:.3})", channel(WARM_TINT.0), channel(WARM_TINT.1), channel(WARM_TINT.2), alpha)
+    }
+}
+
+/// Tint per display name
+static TINTS: Mutex<BTreeMap<String, ReadingTint>> = Mutex::new(BTreeMap::new());
+
+fn monitors(app: &AppHandle) -> Result<Vec<Monitor>, String> {
+    let window = app
+        .get_window(crate::headless::MAIN_LABEL)
+        .ok_or_else(|| "❌ Main window not found".to_string())?;
+    window.available_monitors().map_err(|e| e.to_string())
+}
+
+fn label_for(display: &str) -> String {
+    let safe: String = display.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+    format!("{}{}", LABEL_PREFIX, safe)
+}
+
+fn show_overlay(app: &AppHandle, monitor: &Monitor, tint: ReadingTint) -> Result<(), String> {
+    let label = label_for(&crate::placement::display_name(monitor));
+    let color = tint.css_color();
+
+    let window = match app.get_window(&label) {
+        Some(window) => window,
+        None => {
+            let script = format!("window.__READING_TINT__ = {:?};{}", color, OVERLAY_SCRIPT);
+            tauri::WindowBuilder::new(app, &label, tauri::WindowUrl::App("index.html".into()))
+                .title("Reading overlay")
+                .decorations(false)
+                .transparent(true)
+                .always_on_top(true)
+                .skip_taskbar(true)
+                .resizable(false)
+                .focused(false)
+                .visible(false)
+                .initialization_script(&script)
+                .build()
+                .map_err(|e| e.to_string())?
+        }
+    };
+
+    window.set_position(PhysicalPosition::new(monitor.position().x, monitor.position().y)).map_err(|e| e.to_string())?;
+    window.set_size(PhysicalSize::new(monitor.size().width, monitor.size().height)).map_err(|e| e.to_string())?;
+    // Clicks and scrolling go to whatever is underneath
+    window.set_ignore_cursor_events(true).map_err(|e| e.to_string())?;
+    window
+        .eval(&format!("window.__READING_TINT__ = {0:?}; window.__setReadingTint && window.__setReadingTint({0:?})", color))
+        .map_err(|e| e.to_string())?;
+    window.show().map_err(|e| e.to_string())
+}
+
+fn close_overlay(app: &AppHandle, display: &str) {
+    if let Some(window) = app.get_window(&label_for(display)) {
+        let _ = window.close();
+    }
+}
+
+fn status(app: &AppHandle) -> Result<Vec<serde_json::Value>, String> {
+    let tints = TINTS.lock().unwrap().clone();
+    Ok(monitors(app)?
+        .iter()
+        .map(crate::placement::display_name)
+        .map(|display| serde_json::json!({ "display": display, "tint": tints.get(&display) }))
+        .collect())
+}
+
+/// Re-creates overlays after the display layout changes; called by the display watcher.
+pub fn reapply(app: &AppHandle) {
+    let tints = TINTS.lock().unwrap().clone();
+    if tints.is_empty() {
+        return;
+    }
+
+    for (label, window) in app.windows() {
+        if label.starts_with(LABEL_PREFIX) {
+            let _ = window.close();
+        }
+    }
+    for monitor in monitors(app).unwrap_or_default() {
+        if let Some(tint) = tints.get(&crate::placement::display_name(&monitor)) {
+            let _ = show_overlay(app, &monitor, *tint);
+        }
+    }
+}
+
+/// Tints `display` (name or index, as in list_displays), or every display; zero warmth and dim removes the overlay.
+#[tauri::command]
+pub async fn set_reading_overlay(app: AppHandle, warmth: f32, dim: f32, display: Option<String>) -> Result<Vec<serde_json::Value>, String> {
+    if !(0.0..=1.0).contains(&warmth) || !(0.0..=1.0).contains(&dim) {
+        return Err("❌ Warmth and dim must be between 0 and 1".to_string());
+    }
+
+    let all = monitors(&app)?;
+    let targets: Vec<Monitor> = match &display {
+        None => all,
+        Some(display) => {
+            let found = match display.parse::<usize>() {
+                Ok(index) => all.get(index).cloned(),
+                Err(_) => all.into_iter().find(|monitor| crate::placement::display_name(monitor).eq_ignore_ascii_case(display)),
+            };
+            vec![found.ok_or_else(|| format!("❌ Display not found: {}", display))?]
+        }
+    };
+
+    let tint = ReadingTint { warmth, dim };
+    for monitor in &targets {
+        let name = crate::placement::display_name(monitor);
+        if warmth == 0.0 && dim == 0.0 {
+            TINTS.lock().unwrap().remove(&name);
+            close_overlay(&app, &name);
+        } else {
+            show_overlay(&app, monitor, tint)?;
+            TINTS.lock().unwrap().insert(name, tint);
+        }
+    }
+
+    println!("🌙 Reading overlay: warmth {:.0}%, dim {:.0}% on {} display(s)", warmth * 100.0, dim * 100.0, targets.len());
+    let displays = status(&app)?;
+    let _ = app.emit_all("reading-overlay://changed", &displays);
+    Ok(displays)
+}
+
+#[tauri::command]
+pub async fn clear_reading_overlay(app: AppHandle) -> Result<(), String> {
+    let displays: Vec<String> = std::mem::take(&mut *TINTS.lock().unwrap()).into_keys().collect();
+    for display in &displays {
+        close_overlay(&app, display);
+    }
+    println!("🌙 Reading overlay off");
+    let _ = app.emit_all("reading-overlay://changed", status(&app)?);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_reading_overlay(app: AppHandle) -> Result<Vec<serde_json::Value>, String> {
+    status(&app)
+}
@@ -0,0 +1,177 @@
+/**
+ * Recents module - Recently used documents from the platform's recents (recently-used.xbel, Recent items, Spotlight)
+ */
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Recents considered before filtering; platform lists rarely go beyond this
+const MAX_CANDIDATES: usize = 200;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentDocument {
+    pub path: String,
+    pub name: String,
+    pub last_used: Option<i64>,
+    /// Applications known to have opened the document
+    pub apps: Vec<String>,
+}
+
+fn document(path: String, last_used: Option<i64>, apps: Vec<String>) -> RecentDocument {
+    let name = Path::new(&path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.clone());
+    RecentDocument { path, name, last_used, apps }
+}
+
+#[cfg(target_os = "linux")]
+fn platform_recents() -> Result<Vec<RecentDocument>, String> {
+    let home = std::env::var_os("HOME").ok_or("HOME is not set")?;
+    let xbel = std::fs::read_to_string(Path::new(&home).join(".local/share/recently-used.xbel")).map_err(|e| e.to_string())?;
+    let tree = roxmltree::Document::parse(&xbel).map_err(|e| e.to_string())?;
+
+    Ok(tree
+        .descendants()
+        .filter(|node| node.has_tag_name("bookmark"))
+        .filter_map(|bookmark| {
+            let path = bookmark.attribute("href")?.strip_prefix("file://")?;
+            let path = percent_decode(path);
+            let last_used = bookmark
+                .attribute("visited")
+                .or_else(|| bookmark.attribute("modified"))
+                .and_then(|stamp| chrono::DateTime::parse_from_rfc3339(stamp).ok())
+                .map(|stamp| stamp.timestamp());
+            let apps = bookmark
+                .descendants()
+                .filter(|node| node.tag_name().name() == "application")
+                .filter_map(|node| node.attribute("name").map(String::from))
+                .collect();
+            Some(document(path, last_used, apps))
+        })
+        .collect())
+}
+
+#[cfg(target_os = "linux")]
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|hex| std::str::from_utf8(hex).ok()).and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+#[cfg(target_os = "windows")]
+fn platform_recents() -> Result<Vec<RecentDocument>, String> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    // Recent items are shortcuts; resolve each to the document it points at
+    let script = "$shell = New-Object -ComObject WScript.Shell; \
+        Get-ChildItem \"$env:APPDATA\\Microsoft\\Windows\\Recent\\*.lnk\" | Sort-Object LastWriteTime -Descending | Select-Object -First 200 | \
+        ForEach-Object { $target = $shell.CreateShortcut($_.FullName).TargetPath; \
+            if ($target) { @{ path = $target; lastUsed = [DateTimeOffset]::new($_.LastWriteTimeUtc).ToUnixTimeSeconds() } } } | \
+        ConvertTo-Json -Compress";
+    let output = Command::new("powershell").args(&["-NoProfile", "-Command", script]).output_with_timeout()?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap_or(serde_json::Value::Null);
+    let entries = match parsed {
+        serde_json::Value::Array(entries) => entries,
+        serde_json::Value::Null => Vec::new(),
+        entry => vec![entry],
+    };
+
+    Ok(entries
+        .iter()
+        .filter_map(|entry| Some(document(entry["path"].as_str()?.to_string(), entry["lastUsed"].as_i64(), Vec::new())))
+        .collect())
+}
+
+#[cfg(target_os = "macos")]
+fn platform_recents() -> Result<Vec<RecentDocument>, String> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    // Spotlight records when any app last opened a document
+    let output = Command::new("mdfind")
+        .arg("kMDItemLastUsedDate >= $time.today(-30) && kMDItemContentTypeTree != com.apple.application")
+        .output_with_timeout()?;
+    let paths: Vec<String> = String::from_utf8_lossy(&output.stdout).lines().map(String::from).collect();
+    if paths.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let dates = Command::new("mdls")
+        .args(&["-raw", "-name", "kMDItemLastUsedDate"])
+        .args(&paths)
+        .output_with_timeout()?;
+    let dates: Vec<Option<i64>> = String::from_utf8_lossy(&dates.stdout)
+        .split('\0')
+        .map(|stamp| chrono::DateTime::parse_from_str(stamp.trim(), "%Y-%m-%d %H:%M:%S %z").ok().map(|stamp| stamp.timestamp()))
+        .collect();
+
+    Ok(paths
+        .into_iter()
+        .enumerate()
+        .map(|(index, path)| document(path, dates.get(index).copied().flatten(), Vec::new()))
+        .collect())
+}
+
+/// Fills in the default app per extension where the platform list doesn't say which app opened a document.
+fn attach_default_apps(documents: &mut [RecentDocument]) {
+    let mut defaults: HashMap<String, Option<String>> = HashMap::new();
+
+    for document in documents.iter_mut().filter(|document| document.apps.is_empty()) {
+        let extension = match Path::new(&document.path).extension() {
+            Some(extension) => extension.to_string_lossy().to_lowercase(),
+            None => continue,
+        };
+        let app = defaults
+            .entry(extension)
+            .or_insert_with_key(|extension| crate::associations::default_app_for_extension(extension).and_then(|app| app.app_name))
+            .clone();
+        document.apps.extend(app);
+    }
+}
+
+#[tauri::command]
+pub async fn get_recent_documents(app: Option<String>, limit: Option<usize>) -> Result<Vec<RecentDocument>, String> {
+    let documents = tauri::async_runtime::spawn_blocking(move || {
+        let mut documents = platform_recents()?;
+        documents.retain(|document| Path::new(&document.path).is_file());
+        documents.sort_by(|a, b| b.last_used.cmp(&a.last_used));
+        let mut seen = HashSet::new();
+        documents.retain(|document| seen.insert(document.path.clone()));
+        documents.truncate(MAX_CANDIDATES);
+
+        if let Some(app) = app {
+            attach_default_apps(&mut documents);
+            let app = app.to_lowercase();
+            documents.retain(|document| document.apps.iter().any(|name| name.to_lowercase().contains(&app)));
+        }
+
+        Ok::<_, String>(documents)
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    println!("🕘 {} recent documents", documents.len());
+    Ok(documents.into_iter().take(limit.unwrap_or(20)).collect())
+}
@@ -0,0 +1,324 @@
+/**
+ * Recording module - Records assistant actions, captures and transcripts for later replay
+ */
+
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, State};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedEvent {
+    pub offset_ms: u64,
+    pub kind: String,
+    pub description: String,
+    pub details: serde_json::Value,
+    pub capture: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionManifest {
+    pub name: String,
+    pub started_at: u64,
+    pub duration_ms: u64,
+    pub platform: String,
+    pub events: Vec<RecordedEvent>,
+}
+
+struct ActiveRecording {
+    name: String,
+    started_at: u64,
+    clock: Instant,
+    events: Vec<RecordedEvent>,
+    captures: Vec<(String, Vec<u8>)>,
+    /// Keeps the machine from sleeping mid-session
+    keep_awake: Option<u64>,
+}
+
+pub struct RecordingState {
+    active: Option<ActiveRecording>,
+    replay_stop: Option<Arc<AtomicBool>>,
+}
+
+impl Default for RecordingState {
+    fn default() -> Self {
+        RecordingState {
+            active: None,
+            replay_stop: None,
+        }
+    }
+}
+
+fn sessions_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = crate::profiles::active_profile_dir(app)?.join("sessions");
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+fn push_event(
+    app: &AppHandle,
+    kind: &str,
+    description: String,
+    details: serde_json::Value,
+    capture: Option<Vec<u8>>,
+) -> Result<(), String> {
+    let state = app.state::<Mutex<RecordingState>>();
+    let mut recording_state = state.lock().unwrap();
+    let recording = recording_state.active.as_mut().ok_or("No session recording active")?;
+
+    let capture_name = capture.map(|bytes| {
+        let name = format!("captures/{:05}.png", recording.captures.len());
+        recording.captures.push((name.clone(), bytes));
+        name
+    });
+
+    recording.events.push(RecordedEvent {
+        offset_ms: recording.clock.elapsed().as_millis() as u64,
+        kind: kind.to_string(),
+        description,
+        details,
+        capture: capture_name,
+    });
+
+    Ok(())
+}
+
+/// Appends an action to the active recording with a screenshot taken right now.
+/// Does nothing when no recording is running, so callers can use it unconditionally.
+pub async fn record_action_event(app: &AppHandle, description: String, details: serde_json::Value) {
+    if app.state::<Mutex<RecordingState>>().lock().unwrap().active.is_none() {
+        return;
+    }
+
+    let capture = crate::commands::capture_screen_png().await.ok();
+
+    let _ = push_event(app, "action", description, details, capture);
+}
+
+#[tauri::command]
+pub async fn record_session(state: State<'_, Mutex<RecordingState>>, name: Option<String>) -> Result<String, String> {
+    let mut recording_state = state.lock().unwrap();
+
+    if recording_state.active.is_some() {
+        return Err("Session recording already active".to_string());
+    }
+
+    let started_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let name = name.unwrap_or_else(|| format!("session-{}", started_at));
+
+    recording_state.active = Some(ActiveRecording {
+        name: name.clone(),
+        started_at,
+        clock: Instant::now(),
+        events: Vec::new(),
+        captures: Vec::new(),
+        keep_awake: crate::power::acquire(&format!("Recording session {}", name), false, None).ok(),
+    });
+
+    crate::recovery::track("recording", "recording", serde_json::json!({ "name": name }));
+    println!("⏺️  Session recording started: {}", name);
+    Ok(name)
+}
+
+#[tauri::command]
+pub async fn record_action(app: AppHandle, description: String, details: Option<serde_json::Value>) -> Result<(), String> {
+    if app.state::<Mutex<RecordingState>>().lock().unwrap().active.is_none() {
+        return Err("No session recording active".to_string());
+    }
+
+    record_action_event(&app, description, details.unwrap_or(serde_json::Value::Null)).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn record_transcript(app: AppHandle, speaker: String, text: String) -> Result<(), String> {
+    push_event(&app, "transcript", text, serde_json::json!({ "speaker": speaker }), None)
+}
+
+#[tauri::command]
+pub async fn stop_session(app: AppHandle, state: State<'_, Mutex<RecordingState>>) -> Result<serde_json::Value, String> {
+    let recording = state
+        .lock()
+        .unwrap()
+        .active
+        .take()
+        .ok_or("No session recording active")?;
+    crate::recovery::untrack("recording");
+    if let Some(id) = recording.keep_awake {
+        crate::power::release(id);
+    }
+
+    let manifest = SessionManifest {
+        name: recording.name.clone(),
+        started_at: recording.started_at,
+        duration_ms: recording.clock.elapsed().as_millis() as u64,
+        platform: std::env::consts::OS.to_string(),
+        events: recording.events,
+    };
+
+    // A session file is a zip of session.json plus the PNG captures it references
+    let path = sessions_dir(&app)?.join(format!("{}.jxsession", recording.name));
+    let file = std::fs::File::create(&path).map_err(|e| e.to_string())?;
+    let mut writer = zip::ZipWriter::new(file);
+    let options = zip::write::FileOptions::default();
+
+    writer.start_file("session.json", options).map_err(|e| e.to_string())?;
+    let json = serde_json::to_vec_pretty(&manifest).map_err(|e| e.to_string())?;
+    writer.write_all(&json).map_err(|e| e.to_string())?;
+
+    for (name, bytes) in recording.captures.iter() {
+        writer.start_file(name.as_str(), options).map_err(|e| e.to_string())?;
+        writer.write_all(bytes).map_err(|e| e.to_string())?;
+    }
+    writer.finish().map_err(|e| e.to_string())?;
+
+    println!("⏹️  Session saved: {}", path.display());
+
+    Ok(serde_json::json!({
+        "path": path.display().to_string(),
+        "events": manifest.events.len(),
+        "captures": recording.captures.len(),
+        "durationMs": manifest.duration_ms,
+    }))
+}
+
+/// Screenshots in a saved session as (count, compressed bytes).
+pub fn capture_usage(path: &Path) -> (usize, u64) {
+    let archive = std::fs::File::open(path).ok().and_then(|file| zip::ZipArchive::new(file).ok());
+    let Some(mut archive) = archive else {
+        return (0, 0);
+    };
+
+    (0..archive.len())
+        .filter_map(|index| {
+            let entry = archive.by_index_raw(index).ok()?;
+            entry.name().starts_with("captures/").then_some(entry.compressed_size())
+        })
+        .fold((0, 0), |(count, bytes), size| (count + 1, bytes + size))
+}
+
+/// Rewrites a saved session without its screenshots; its events still replay, just without pictures.
+pub fn strip_captures(path: &Path) -> Result<(), String> {
+    let file = std::fs::File::open(path).map_err(|e| e.to_string())?;
+    let modified = file.metadata().and_then(|metadata| metadata.modified()).map_err(|e| e.to_string())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+
+    let stripped = path.with_extension("jxsession.tmp");
+    let mut writer = zip::ZipWriter::new(std::fs::File::create(&stripped).map_err(|e| e.to_string())?);
+    for index in 0..archive.len() {
+        let entry = archive.by_index_raw(index).map_err(|e| e.to_string())?;
+        if !entry.name().starts_with("captures/") {
+            writer.raw_copy_file(entry).map_err(|e| e.to_string())?;
+        }
+    }
+    let file = writer.finish().map_err(|e| e.to_string())?;
+    // The recordings rule keeps aging the session from when it was recorded
+    file.set_modified(modified).map_err(|e| e.to_string())?;
+    std::fs::rename(&stripped, path).map_err(|e| e.to_string())
+}
+
+fn read_session_file(path: &str) -> Result<(SessionManifest, zip::ZipArchive<std::fs::File>), String> {
+    let file = std::fs::File::open(path).map_err(|e| e.to_string())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+
+    let mut json = String::new();
+    archive
+        .by_name("session.json")
+        .map_err(|e| e.to_string())?
+        .read_to_string(&mut json)
+        .map_err(|e| e.to_string())?;
+
+    let manifest = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+    Ok((manifest, archive))
+}
+
+fn read_capture(archive: &mut zip::ZipArchive<std::fs::File>, name: &str) -> Option<String> {
+    use base64::{Engine as _, engine::general_purpose};
+
+    let mut bytes = Vec::new();
+    archive.by_name(name).ok()?.read_to_end(&mut bytes).ok()?;
+    Some(general_purpose::STANDARD.encode(bytes))
+}
+
+#[tauri::command]
+pub async fn list_sessions(app: AppHandle) -> Result<Vec<String>, String> {
+    let mut sessions: Vec<String> = std::fs::read_dir(sessions_dir(&app)?)
+        .map_err(|e| e.to_string())?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().map_or(false, |ext| ext == "jxsession"))
+        .map(|path| path.display().to_string())
+        .collect();
+
+    sessions.sort();
+    Ok(sessions)
+}
+
+#[tauri::command]
+pub async fn replay_session(app: AppHandle, path: String, speed: Option<f64>) -> Result<serde_json::Value, String> {
+    let (manifest, mut archive) = read_session_file(&path)?;
+    let speed = speed.unwrap_or(1.0).max(0.1);
+    let stop = Arc::new(AtomicBool::new(false));
+
+    {
+        let state = app.state::<Mutex<RecordingState>>();
+        let mut recording_state = state.lock().unwrap();
+        if let Some(previous) = recording_state.replay_stop.replace(stop.clone()) {
+            previous.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let summary = serde_json::json!({
+        "name": manifest.name,
+        "events": manifest.events.len(),
+        "durationMs": manifest.duration_ms,
+    });
+
+    println!("▶️  Replaying session: {}", manifest.name);
+
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut previous_offset = 0;
+
+        for (index, event) in manifest.events.iter().enumerate() {
+            let wait = (event.offset_ms.saturating_sub(previous_offset) as f64 / speed) as u64;
+            tokio::time::sleep(Duration::from_millis(wait)).await;
+            previous_offset = event.offset_ms;
+
+            if stop.load(Ordering::SeqCst) {
+                return;
+            }
+
+            let capture = event.capture.as_ref().and_then(|name| read_capture(&mut archive, name));
+            let _ = handle.emit_all("session-replay-step", serde_json::json!({
+                "index": index,
+                "total": manifest.events.len(),
+                "event": event,
+                "capture": capture,
+            }));
+        }
+
+        let _ = handle.emit_all("session-replay-complete", manifest.name.clone());
+    });
+
+    Ok(summary)
+}
+
+#[tauri::command]
+pub async fn stop_replay(state: State<'_, Mutex<RecordingState>>) -> Result<(), String> {
+    match state.lock().unwrap().replay_stop.take() {
+        Some(stop) => {
+            stop.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+        None => Err("No replay running".to_string()),
+    }
+}
@@ -0,0 +1,161 @@
+/**
+ * Recovery module - Journal of in-flight runtime state (held input, recordings, streams, plans) and the startup pass
+ * that cleans up after a crash or forced quit
+ *
+ * Entries are added when an activity starts and removed when it ends, so anything still in the journal at startup
+ * was interrupted.
+ */
+
+use enigo::{Button, Direction, Enigo, Key, Keyboard, Mouse, Settings};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeEntry {
+    /// "input", "recording", "stream", "capture" or "plan"
+    pub kind: String,
+    pub detail: serde_json::Value,
+    pub since: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveryAction {
+    pub key: String,
+    pub kind: String,
+    /// What was done: "released", "discarded", "closed", "cancelled" or "failed"
+    pub action: String,
+    pub detail: serde_json::Value,
+}
+
+struct Journal {
+    path: PathBuf,
+    entries: BTreeMap<String, RuntimeEntry>,
+}
+
+static JOURNAL: Mutex<Option<Journal>> = Mutex::new(None);
+static LAST_RECOVERY: Mutex<Vec<RecoveryAction>> = Mutex::new(Vec::new());
+
+fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Writes through a temp file so a crash mid-write never leaves a truncated journal.
+fn flush(journal: &Journal) {
+    let temp = journal.path.with_extension("json.tmp");
+    let written = serde_json::to_vec(&journal.entries)
+        .map_err(|e| e.to_string())
+        .and_then(|json| std::fs::write(&temp, json).map_err(|e| e.to_string()))
+        .and_then(|_| std::fs::rename(&temp, &journal.path).map_err(|e| e.to_string()));
+
+    if let Err(e) = written {
+        println!("⚠️  Failed to persist runtime state: {}", e);
+    }
+}
+
+/// Records that an activity is in flight; no-op until the journal is opened at startup.
+pub fn track(key: &str, kind: &str, detail: serde_json::Value) {
+    if kind == "input" {
+        crate::stuck_keys::arm();
+    }
+    if let Some(journal) = JOURNAL.lock().unwrap().as_mut() {
+        journal.entries.insert(key.to_string(), RuntimeEntry { kind: kind.to_string(), detail, since: now_secs() });
+        flush(journal);
+    }
+}
+
+pub fn untrack(key: &str) {
+    if let Some(journal) = JOURNAL.lock().unwrap().as_mut() {
+        if journal.entries.remove(key).is_some() {
+            flush(journal);
+        }
+    }
+}
+
+/// Whether any in-flight activity of `kind` is journaled.
+pub fn is_tracking(kind: &str) -> bool {
+    JOURNAL.lock().unwrap().as_ref().map_or(false, |journal| journal.entries.values().any(|entry| entry.kind == kind))
+}
+
+/// Releases every modifier and mouse button automation may have left pressed.
+pub fn release_held_input() -> Result<(), String> {
+    let _injection = crate::automation_guard::injecting();
+    let mut enigo = Enigo::new(&Settings::default()).map_err(|e| e.to_string())?;
+
+    for key in [Key::Shift, Key::Control, Key::Alt, Key::Meta] {
+        enigo.key(key, Direction::Release).map_err(|e| e.to_string())?;
+    }
+    for button in [Button::Left, Button::Right, Button::Middle] {
+        enigo.button(button, Direction::Release).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn recover_entry(key: &str, entry: &RuntimeEntry) -> RecoveryAction {
+    let action = match entry.kind.as_str() {
+        "input" => match release_held_input() {
+            Ok(()) => "released",
+            Err(e) => {
+                println!("⚠️  Could not release held input: {}", e);
+                "failed"
+            }
+        },
+        // Events and captures were only held in memory
+        "recording" => "discarded",
+        "stream" | "capture" => "closed",
+        // Plans need fresh approvals, so they are reported for the user to re-run rather than resumed
+        _ => "cancelled",
+    };
+
+    RecoveryAction {
+        key: key.to_string(),
+        kind: entry.kind.clone(),
+        action: action.to_string(),
+        detail: entry.detail.clone(),
+    }
+}
+
+/// Cleans up whatever the previous run left in flight, then opens a fresh journal.
+pub fn recover_runtime_state(app: AppHandle) {
+    let path = match app.path_resolver().app_data_dir() {
+        Some(dir) => dir.join("runtime_state.json"),
+        None => return,
+    };
+    let _ = std::fs::create_dir_all(path.parent().unwrap_or(&path));
+
+    let leftover: BTreeMap<String, RuntimeEntry> = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+
+    let actions: Vec<RecoveryAction> = leftover.iter().map(|(key, entry)| recover_entry(key, entry)).collect();
+    if !actions.is_empty() {
+        println!("🩹 Recovered from an unclean exit: {} interrupted activities", actions.len());
+        let _ = app.emit_all("recovery://completed", &actions);
+    }
+    *LAST_RECOVERY.lock().unwrap() = actions;
+
+    let journal = Journal { path, entries: BTreeMap::new() };
+    flush(&journal);
+    *JOURNAL.lock().unwrap() = Some(journal);
+}
+
+/// What the startup recovery pass found and did; empty after a clean exit.
+#[tauri::command]
+pub async fn get_recovery_report() -> Result<Vec<RecoveryAction>, String> {
+    Ok(LAST_RECOVERY.lock().unwrap().clone())
+}
+
+/// Activities currently in flight, as they would be recovered after a crash.
+#[tauri::command]
+pub async fn get_runtime_state() -> Result<BTreeMap<String, RuntimeEntry>, String> {
+    Ok(JOURNAL.lock().unwrap().as_ref().map(|journal| journal.entries.clone()).unwrap_or_default())
+}
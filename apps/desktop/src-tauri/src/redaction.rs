@@ -0,0 +1,217 @@
+/**
+ * Redaction module - Masks sensitive data (card numbers, NIC numbers, API keys) in text bound for cloud services
+ *
+ * Only counts per category and source are reported; matched values are never stored.
+ */
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, State};
+
+const MAX_REPORT_ENTRIES: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RedactionMode {
+    /// Replace all but the last four characters with bullets
+    Mask,
+    /// Replace the whole match with a "[redacted <category>]" marker
+    Remove,
+    Off,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedactionPolicy {
+    pub credit_cards: RedactionMode,
+    pub nic_numbers: RedactionMode,
+    pub api_keys: RedactionMode,
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        RedactionPolicy {
+            credit_cards: RedactionMode::Mask,
+            nic_numbers: RedactionMode::Mask,
+            api_keys: RedactionMode::Remove,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedactionEntry {
+    pub timestamp: i64,
+    /// Where the text was headed: "transcript", "ocr", "clipboard", "translation", ...
+    pub source: String,
+    pub category: String,
+    pub count: usize,
+}
+
+#[derive(Default)]
+pub struct RedactionReport {
+    totals: HashMap<String, usize>,
+    recent: VecDeque<RedactionEntry>,
+}
+
+fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+fn policy_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("redaction.json"))
+}
+
+fn load_policy(app: &AppHandle) -> RedactionPolicy {
+    policy_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn patterns() -> &'static [(&'static str, Regex)] {
+    static PATTERNS: OnceLock<Vec<(&'static str, Regex)>> = OnceLock::new();
+
+    PATTERNS.get_or_init(|| {
+        vec![
+            // OpenAI/Anthropic-style secret keys, AWS access key ids, GitHub and Slack tokens, Google API keys
+            ("apiKey", Regex::new(r"\b(?:sk-[A-Za-z0-9_-]{20,}|AKIA[0-9A-Z]{16}|gh[pousr]_[A-Za-z0-9]{36,}|xox[abpr]-[A-Za-z0-9-]{10,}|AIza[0-9A-Za-z_-]{35})\b").unwrap()),
+            // 13-19 digits, optionally grouped with spaces or dashes; only Luhn-valid runs count
+            ("creditCard", Regex::new(r"\b(?:\d[ -]?){12,18}\d\b").unwrap()),
+            // Sri Lankan NIC: old 9 digits + V/X, new 12 digits starting with the birth year
+            ("nic", Regex::new(r"\b(?:\d{9}[VvXx]|(?:19|20)\d{10})\b").unwrap()),
+        ]
+    })
+}
+
+fn luhn_valid(candidate: &str) -> bool {
+    let digits: Vec<u32> = candidate.chars().filter_map(|c| c.to_digit(10)).collect();
+
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, &digit)| match (index % 2, digit * 2) {
+            (1, doubled) if doubled > 9 => doubled - 9,
+            (1, doubled) => doubled,
+            _ => digit,
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+fn mode_for(policy: &RedactionPolicy, category: &str) -> RedactionMode {
+    match category {
+        "creditCard" => policy.credit_cards,
+        "nic" => policy.nic_numbers,
+        _ => policy.api_keys,
+    }
+}
+
+fn mask(value: &str) -> String {
+    let keep_from = value.chars().filter(|c| c.is_ascii_alphanumeric()).count().saturating_sub(4);
+    let mut seen = 0;
+
+    value
+        .chars()
+        .map(|c| {
+            if !c.is_ascii_alphanumeric() {
+                return c;
+            }
+            seen += 1;
+            if seen > keep_from { c } else { '•' }
+        })
+        .collect()
+}
+
+/// Applies `policy` to `text`, returning the filtered text and the number of matches per category.
+fn apply(policy: &RedactionPolicy, text: &str) -> (String, Vec<(&'static str, usize)>) {
+    let mut text = text.to_string();
+    let mut counts = Vec::new();
+
+    for (category, pattern) in patterns() {
+        let mode = mode_for(policy, category);
+        if mode == RedactionMode::Off {
+            continue;
+        }
+
+        let mut count = 0;
+        text = pattern
+            .replace_all(&text, |captures: &regex::Captures| {
+                let value = &captures[0];
+                if *category == "creditCard" && !luhn_valid(value) {
+                    return value.to_string();
+                }
+                count += 1;
+                match mode {
+                    RedactionMode::Mask => mask(value),
+                    _ => format!("[redacted {}]", category),
+                }
+            })
+            .into_owned();
+
+        if count > 0 {
+            counts.push((*category, count));
+        }
+    }
+
+    (text, counts)
+}
+
+/// Filters text on its way to a cloud service and records what was redacted against `source`.
+pub fn redact(app: &AppHandle, source: &str, text: &str) -> String {
+    let (redacted, counts) = apply(&load_policy(app), text);
+    if counts.is_empty() {
+        return redacted;
+    }
+
+    let state = app.state::<Mutex<RedactionReport>>();
+    let mut report = state.lock().unwrap();
+    for (category, count) in counts {
+        println!("🕶️  Redacted {} {} from {}", count, category, source);
+        *report.totals.entry(category.to_string()).or_insert(0) += count;
+        report.recent.push_back(RedactionEntry {
+            timestamp: now_secs(),
+            source: source.to_string(),
+            category: category.to_string(),
+            count,
+        });
+        if report.recent.len() > MAX_REPORT_ENTRIES {
+            report.recent.pop_front();
+        }
+    }
+
+    redacted
+}
+
+/// Redacts text the frontend is about to send (transcripts, OCR output) under the current policy.
+#[tauri::command]
+pub async fn redact_text(app: AppHandle, source: String, text: String) -> Result<String, String> {
+    Ok(redact(&app, &source, &text))
+}
+
+#[tauri::command]
+pub async fn get_redaction_report(app: AppHandle, state: State<'_, Mutex<RedactionReport>>) -> Result<serde_json::Value, String> {
+    let report = state.lock().unwrap();
+
+    Ok(serde_json::json!({
+        "policy": load_policy(&app),
+        "totals": report.totals,
+        "recent": report.recent.iter().rev().collect::<Vec<_>>(),
+    }))
+}
+
+#[tauri::command]
+pub async fn set_redaction_policy(app: AppHandle, policy: RedactionPolicy) -> Result<RedactionPolicy, String> {
+    let json = serde_json::to_string_pretty(&policy).map_err(|e| e.to_string())?;
+    std::fs::write(policy_path(&app)?, json).map_err(|e| e.to_string())?;
+    println!("🕶️  Redaction policy updated");
+    Ok(policy)
+}
@@ -0,0 +1,316 @@
+/**
+ * Removable module - USB device and removable drive attach/remove events, safe eject, and scripts run on insertion
+ *
+ * Drives come from the OS volume list (removable media only) and USB devices from sysfs on Linux, ioreg on macOS
+ * and PnP on Windows, polled and diffed. Each change emits `usb://volume-attached`, `usb://volume-removed`,
+ * `usb://device-attached` or `usb://device-removed`; `removable.json` maps volume names to scripts (e.g. back up
+ * photos when the camera card goes in), which receive the attach event; backup jobs can wait for a volume too.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::process::Command;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use crate::watchdog::WatchdogCommand;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+/// USB device enumeration spawns a process on Windows and macOS, so it runs every few volume polls
+const DEVICE_POLL_EVERY: u32 = 2;
+const EJECT_WAIT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovableVolume {
+    /// Volume label, e.g. "EOS_DIGITAL"
+    pub name: String,
+    pub mount_point: String,
+    /// Block device on Unix ("/dev/sdb1", "/dev/disk4s1"), the drive letter on Windows
+    pub device: String,
+    pub file_system: String,
+    pub total_bytes: u64,
+    pub available_bytes: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsbDevice {
+    /// Stable while plugged into the same port
+    pub id: String,
+    pub name: String,
+    pub vendor_id: Option<String>,
+    pub product_id: Option<String>,
+}
+
+/// Runs `script` when a volume whose name contains `volume_name` (case-insensitive; any volume when empty) is attached.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VolumeTrigger {
+    #[serde(default)]
+    pub volume_name: String,
+    pub script: String,
+}
+
+fn triggers_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("removable.json"))
+}
+
+fn load_triggers(app: &AppHandle) -> Vec<VolumeTrigger> {
+    triggers_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+pub fn volumes() -> Vec<RemovableVolume> {
+    use sysinfo::{DiskExt, System, SystemExt};
+
+    if crate::mock_platform::is_active() {
+        return Vec::new();
+    }
+
+    let mut sys = System::new();
+    sys.refresh_disks_list();
+    let mut volumes: Vec<RemovableVolume> = sys
+        .disks()
+        .iter()
+        .filter(|disk| disk.is_removable())
+        .map(|disk| {
+            let mount_point = disk.mount_point().display().to_string();
+            let device = disk.name().to_string_lossy().to_string();
+            // Unix reports the device as the name; the label is the mount folder's name there
+            let name = if cfg!(target_os = "windows") {
+                device.clone()
+            } else {
+                disk.mount_point().file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_else(|| device.clone())
+            };
+            RemovableVolume {
+                name,
+                device: if cfg!(target_os = "windows") { mount_point.trim_end_matches('\\').to_string() } else { device },
+                mount_point,
+                file_system: String::from_utf8_lossy(disk.file_system()).to_string(),
+                total_bytes: disk.total_space(),
+                available_bytes: disk.available_space(),
+            }
+        })
+        .collect();
+    volumes.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+    volumes
+}
+
+#[cfg(target_os = "linux")]
+fn platform_usb_devices() -> Vec<UsbDevice> {
+    let read = |path: std::path::PathBuf| std::fs::read_to_string(path).ok().map(|text| text.trim().to_string());
+
+    let Ok(entries) = std::fs::read_dir("/sys/bus/usb/devices") else { return Vec::new() };
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let vendor_id = read(path.join("idVendor"))?;
+            // Root hubs belong to the host controller, not to anything plugged in
+            if vendor_id == "1d6b" {
+                return None;
+            }
+            let name = [read(path.join("manufacturer")), read(path.join("product"))].into_iter().flatten().collect::<Vec<_>>().join(" ");
+            Some(UsbDevice {
+                id: entry.file_name().to_string_lossy().to_string(),
+                name,
+                vendor_id: Some(vendor_id),
+                product_id: read(path.join("idProduct")),
+            })
+        })
+        .collect()
+}
+
+#[cfg(target_os = "macos")]
+fn platform_usb_devices() -> Vec<UsbDevice> {
+    let Ok(output) = Command::new("ioreg").args(&["-p", "IOUSB", "-w0"]).output_with_timeout() else { return Vec::new() };
+
+    // "  | +-o USB Keyboard@14100000  <class IOUSBHostDevice, id 0x100000a2f, registered, ...>"
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.split_once("+-o ").map(|(_, rest)| rest))
+        .filter(|rest| rest.contains("IOUSBHostDevice") || rest.contains("IOUSBDevice"))
+        .filter_map(|rest| {
+            let (name, location) = rest.split_once("  <")?.0.rsplit_once('@')?;
+            Some(UsbDevice { id: location.to_string(), name: name.to_string(), vendor_id: None, product_id: None })
+        })
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn platform_usb_devices() -> Vec<UsbDevice> {
+    let script = "Get-PnpDevice -PresentOnly | Where-Object { $_.InstanceId -like 'USB\\VID_*' } | ForEach-Object { $_.InstanceId + '|' + $_.FriendlyName }";
+    let Ok(output) = Command::new("powershell").args(&["-NoProfile", "-Command", script]).output_with_timeout() else { return Vec::new() };
+
+    // "USB\VID_046D&PID_C52B\5&2B7E8A3F&0&3|Logitech USB Receiver"
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.trim().split_once('|'))
+        .map(|(instance, name)| {
+            let id_part = |prefix: &str| instance.split(['\\', '&']).find_map(|part| part.strip_prefix(prefix)).map(str::to_lowercase);
+            UsbDevice { id: instance.to_string(), name: name.to_string(), vendor_id: id_part("VID_"), product_id: id_part("PID_") }
+        })
+        .collect()
+}
+
+pub fn usb_devices() -> Vec<UsbDevice> {
+    if crate::mock_platform::is_active() {
+        return Vec::new();
+    }
+    let mut devices = platform_usb_devices();
+    devices.sort_by(|a, b| a.id.cmp(&b.id));
+    devices
+}
+
+fn run_triggers(app: &AppHandle, volume: &RemovableVolume) {
+    let name = volume.name.to_lowercase();
+    for trigger in load_triggers(app).iter().filter(|trigger| name.contains(&trigger.volume_name.to_lowercase())) {
+        let event = serde_json::json!({ "kind": "volume-attached", "volume": volume });
+        let result = crate::scripting::load_script(app, &trigger.script)
+            .and_then(|source| crate::scripting::execute(app, &source, event, crate::scripting::DEFAULT_TIME_LIMIT, None));
+        if let Err(e) = result {
+            println!("⚠️  Removable drive script {} failed: {}", trigger.script, e);
+            let _ = app.emit_all("usb://script-failed", serde_json::json!({ "script": trigger.script, "error": e }));
+        }
+    }
+}
+
+/// Polls removable volumes and USB devices, emitting attach/remove events and running volume triggers.
+pub fn spawn_removable_watcher(app: AppHandle) {
+    std::thread::spawn(move || {
+        let mut known_volumes: BTreeMap<String, RemovableVolume> =
+            volumes().into_iter().map(|volume| (volume.mount_point.clone(), volume)).collect();
+        let mut known_devices: BTreeMap<String, UsbDevice> = usb_devices().into_iter().map(|device| (device.id.clone(), device)).collect();
+        let mut tick = 0u32;
+
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            tick = tick.wrapping_add(1);
+
+            let current: BTreeMap<String, RemovableVolume> =
+                volumes().into_iter().map(|volume| (volume.mount_point.clone(), volume)).collect();
+            for (mount_point, volume) in current.iter().filter(|(mount_point, _)| !known_volumes.contains_key(*mount_point)) {
+                println!("💾 Volume attached: {} at {}", volume.name, mount_point);
+                let _ = app.emit_all("usb://volume-attached", volume);
+                run_triggers(&app, volume);
+                crate::backups::on_volume_attached(&app, volume);
+            }
+            for (mount_point, volume) in known_volumes.iter().filter(|(mount_point, _)| !current.contains_key(*mount_point)) {
+                println!("💾 Volume removed: {} from {}", volume.name, mount_point);
+                let _ = app.emit_all("usb://volume-removed", volume);
+            }
+            known_volumes = current;
+
+            if tick % DEVICE_POLL_EVERY != 0 {
+                continue;
+            }
+            let current: BTreeMap<String, UsbDevice> = usb_devices().into_iter().map(|device| (device.id.clone(), device)).collect();
+            for device in current.values().filter(|device| !known_devices.contains_key(&device.id)) {
+                println!("🔌 USB device attached: {}", device.name);
+                let _ = app.emit_all("usb://device-attached", device);
+            }
+            for device in known_devices.values().filter(|device| !current.contains_key(&device.id)) {
+                println!("🔌 USB device removed: {}", device.name);
+                let _ = app.emit_all("usb://device-removed", device);
+            }
+            known_devices = current;
+        }
+    });
+}
+
+/// Matches a volume by mount point, device or name (case-insensitive).
+fn find_volume(volume: &str) -> Result<RemovableVolume, String> {
+    let wanted = volume.trim().trim_end_matches(['/', '\\']).to_lowercase();
+    volumes()
+        .into_iter()
+        .find(|candidate| {
+            [&candidate.mount_point, &candidate.device, &candidate.name]
+                .iter()
+                .any(|value| value.trim_end_matches(['/', '\\']).to_lowercase() == wanted)
+        })
+        .ok_or_else(|| format!("❌ Removable volume not found: {}", volume))
+}
+
+fn check(output: std::process::Output) -> Result<(), String> {
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn eject(volume: &RemovableVolume) -> Result<(), String> {
+    // Explorer's "Eject" verb flushes and releases the drive the same way the tray icon does
+    let script = format!(
+        "(New-Object -ComObject Shell.Application).Namespace(17).ParseName('{}').InvokeVerb('Eject')",
+        volume.device.replace('\'', "''")
+    );
+    check(Command::new("powershell").args(&["-NoProfile", "-Command", &script]).output_with_timeout()?)
+}
+
+#[cfg(target_os = "macos")]
+fn eject(volume: &RemovableVolume) -> Result<(), String> {
+    check(Command::new("diskutil").args(&["eject", &volume.mount_point]).output_with_timeout()?)
+}
+
+#[cfg(target_os = "linux")]
+fn eject(volume: &RemovableVolume) -> Result<(), String> {
+    check(Command::new("udisksctl").args(&["unmount", "-b", &volume.device]).output_with_timeout()?)?;
+    // Powering off lets the drive be pulled; card readers that can't power off are still safely unmounted
+    if let Err(e) = check(Command::new("udisksctl").args(&["power-off", "-b", &volume.device]).output_with_timeout()?) {
+        println!("⚠️  Unmounted {} but could not power it off: {}", volume.device, e);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn list_removable_volumes() -> Result<Vec<RemovableVolume>, String> {
+    tauri::async_runtime::spawn_blocking(volumes).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn list_usb_devices() -> Result<Vec<UsbDevice>, String> {
+    tauri::async_runtime::spawn_blocking(usb_devices).await.map_err(|e| e.to_string())
+}
+
+/// Flushes and ejects a removable volume, given by mount point, device or name; errors if it is still in use.
+#[tauri::command]
+pub async fn safely_eject(volume: String) -> Result<RemovableVolume, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let target = find_volume(&volume)?;
+        println!("⏏️  Ejecting {} ({})", target.name, target.mount_point);
+        eject(&target).map_err(|e| format!("❌ Could not eject {}: {}", target.name, e))?;
+
+        // Windows' eject verb returns before the drive is gone, and refuses silently while files are open
+        let deadline = std::time::Instant::now() + EJECT_WAIT;
+        while volumes().iter().any(|volume| volume.mount_point == target.mount_point) {
+            if std::time::Instant::now() >= deadline {
+                return Err(format!("❌ {} is still in use; close open files and try again", target.name));
+            }
+            std::thread::sleep(Duration::from_millis(250));
+        }
+        Ok(target)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+pub async fn set_removable_triggers(app: AppHandle, triggers: Vec<VolumeTrigger>) -> Result<Vec<VolumeTrigger>, String> {
+    for trigger in &triggers {
+        crate::scripting::load_script(&app, &trigger.script)?;
+    }
+    let json = serde_json::to_string_pretty(&triggers).map_err(|e| e.to_string())?;
+    std::fs::write(triggers_path(&app)?, json).map_err(|e| e.to_string())?;
+    Ok(triggers)
+}
+
+#[tauri::command]
+pub async fn get_removable_triggers(app: AppHandle) -> Result<Vec<VolumeTrigger>, String> {
+    Ok(load_triggers(&app))
+}
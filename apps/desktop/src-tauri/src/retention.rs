@@ -0,0 +1,275 @@
+/**
+ * Retention module - Per-category age and size caps for stored data, enforced by a background janitor
+ *
+ * Categories: recordings (sessions), captures (the screenshots inside sessions), audit (supervised-mode logs),
+ * orders (webhook order log), clipboard (history).
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::AppHandle;
+
+const CATEGORIES: &[&str] = &["recordings", "captures", "audit", "orders", "clipboard"];
+const JANITOR_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionRule {
+    pub max_age_days: Option<u64>,
+    /// Ignored for the clipboard history, which only lives in memory
+    pub max_size_mb: Option<u64>,
+}
+
+fn default_rules() -> BTreeMap<String, RetentionRule> {
+    let rule = |max_age_days, max_size_mb| RetentionRule { max_age_days, max_size_mb };
+
+    BTreeMap::from([
+        ("recordings".to_string(), rule(Some(30), Some(2048))),
+        ("captures".to_string(), rule(Some(7), Some(512))),
+        ("audit".to_string(), rule(Some(365), None)),
+        ("orders".to_string(), rule(Some(365), Some(50))),
+        ("clipboard".to_string(), rule(Some(7), None)),
+    ])
+}
+
+fn rules_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("retention.json"))
+}
+
+/// Saved rules over the defaults, so new categories get a sensible cap.
+fn load_rules(app: &AppHandle) -> BTreeMap<String, RetentionRule> {
+    let saved: BTreeMap<String, RetentionRule> = rules_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+
+    let mut rules = default_rules();
+    rules.extend(saved);
+    rules
+}
+
+fn category_path(dir: &Path, category: &str) -> Option<PathBuf> {
+    match category {
+        "recordings" | "captures" => Some(dir.join("sessions")),
+        "audit" => Some(dir.join("supervised")),
+        "orders" => Some(dir.join("orders").join("orders.jsonl")),
+        _ => None,
+    }
+}
+
+/// Files of a folder category as (path, modified, size), oldest first.
+fn files_oldest_first(folder: &Path) -> Vec<(PathBuf, SystemTime, u64)> {
+    let mut files: Vec<_> = std::fs::read_dir(folder)
+        .map(|entries| entries.filter_map(|entry| entry.ok()).collect::<Vec<_>>())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok().filter(|metadata| metadata.is_file())?;
+            Some((entry.path(), metadata.modified().unwrap_or(UNIX_EPOCH), metadata.len()))
+        })
+        .collect();
+    files.sort_by_key(|(_, modified, _)| *modified);
+    files
+}
+
+fn is_expired(age: Duration, rule: &RetentionRule) -> bool {
+    rule.max_age_days.map_or(false, |days| age > Duration::from_secs(days * DAY_SECS))
+}
+
+fn size_cap(rule: &RetentionRule) -> Option<u64> {
+    rule.max_size_mb.map(|mb| mb * 1024 * 1024)
+}
+
+/// Deletes expired files, then the oldest ones until the folder fits the size cap.
+fn prune_folder(folder: &Path, rule: &RetentionRule) -> usize {
+    let files = files_oldest_first(folder);
+    let mut total: u64 = files.iter().map(|(_, _, size)| size).sum();
+    let mut removed = 0;
+
+    for (path, modified, size) in files {
+        let expired = is_expired(modified.elapsed().unwrap_or_default(), rule);
+        let over_cap = size_cap(rule).map_or(false, |cap| total > cap);
+        if (expired || over_cap) && std::fs::remove_file(&path).is_ok() {
+            total -= size;
+            removed += 1;
+        }
+    }
+
+    removed
+}
+
+/// Strips the screenshots from expired sessions, then from the oldest ones until the captures fit the size cap.
+/// The sessions themselves are left to the recordings rule.
+fn prune_captures(folder: &Path, rule: &RetentionRule) -> usize {
+    let sessions: Vec<_> = files_oldest_first(folder)
+        .into_iter()
+        .map(|(path, modified, _)| (crate::recording::capture_usage(&path), path, modified))
+        .filter(|((count, _), _, _)| *count > 0)
+        .collect();
+    let mut total: u64 = sessions.iter().map(|((_, bytes), _, _)| bytes).sum();
+    let mut removed = 0;
+
+    for ((count, bytes), path, modified) in sessions {
+        let expired = is_expired(modified.elapsed().unwrap_or_default(), rule);
+        let over_cap = size_cap(rule).map_or(false, |cap| total > cap);
+        if (expired || over_cap) && crate::recording::strip_captures(&path).is_ok() {
+            total -= bytes;
+            removed += count;
+        }
+    }
+
+    removed
+}
+
+/// Drops order log lines older than the age cap, then the oldest lines until the file fits the size cap.
+fn prune_log(path: &Path, rule: &RetentionRule) -> Result<usize, String> {
+    // Held through the rewrite so a webhook can't append an order that the write then drops
+    let _log = crate::orders::ORDER_LOG.lock().unwrap();
+    let log = match std::fs::read_to_string(path) {
+        Ok(log) => log,
+        Err(_) => return Ok(0),
+    };
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+
+    let mut lines: Vec<&str> = log
+        .lines()
+        .filter(|line| {
+            let received_at = serde_json::from_str::<serde_json::Value>(line)
+                .ok()
+                .and_then(|event| event["receivedAt"].as_u64())
+                .unwrap_or(now);
+            !is_expired(Duration::from_secs(now.saturating_sub(received_at)), rule)
+        })
+        .collect();
+
+    if let Some(cap) = size_cap(rule) {
+        let mut total: u64 = lines.iter().map(|line| line.len() as u64 + 1).sum();
+        let mut skip = 0;
+        while total > cap && skip < lines.len() {
+            total -= lines[skip].len() as u64 + 1;
+            skip += 1;
+        }
+        lines.drain(..skip);
+    }
+
+    let removed = log.lines().count() - lines.len();
+    if removed > 0 {
+        let kept: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+        std::fs::write(path, kept).map_err(|e| e.to_string())?;
+    }
+    Ok(removed)
+}
+
+/// Applies every category's rule once, returning how many items each lost.
+fn enforce(app: &AppHandle) -> Result<BTreeMap<String, usize>, String> {
+    let dir = crate::profiles::active_profile_dir(app)?;
+    let mut removed = BTreeMap::new();
+
+    for (category, rule) in load_rules(app) {
+        let count = match (category.as_str(), category_path(&dir, &category)) {
+            ("clipboard", _) => rule
+                .max_age_days
+                .map_or(0, |days| crate::system::prune_clipboard_history(Duration::from_secs(days * DAY_SECS))),
+            ("captures", Some(folder)) => prune_captures(&folder, &rule),
+            ("orders", Some(path)) => prune_log(&path, &rule)?,
+            (_, Some(folder)) => prune_folder(&folder, &rule),
+            _ => 0,
+        };
+        if count > 0 {
+            removed.insert(category, count);
+        }
+    }
+
+    Ok(removed)
+}
+
+pub async fn run_janitor(app: &AppHandle) {
+    let task_app = app.clone();
+    match tauri::async_runtime::spawn_blocking(move || enforce(&task_app)).await {
+        Ok(Ok(removed)) if !removed.is_empty() => println!("🧹 Retention janitor removed {:?}", removed),
+        Ok(Err(e)) => println!("⚠️  Retention janitor failed: {}", e),
+        _ => {}
+    }
+}
+
+pub fn spawn_retention_janitor(app: AppHandle) {
+    crate::scheduler::register_task(&app, "retention-janitor", crate::scheduler::TaskPriority::Low);
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            if !crate::scheduler::is_paused(&app, "retention-janitor") {
+                run_janitor(&app).await;
+            }
+            tokio::time::sleep(JANITOR_INTERVAL).await;
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn get_retention_policies(app: AppHandle) -> Result<BTreeMap<String, RetentionRule>, String> {
+    Ok(load_rules(&app))
+}
+
+/// Updates one category's rule and enforces it right away.
+#[tauri::command]
+pub async fn set_retention_policy(app: AppHandle, category: String, rule: RetentionRule) -> Result<BTreeMap<String, RetentionRule>, String> {
+    if !CATEGORIES.contains(&category.as_str()) {
+        return Err(format!("❌ Unknown retention category: {} (expected one of {})", category, CATEGORIES.join(", ")));
+    }
+
+    let mut rules = load_rules(&app);
+    rules.insert(category.clone(), rule);
+    let json = serde_json::to_string_pretty(&rules).map_err(|e| e.to_string())?;
+    std::fs::write(rules_path(&app)?, json).map_err(|e| e.to_string())?;
+
+    println!("🗄️  Retention for {}: {:?} days, {:?} MB", category, rule.max_age_days, rule.max_size_mb);
+    run_janitor(&app).await;
+    Ok(rules)
+}
+
+/// Disk usage of the active profile by category, with each category's retention rule.
+#[tauri::command]
+pub async fn get_storage_breakdown(app: AppHandle) -> Result<serde_json::Value, String> {
+    let dir = crate::profiles::active_profile_dir(&app)?;
+    let rules = load_rules(&app);
+
+    let total = crate::local_data::size_of(&dir);
+    let memory_db = crate::local_data::size_of(&dir.join("memory.db"));
+    let mut categories = serde_json::Map::new();
+    let mut accounted = memory_db;
+
+    for category in CATEGORIES {
+        let (bytes, items) = match (*category, category_path(&dir, category)) {
+            ("captures", Some(folder)) => files_oldest_first(&folder)
+                .iter()
+                .map(|(path, _, _)| crate::recording::capture_usage(path))
+                .fold((0, 0), |(bytes, items), (count, size)| (bytes + size, items + count)),
+            (_, Some(path)) if path.is_dir() => (crate::local_data::size_of(&path), files_oldest_first(&path).len()),
+            (_, Some(path)) => (
+                crate::local_data::size_of(&path),
+                std::fs::read_to_string(&path).map_or(0, |log| log.lines().count()),
+            ),
+            (_, None) => (0, crate::system::clipboard_history().len()),
+        };
+        // Captures are part of the recordings' bytes
+        if *category != "captures" {
+            accounted += bytes;
+        }
+        categories.insert(category.to_string(), serde_json::json!({
+            "bytes": bytes,
+            "items": items,
+            "rule": rules.get(*category),
+        }));
+    }
+
+    Ok(serde_json::json!({
+        "totalBytes": total,
+        "memoryDbBytes": memory_db,
+        "otherBytes": total.saturating_sub(accounted),
+        "categories": categories,
+    }))
+}
@@ -0,0 +1,105 @@
+/**
+ * Retry module - Exponential backoff with jitter for flaky platform calls (focus, capture, accessibility)
+ */
+
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::RwLock;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_delay_ms: u64,
+    pub max_delay_ms: u64,
+    pub multiplier: f64,
+    /// Fraction (0..1) of each delay that is randomised
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        DEFAULT_POLICY
+    }
+}
+
+const DEFAULT_POLICY: RetryPolicy = RetryPolicy {
+    max_attempts: 3,
+    initial_delay_ms: 150,
+    max_delay_ms: 2_000,
+    multiplier: 2.0,
+    jitter: 0.25,
+};
+
+static POLICY: RwLock<RetryPolicy> = RwLock::new(DEFAULT_POLICY);
+
+pub fn current_policy() -> RetryPolicy {
+    *POLICY.read().unwrap()
+}
+
+/// Delay before retry number `attempt` (1-based), capped and jittered.
+pub fn backoff_delay(policy: &RetryPolicy, attempt: u32) -> Duration {
+    let base = policy.initial_delay_ms as f64 * policy.multiplier.powi(attempt.saturating_sub(1) as i32);
+    let capped = base.min(policy.max_delay_ms as f64);
+    let spread = capped * policy.jitter.clamp(0.0, 1.0);
+    let jittered = capped - spread + rand::random::<f64>() * spread * 2.0;
+
+    Duration::from_millis(jittered.max(0.0) as u64)
+}
+
+pub struct Retried<T> {
+    pub value: T,
+    pub attempts: u32,
+}
+
+/// Appends the attempt count to a result message when it took more than one try.
+pub fn with_attempts(message: String, attempts: u32) -> String {
+    if attempts > 1 {
+        format!("{} (after {} attempts)", message, attempts)
+    } else {
+        message
+    }
+}
+
+/// Runs `operation` until it succeeds or the policy gives up.
+/// Watchdog timeouts are not retried: a hung helper will just hang again.
+pub async fn retry<T, F, Fut>(policy: &RetryPolicy, label: &str, mut operation: F) -> Result<Retried<T>, String>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, String>>,
+{
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+        match operation().await {
+            Ok(value) => return Ok(Retried { value, attempts }),
+            Err(e) if attempts < max_attempts && !e.starts_with(crate::watchdog::TIMEOUT_ERROR) => {
+                let delay = backoff_delay(policy, attempts);
+                println!("🔁 {} failed (attempt {}/{}), retrying in {:?}: {}", label, attempts, max_attempts, delay, e);
+                tokio::time::sleep(delay).await;
+            }
+            Err(e) => return Err(with_attempts(e, attempts)),
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn set_retry_policy(policy: RetryPolicy) -> Result<RetryPolicy, String> {
+    if policy.max_attempts == 0 || policy.max_attempts > 10 {
+        return Err("Retry attempts must be between 1 and 10".to_string());
+    }
+    if policy.multiplier < 1.0 {
+        return Err("Backoff multiplier must be at least 1".to_string());
+    }
+
+    *POLICY.write().unwrap() = policy;
+    Ok(policy)
+}
+
+#[tauri::command]
+pub async fn get_retry_policy() -> Result<RetryPolicy, String> {
+    Ok(current_policy())
+}
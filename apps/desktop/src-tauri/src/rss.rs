@@ -0,0 +1,233 @@
+/**
+ * RSS module - Feed subscriptions and a deduplicated, read-tracked news briefing
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::time::Duration;
+use tauri::AppHandle;
+
+const FEED_TTL: Duration = Duration::from_secs(15 * 60);
+/// Read markers kept per profile; older ones fall off first
+const MAX_READ_MARKERS: usize = 5000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedConfig {
+    pub url: String,
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FeedStore {
+    feeds: Vec<FeedConfig>,
+    read: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedItem {
+    pub id: String,
+    pub feed: String,
+    pub title: String,
+    pub link: Option<String>,
+    pub summary: Option<String>,
+    pub published: Option<i64>,
+}
+
+fn rss_dir(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = crate::profiles::active_profile_dir(app)?.join("rss");
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+fn load_store(app: &AppHandle) -> FeedStore {
+    rss_dir(app)
+        .ok()
+        .and_then(|dir| std::fs::read_to_string(dir.join("feeds.json")).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_store(app: &AppHandle, store: &FeedStore) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(store).map_err(|e| e.to_string())?;
+    std::fs::write(rss_dir(app)?.join("feeds.json"), json).map_err(|e| e.to_string())
+}
+
+fn feed_cache_path(app: &AppHandle, url: &str) -> Result<std::path::PathBuf, String> {
+    use sha2::{Digest, Sha256};
+
+    let digest = Sha256::digest(url.as_bytes());
+    let name: String = digest[..8].iter().map(|byte| format!("{:02x}", byte)).collect();
+    Ok(rss_dir(app)?.join(format!("{}.json", name)))
+}
+
+/// Dedupe key: the link when present, otherwise the normalized title.
+fn item_key(item: &FeedItem) -> String {
+    item.link
+        .clone()
+        .unwrap_or_else(|| item.title.to_lowercase().split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+fn strip_html(text: &str) -> String {
+    let mut plain = String::new();
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => plain.push(c),
+            _ => {}
+        }
+    }
+    plain.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+async fn download_feed(feed: &FeedConfig) -> Result<Vec<FeedItem>, String> {
+    let request = crate::proxy::client().get(&feed.url).timeout(Duration::from_secs(15));
+    let bytes = crate::egress::send(crate::bandwidth::Feature::Feeds, request)
+        .await?
+        .bytes()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let parsed = feed_rs::parser::parse(&bytes[..]).map_err(|e| e.to_string())?;
+    let feed_title = feed
+        .title
+        .clone()
+        .or_else(|| parsed.title.as_ref().map(|title| title.content.clone()))
+        .unwrap_or_else(|| feed.url.clone());
+
+    Ok(parsed
+        .entries
+        .into_iter()
+        .map(|entry| FeedItem {
+            title: entry.title.map(|title| title.content).unwrap_or_default(),
+            link: entry.links.first().map(|link| link.href.clone()),
+            summary: entry.summary.map(|summary| strip_html(&summary.content)),
+            published: entry.published.or(entry.updated).map(|date| date.timestamp()),
+            feed: feed_title.clone(),
+            id: entry.id,
+        })
+        .collect())
+}
+
+/// Fetches a feed through the response cache; the last good copy on disk covers outages.
+async fn fetch_feed(app: &AppHandle, feed: &FeedConfig, refresh: bool) -> Result<Vec<FeedItem>, String> {
+    let key = format!("rss:{}", feed.url);
+
+    let items = crate::cache::cached(&key, FEED_TTL, refresh, || async {
+        let path = feed_cache_path(app, &feed.url)?;
+        match download_feed(feed).await {
+            Ok(items) => {
+                let json = serde_json::to_value(&items).map_err(|e| e.to_string())?;
+                let _ = std::fs::write(&path, json.to_string());
+                Ok(json)
+            }
+            Err(e) => {
+                println!("⚠️  Feed {} unavailable ({}), using saved copy", feed.url, e);
+                std::fs::read_to_string(&path)
+                    .ok()
+                    .and_then(|json| serde_json::from_str(&json).ok())
+                    .ok_or(e)
+            }
+        }
+    })
+    .await?;
+
+    serde_json::from_value(items).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn add_feed(app: AppHandle, url: String, title: Option<String>) -> Result<FeedConfig, String> {
+    let mut store = load_store(&app);
+    if store.feeds.iter().any(|feed| feed.url == url) {
+        return Err(format!("❌ Feed already added: {}", url));
+    }
+
+    let feed = FeedConfig { url, title };
+    let items = fetch_feed(&app, &feed, true).await?;
+    println!("📰 Added feed {} ({} items)", feed.url, items.len());
+
+    store.feeds.push(feed.clone());
+    save_store(&app, &store)?;
+    Ok(feed)
+}
+
+#[tauri::command]
+pub async fn remove_feed(app: AppHandle, url: String) -> Result<(), String> {
+    let mut store = load_store(&app);
+    let before = store.feeds.len();
+    store.feeds.retain(|feed| feed.url != url);
+
+    if store.feeds.len() == before {
+        return Err(format!("❌ Feed not found: {}", url));
+    }
+
+    crate::cache::invalidate(&format!("rss:{}", url));
+    if let Ok(path) = feed_cache_path(&app, &url) {
+        let _ = std::fs::remove_file(path);
+    }
+    save_store(&app, &store)
+}
+
+#[tauri::command]
+pub async fn list_feeds(app: AppHandle) -> Result<Vec<FeedConfig>, String> {
+    Ok(load_store(&app).feeds)
+}
+
+/// Newest unread items across all feeds, deduplicated; returned items are marked read unless `mark_read` is false.
+#[tauri::command]
+pub async fn get_briefing(
+    app: AppHandle,
+    max_items: Option<usize>,
+    include_read: Option<bool>,
+    mark_read: Option<bool>,
+    refresh: Option<bool>,
+) -> Result<Vec<FeedItem>, String> {
+    let mut store = load_store(&app);
+    let refresh = refresh.unwrap_or(false);
+
+    let handles: Vec<_> = store
+        .feeds
+        .iter()
+        .cloned()
+        .map(|feed| {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move { fetch_feed(&app, &feed, refresh).await })
+        })
+        .collect();
+
+    let mut items = Vec::new();
+    for handle in handles {
+        match handle.await.map_err(|e| e.to_string())? {
+            Ok(feed_items) => items.extend(feed_items),
+            Err(e) => println!("⚠️  Skipping feed in briefing: {}", e),
+        }
+    }
+
+    items.sort_by(|a, b| b.published.cmp(&a.published));
+
+    let include_read = include_read.unwrap_or(false);
+    let briefing: Vec<FeedItem> = {
+        let read: HashSet<&String> = store.read.iter().collect();
+        let mut seen = HashSet::new();
+        items
+            .into_iter()
+            .filter(|item| seen.insert(item_key(item)))
+            .filter(|item| include_read || !read.contains(&item_key(item)))
+            .take(max_items.unwrap_or(10))
+            .collect()
+    };
+
+    if mark_read.unwrap_or(true) {
+        store.read.extend(briefing.iter().map(item_key));
+        let overflow = store.read.len().saturating_sub(MAX_READ_MARKERS);
+        store.read.drain(..overflow);
+        save_store(&app, &store)?;
+    }
+
+    println!("📰 Briefing: {} items", briefing.len());
+    Ok(briefing)
+}
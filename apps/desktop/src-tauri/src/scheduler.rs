@@ -0,0 +1,177 @@
+/**
+ * Scheduler module - Background task priorities and a global CPU budget per performance profile
+ *
+ * Long-running loops register themselves and check `is_paused` between units of work; the monitor
+ * pauses lower-priority work while realtime tasks (streaming) run or CPU exceeds the profile's budget.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskPriority {
+    /// Never paused: screen/camera streaming
+    Realtime,
+    /// Presence detection and other user-facing loops
+    High,
+    /// Watchers and sync
+    Normal,
+    /// Indexing, OCR batches, model downloads
+    Low,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PerformanceProfile {
+    Battery,
+    Balanced,
+    Performance,
+}
+
+struct Budget {
+    cpu_percent: f32,
+    pause_low_while_realtime: bool,
+    pause_normal_while_realtime: bool,
+}
+
+impl PerformanceProfile {
+    fn budget(self) -> Budget {
+        match self {
+            PerformanceProfile::Battery => Budget {
+                cpu_percent: 40.0,
+                pause_low_while_realtime: true,
+                pause_normal_while_realtime: true,
+            },
+            PerformanceProfile::Balanced => Budget {
+                cpu_percent: 70.0,
+                pause_low_while_realtime: true,
+                pause_normal_while_realtime: false,
+            },
+            PerformanceProfile::Performance => Budget {
+                cpu_percent: 90.0,
+                pause_low_while_realtime: false,
+                pause_normal_while_realtime: false,
+            },
+        }
+    }
+}
+
+pub struct SchedulerState {
+    pub profile: PerformanceProfile,
+    pub tasks: BTreeMap<String, TaskPriority>,
+    pub paused: BTreeSet<String>,
+    pub cpu_percent: f32,
+}
+
+impl Default for SchedulerState {
+    fn default() -> Self {
+        SchedulerState {
+            profile: PerformanceProfile::Balanced,
+            tasks: BTreeMap::new(),
+            paused: BTreeSet::new(),
+            cpu_percent: 0.0,
+        }
+    }
+}
+
+fn should_pause(budget: &Budget, priority: TaskPriority, realtime_active: bool, cpu: f32) -> bool {
+    match priority {
+        TaskPriority::Realtime => false,
+        TaskPriority::High => cpu > budget.cpu_percent + 30.0,
+        TaskPriority::Normal => (realtime_active && budget.pause_normal_while_realtime) || cpu > budget.cpu_percent + 15.0,
+        TaskPriority::Low => (realtime_active && budget.pause_low_while_realtime) || cpu > budget.cpu_percent,
+    }
+}
+
+/// Recomputes the paused set, emitting `scheduler://changed` when it differs.
+fn rebalance(app: &AppHandle) {
+    let snapshot = {
+        let state = app.state::<Mutex<SchedulerState>>();
+        let mut scheduler = state.lock().unwrap();
+
+        let budget = scheduler.profile.budget();
+        let realtime_active = scheduler.tasks.values().any(|priority| *priority == TaskPriority::Realtime);
+        let paused: BTreeSet<String> = scheduler
+            .tasks
+            .iter()
+            .filter(|(_, priority)| should_pause(&budget, **priority, realtime_active, scheduler.cpu_percent))
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        if paused == scheduler.paused {
+            return;
+        }
+        scheduler.paused = paused;
+        status_json(&scheduler)
+    };
+
+    println!("⚖️  Scheduler paused: {}", snapshot["paused"]);
+    let _ = app.emit_all("scheduler://changed", snapshot);
+}
+
+fn status_json(scheduler: &SchedulerState) -> serde_json::Value {
+    serde_json::json!({
+        "profile": scheduler.profile,
+        "cpuPercent": scheduler.cpu_percent,
+        "cpuBudget": scheduler.profile.budget().cpu_percent,
+        "tasks": scheduler.tasks,
+        "paused": scheduler.paused,
+    })
+}
+
+pub fn register_task(app: &AppHandle, name: &str, priority: TaskPriority) {
+    app.state::<Mutex<SchedulerState>>().lock().unwrap().tasks.insert(name.to_string(), priority);
+    rebalance(app);
+}
+
+pub fn unregister_task(app: &AppHandle, name: &str) {
+    {
+        let state = app.state::<Mutex<SchedulerState>>();
+        let mut scheduler = state.lock().unwrap();
+        scheduler.tasks.remove(name);
+        scheduler.paused.remove(name);
+    }
+    rebalance(app);
+}
+
+pub fn is_paused(app: &AppHandle, name: &str) -> bool {
+    app.state::<Mutex<SchedulerState>>().lock().unwrap().paused.contains(name)
+}
+
+/// Samples CPU usage and keeps the paused set current.
+pub fn spawn_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        use sysinfo::{CpuExt, System, SystemExt};
+
+        let mut sys = System::new();
+
+        loop {
+            sys.refresh_cpu();
+            tokio::time::sleep(SAMPLE_INTERVAL).await;
+            sys.refresh_cpu();
+
+            app.state::<Mutex<SchedulerState>>().lock().unwrap().cpu_percent = sys.global_cpu_info().cpu_usage();
+            rebalance(&app);
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn set_performance_profile(app: AppHandle, profile: PerformanceProfile) -> Result<serde_json::Value, String> {
+    app.state::<Mutex<SchedulerState>>().lock().unwrap().profile = profile;
+    println!("⚖️  Performance profile: {:?}", profile);
+    rebalance(&app);
+
+    Ok(status_json(&app.state::<Mutex<SchedulerState>>().lock().unwrap()))
+}
+
+#[tauri::command]
+pub async fn get_scheduler_status(state: State<'_, Mutex<SchedulerState>>) -> Result<serde_json::Value, String> {
+    Ok(status_json(&state.lock().unwrap()))
+}
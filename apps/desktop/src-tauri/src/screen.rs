@@ -1,13 +1,49 @@
 /**
  * Screen module - Screen capture and streaming
+ *
+ * Stream frames are captured from the primary display, scaled by the frame scaler (GPU when available) and emitted
+ * as `screen://frame` with a `jarvisx://` blob of the JPEG.
  */
 
+use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
-use tauri::State;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdaptivePolicy {
+    pub enabled: bool,
+    /// CPU usage (percent) above which quality steps down
+    pub max_cpu_percent: f32,
+    /// CPU usage (percent) below which quality steps back up
+    pub idle_cpu_percent: f32,
+    /// Battery discharge rate (watts) above which quality steps down
+    pub max_battery_drain_watts: Option<f32>,
+    pub sample_interval_secs: u64,
+}
+
+impl Default for AdaptivePolicy {
+    fn default() -> Self {
+        AdaptivePolicy {
+            enabled: true,
+            max_cpu_percent: 75.0,
+            idle_cpu_percent: 35.0,
+            max_battery_drain_watts: Some(20.0),
+            sample_interval_secs: 5,
+        }
+    }
+}
 
 pub struct ScreenState {
     pub is_streaming: bool,
     pub stream_quality: String,
+    /// Quality requested by the caller; the adaptive controller never exceeds it
+    pub requested_quality: String,
+    pub adaptive_policy: Option<AdaptivePolicy>,
+    pub controller_generation: u64,
+    /// Bumped on every start so a restarted stream never shares the screen with an old loop
+    pub stream_generation: u64,
 }
 
 impl Default for ScreenState {
@@ -15,10 +51,199 @@ impl Default for ScreenState {
         ScreenState {
             is_streaming: false,
             stream_quality: "medium".to_string(),
+            requested_quality: "medium".to_string(),
+            adaptive_policy: None,
+            controller_generation: 0,
+            stream_generation: 0,
+        }
+    }
+}
+
+const QUALITY_LEVELS: [&str; 3] = ["low", "medium", "high"];
+
+/// Frames per second and resolution scale for a stream quality level.
+pub fn quality_settings(quality: &str) -> (u32, f32) {
+    match quality {
+        "high" => (30, 1.0),
+        "low" => (5, 0.5),
+        _ => (15, 0.75),
+    }
+}
+
+fn quality_index(quality: &str) -> usize {
+    QUALITY_LEVELS.iter().position(|level| *level == quality).unwrap_or(1)
+}
+
+fn battery_drain_watts() -> Option<f32> {
+    let manager = battery::Manager::new().ok()?;
+    let battery = manager.batteries().ok()?.next()?.ok()?;
+
+    match battery.state() {
+        battery::State::Discharging => Some(battery.energy_rate().value),
+        _ => None,
+    }
+}
+
+async fn run_adaptive_controller(app: AppHandle, generation: u64) {
+    use sysinfo::{CpuExt, System, SystemExt};
+
+    let mut sys = System::new();
+
+    loop {
+        let policy = {
+            let state = app.state::<Mutex<ScreenState>>();
+            let screen_state = state.lock().unwrap();
+            match &screen_state.adaptive_policy {
+                Some(policy) if policy.enabled && screen_state.controller_generation == generation => policy.clone(),
+                _ => return,
+            }
+        };
+
+        sys.refresh_cpu();
+        tokio::time::sleep(Duration::from_secs(policy.sample_interval_secs.max(1))).await;
+        sys.refresh_cpu();
+
+        let cpu = sys.global_cpu_info().cpu_usage();
+        let drain = battery_drain_watts();
+        let over_budget = cpu > policy.max_cpu_percent
+            || matches!((drain, policy.max_battery_drain_watts), (Some(d), Some(max)) if d > max);
+        let idle = cpu < policy.idle_cpu_percent && !over_budget;
+
+        let changed = {
+            let state = app.state::<Mutex<ScreenState>>();
+            let mut screen_state = state.lock().unwrap();
+            if !screen_state.is_streaming {
+                None
+            } else {
+                let current = quality_index(&screen_state.stream_quality);
+                let ceiling = quality_index(&screen_state.requested_quality);
+                let next = if over_budget && current > 0 {
+                    current - 1
+                } else if idle && current < ceiling {
+                    current + 1
+                } else {
+                    current
+                };
+
+                if next != current {
+                    screen_state.stream_quality = QUALITY_LEVELS[next].to_string();
+                    Some(screen_state.stream_quality.clone())
+                } else {
+                    None
+                }
+            }
+        };
+
+        if let Some(quality) = changed {
+            let (fps, scale) = quality_settings(&quality);
+            println!("📹 Adaptive streaming: {} (cpu {:.0}%)", quality, cpu);
+            let _ = app.emit_all("screen://stream-quality-changed", serde_json::json!({
+                "quality": quality,
+                "fps": fps,
+                "scale": scale,
+                "cpuPercent": cpu,
+                "batteryDrainWatts": drain,
+            }));
         }
     }
 }
 
+#[tauri::command]
+pub async fn set_adaptive_streaming(
+    app: AppHandle,
+    state: State<'_, Mutex<ScreenState>>,
+    policy: Option<AdaptivePolicy>,
+) -> Result<AdaptivePolicy, String> {
+    let policy = policy.unwrap_or_default();
+
+    let generation = {
+        let mut screen_state = state.lock().unwrap();
+        screen_state.controller_generation += 1;
+        screen_state.adaptive_policy = Some(policy.clone());
+
+        if !policy.enabled {
+            // Hand control back to the requested quality
+            screen_state.stream_quality = screen_state.requested_quality.clone();
+        }
+        screen_state.controller_generation
+    };
+
+    if policy.enabled {
+        tauri::async_runtime::spawn(run_adaptive_controller(app, generation));
+    }
+
+    Ok(policy)
+}
+
+/// Grabs the primary display as RGBA.
+fn capture_frame() -> Result<image::RgbaImage, String> {
+    if crate::mock_platform::is_active() {
+        let png = crate::mock_platform::capture(0, None)?;
+        return image::load_from_memory(&png).map(|image| image.to_rgba8()).map_err(|e| e.to_string());
+    }
+
+    let screens = crate::subsystems::screens()?;
+    let screen = screens.first().ok_or("No screens found")?;
+    screen.capture().map_err(|e| {
+        crate::subsystems::invalidate_screens();
+        e.to_string()
+    })
+}
+
+fn run_screen_stream(app: AppHandle, generation: u64) {
+    use std::io::Cursor;
+    use std::time::Instant;
+
+    let mut held_back = false;
+    loop {
+        let quality = {
+            let state = app.state::<Mutex<ScreenState>>();
+            let screen_state = state.lock().unwrap();
+            if !screen_state.is_streaming || screen_state.stream_generation != generation {
+                return;
+            }
+            screen_state.stream_quality.clone()
+        };
+        let (fps, scale) = quality_settings(&quality);
+        let started = Instant::now();
+
+        // Frames are held back, not queued, while a metered connection's cap is reached
+        let admitted = crate::bandwidth::admit(crate::bandwidth::Feature::ScreenStream);
+        if let (Err(reason), false) = (&admitted, held_back) {
+            let _ = app.emit_all("screen://stream-paused", reason);
+        }
+        held_back = admitted.is_err();
+
+        if !held_back && !crate::scheduler::is_paused(&app, "screen-stream") {
+            match capture_frame() {
+                Ok(frame) => {
+                    let width = (frame.width() as f32 * scale) as u32;
+                    let height = (frame.height() as f32 * scale) as u32;
+                    let scaled = crate::frame_scaler::scale_frame(&frame, width, height);
+
+                    let mut cursor = Cursor::new(Vec::new());
+                    if image::DynamicImage::ImageRgb8(scaled).write_to(&mut cursor, image::ImageOutputFormat::Jpeg(70)).is_ok() {
+                        let jpeg = cursor.into_inner();
+                        crate::bandwidth::record(crate::bandwidth::Feature::ScreenStream, jpeg.len() as u64, 0);
+                        let _ = app.emit_all("screen://frame", serde_json::json!({
+                            "frame": crate::binary::store_blob(jpeg, "image/jpeg"),
+                            "width": width,
+                            "height": height,
+                            "quality": quality,
+                        }));
+                    }
+                }
+                Err(e) => {
+                    let _ = app.emit_all("screen://stream-error", e);
+                }
+            }
+        }
+
+        let interval = Duration::from_millis(1000 / fps.max(1) as u64);
+        std::thread::sleep(interval.saturating_sub(started.elapsed()));
+    }
+}
+
 #[tauri::command]
 pub async fn capture_screen() -> Result<String, String> {
     println!("📸 Capturing screen");
@@ -30,33 +255,49 @@ pub async fn capture_screen() -> Result<String, String> {
 
 #[tauri::command]
 pub async fn start_screen_stream(
+    app: AppHandle,
     state: State<'_, Mutex<ScreenState>>,
     quality: String
 ) -> Result<String, String> {
-    let mut screen_state = state.lock().unwrap();
-    
-    if screen_state.is_streaming {
-        return Err("Screen streaming already active".to_string());
-    }
+    crate::screen_permission::ensure_screen_capture()?;
 
-    screen_state.is_streaming = true;
-    screen_state.stream_quality = quality;
+    let generation = {
+        let mut screen_state = state.lock().unwrap();
+        
+        if screen_state.is_streaming {
+            return Err("Screen streaming already active".to_string());
+        }
+
+        screen_state.is_streaming = true;
+        screen_state.requested_quality = quality.clone();
+        screen_state.stream_quality = quality;
+        screen_state.stream_generation += 1;
+        screen_state.stream_generation
+    };
     
+    crate::scheduler::register_task(&app, "screen-stream", crate::scheduler::TaskPriority::Realtime);
+    crate::recovery::track("stream:screen", "stream", serde_json::json!({ "source": "screen" }));
     println!("📹 Screen streaming started");
-    
-    // TODO: Setup WebRTC stream or periodic screenshots
+
+    let handle = app.clone();
+    std::thread::spawn(move || run_screen_stream(handle, generation));
     Ok("Screen streaming started".to_string())
 }
 
 #[tauri::command]
-pub async fn stop_screen_stream(state: State<'_, Mutex<ScreenState>>) -> Result<String, String> {
-    let mut screen_state = state.lock().unwrap();
-    
-    if !screen_state.is_streaming {
-        return Err("Screen streaming not active".to_string());
+pub async fn stop_screen_stream(app: AppHandle, state: State<'_, Mutex<ScreenState>>) -> Result<String, String> {
+    {
+        let mut screen_state = state.lock().unwrap();
+        
+        if !screen_state.is_streaming {
+            return Err("Screen streaming not active".to_string());
+        }
+
+        screen_state.is_streaming = false;
     }
 
-    screen_state.is_streaming = false;
+    crate::scheduler::unregister_task(&app, "screen-stream");
+    crate::recovery::untrack("stream:screen");
     println!("📹 Screen streaming stopped");
     
     Ok("Screen streaming stopped".to_string())
@@ -0,0 +1,112 @@
+/**
+ * Screen permission module - Pre-flight check of screen-recording authorization before any capture or stream
+ *
+ * A missing permission is reported as a PermissionDenied error (JSON in the error string) with remediation steps,
+ * instead of an opaque capture failure or a silently black image. Callers that can live without pixels may fall
+ * back to window titles only.
+ */
+
+use serde::Serialize;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenCaptureAccess {
+    pub granted: bool,
+    pub platform: String,
+    /// What the user can do to grant access; None when granted
+    pub remediation: Option<String>,
+    /// Deep link into the relevant system settings page, where one exists
+    pub settings_url: Option<String>,
+}
+
+#[cfg(target_os = "macos")]
+pub fn screen_capture_access() -> ScreenCaptureAccess {
+    #[link(name = "CoreGraphics", kind = "framework")]
+    extern "C" {
+        fn CGPreflightScreenCaptureAccess() -> bool;
+    }
+
+    // Preflight never prompts; it only reports the current TCC decision (macOS 10.15+)
+    let granted = unsafe { CGPreflightScreenCaptureAccess() };
+    ScreenCaptureAccess {
+        granted,
+        platform: "macos".to_string(),
+        remediation: (!granted).then(|| {
+            "Allow JarvisX under System Settings → Privacy & Security → Screen Recording, then restart JarvisX".to_string()
+        }),
+        settings_url: (!granted).then(|| "x-apple.systempreferences:com.apple.preference.security?Privacy_ScreenCapture".to_string()),
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub fn screen_capture_access() -> ScreenCaptureAccess {
+    // Desktop apps need no consent to capture the screen on Windows
+    ScreenCaptureAccess { granted: true, platform: "windows".to_string(), remediation: None, settings_url: None }
+}
+
+#[cfg(target_os = "linux")]
+pub fn screen_capture_access() -> ScreenCaptureAccess {
+    // X11 capture is unrestricted; Wayland compositors refuse direct capture outside the portal
+    let wayland = std::env::var("XDG_SESSION_TYPE").map_or(false, |session| session == "wayland")
+        || std::env::var_os("WAYLAND_DISPLAY").is_some();
+    ScreenCaptureAccess {
+        granted: !wayland,
+        platform: if wayland { "linux-wayland" } else { "linux-x11" }.to_string(),
+        remediation: wayland.then(|| "Screen capture is blocked on Wayland sessions; log in with an X11 (Xorg) session to enable it".to_string()),
+        settings_url: None,
+    }
+}
+
+/// The PermissionDenied error, serialized so the frontend can tell it apart from other failures.
+pub fn permission_denied(access: &ScreenCaptureAccess) -> String {
+    serde_json::json!({
+        "error": "PermissionDenied",
+        "permission": "screenRecording",
+        "message": "❌ Screen recording permission is not granted",
+        "remediation": access.remediation,
+        "settingsUrl": access.settings_url,
+    })
+    .to_string()
+}
+
+/// Fails with PermissionDenied when the screen can't be captured; the mock platform is always allowed.
+pub fn ensure_screen_capture() -> Result<(), String> {
+    if crate::mock_platform::is_active() {
+        return Ok(());
+    }
+
+    let access = screen_capture_access();
+    if access.granted {
+        Ok(())
+    } else {
+        Err(permission_denied(&access))
+    }
+}
+
+pub fn is_permission_denied(error: &str) -> bool {
+    error.starts_with('{') && error.contains("\"PermissionDenied\"")
+}
+
+/// Degraded context when pixels are unavailable: the active window and the titles of open windows.
+pub async fn title_only_context(app: &AppHandle) -> serde_json::Value {
+    let registry = app.state::<Mutex<crate::window_registry::WindowRegistry>>();
+    let windows = crate::window_registry::cached_window_list(&registry, false).await.unwrap_or_default();
+
+    serde_json::json!({
+        "degraded": true,
+        "reason": "screenRecordingDenied",
+        "activeWindow": crate::system::get_active_window().await.ok(),
+        "openWindows": windows
+            .iter()
+            .map(crate::window_registry::window_title)
+            .filter(|title| !title.is_empty())
+            .collect::<Vec<_>>(),
+    })
+}
+
+#[tauri::command]
+pub async fn check_screen_capture_permission() -> Result<ScreenCaptureAccess, String> {
+    Ok(screen_capture_access())
+}
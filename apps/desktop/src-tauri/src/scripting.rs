@@ -0,0 +1,291 @@
+/**
+ * Scripting module - Sandboxed Rhai scripts for user-defined conditional logic
+ *
+ * Scripts can only reach the desktop through `call(command, args)` for whitelisted commands,
+ * read the triggering `event`, and keep state with `get_var`/`set_var`. Each call is checked against the
+ * capability the script was started with.
+ */
+
+use rhai::{Dynamic, Engine, EvalAltResult, Map, Scope};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+const SCRIPT_EXTENSION: &str = "rhai";
+const MAX_OPERATIONS: u64 = 1_000_000;
+pub const DEFAULT_TIME_LIMIT: Duration = Duration::from_secs(10);
+
+/// Commands a script may invoke through `call`.
+const SCRIPT_COMMANDS: &[&str] = &[
+    "get_clipboard_content",
+    "set_clipboard_content",
+    "send_notification",
+    "get_active_window",
+    "open_application",
+    "open_with",
+    "open_uri",
+    "type_text",
+    "press_hotkey",
+    "get_system_theme",
+    "get_vpn_status",
+    "print_file",
+];
+
+fn scripts_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = crate::profiles::active_profile_dir(app)?.join("scripts");
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+fn script_path(app: &AppHandle, name: &str) -> Result<PathBuf, String> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(format!("❌ Invalid script name: {}", name));
+    }
+    Ok(scripts_dir(app)?.join(format!("{}.{}", name, SCRIPT_EXTENSION)))
+}
+
+pub fn load_script(app: &AppHandle, name: &str) -> Result<String, String> {
+    std::fs::read_to_string(script_path(app, name)?).map_err(|e| format!("❌ Script not found: {} ({})", name, e))
+}
+
+fn load_vars(app: &AppHandle) -> serde_json::Map<String, serde_json::Value> {
+    scripts_dir(app)
+        .ok()
+        .and_then(|dir| std::fs::read_to_string(dir.join("vars.json")).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_vars(app: &AppHandle, vars: &serde_json::Map<String, serde_json::Value>) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(vars).map_err(|e| e.to_string())?;
+    std::fs::write(scripts_dir(app)?.join("vars.json"), json).map_err(|e| e.to_string())
+}
+
+fn str_arg(args: &serde_json::Value, key: &str) -> Result<String, String> {
+    args.get(key)
+        .and_then(|value| value.as_str())
+        .map(|value| value.to_string())
+        .ok_or_else(|| format!("Missing string argument: {}", key))
+}
+
+fn to_json<T: serde::Serialize>(value: T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap_or(serde_json::Value::Null)
+}
+
+/// Runs one whitelisted command on behalf of a script, scoped to the capability that started it. Scripts
+/// started without one (triggers, voice shortcuts, the local UI) may only touch paths in the home folder.
+pub fn dispatch(
+    app: &AppHandle,
+    command: &str,
+    args: serde_json::Value,
+    capability_token: Option<&str>,
+) -> Result<serde_json::Value, String> {
+    if !SCRIPT_COMMANDS.contains(&command) {
+        return Err(format!("❌ Command not available to scripts: {}", command));
+    }
+    let args = crate::permissions::authorize_nested(app, capability_token, command, &args)?;
+    if capability_token.is_none() {
+        if let Some(path) = args.get("path").and_then(|path| path.as_str()) {
+            crate::permissions::authorize_path(app, None, path)?;
+        }
+    }
+
+    use tauri::async_runtime::block_on;
+
+    match command {
+        "get_clipboard_content" => block_on(crate::system::get_clipboard_content()).map(to_json),
+        "set_clipboard_content" => block_on(crate::system::set_clipboard_content(str_arg(&args, "content")?)).map(to_json),
+        "send_notification" => {
+            block_on(crate::system::send_notification(app.clone(), str_arg(&args, "title")?, str_arg(&args, "body")?)).map(to_json)
+        }
+        "get_active_window" => block_on(crate::system::get_active_window()).map(to_json),
+        "open_application" => {
+            let launch_args = serde_json::from_value(args["args"].clone()).ok();
+            let wait = args["waitUntilReady"].as_bool();
+            block_on(crate::commands::open_application(app.clone(), str_arg(&args, "appName")?, launch_args, wait, args["timeoutMs"].as_u64()))
+                .map(to_json)
+        }
+        "open_with" => block_on(crate::commands::open_with(str_arg(&args, "path")?, str_arg(&args, "appName")?)).map(to_json),
+        "open_uri" => block_on(crate::commands::open_uri(str_arg(&args, "uri")?)).map(to_json),
+        "type_text" => block_on(crate::system::type_text(str_arg(&args, "text")?, args["mode"].as_str().map(String::from))).map(to_json),
+        "press_hotkey" => {
+            let modifiers = serde_json::from_value(args["modifiers"].clone()).unwrap_or_default();
+            block_on(crate::system::press_hotkey(modifiers, str_arg(&args, "key")?)).map(to_json)
+        }
+        "get_system_theme" => block_on(crate::theme::get_system_theme()).map(to_json),
+        "get_vpn_status" => block_on(crate::vpn::get_vpn_status(app.clone())),
+        "print_file" => {
+            let options = serde_json::from_value(args["options"].clone()).ok();
+            block_on(crate::printing::print_file(str_arg(&args, "path")?, args["printer"].as_str().map(String::from), options))
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn build_engine(
+    app: &AppHandle,
+    vars: Arc<Mutex<serde_json::Map<String, serde_json::Value>>>,
+    output: Arc<Mutex<Vec<String>>>,
+    time_limit: Duration,
+    capability_token: Option<String>,
+) -> Engine {
+    let mut engine = Engine::new();
+
+    engine.set_max_operations(MAX_OPERATIONS);
+    engine.set_max_call_levels(32);
+    engine.set_max_expr_depths(64, 32);
+    engine.set_max_string_size(64 * 1024);
+    engine.set_max_array_size(10_000);
+    engine.set_max_map_size(10_000);
+    engine.disable_symbol("eval");
+
+    let started = Instant::now();
+    let guard_app = app.clone();
+    let input_mark = AtomicU64::new(crate::automation_guard::input_mark());
+    let paused_ms = AtomicU64::new(0);
+    engine.on_progress(move |_| {
+        // User input pauses or aborts the script per the automation guard policy; paused time doesn't count
+        let mark = input_mark.load(Ordering::Relaxed);
+        if crate::automation_guard::input_mark() != mark {
+            let pause_started = Instant::now();
+            if let Err(e) = crate::automation_guard::wait_if_user_active_blocking(&guard_app, "script", mark) {
+                return Some(Dynamic::from(e));
+            }
+            paused_ms.fetch_add(pause_started.elapsed().as_millis() as u64, Ordering::Relaxed);
+            input_mark.store(crate::automation_guard::input_mark(), Ordering::Relaxed);
+        }
+
+        if started.elapsed().saturating_sub(Duration::from_millis(paused_ms.load(Ordering::Relaxed))) > time_limit {
+            Some(Dynamic::from("Script time limit exceeded"))
+        } else {
+            None
+        }
+    });
+
+    let print_output = output.clone();
+    engine.on_print(move |line| print_output.lock().unwrap().push(line.to_string()));
+    engine.on_debug(move |line, _, _| output.lock().unwrap().push(line.to_string()));
+
+    let (call_app, call_token) = (app.clone(), capability_token.clone());
+    engine.register_fn("call", move |command: &str, args: Map| -> Result<Dynamic, Box<EvalAltResult>> {
+        let args: serde_json::Value = rhai::serde::from_dynamic(&Dynamic::from_map(args))?;
+        let result = dispatch(&call_app, command, args, call_token.as_deref())?;
+        rhai::serde::to_dynamic(result)
+    });
+    let call_app = app.clone();
+    engine.register_fn("call", move |command: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+        let result = dispatch(&call_app, command, serde_json::json!({}), capability_token.as_deref())?;
+        rhai::serde::to_dynamic(result)
+    });
+
+    let get_vars = vars.clone();
+    engine.register_fn("get_var", move |key: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+        match get_vars.lock().unwrap().get(key) {
+            Some(value) => rhai::serde::to_dynamic(value),
+            None => Ok(Dynamic::UNIT),
+        }
+    });
+    engine.register_fn("set_var", move |key: &str, value: Dynamic| -> Result<(), Box<EvalAltResult>> {
+        let value: serde_json::Value = rhai::serde::from_dynamic(&value)?;
+        vars.lock().unwrap().insert(key.to_string(), value);
+        Ok(())
+    });
+
+    engine
+}
+
+pub fn execute(
+    app: &AppHandle,
+    source: &str,
+    event: serde_json::Value,
+    time_limit: Duration,
+    capability_token: Option<&str>,
+) -> Result<serde_json::Value, String> {
+    let vars = Arc::new(Mutex::new(load_vars(app)));
+    let output = Arc::new(Mutex::new(Vec::new()));
+    let engine = build_engine(app, vars.clone(), output.clone(), time_limit, capability_token.map(String::from));
+
+    let mut scope = Scope::new();
+    scope.push_dynamic("event", rhai::serde::to_dynamic(event).map_err(|e| e.to_string())?);
+
+    let started = Instant::now();
+    let result = engine.eval_with_scope::<Dynamic>(&mut scope, source);
+
+    // Variables persist even when the script fails part-way
+    save_vars(app, &vars.lock().unwrap())?;
+
+    let output = output.lock().unwrap().clone();
+    match result {
+        Ok(value) => Ok(serde_json::json!({
+            "result": rhai::serde::from_dynamic::<serde_json::Value>(&value).unwrap_or(serde_json::Value::Null),
+            "output": output,
+            "durationMs": started.elapsed().as_millis() as u64,
+        })),
+        Err(e) => Err(format!("❌ Script failed: {}", e)),
+    }
+}
+
+#[tauri::command]
+pub async fn save_script(app: AppHandle, name: String, source: String) -> Result<(), String> {
+    // Compile once so syntax errors surface at save time
+    Engine::new().compile(&source).map_err(|e| format!("❌ Syntax error: {}", e))?;
+    std::fs::write(script_path(&app, &name)?, source).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_script(app: AppHandle, name: String) -> Result<(), String> {
+    std::fs::remove_file(script_path(&app, &name)?).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn list_scripts(app: AppHandle) -> Result<Vec<serde_json::Value>, String> {
+    let mut scripts: Vec<serde_json::Value> = std::fs::read_dir(scripts_dir(&app)?)
+        .map_err(|e| e.to_string())?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some(SCRIPT_EXTENSION))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_str()?.to_string();
+            let size = std::fs::metadata(&path).map(|meta| meta.len()).unwrap_or(0);
+            Some(serde_json::json!({ "name": name, "path": path, "size": size }))
+        })
+        .collect();
+
+    scripts.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+    Ok(scripts)
+}
+
+/// Runs a saved script by name, or inline `source` when given. The script's calls are limited to what
+/// `capability_token` allows.
+#[tauri::command]
+pub async fn run_script(
+    app: AppHandle,
+    name: Option<String>,
+    source: Option<String>,
+    event: Option<serde_json::Value>,
+    time_limit_ms: Option<u64>,
+    capability_token: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let source = match (source, &name) {
+        (Some(source), _) => source,
+        (None, Some(name)) => load_script(&app, name)?,
+        (None, None) => return Err("Provide a script name or source".to_string()),
+    };
+    let time_limit = time_limit_ms
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_TIME_LIMIT)
+        .min(Duration::from_secs(60));
+
+    println!("📜 Running script: {}", name.as_deref().unwrap_or("<inline>"));
+
+    let user_snapshot = crate::automation_guard::snapshot();
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        execute(&app, &source, event.unwrap_or(serde_json::Value::Null), time_limit, capability_token.as_deref())
+    })
+    .await
+    .map_err(|e| e.to_string());
+    crate::automation_guard::restore(user_snapshot).await;
+    result?
+}
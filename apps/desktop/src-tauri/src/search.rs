@@ -0,0 +1,219 @@
+/**
+ * Search module - Spotlight-style search across apps, files, clipboard history, memories and open windows
+ */
+
+use serde::Serialize;
+#[cfg(not(target_os = "windows"))]
+use std::process::Command;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+use crate::watchdog::WatchdogCommand;
+use crate::window_registry::WindowRegistry;
+
+const MAX_PER_SOURCE: usize = 20;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    /// "app", "window", "file", "memory" or "clipboard"
+    pub kind: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub score: f64,
+    /// Payload the launcher passes back to act on the result (path, window title, memory id, ...)
+    pub target: serde_json::Value,
+}
+
+/// Scores how well `text` matches `query` (0 = no match): exact, prefix, word prefix, substring, then in-order letters.
+fn match_score(query: &str, text: &str) -> f64 {
+    let query = query.to_lowercase();
+    let text = text.to_lowercase();
+
+    if text == query {
+        return 1.0;
+    }
+    if text.starts_with(&query) {
+        return 0.9;
+    }
+    if text.split(|c: char| !c.is_alphanumeric()).any(|word| word.starts_with(&query)) {
+        return 0.75;
+    }
+    if text.contains(&query) {
+        return 0.6;
+    }
+
+    let mut remaining = text.chars();
+    if query.chars().filter(|c| !c.is_whitespace()).all(|c| remaining.any(|t| t == c)) {
+        return 0.3;
+    }
+
+    0.0
+}
+
+/// Apps and windows are what a launcher is usually after, so they edge out other sources on equal matches.
+fn source_weight(kind: &str) -> f64 {
+    match kind {
+        "app" => 1.0,
+        "window" => 0.95,
+        "file" => 0.85,
+        "memory" => 0.8,
+        _ => 0.7,
+    }
+}
+
+fn result(query: &str, kind: &str, title: String, subtitle: Option<String>, target: serde_json::Value) -> Option<SearchResult> {
+    let score = match_score(query, &title) * source_weight(kind);
+    if score <= 0.0 {
+        return None;
+    }
+
+    Some(SearchResult {
+        kind: kind.to_string(),
+        title,
+        subtitle,
+        score,
+        target,
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn indexed_files(query: &str) -> Vec<String> {
+    // Spotlight's index
+    Command::new("mdfind")
+        .args(&["-name", query])
+        .output_with_timeout()
+        .map(|output| String::from_utf8_lossy(&output.stdout).lines().take(MAX_PER_SOURCE).map(String::from).collect())
+        .unwrap_or_default()
+}
+
+/// Escapes `query` for a Windows Search SQL string literal used with LIKE: quotes are doubled and the
+/// wildcards `%`, `_` and `[` are bracketed so they match themselves.
+#[cfg(any(target_os = "windows", test))]
+fn like_literal(query: &str) -> String {
+    query
+        .chars()
+        .filter(|c| !c.is_control())
+        .map(|c| match c {
+            '\'' => "''".to_string(),
+            '%' | '_' | '[' => format!("[{}]", c),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn indexed_files(query: &str) -> Vec<String> {
+    // Windows Search index; the escaped term reaches the script as data and the SQL as one literal
+    let term = like_literal(query.trim());
+    if term.is_empty() {
+        return Vec::new();
+    }
+
+    let script = "$c = New-Object -ComObject ADODB.Connection; \
+        $c.Open(\"Provider=Search.CollatorDSO;Extended Properties='Application=Windows';\"); \
+        $r = $c.Execute(\"SELECT TOP 20 System.ItemPathDisplay FROM SYSTEMINDEX WHERE System.FileName LIKE '%\" + $env:JARVISX_SEARCH_TERM + \"%'\"); \
+        while (-not $r.EOF) { $r.Fields.Item(0).Value; $r.MoveNext() }";
+
+    crate::commands::powershell(script, &[("JARVISX_SEARCH_TERM", &term)])
+        .output_with_timeout()
+        .map(|output| String::from_utf8_lossy(&output.stdout).lines().map(|line| line.trim().to_string()).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "linux")]
+fn indexed_files(query: &str) -> Vec<String> {
+    // The locate database (mlocate/plocate)
+    Command::new("locate")
+        .args(&["-i", "-b", "-l", &MAX_PER_SOURCE.to_string(), "--", query])
+        .output_with_timeout()
+        .map(|output| String::from_utf8_lossy(&output.stdout).lines().map(String::from).collect())
+        .unwrap_or_default()
+}
+
+fn file_results(query: &str) -> Vec<SearchResult> {
+    indexed_files(query)
+        .into_iter()
+        .filter(|path| !path.is_empty())
+        .filter_map(|path| {
+            let name = std::path::Path::new(&path).file_name()?.to_string_lossy().to_string();
+            result(query, "file", name, Some(path.clone()), serde_json::json!({ "path": path }))
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub async fn universal_search(app: AppHandle, query: String, limit: Option<usize>) -> Result<Vec<SearchResult>, String> {
+    let query = query.trim().to_string();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let registry = app.state::<Mutex<WindowRegistry>>();
+    let file_query = query.clone();
+
+    let (apps, windows, files, memories) = tokio::join!(
+        crate::commands::list_installed_applications(None),
+        crate::window_registry::cached_window_list(&registry, false),
+        tauri::async_runtime::spawn_blocking(move || file_results(&file_query)),
+        crate::memory::list_memories(app.clone(), None, Some(query.clone()), Some(MAX_PER_SOURCE as u32)),
+    );
+
+    let mut results = Vec::new();
+
+    if let Ok(apps) = apps {
+        results.extend(apps.as_array().into_iter().flatten().filter_map(|entry| {
+            let name = entry["name"].as_str()?.to_string();
+            result(&query, "app", name.clone(), entry["path"].as_str().map(String::from), serde_json::json!({ "appName": name }))
+        }));
+    }
+
+    if let Ok(windows) = windows {
+        results.extend(windows.iter().filter_map(|window| {
+            let title = crate::window_registry::window_title(window);
+            result(&query, "window", title.clone(), window["name"].as_str().map(String::from), serde_json::json!({ "title": title }))
+        }));
+    }
+
+    results.extend(files.unwrap_or_default());
+
+    // The store already filtered on title and content, so content-only hits still count as matches
+    if let Ok(memories) = memories {
+        results.extend(memories.into_iter().map(|memory| {
+            let score = match_score(&query, &memory.title).max(0.5) * source_weight("memory");
+            SearchResult {
+                kind: "memory".to_string(),
+                title: memory.title,
+                subtitle: Some(memory.kind),
+                score,
+                target: serde_json::json!({ "id": memory.id }),
+            }
+        }));
+    }
+
+    results.extend(crate::system::clipboard_history().into_iter().filter_map(|entry| {
+        let mut found = result(&query, "clipboard", entry.clone(), None, serde_json::json!({ "content": entry }))?;
+        found.title = found.title.chars().take(80).collect();
+        Some(found)
+    }));
+
+    results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    results.truncate(limit.unwrap_or(30));
+
+    println!("🔎 Search \"{}\": {} results", query, results.len());
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_quotes_and_wildcards_for_like() {
+        assert_eq!(like_literal("report 2024.pdf"), "report 2024.pdf");
+        assert_eq!(like_literal("o'brien"), "o''brien");
+        assert_eq!(like_literal("100%_done[1]"), "100[%][_]done[[]1]");
+        assert_eq!(like_literal("x' OR 1=1 --"), "x'' OR 1=1 --");
+        assert_eq!(like_literal("tab\there"), "tabhere");
+    }
+}
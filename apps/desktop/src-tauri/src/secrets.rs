@@ -0,0 +1,28 @@
+/**
+ * Secrets module - OS keychain backed vault for tokens and credentials
+ */
+
+const SERVICE_NAME: &str = "com.jarvisx.desktop";
+
+fn entry(namespace: &str, key: &str) -> Result<keyring::Entry, String> {
+    keyring::Entry::new(SERVICE_NAME, &format!("{}:{}", namespace, key)).map_err(|e| e.to_string())
+}
+
+pub fn store_secret(namespace: &str, key: &str, value: &str) -> Result<(), String> {
+    entry(namespace, key)?.set_password(value).map_err(|e| e.to_string())
+}
+
+pub fn load_secret(namespace: &str, key: &str) -> Result<Option<String>, String> {
+    match entry(namespace, key)?.get_password() {
+        Ok(value) => Ok(Some(value)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+pub fn delete_secret(namespace: &str, key: &str) -> Result<(), String> {
+    match entry(namespace, key)?.delete_password() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
@@ -0,0 +1,306 @@
+/**
+ * Secure channel module - Noise XX end-to-end encryption for remote control sessions
+ */
+
+use base64::{Engine as _, engine::general_purpose};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, State};
+
+/// X25519 key agreement, ChaCha20-Poly1305 AEAD, BLAKE2s hashing.
+const NOISE_PATTERN: &str = "Noise_XX_25519_ChaChaPoly_BLAKE2s";
+const STATIC_KEY_SECRET: &str = "noise_static_key";
+const MAX_MESSAGE_LEN: usize = 65535;
+
+enum ChannelStage {
+    Handshake(snow::HandshakeState),
+    Transport(snow::TransportState),
+    Failed,
+}
+
+pub struct SecureSession {
+    peer_id: String,
+    stage: ChannelStage,
+    remote_fingerprint: Option<String>,
+    newly_pinned: bool,
+}
+
+pub struct SecureChannelState {
+    pub sessions: HashMap<String, SecureSession>,
+    pub next_session: u64,
+}
+
+impl Default for SecureChannelState {
+    fn default() -> Self {
+        SecureChannelState {
+            sessions: HashMap::new(),
+            next_session: 1,
+        }
+    }
+}
+
+/// Short, human-comparable hex fingerprint of a public key.
+pub fn fingerprint(public_key: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+
+    let digest = Sha256::digest(public_key);
+    digest[..16]
+        .chunks(2)
+        .map(|pair| format!("{:02X}{:02X}", pair[0], pair[1]))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+fn pinned_devices_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("paired_devices.json"))
+}
+
+fn load_pinned_devices(app: &AppHandle) -> HashMap<String, String> {
+    pinned_devices_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_pinned_devices(app: &AppHandle, pinned: &HashMap<String, String>) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(pinned).map_err(|e| e.to_string())?;
+    std::fs::write(pinned_devices_path(app)?, json).map_err(|e| e.to_string())
+}
+
+/// Loads this device's static Noise keypair from the vault, generating it on first use.
+fn local_static_keypair(app: &AppHandle) -> Result<snow::Keypair, String> {
+    let namespace = crate::profiles::active_secrets_namespace(app);
+
+    if let Some(encoded) = crate::secrets::load_secret(&namespace, STATIC_KEY_SECRET)? {
+        let bytes = general_purpose::STANDARD.decode(encoded).map_err(|e| e.to_string())?;
+        if bytes.len() == 64 {
+            return Ok(snow::Keypair {
+                private: bytes[..32].to_vec(),
+                public: bytes[32..].to_vec(),
+            });
+        }
+    }
+
+    let builder = snow::Builder::new(NOISE_PATTERN.parse().map_err(|e: snow::Error| e.to_string())?);
+    let keypair = builder.generate_keypair().map_err(|e| e.to_string())?;
+
+    let mut bytes = keypair.private.clone();
+    bytes.extend_from_slice(&keypair.public);
+    crate::secrets::store_secret(&namespace, STATIC_KEY_SECRET, &general_purpose::STANDARD.encode(bytes))?;
+
+    Ok(keypair)
+}
+
+/// Checks the peer's static key against the pin, pinning it on first contact.
+fn verify_remote_key(app: &AppHandle, session: &mut SecureSession, remote_static: &[u8]) -> Result<(), String> {
+    let remote_fingerprint = fingerprint(remote_static);
+    let mut pinned = load_pinned_devices(app);
+
+    match pinned.get(&session.peer_id) {
+        Some(expected) if *expected != remote_fingerprint => {
+            return Err(format!(
+                "❌ Key mismatch for {}: expected {}, got {}",
+                session.peer_id, expected, remote_fingerprint
+            ));
+        }
+        Some(_) => {}
+        None => {
+            pinned.insert(session.peer_id.clone(), remote_fingerprint.clone());
+            save_pinned_devices(app, &pinned)?;
+            session.newly_pinned = true;
+            println!("📌 Pinned key for {}: {}", session.peer_id, remote_fingerprint);
+        }
+    }
+
+    session.remote_fingerprint = Some(remote_fingerprint);
+    Ok(())
+}
+
+/// Moves the session to transport mode once the handshake has finished.
+fn finish_handshake_if_done(app: &AppHandle, session: &mut SecureSession) -> Result<(), String> {
+    let done = matches!(&session.stage, ChannelStage::Handshake(handshake) if handshake.is_handshake_finished());
+    if !done {
+        return Ok(());
+    }
+
+    let stage = std::mem::replace(&mut session.stage, ChannelStage::Failed);
+    if let ChannelStage::Handshake(handshake) = stage {
+        let remote_static = handshake.get_remote_static().ok_or("Peer did not send a static key")?.to_vec();
+        verify_remote_key(app, session, &remote_static)?;
+        session.stage = ChannelStage::Transport(handshake.into_transport_mode().map_err(|e| e.to_string())?);
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn begin_secure_session(
+    app: AppHandle,
+    state: State<'_, Mutex<SecureChannelState>>,
+    peer_id: String,
+    initiator: bool,
+) -> Result<serde_json::Value, String> {
+    let keypair = local_static_keypair(&app)?;
+    let builder = snow::Builder::new(NOISE_PATTERN.parse().map_err(|e: snow::Error| e.to_string())?)
+        .local_private_key(&keypair.private);
+
+    let mut handshake = if initiator {
+        builder.build_initiator()
+    } else {
+        builder.build_responder()
+    }
+    .map_err(|e| e.to_string())?;
+
+    // The initiator sends the first handshake message (-> e)
+    let first_message = if initiator {
+        let mut buffer = vec![0u8; MAX_MESSAGE_LEN];
+        let len = handshake.write_message(&[], &mut buffer).map_err(|e| e.to_string())?;
+        Some(general_purpose::STANDARD.encode(&buffer[..len]))
+    } else {
+        None
+    };
+
+    let mut channel_state = state.lock().unwrap();
+    let session_id = format!("secure-{}", channel_state.next_session);
+    channel_state.next_session += 1;
+    channel_state.sessions.insert(session_id.clone(), SecureSession {
+        peer_id: peer_id.clone(),
+        stage: ChannelStage::Handshake(handshake),
+        remote_fingerprint: None,
+        newly_pinned: false,
+    });
+
+    println!("🔐 Secure session {} started with {}", session_id, peer_id);
+
+    Ok(serde_json::json!({
+        "sessionId": session_id,
+        "message": first_message,
+        "localFingerprint": fingerprint(&keypair.public),
+    }))
+}
+
+#[tauri::command]
+pub async fn process_handshake_message(
+    app: AppHandle,
+    state: State<'_, Mutex<SecureChannelState>>,
+    session_id: String,
+    message: String,
+) -> Result<serde_json::Value, String> {
+    let incoming = general_purpose::STANDARD.decode(message).map_err(|e| e.to_string())?;
+    let mut channel_state = state.lock().unwrap();
+    let session = channel_state.sessions.get_mut(&session_id).ok_or("Unknown secure session")?;
+
+    let reply = match &mut session.stage {
+        ChannelStage::Handshake(handshake) => {
+            let mut payload = vec![0u8; MAX_MESSAGE_LEN];
+            handshake.read_message(&incoming, &mut payload).map_err(|e| e.to_string())?;
+
+            if !handshake.is_handshake_finished() && handshake.is_my_turn() {
+                let mut buffer = vec![0u8; MAX_MESSAGE_LEN];
+                let len = handshake.write_message(&[], &mut buffer).map_err(|e| e.to_string())?;
+                Some(general_purpose::STANDARD.encode(&buffer[..len]))
+            } else {
+                None
+            }
+        }
+        _ => return Err("Handshake already complete".to_string()),
+    };
+
+    if let Err(e) = finish_handshake_if_done(&app, session) {
+        // A pin mismatch means a possible MITM: never keep the session around
+        channel_state.sessions.remove(&session_id);
+        let _ = app.emit_all("secure-session-rejected", serde_json::json!({ "sessionId": session_id, "reason": e }));
+        return Err(e);
+    }
+
+    let established = matches!(session.stage, ChannelStage::Transport(_));
+    Ok(serde_json::json!({
+        "message": reply,
+        "established": established,
+        "remoteFingerprint": session.remote_fingerprint,
+    }))
+}
+
+#[tauri::command]
+pub async fn encrypt_message(
+    state: State<'_, Mutex<SecureChannelState>>,
+    session_id: String,
+    plaintext: String,
+) -> Result<String, String> {
+    let mut channel_state = state.lock().unwrap();
+    let session = channel_state.sessions.get_mut(&session_id).ok_or("Unknown secure session")?;
+
+    match &mut session.stage {
+        ChannelStage::Transport(transport) => {
+            let mut buffer = vec![0u8; plaintext.len() + 16];
+            let len = transport.write_message(plaintext.as_bytes(), &mut buffer).map_err(|e| e.to_string())?;
+            Ok(general_purpose::STANDARD.encode(&buffer[..len]))
+        }
+        _ => Err("Secure session not established".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn decrypt_message(
+    state: State<'_, Mutex<SecureChannelState>>,
+    session_id: String,
+    ciphertext: String,
+) -> Result<String, String> {
+    let incoming = general_purpose::STANDARD.decode(ciphertext).map_err(|e| e.to_string())?;
+    let mut channel_state = state.lock().unwrap();
+    let session = channel_state.sessions.get_mut(&session_id).ok_or("Unknown secure session")?;
+
+    match &mut session.stage {
+        ChannelStage::Transport(transport) => {
+            let mut buffer = vec![0u8; incoming.len()];
+            let len = transport.read_message(&incoming, &mut buffer).map_err(|e| e.to_string())?;
+            String::from_utf8(buffer[..len].to_vec()).map_err(|e| e.to_string())
+        }
+        _ => Err("Secure session not established".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn close_secure_session(state: State<'_, Mutex<SecureChannelState>>, session_id: String) -> Result<(), String> {
+    state
+        .lock()
+        .unwrap()
+        .sessions
+        .remove(&session_id)
+        .map(|_| ())
+        .ok_or_else(|| "Unknown secure session".to_string())
+}
+
+#[tauri::command]
+pub async fn get_session_security_info(
+    app: AppHandle,
+    state: State<'_, Mutex<SecureChannelState>>,
+    session_id: String,
+) -> Result<serde_json::Value, String> {
+    let local_fingerprint = fingerprint(&local_static_keypair(&app)?.public);
+    let channel_state = state.lock().unwrap();
+    let session = channel_state.sessions.get(&session_id).ok_or("Unknown secure session")?;
+
+    Ok(serde_json::json!({
+        "sessionId": session_id,
+        "peerId": session.peer_id,
+        "protocol": NOISE_PATTERN,
+        "established": matches!(session.stage, ChannelStage::Transport(_)),
+        "localFingerprint": local_fingerprint,
+        "remoteFingerprint": session.remote_fingerprint,
+        "pinnedFingerprint": load_pinned_devices(&app).get(&session.peer_id),
+        "newlyPinned": session.newly_pinned,
+    }))
+}
+
+#[tauri::command]
+pub async fn unpin_device(app: AppHandle, peer_id: String) -> Result<(), String> {
+    let mut pinned = load_pinned_devices(&app);
+
+    if pinned.remove(&peer_id).is_none() {
+        return Err(format!("❌ No pinned key for: {}", peer_id));
+    }
+
+    save_pinned_devices(&app, &pinned)
+}
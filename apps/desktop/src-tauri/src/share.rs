@@ -0,0 +1,393 @@
+/**
+ * Share module - Captures the screen, a region or a window, masks private content and uploads it for a short link
+ *
+ * Before anything leaves the machine, windows of apps on the profile's masked list (password managers by default)
+ * and any caller-given regions are pixelated. Uploads go to the JarvisX backend (signed-in account) or to an
+ * S3-compatible bucket (AWS, R2, B2, MinIO) signed with SigV4; the bucket or its public base URL must be readable
+ * for the link to open. The link is copied to the clipboard. Settings live in `share.json`, keys in the vault.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tauri::{AppHandle, ClipboardManager, Manager};
+
+use crate::window_ids::WindowBounds;
+
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(60);
+const SHORT_ID_LENGTH: usize = 10;
+/// Pixel block size for masked regions, large enough that text can't be read back
+const MASK_BLOCK: u32 = 24;
+const S3_SIGNED_HEADERS: &str = "content-type;host;x-amz-content-sha256;x-amz-date";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ShareProvider {
+    /// Upload to the JarvisX backend under the signed-in account
+    Backend,
+    #[serde(rename_all = "camelCase")]
+    S3 {
+        /// e.g. "https://s3.eu-west-1.amazonaws.com" or "https://<account>.r2.cloudflarestorage.com"
+        endpoint: String,
+        bucket: String,
+        #[serde(default = "default_region")]
+        region: String,
+        /// Where uploaded keys are publicly readable (a CDN or custom domain); defaults to the object URL
+        #[serde(default)]
+        public_base_url: Option<String>,
+        #[serde(default)]
+        prefix: String,
+    },
+}
+
+fn default_region() -> String {
+    "us-east-1".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShareConfig {
+    pub provider: ShareProvider,
+    /// Apps (or window titles) whose windows are always masked, matched case-insensitively
+    #[serde(default = "default_masked_apps")]
+    pub masked_apps: Vec<String>,
+}
+
+fn default_masked_apps() -> Vec<String> {
+    ["1Password", "Bitwarden", "KeePass", "LastPass", "Dashlane", "Keychain Access", "Enpass"]
+        .iter()
+        .map(|app| app.to_string())
+        .collect()
+}
+
+impl Default for ShareConfig {
+    fn default() -> Self {
+        ShareConfig { provider: ShareProvider::Backend, masked_apps: default_masked_apps() }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ShareTarget {
+    /// A whole display (0 = primary)
+    Screen {
+        #[serde(default)]
+        display: Option<usize>,
+    },
+    /// A rectangle of the primary display
+    Region { x: i32, y: i32, width: i32, height: i32 },
+    #[serde(rename_all = "camelCase")]
+    Window { window_id: String },
+}
+
+/// Extra area to mask, in pixels of the captured image.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MaskRegion {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedCapture {
+    pub url: String,
+    /// "backend" or "s3"
+    pub provider: String,
+    pub width: u32,
+    pub height: u32,
+    pub bytes: usize,
+    /// Regions pixelated before upload
+    pub masked_regions: usize,
+}
+
+fn config_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("share.json"))
+}
+
+fn load_config(app: &AppHandle) -> ShareConfig {
+    config_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn vault_key(name: &str) -> String {
+    format!("share:s3:{}", name)
+}
+
+/// Vault keys holding the S3 credentials.
+pub fn vault_keys() -> Vec<String> {
+    ["access_key", "secret_key"].map(vault_key).to_vec()
+}
+
+fn short_id() -> String {
+    use rand::Rng;
+
+    rand::thread_rng()
+        .sample_iter(&rand::distributions::Alphanumeric)
+        .take(SHORT_ID_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+/// Screen-coordinate bounds of a display, for mapping window bounds into the capture.
+fn display_bounds(index: usize) -> Option<WindowBounds> {
+    if crate::mock_platform::is_active() {
+        return None;
+    }
+    let screens = crate::subsystems::screens().ok()?;
+    let info = &screens.get(index)?.display_info;
+    Some(WindowBounds { x: info.x, y: info.y, width: info.width as i32, height: info.height as i32 })
+}
+
+/// PNG of the target and the screen area it covers (None when it spans the whole mock screen).
+async fn capture(target: &ShareTarget) -> Result<(Vec<u8>, Option<WindowBounds>), String> {
+    match target {
+        ShareTarget::Screen { display } => {
+            let index = display.unwrap_or(0);
+            Ok((crate::commands::capture_display_png(index).await?, display_bounds(index)))
+        }
+        ShareTarget::Region { x, y, width, height } => {
+            if *width <= 0 || *height <= 0 {
+                return Err("❌ Region must have a positive width and height".to_string());
+            }
+            let png = crate::commands::capture_screen_region_png(*x, *y, *width, *height).await?;
+            Ok((png, Some(WindowBounds { x: *x, y: *y, width: *width, height: *height })))
+        }
+        ShareTarget::Window { window_id } => {
+            let id = window_id.clone();
+            let window = tauri::async_runtime::spawn_blocking(move || crate::window_ids::resolve(&id))
+                .await
+                .map_err(|e| e.to_string())??;
+            let bounds = window.bounds.ok_or_else(|| format!("❌ No bounds known for {}", window.window_id))?;
+            let png = crate::commands::capture_screen_region_png(bounds.x, bounds.y, bounds.width, bounds.height).await?;
+            Ok((png, Some(bounds)))
+        }
+    }
+}
+
+/// Windows of masked apps that overlap the captured area, in image pixels. Fails when masked apps are configured
+/// but the windows can't be listed, so nothing is uploaded unmasked.
+async fn masked_window_regions(config: &ShareConfig, area: WindowBounds, image_size: (u32, u32)) -> Result<Vec<MaskRegion>, String> {
+    let masked: Vec<String> = config.masked_apps.iter().map(|app| app.to_lowercase()).collect();
+    if masked.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let windows = tauri::async_runtime::spawn_blocking(crate::window_ids::enumerate_windows)
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|windows| windows)
+        .map_err(|e| format!("❌ Could not find windows to mask, nothing was shared: {}", e))?;
+    // Window bounds are in points while captures are in pixels on HiDPI displays
+    let scale = image_size.0 as f64 / area.width.max(1) as f64;
+
+    Ok(windows
+        .iter()
+        .filter(|window| {
+            let (app, title) = (window.app.to_lowercase(), window.title.to_lowercase());
+            masked.iter().any(|name| app.contains(name) || title.contains(name))
+        })
+        .filter_map(|window| window.bounds)
+        .map(|bounds| MaskRegion {
+            x: ((bounds.x - area.x) as f64 * scale) as i32,
+            y: ((bounds.y - area.y) as f64 * scale) as i32,
+            width: (bounds.width.max(0) as f64 * scale).ceil() as u32,
+            height: (bounds.height.max(0) as f64 * scale).ceil() as u32,
+        })
+        .filter(|region| crate::imaging::clamp_region(region.x, region.y, region.width, region.height, image_size).is_some())
+        .collect())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+
+    hex(&Sha256::digest(data))
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Result<Vec<u8>, String> {
+    use hmac::{Hmac, Mac};
+
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(key).map_err(|e| e.to_string())?;
+    mac.update(data.as_bytes());
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+async fn upload_backend(app: &AppHandle, png: Vec<u8>) -> Result<String, String> {
+    let token = crate::auth::access_token(app).await.map_err(|e| format!("❌ Sign in to share through JarvisX: {}", e))?;
+    let file = reqwest::multipart::Part::bytes(png)
+        .file_name("capture.png")
+        .mime_str("image/png")
+        .map_err(|e| e.to_string())?;
+
+    let response = crate::egress::send(
+        crate::bandwidth::Feature::Sharing,
+        crate::proxy::client()
+            .post(format!("{}/share", crate::auth::api_url()))
+            .bearer_auth(token)
+            .timeout(UPLOAD_TIMEOUT)
+            .multipart(reqwest::multipart::Form::new().part("file", file)),
+    )
+    .await?;
+
+    if !response.status().is_success() {
+        return Err(format!("❌ Upload failed: backend returned {}", response.status()));
+    }
+    let body: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+    body["shortUrl"]
+        .as_str()
+        .or_else(|| body["url"].as_str())
+        .map(str::to_string)
+        .ok_or_else(|| "Share response missing url".to_string())
+}
+
+async fn upload_s3(app: &AppHandle, provider: &ShareProvider, png: Vec<u8>) -> Result<String, String> {
+    let ShareProvider::S3 { endpoint, bucket, region, public_base_url, prefix } = provider else {
+        return Err("Not an S3 provider".to_string());
+    };
+    let namespace = crate::profiles::active_secrets_namespace(app);
+    let access_key = crate::secrets::load_secret(&namespace, &vault_key("access_key"))?.ok_or("S3 access key not in vault")?;
+    let secret = crate::secrets::load_secret(&namespace, &vault_key("secret_key"))?.ok_or("S3 secret key not in vault")?;
+
+    let key = format!("{}{}.png", prefix.trim_start_matches('/'), short_id());
+    let url = reqwest::Url::parse(&format!("{}/{}/{}", endpoint.trim_end_matches('/'), bucket, key))
+        .map_err(|e| format!("❌ Invalid S3 endpoint {}: {}", endpoint, e))?;
+    let host = match (url.host_str(), url.port()) {
+        (Some(host), Some(port)) => format!("{}:{}", host, port),
+        (Some(host), None) => host.to_string(),
+        (None, _) => return Err(format!("❌ Invalid S3 endpoint: {}", endpoint)),
+    };
+
+    // AWS Signature Version 4, path-style, which every S3-compatible service accepts
+    let now = chrono::Utc::now();
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let scope = format!("{}/{}/s3/aws4_request", now.format("%Y%m%d"), region);
+    let payload_hash = sha256_hex(&png);
+    let canonical_request = format!(
+        "PUT\n{}\n\ncontent-type:image/png\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+        url.path(),
+        host,
+        payload_hash,
+        amz_date,
+        S3_SIGNED_HEADERS,
+        payload_hash
+    );
+    let string_to_sign = format!("AWS4-HMAC-SHA256\n{}\n{}\n{}", amz_date, scope, sha256_hex(canonical_request.as_bytes()));
+
+    let mut signing_key = format!("AWS4{}", secret).into_bytes();
+    for part in [now.format("%Y%m%d").to_string(), region.clone(), "s3".to_string(), "aws4_request".to_string()] {
+        signing_key = hmac_sha256(&signing_key, &part)?;
+    }
+    let signature = hex(&hmac_sha256(&signing_key, &string_to_sign)?);
+    let authorization = format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+        access_key, scope, S3_SIGNED_HEADERS, signature
+    );
+
+    let response = crate::egress::send(
+        crate::bandwidth::Feature::Sharing,
+        crate::proxy::client()
+            .put(url.clone())
+            .header("content-type", "image/png")
+            .header("x-amz-content-sha256", &payload_hash)
+            .header("x-amz-date", &amz_date)
+            .header("authorization", authorization)
+            .timeout(UPLOAD_TIMEOUT)
+            .body(png),
+    )
+    .await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("❌ Upload failed: S3 returned {} {}", status, body.trim()));
+    }
+    Ok(match public_base_url {
+        Some(base) => format!("{}/{}", base.trim_end_matches('/'), key),
+        None => url.to_string(),
+    })
+}
+
+/// Sets where captures are uploaded; S3 keys go to the vault and can be omitted to keep the stored ones.
+#[tauri::command]
+pub async fn configure_share_provider(
+    app: AppHandle,
+    provider: ShareProvider,
+    access_key: Option<String>,
+    secret_key: Option<String>,
+    masked_apps: Option<Vec<String>>,
+) -> Result<ShareConfig, String> {
+    if let ShareProvider::S3 { endpoint, bucket, .. } = &provider {
+        reqwest::Url::parse(endpoint).map_err(|e| format!("❌ Invalid S3 endpoint {}: {}", endpoint, e))?;
+        if bucket.trim().is_empty() {
+            return Err("❌ An S3 bucket name is required".to_string());
+        }
+
+        let namespace = crate::profiles::active_secrets_namespace(&app);
+        for (name, value) in [("access_key", access_key), ("secret_key", secret_key)] {
+            match value {
+                Some(value) => crate::secrets::store_secret(&namespace, &vault_key(name), &value)?,
+                None if crate::secrets::load_secret(&namespace, &vault_key(name))?.is_none() => {
+                    return Err(format!("❌ S3 {} is required", name.replace('_', " ")));
+                }
+                None => {}
+            }
+        }
+    }
+
+    let config = ShareConfig { provider, masked_apps: masked_apps.unwrap_or_else(|| load_config(&app).masked_apps) };
+    let json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    std::fs::write(config_path(&app)?, json).map_err(|e| e.to_string())?;
+    Ok(config)
+}
+
+#[tauri::command]
+pub async fn get_share_config(app: AppHandle) -> Result<ShareConfig, String> {
+    Ok(load_config(&app))
+}
+
+/// Captures `target`, pixelates masked apps' windows and `mask` regions, uploads it and copies the link.
+#[tauri::command]
+pub async fn capture_and_share(app: AppHandle, target: ShareTarget, mask: Option<Vec<MaskRegion>>) -> Result<SharedCapture, String> {
+    let config = load_config(&app);
+    let (png, area) = capture(&target).await?;
+
+    let mut canvas = image::load_from_memory(&png).map_err(|e| e.to_string())?.to_rgba8();
+    let image_size = canvas.dimensions();
+    let area = area.unwrap_or(WindowBounds { x: 0, y: 0, width: image_size.0 as i32, height: image_size.1 as i32 });
+    let mut regions = masked_window_regions(&config, area, image_size).await?;
+    regions.extend(mask.unwrap_or_default());
+    for region in &regions {
+        crate::imaging::pixelate_region(&mut canvas, region.x, region.y, region.width, region.height, MASK_BLOCK);
+    }
+
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    image::DynamicImage::ImageRgba8(canvas).write_to(&mut cursor, image::ImageOutputFormat::Png).map_err(|e| e.to_string())?;
+    let png = cursor.into_inner();
+    let bytes = png.len();
+
+    let (url, provider) = match &config.provider {
+        ShareProvider::Backend => (upload_backend(&app, png).await?, "backend"),
+        provider @ ShareProvider::S3 { .. } => (upload_s3(&app, provider, png).await?, "s3"),
+    };
+
+    app.clipboard_manager().write_text(url.clone()).map_err(|e| e.to_string())?;
+
+    let shared = SharedCapture {
+        url,
+        provider: provider.to_string(),
+        width: image_size.0,
+        height: image_size.1,
+        bytes,
+        masked_regions: regions.len(),
+    };
+    println!("🔗 Shared capture ({} masked region(s)): {}", shared.masked_regions, shared.url);
+    let _ = app.emit_all("share://uploaded", &shared);
+    Ok(shared)
+}
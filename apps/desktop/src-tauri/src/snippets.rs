@@ -0,0 +1,196 @@
+/**
+ * Snippets module - Abbreviations that expand to full text when typed in any application
+ *
+ * Expansions may contain {date}, {time}, {datetime} and {clipboard} placeholders.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, State};
+
+/// Typed characters remembered for matching; longer abbreviations are rejected
+const MAX_ABBREVIATION_LEN: usize = 32;
+
+/// Set while an expansion is being typed so the listener ignores our own keystrokes
+static EXPANDING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Snippet {
+    pub abbreviation: String,
+    pub expansion: String,
+}
+
+#[derive(Default)]
+pub struct SnippetState {
+    pub snippets: Vec<Snippet>,
+    typed: String,
+}
+
+fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+fn snippets_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("snippets.json"))
+}
+
+fn save_snippets(app: &AppHandle, snippets: &[Snippet]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(snippets).map_err(|e| e.to_string())?;
+    std::fs::write(snippets_path(app)?, json).map_err(|e| e.to_string())
+}
+
+async fn fill_placeholders(expansion: &str) -> String {
+    let now = now_secs();
+    let mut text = expansion
+        .replace("{date}", &crate::locale::format_date(now, false))
+        .replace("{datetime}", &crate::locale::format_date(now, true))
+        .replace("{time}", &chrono::Local::now().format("%H:%M").to_string());
+
+    if text.contains("{clipboard}") {
+        let clipboard = crate::system::get_clipboard_content().await.unwrap_or_default();
+        text = text.replace("{clipboard}", &clipboard);
+    }
+
+    text
+}
+
+/// Returns the snippet whose abbreviation was just completed at a word boundary.
+fn completed_snippet(snippets: &[Snippet], typed: &str) -> Option<Snippet> {
+    snippets
+        .iter()
+        .filter(|snippet| typed.ends_with(&snippet.abbreviation))
+        .filter(|snippet| {
+            typed[..typed.len() - snippet.abbreviation.len()]
+                .chars()
+                .last()
+                .map_or(true, |before| !before.is_alphanumeric())
+        })
+        .max_by_key(|snippet| snippet.abbreviation.len())
+        .cloned()
+}
+
+async fn expand(snippet: Snippet) {
+    use enigo::{Direction, Key, Keyboard};
+
+    let text = fill_placeholders(&snippet.expansion).await;
+    println!("✂️  Expanding {}", snippet.abbreviation);
+
+    let _injection = crate::automation_guard::injecting();
+    let erased = crate::keyboard::new_enigo().and_then(|mut enigo| {
+        for _ in snippet.abbreviation.chars() {
+            enigo.key(Key::Backspace, Direction::Click).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    });
+
+    let result = match erased {
+        Ok(()) => crate::system::type_text(text, None).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        println!("⚠️  Snippet expansion failed: {}", e);
+    }
+
+    EXPANDING.store(false, Ordering::SeqCst);
+}
+
+pub fn handle_key_event(app: &AppHandle, event: &rdev::Event) {
+    use rdev::{EventType, Key};
+
+    if EXPANDING.load(Ordering::SeqCst) {
+        return;
+    }
+
+    let state = app.state::<Mutex<SnippetState>>();
+    let mut snippet_state = state.lock().unwrap();
+
+    match event.event_type {
+        EventType::KeyPress(Key::Backspace) => {
+            snippet_state.typed.pop();
+        }
+        EventType::KeyPress(Key::ShiftLeft | Key::ShiftRight | Key::CapsLock | Key::AltGr) => {}
+        EventType::KeyPress(_) => {
+            // Navigation, shortcuts and control keys move the caret somewhere we can't follow
+            match event.name.clone().filter(|name| !name.is_empty() && !name.chars().any(char::is_control)) {
+                Some(name) => snippet_state.typed.push_str(&name),
+                None => snippet_state.typed.clear(),
+            }
+
+            let overflow = snippet_state.typed.chars().count().saturating_sub(MAX_ABBREVIATION_LEN);
+            if overflow > 0 {
+                snippet_state.typed = snippet_state.typed.chars().skip(overflow).collect();
+            }
+
+            if let Some(snippet) = completed_snippet(&snippet_state.snippets, &snippet_state.typed) {
+                snippet_state.typed.clear();
+                EXPANDING.store(true, Ordering::SeqCst);
+                tauri::async_runtime::spawn(expand(snippet));
+            }
+        }
+        EventType::ButtonPress(_) => snippet_state.typed.clear(),
+        _ => {}
+    }
+}
+
+/// Loads the profile's snippets; keystrokes arrive through the shared input listener.
+pub fn load_snippets(app: AppHandle) {
+    let loaded: Vec<Snippet> = snippets_path(&app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    app.state::<Mutex<SnippetState>>().lock().unwrap().snippets = loaded;
+}
+
+#[tauri::command]
+pub async fn add_snippet(
+    app: AppHandle,
+    state: State<'_, Mutex<SnippetState>>,
+    abbreviation: String,
+    expansion: String,
+) -> Result<Snippet, String> {
+    let abbreviation = abbreviation.trim().to_string();
+    if abbreviation.is_empty() || abbreviation.chars().count() > MAX_ABBREVIATION_LEN {
+        return Err(format!("Abbreviations must be 1-{} characters", MAX_ABBREVIATION_LEN));
+    }
+    if abbreviation.chars().any(char::is_whitespace) {
+        return Err("Abbreviations cannot contain spaces".to_string());
+    }
+
+    let mut snippet_state = state.lock().unwrap();
+    let snippet = Snippet { abbreviation, expansion };
+    snippet_state.snippets.retain(|existing| existing.abbreviation != snippet.abbreviation);
+    snippet_state.snippets.push(snippet.clone());
+    save_snippets(&app, &snippet_state.snippets)?;
+
+    println!("✂️  Added snippet {}", snippet.abbreviation);
+    Ok(snippet)
+}
+
+#[tauri::command]
+pub async fn list_snippets(state: State<'_, Mutex<SnippetState>>) -> Result<Vec<Snippet>, String> {
+    Ok(state.lock().unwrap().snippets.clone())
+}
+
+#[tauri::command]
+pub async fn remove_snippet(
+    app: AppHandle,
+    state: State<'_, Mutex<SnippetState>>,
+    abbreviation: String,
+) -> Result<(), String> {
+    let mut snippet_state = state.lock().unwrap();
+    let before = snippet_state.snippets.len();
+    snippet_state.snippets.retain(|snippet| snippet.abbreviation != abbreviation);
+
+    if snippet_state.snippets.len() == before {
+        return Err(format!("❌ Snippet not found: {}", abbreviation));
+    }
+
+    save_snippets(&app, &snippet_state.snippets)
+}
@@ -0,0 +1,110 @@
+/**
+ * Spectrum module - Band energies and a downsampled waveform of the open microphone for visualizations
+ *
+ * Computed in Rust and sent as small `voice://spectrum` frames so the frontend never handles raw PCM.
+ */
+
+use rustfft::num_complex::Complex;
+use rustfft::{Fft, FftPlanner};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+const FFT_SIZE: usize = 1024;
+const BANDS: usize = 32;
+const WAVEFORM_POINTS: usize = 64;
+const MIN_FREQ: f32 = 60.0;
+const MAX_FREQ: f32 = 8000.0;
+/// Band levels are mapped from this dB floor up to 0 dB onto 0..1
+const FLOOR_DB: f32 = -80.0;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpectrumFrame {
+    /// Log-spaced band levels from MIN_FREQ to MAX_FREQ, 0..1
+    pub bands: Vec<f32>,
+    /// Peak amplitude per slice of the latest samples, -1..1 with the peak's sign
+    pub waveform: Vec<f32>,
+    pub sample_rate: u32,
+}
+
+pub struct SpectrumAnalyzer {
+    fft: Arc<dyn Fft<f32>>,
+    window: Vec<f32>,
+    history: VecDeque<f32>,
+    /// Inclusive-exclusive FFT bin range for each band
+    band_bins: Vec<(usize, usize)>,
+    sample_rate: u32,
+}
+
+impl SpectrumAnalyzer {
+    pub fn new(sample_rate: u32) -> Self {
+        let window = (0..FFT_SIZE)
+            .map(|i| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (FFT_SIZE - 1) as f32).cos())
+            .collect();
+
+        let bin_hz = sample_rate as f32 / FFT_SIZE as f32;
+        let max_freq = MAX_FREQ.min(sample_rate as f32 / 2.0);
+        let ratio = (max_freq / MIN_FREQ).powf(1.0 / BANDS as f32);
+        let band_bins = (0..BANDS)
+            .map(|band| {
+                let low = (MIN_FREQ * ratio.powi(band as i32) / bin_hz) as usize;
+                let high = (MIN_FREQ * ratio.powi(band as i32 + 1) / bin_hz).ceil() as usize;
+                (low.max(1), high.max(low + 1).min(FFT_SIZE / 2))
+            })
+            .collect();
+
+        SpectrumAnalyzer {
+            fft: FftPlanner::new().plan_fft_forward(FFT_SIZE),
+            window,
+            history: VecDeque::with_capacity(FFT_SIZE),
+            band_bins,
+            sample_rate,
+        }
+    }
+
+    /// Adds the latest mono samples and returns a frame over the most recent FFT_SIZE of them.
+    pub fn push(&mut self, samples: &[f32]) -> SpectrumFrame {
+        for &sample in samples {
+            if self.history.len() == FFT_SIZE {
+                self.history.pop_front();
+            }
+            self.history.push_back(sample);
+        }
+
+        // Zero-padded at the front until enough audio has arrived
+        let padding = FFT_SIZE - self.history.len();
+        let mut buffer: Vec<Complex<f32>> = std::iter::repeat(0.0)
+            .take(padding)
+            .chain(self.history.iter().copied())
+            .zip(&self.window)
+            .map(|(sample, weight)| Complex::new(sample * weight, 0.0))
+            .collect();
+        self.fft.process(&mut buffer);
+
+        // Hann window halves the amplitude; normalise so a full-scale sine reads ~0 dB
+        let scale = 4.0 / FFT_SIZE as f32;
+        let bands = self
+            .band_bins
+            .iter()
+            .map(|&(low, high)| {
+                let peak = buffer[low..high].iter().map(|bin| bin.norm() * scale).fold(0.0f32, f32::max);
+                let db = 20.0 * peak.max(1e-9).log10();
+                ((db - FLOOR_DB) / -FLOOR_DB).clamp(0.0, 1.0)
+            })
+            .collect();
+
+        SpectrumFrame { bands, waveform: waveform(samples), sample_rate: self.sample_rate }
+    }
+}
+
+fn waveform(samples: &[f32]) -> Vec<f32> {
+    if samples.is_empty() {
+        return vec![0.0; WAVEFORM_POINTS];
+    }
+    let slice = (samples.len() + WAVEFORM_POINTS - 1) / WAVEFORM_POINTS;
+    samples
+        .chunks(slice.max(1))
+        .map(|chunk| chunk.iter().copied().fold(0.0f32, |peak, s| if s.abs() > peak.abs() { s } else { peak }))
+        .collect()
+}
@@ -0,0 +1,130 @@
+/**
+ * Speech module - Text-to-speech through the JarvisX TTS service, with the OS voice as fallback
+ */
+
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Bumped by every new utterance or stop request; playback checks it to cut itself off.
+static SPEECH_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+pub fn tts_url() -> String {
+    std::env::var("JARVISX_TTS_URL").unwrap_or_else(|_| "http://localhost:8002".to_string())
+}
+
+async fn synthesize(text: &str, language: &str) -> Result<Vec<u8>, String> {
+    let response = crate::egress::send(
+        crate::bandwidth::Feature::TextToSpeech,
+        crate::proxy::client()
+            .post(format!("{}/synthesize", tts_url()))
+            .timeout(Duration::from_secs(10))
+            .json(&serde_json::json!({ "text": text, "language": language })),
+    )
+    .await?;
+
+    if !response.status().is_success() {
+        return Err(format!("TTS service returned {}", response.status()));
+    }
+
+    response.bytes().await.map(|bytes| bytes.to_vec()).map_err(|e| e.to_string())
+}
+
+fn play_wav(wav: Vec<u8>, generation: u64) -> Result<(), String> {
+    let (_stream, handle) = rodio::OutputStream::try_default().map_err(|e| e.to_string())?;
+    let sink = rodio::Sink::try_new(&handle).map_err(|e| e.to_string())?;
+    let source = rodio::Decoder::new(std::io::Cursor::new(wav)).map_err(|e| e.to_string())?;
+    sink.append(source);
+
+    while !sink.empty() {
+        if SPEECH_GENERATION.load(Ordering::SeqCst) != generation {
+            sink.stop();
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    Ok(())
+}
+
+fn platform_voice_command(text: &str) -> Command {
+    #[cfg(target_os = "macos")]
+    {
+        let mut command = Command::new("say");
+        command.arg(text);
+        command
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        // Narrated text comes from other apps, so it goes in as data rather than script
+        crate::commands::powershell(
+            "Add-Type -AssemblyName System.Speech; (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak($env:JARVISX_TTS_TEXT)",
+            &[("JARVISX_TTS_TEXT", text)],
+        )
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let mut command = Command::new("spd-say");
+        command.args(&["--wait", text]);
+        command
+    }
+}
+
+fn speak_with_platform_voice(text: &str, generation: u64) -> Result<(), String> {
+    let mut child = platform_voice_command(text).spawn().map_err(|e| e.to_string())?;
+
+    loop {
+        if child.try_wait().map_err(|e| e.to_string())?.is_some() {
+            return Ok(());
+        }
+        if SPEECH_GENERATION.load(Ordering::SeqCst) != generation {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(());
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+/// Speaks `text`, interrupting anything already being spoken.
+pub async fn speak(text: &str, language: Option<&str>) -> Result<(), String> {
+    let generation = SPEECH_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let language = language.map(String::from).unwrap_or_else(|| {
+        crate::locale::current_locale().split('-').next().unwrap_or("en").to_string()
+    });
+
+    let synthesized = synthesize(text, &language).await;
+    let text = text.to_string();
+
+    crate::ducking::duck("speech");
+    let spoken = tauri::async_runtime::spawn_blocking(move || match synthesized {
+        Ok(wav) => play_wav(wav, generation),
+        Err(e) => {
+            println!("⚠️  TTS service unavailable ({}), using system voice", e);
+            speak_with_platform_voice(&text, generation)
+        }
+    })
+    .await
+    .map_err(|e| e.to_string());
+    crate::ducking::restore("speech");
+
+    spoken?
+}
+
+pub fn stop() {
+    SPEECH_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+#[tauri::command]
+pub async fn speak_text(text: String, language: Option<String>) -> Result<(), String> {
+    println!("🗣️  Speaking: {}", text);
+    speak(&text, language.as_deref()).await
+}
+
+#[tauri::command]
+pub async fn stop_speaking() -> Result<(), String> {
+    stop();
+    Ok(())
+}
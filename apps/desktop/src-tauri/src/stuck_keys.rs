@@ -0,0 +1,157 @@
+/**
+ * Stuck keys module - Watchdog that releases modifiers automation left pressed, plus a manual release_all_keys
+ *
+ * A macro that aborts mid-hotkey can leave Shift/Ctrl/Alt/Meta held, which makes every later keystroke a shortcut.
+ * Modifier state comes from the OS key-state APIs (the global input hook on Linux). Only modifiers still held shortly
+ * after automation ran are released, so keys the user is holding are left alone.
+ */
+
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// How long after automation touched input the watchdog keeps checking
+const ARMED_FOR: Duration = Duration::from_secs(30);
+/// Consecutive polls a modifier must stay down before it counts as stuck
+const STUCK_POLLS: u32 = 2;
+
+static ARMED_AT: Mutex<Option<Instant>> = Mutex::new(None);
+/// Modifiers currently down according to the global input hook (used where the OS has no key-state query)
+static HOOK_HELD: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+/// Starts a watch window; called whenever automation begins holding input.
+pub fn arm() {
+    *ARMED_AT.lock().unwrap() = Some(Instant::now());
+}
+
+fn is_armed() -> bool {
+    ARMED_AT.lock().unwrap().map_or(false, |armed_at| armed_at.elapsed() < ARMED_FOR)
+}
+
+fn modifier_name(key: &rdev::Key) -> Option<&'static str> {
+    use rdev::Key;
+
+    match key {
+        Key::ShiftLeft | Key::ShiftRight => Some("shift"),
+        Key::ControlLeft | Key::ControlRight => Some("control"),
+        Key::Alt | Key::AltGr => Some("alt"),
+        Key::MetaLeft | Key::MetaRight => Some("meta"),
+        _ => None,
+    }
+}
+
+/// Tracks modifier presses and releases; called by the shared input listener.
+pub fn observe(event: &rdev::Event) {
+    use rdev::EventType;
+
+    match &event.event_type {
+        EventType::KeyPress(key) => {
+            if let Some(name) = modifier_name(key) {
+                HOOK_HELD.lock().unwrap().insert(name);
+            }
+        }
+        EventType::KeyRelease(key) => {
+            if let Some(name) = modifier_name(key) {
+                HOOK_HELD.lock().unwrap().remove(name);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn held_modifiers() -> BTreeSet<&'static str> {
+    use windows::Win32::UI::Input::KeyboardAndMouse::{GetAsyncKeyState, VIRTUAL_KEY, VK_CONTROL, VK_LWIN, VK_MENU, VK_RWIN, VK_SHIFT};
+
+    let down = |key: VIRTUAL_KEY| unsafe { GetAsyncKeyState(key.0 as i32) } as u16 & 0x8000 != 0;
+    [("shift", vec![VK_SHIFT]), ("control", vec![VK_CONTROL]), ("alt", vec![VK_MENU]), ("meta", vec![VK_LWIN, VK_RWIN])]
+        .into_iter()
+        .filter(|(_, keys)| keys.iter().any(|key| down(*key)))
+        .map(|(name, _)| name)
+        .collect()
+}
+
+#[cfg(target_os = "macos")]
+fn held_modifiers() -> BTreeSet<&'static str> {
+    #[link(name = "CoreGraphics", kind = "framework")]
+    extern "C" {
+        fn CGEventSourceFlagsState(state_id: i32) -> u64;
+    }
+
+    // kCGEventSourceStateCombinedSessionState
+    let flags = unsafe { CGEventSourceFlagsState(0) };
+    [("shift", 0x0002_0000), ("control", 0x0004_0000), ("alt", 0x0008_0000), ("meta", 0x0010_0000)]
+        .into_iter()
+        .filter(|(_, mask)| flags & mask != 0)
+        .map(|(name, _)| name)
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn held_modifiers() -> BTreeSet<&'static str> {
+    // X11 has no cheap global key-state query without a display handle; the hook sees XTest events too
+    HOOK_HELD.lock().unwrap().clone()
+}
+
+fn release(app: &AppHandle, held: &BTreeSet<&'static str>, reason: &str) -> Result<(), String> {
+    if crate::mock_platform::is_active() {
+        crate::mock_platform::record_input("release", serde_json::json!({ "keys": held }));
+    } else {
+        crate::recovery::release_held_input()?;
+    }
+    HOOK_HELD.lock().unwrap().clear();
+
+    println!("🔓 Released stuck keys ({}): {:?}", reason, held);
+    let _ = app.emit_all("input://keys-released", serde_json::json!({
+        "keys": held,
+        "reason": reason,
+    }));
+    Ok(())
+}
+
+pub fn spawn_stuck_key_watchdog(app: AppHandle) {
+    crate::scheduler::register_task(&app, "stuck-key-watchdog", crate::scheduler::TaskPriority::High);
+
+    tauri::async_runtime::spawn(async move {
+        let mut stuck_polls = 0;
+
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+
+            // Automation that is still holding its chord is not stuck
+            if crate::mock_platform::is_active()
+                || crate::scheduler::is_paused(&app, "stuck-key-watchdog")
+                || !is_armed()
+                || crate::recovery::is_tracking("input")
+            {
+                stuck_polls = 0;
+                continue;
+            }
+
+            let held = held_modifiers();
+            if held.is_empty() {
+                stuck_polls = 0;
+                continue;
+            }
+
+            stuck_polls += 1;
+            if stuck_polls >= STUCK_POLLS {
+                stuck_polls = 0;
+                *ARMED_AT.lock().unwrap() = None;
+                if let Err(e) = release(&app, &held, "watchdog") {
+                    println!("⚠️  Could not release stuck keys: {}", e);
+                }
+            }
+        }
+    });
+}
+
+/// Releases every modifier and mouse button, returning the modifiers that were held.
+#[tauri::command]
+pub async fn release_all_keys(app: AppHandle) -> Result<Vec<String>, String> {
+    let held = held_modifiers();
+    release(&app, &held, "manual")?;
+    Ok(held.into_iter().map(String::from).collect())
+}
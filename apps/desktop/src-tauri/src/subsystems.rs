@@ -0,0 +1,119 @@
+/**
+ * Subsystems module - Lazy initialization of heavy subsystems with an init-state API
+ *
+ * Nothing here runs at startup, so the window shows immediately; each subsystem loads on first
+ * use or when the UI asks for it ahead of time with preload_subsystem.
+ */
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+use tauri::{AppHandle, Manager};
+
+pub const SUBSYSTEMS: &[&str] = &["system", "screens", "face-detection", "whisper"];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "state", rename_all = "camelCase")]
+pub enum SubsystemStatus {
+    NotLoaded,
+    Loading,
+    #[serde(rename_all = "camelCase")]
+    Ready { load_ms: u64 },
+    Failed { error: String },
+    Unavailable { reason: String },
+}
+
+static STATUS: Mutex<BTreeMap<&'static str, SubsystemStatus>> = Mutex::new(BTreeMap::new());
+static SYSTEM: OnceLock<Mutex<sysinfo::System>> = OnceLock::new();
+static SCREENS: Mutex<Option<Vec<screenshots::Screen>>> = Mutex::new(None);
+
+pub fn status(name: &'static str) -> SubsystemStatus {
+    STATUS.lock().unwrap().get(name).cloned().unwrap_or_else(|| match name {
+        "whisper" => SubsystemStatus::Unavailable {
+            reason: "No local Whisper model is bundled yet".to_string(),
+        },
+        _ => SubsystemStatus::NotLoaded,
+    })
+}
+
+/// Runs a loader, recording Loading → Ready/Failed for `name`.
+pub fn track_load<T>(name: &'static str, load: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    STATUS.lock().unwrap().insert(name, SubsystemStatus::Loading);
+    let started = Instant::now();
+    let result = load();
+
+    let status = match &result {
+        Ok(_) => SubsystemStatus::Ready { load_ms: started.elapsed().as_millis() as u64 },
+        Err(e) => SubsystemStatus::Failed { error: e.clone() },
+    };
+    println!("🧩 Subsystem {}: {:?}", name, status);
+    STATUS.lock().unwrap().insert(name, status);
+    result
+}
+
+/// Shared sysinfo handle; `System::new_all` only runs the first time.
+pub fn with_system<T>(f: impl FnOnce(&mut sysinfo::System) -> T) -> T {
+    use sysinfo::SystemExt;
+
+    let system = SYSTEM.get_or_init(|| {
+        let sys = track_load("system", || Ok::<_, String>(sysinfo::System::new_all())).unwrap();
+        Mutex::new(sys)
+    });
+    f(&mut system.lock().unwrap())
+}
+
+/// Enumerated displays, cached until `invalidate_screens`.
+pub fn screens() -> Result<Vec<screenshots::Screen>, String> {
+    let mut cached = SCREENS.lock().unwrap();
+
+    if let Some(screens) = cached.as_ref() {
+        return Ok(screens.clone());
+    }
+
+    let screens = track_load("screens", || {
+        let screens = screenshots::Screen::all().map_err(|e| e.to_string())?;
+        if screens.is_empty() {
+            return Err("No screens found".to_string());
+        }
+        Ok(screens)
+    })?;
+    *cached = Some(screens.clone());
+    Ok(screens)
+}
+
+/// Forgets the display list, e.g. after a capture fails because a monitor went away.
+pub fn invalidate_screens() {
+    *SCREENS.lock().unwrap() = None;
+    STATUS.lock().unwrap().insert("screens", SubsystemStatus::NotLoaded);
+}
+
+#[tauri::command]
+pub async fn get_subsystem_status() -> Result<BTreeMap<&'static str, SubsystemStatus>, String> {
+    Ok(SUBSYSTEMS.iter().map(|name| (*name, status(name))).collect())
+}
+
+#[tauri::command]
+pub async fn preload_subsystem(app: AppHandle, name: String) -> Result<SubsystemStatus, String> {
+    let name: &'static str = SUBSYSTEMS
+        .iter()
+        .find(|known| **known == name)
+        .ok_or_else(|| format!("❌ Unknown subsystem: {}", name))?;
+
+    let handle = app.clone();
+    tauri::async_runtime::spawn_blocking(move || match name {
+        "system" => {
+            with_system(|_| ());
+            Ok(())
+        }
+        "screens" => screens().map(|_| ()),
+        "face-detection" => crate::presence::face_model(&handle).map(|_| ()),
+        _ => Err(format!("{} cannot be preloaded", name)),
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    let status = status(name);
+    let _ = app.emit_all("subsystem://status-changed", serde_json::json!({ "name": name, "status": status }));
+    Ok(status)
+}
@@ -0,0 +1,244 @@
+/**
+ * Supervised module - Time-boxed elevated mode that unlocks dangerous commands after confirmation
+ */
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, State};
+
+/// Upper bound on a single elevated window, however long the caller asks for.
+const MAX_SUPERVISED_SECS: u64 = 30 * 60;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupervisedAction {
+    pub timestamp: u64,
+    pub command: String,
+    pub details: serde_json::Value,
+}
+
+pub struct SupervisedState {
+    pub session_id: u64,
+    pub started_at: u64,
+    pub expires_at: Option<Instant>,
+    pub reason: String,
+    pub actions: Vec<SupervisedAction>,
+}
+
+impl Default for SupervisedState {
+    fn default() -> Self {
+        SupervisedState {
+            session_id: 0,
+            started_at: 0,
+            expires_at: None,
+            reason: String::new(),
+            actions: Vec::new(),
+        }
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// True while an elevated window is open.
+pub fn is_supervised(app: &AppHandle) -> bool {
+    app.state::<Mutex<SupervisedState>>()
+        .lock()
+        .unwrap()
+        .expires_at
+        .map_or(false, |expires_at| Instant::now() < expires_at)
+}
+
+/// Allows a normally-blocked action only inside the window, recording it in the audit log.
+pub fn require_supervised(app: &AppHandle, command: &str, details: serde_json::Value) -> Result<(), String> {
+    if !is_supervised(app) {
+        return Err(format!("❌ {} requires supervised mode", command));
+    }
+
+    println!("🛡️  Supervised action: {} {}", command, details);
+    app.state::<Mutex<SupervisedState>>().lock().unwrap().actions.push(SupervisedAction {
+        timestamp: now_secs(),
+        command: command.to_string(),
+        details,
+    });
+    Ok(())
+}
+
+/// Files under the app data directory are the sandbox; anything else is "outside".
+pub fn is_inside_sandbox(app: &AppHandle, path: &Path) -> bool {
+    let sandbox = match app.path_resolver().app_data_dir() {
+        Some(dir) => dir,
+        None => return false,
+    };
+
+    match (path.canonicalize(), sandbox.canonicalize()) {
+        (Ok(path), Ok(sandbox)) => path.starts_with(sandbox),
+        _ => false,
+    }
+}
+
+fn audit_log_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = crate::profiles::active_profile_dir(app)?.join("supervised");
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+/// Closes the window and writes everything done during it to the audit log.
+fn end_supervised_mode(app: &AppHandle, reason: &str) -> Result<serde_json::Value, String> {
+    let summary = {
+        let state = app.state::<Mutex<SupervisedState>>();
+        let mut supervised = state.lock().unwrap();
+
+        if supervised.expires_at.take().is_none() {
+            return Err("Supervised mode not active".to_string());
+        }
+
+        serde_json::json!({
+            "sessionId": supervised.session_id,
+            "reason": supervised.reason,
+            "startedAt": supervised.started_at,
+            "endedAt": now_secs(),
+            "endReason": reason,
+            "actions": std::mem::take(&mut supervised.actions),
+        })
+    };
+
+    let path = audit_log_dir(app)?.join(format!("{}-{}.json", summary["startedAt"], summary["sessionId"]));
+    let json = serde_json::to_string_pretty(&summary).map_err(|e| e.to_string())?;
+    std::fs::write(path, json).map_err(|e| e.to_string())?;
+
+    println!("🛡️  Supervised mode ended ({}), {} actions logged", reason, summary["actions"].as_array().map_or(0, |a| a.len()));
+    let _ = app.emit_all("supervised-mode-changed", serde_json::json!({ "active": false, "summary": summary }));
+    Ok(summary)
+}
+
+#[tauri::command]
+pub async fn enter_supervised_mode(app: AppHandle, duration_secs: u64, reason: String) -> Result<serde_json::Value, String> {
+    if is_supervised(&app) {
+        return Err("Supervised mode already active".to_string());
+    }
+
+    let duration_secs = duration_secs.clamp(1, MAX_SUPERVISED_SECS);
+    let parent = app.get_window("main");
+    let confirmed = tauri::api::dialog::blocking::ask(
+        parent.as_ref(),
+        "Enter supervised mode",
+        format!(
+            "JarvisX is asking to unlock shutdown, arbitrary shell commands and file deletion outside its sandbox for {} minutes.\n\nReason: {}\n\nAllow?",
+            (duration_secs + 59) / 60,
+            reason
+        ),
+    );
+
+    if !confirmed {
+        return Err("❌ Supervised mode declined".to_string());
+    }
+
+    let session_id = {
+        let state = app.state::<Mutex<SupervisedState>>();
+        let mut supervised = state.lock().unwrap();
+        supervised.session_id += 1;
+        supervised.started_at = now_secs();
+        supervised.expires_at = Some(Instant::now() + Duration::from_secs(duration_secs));
+        supervised.reason = reason;
+        supervised.actions.clear();
+        supervised.session_id
+    };
+
+    println!("🛡️  Supervised mode active for {}s", duration_secs);
+    let _ = app.emit_all("supervised-mode-changed", serde_json::json!({
+        "active": true,
+        "sessionId": session_id,
+        "durationSecs": duration_secs,
+    }));
+
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(duration_secs)).await;
+
+        // Only revert the window this timer belongs to
+        let current = handle.state::<Mutex<SupervisedState>>().lock().unwrap().session_id;
+        if current == session_id {
+            let _ = end_supervised_mode(&handle, "expired");
+        }
+    });
+
+    Ok(serde_json::json!({ "sessionId": session_id, "durationSecs": duration_secs }))
+}
+
+#[tauri::command]
+pub async fn exit_supervised_mode(app: AppHandle) -> Result<serde_json::Value, String> {
+    end_supervised_mode(&app, "exited")
+}
+
+#[tauri::command]
+pub async fn get_supervised_status(state: State<'_, Mutex<SupervisedState>>) -> Result<serde_json::Value, String> {
+    let supervised = state.lock().unwrap();
+    let remaining = supervised
+        .expires_at
+        .map(|expires_at| expires_at.saturating_duration_since(Instant::now()).as_secs())
+        .unwrap_or(0);
+
+    Ok(serde_json::json!({
+        "active": remaining > 0,
+        "sessionId": supervised.session_id,
+        "remainingSecs": remaining,
+        "reason": supervised.reason,
+        "actions": supervised.actions,
+    }))
+}
+
+#[tauri::command]
+pub async fn shutdown_system(app: AppHandle, restart: bool) -> Result<String, String> {
+    require_supervised(&app, "shutdown_system", serde_json::json!({ "restart": restart }))?;
+
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    #[cfg(target_os = "macos")]
+    let output = Command::new("osascript")
+        .args(&["-e", if restart { "tell application \"System Events\" to restart" } else { "tell application \"System Events\" to shut down" }])
+        .output_with_timeout()?;
+
+    #[cfg(target_os = "windows")]
+    let output = Command::new("shutdown")
+        .args(&[if restart { "/r" } else { "/s" }, "/t", "0"])
+        .output_with_timeout()?;
+
+    #[cfg(target_os = "linux")]
+    let output = Command::new("systemctl")
+        .arg(if restart { "reboot" } else { "poweroff" })
+        .output_with_timeout()?;
+
+    if output.status.success() {
+        Ok(format!("✅ {} initiated", if restart { "Restart" } else { "Shutdown" }))
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[tauri::command]
+pub async fn delete_path(app: AppHandle, path: String) -> Result<String, String> {
+    let target = PathBuf::from(&path);
+
+    if !target.exists() {
+        return Err(format!("❌ Path not found: {}", path));
+    }
+    if !is_inside_sandbox(&app, &target) {
+        require_supervised(&app, "delete_path", serde_json::json!({ "path": path }))?;
+    }
+
+    if target.is_dir() {
+        std::fs::remove_dir_all(&target).map_err(|e| e.to_string())?;
+    } else {
+        std::fs::remove_file(&target).map_err(|e| e.to_string())?;
+    }
+
+    Ok(format!("🗑️  Deleted: {}", path))
+}
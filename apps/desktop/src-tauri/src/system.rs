@@ -2,6 +2,28 @@
  * System module - Native system control (keyboard, mouse, clipboard)
  */
 
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+use tauri::AppHandle;
+
+const CLIPBOARD_HISTORY_LIMIT: usize = 50;
+
+/// Recent clipboard contents set through JarvisX with when they were set, newest first
+static CLIPBOARD_HISTORY: Mutex<VecDeque<(SystemTime, String)>> = Mutex::new(VecDeque::new());
+
+pub fn clipboard_history() -> Vec<String> {
+    CLIPBOARD_HISTORY.lock().unwrap().iter().map(|(_, entry)| entry.clone()).collect()
+}
+
+/// Drops clipboard history entries older than `max_age`, returning how many were removed.
+pub fn prune_clipboard_history(max_age: Duration) -> usize {
+    let mut history = CLIPBOARD_HISTORY.lock().unwrap();
+    let before = history.len();
+    history.retain(|(set_at, _)| set_at.elapsed().map_or(true, |age| age <= max_age));
+    before - history.len()
+}
+
 #[tauri::command]
 pub async fn get_clipboard_content() -> Result<String, String> {
     // TODO: Use clipboard crate
@@ -13,34 +35,114 @@ pub async fn get_clipboard_content() -> Result<String, String> {
 pub async fn set_clipboard_content(content: String) -> Result<(), String> {
     // TODO: Use clipboard crate
     println!("📋 Setting clipboard: {}", content);
+
+    let mut history = CLIPBOARD_HISTORY.lock().unwrap();
+    history.retain(|(_, entry)| *entry != content);
+    history.push_front((SystemTime::now(), content));
+    history.truncate(CLIPBOARD_HISTORY_LIMIT);
     Ok(())
 }
 
+/// Shows an OS notification and reads it aloud when narration is on; held until presentation mode ends.
+pub fn show_notification(app: &AppHandle, title: &str, body: &str) {
+    if crate::presentation::hold_notification(title, body) {
+        return;
+    }
+    let _ = tauri::api::notification::Notification::new(&app.config().tauri.bundle.identifier)
+        .title(title)
+        .body(body)
+        .show();
+    crate::narration::narrate_notification(app, title, body);
+}
+
 #[tauri::command]
-pub async fn send_notification(title: String, body: String) -> Result<(), String> {
+pub async fn send_notification(app: AppHandle, title: String, body: String) -> Result<(), String> {
     println!("🔔 Sending notification: {} - {}", title, body);
-    // TODO: Use notify-rust or tauri's notification API
+    show_notification(&app, &title, &body);
     Ok(())
 }
 
 #[tauri::command]
 pub async fn get_active_window() -> Result<String, String> {
     println!("🪟 Getting active window");
-    // TODO: Platform-specific active window detection
-    Ok("Active Window Name".to_string())
+    if crate::mock_platform::is_active() {
+        return Ok(crate::mock_platform::active_window());
+    }
+    tauri::async_runtime::spawn_blocking(crate::window_ids::active_window)
+        .await
+        .map_err(|e| e.to_string())?
+        .map(|window| window.title)
+        .ok_or_else(|| "No active window".to_string())
 }
 
 #[tauri::command]
-pub async fn type_text(text: String) -> Result<(), String> {
+pub async fn type_text(text: String, mode: Option<String>) -> Result<(), String> {
     println!("⌨️  Typing text: {}", text);
-    // TODO: Use enigo for keyboard simulation
+    if crate::mock_platform::is_active() {
+        crate::mock_platform::record_input("text", serde_json::json!({ "text": text }));
+        return Ok(());
+    }
+
+    let layout = crate::keyboard::current_layout();
+
+    // "unicode" inserts committed text directly; "keys" simulates each keystroke
+    let use_unicode = match mode.as_deref() {
+        Some("unicode") | Some("ime") => true,
+        Some("keys") => false,
+        _ => crate::text_input::should_use_unicode_input(&text, &layout.code),
+    };
+
+    if use_unicode {
+        return crate::text_input::insert_text(&text);
+    }
+
+    let _injection = crate::automation_guard::injecting();
+    let mut enigo = crate::keyboard::new_enigo()?;
+
+    for ch in text.chars() {
+        crate::keyboard::type_char(&mut enigo, &layout.code, ch)?;
+    }
+
     Ok(())
 }
 
 #[tauri::command]
 pub async fn press_hotkey(modifiers: Vec<String>, key: String) -> Result<(), String> {
+    use enigo::Key;
+
     println!("⌨️  Hotkey: {:?} + {}", modifiers, key);
-    // TODO: Use enigo for hotkey simulation
-    Ok(())
-}
+    if crate::mock_platform::is_active() {
+        crate::mock_platform::record_input("hotkey", serde_json::json!({ "modifiers": modifiers, "key": key }));
+        return Ok(());
+    }
+
+    let mut held = modifiers
+        .iter()
+        .map(|name| crate::keyboard::parse_modifier(name))
+        .collect::<Result<Vec<Key>, String>>()?;
 
+    let mut chars = key.chars();
+    let key = match (crate::keyboard::named_key(&key), chars.next(), chars.next()) {
+        (Some(named), _, _) => named,
+        (None, Some(ch), None) => {
+            // Symbols like "/" need Shift or AltGr on some layouts
+            let layout = crate::keyboard::current_layout();
+            match crate::keyboard::layout_stroke(&layout.code, ch) {
+                Some((base, extra)) if !ch.is_uppercase() => {
+                    for modifier in extra.iter().flat_map(|modifier| crate::keyboard::modifier_keys(*modifier)) {
+                        if !held.contains(&modifier) {
+                            held.push(modifier);
+                        }
+                    }
+                    Key::Unicode(base)
+                }
+                _ => Key::Unicode(ch.to_lowercase().next().unwrap_or(ch)),
+            }
+        }
+        _ => return Err(format!("Unsupported key: {}", key)),
+    };
+
+    let _injection = crate::automation_guard::injecting();
+    let mut enigo = crate::keyboard::new_enigo()?;
+    crate::keyboard::press_chord(&mut enigo, &held, key)
+}
@@ -0,0 +1,380 @@
+/**
+ * Tables module - Turns a table in a screenshot, PDF page image or screen region into CSV and JSON rows
+ *
+ * Words and their boxes come from the OCR service. Words sharing a baseline form rows, wide horizontal gaps split
+ * rows into cells, and the x-extents of cells across the multi-cell rows are merged into column bands. Single-cell
+ * lines inside the table are treated as wrapped text and appended to the row above.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tauri::{AppHandle, ClipboardManager};
+
+const OCR_TIMEOUT: Duration = Duration::from_secs(60);
+/// Words below this OCR confidence (0-100) are dropped
+const MIN_CONFIDENCE: f64 = 50.0;
+/// A horizontal gap wider than this many line heights separates cells rather than words
+const CELL_GAP_LINE_HEIGHTS: f64 = 1.0;
+
+pub fn ocr_url() -> String {
+    std::env::var("JARVISX_OCR_URL").unwrap_or_else(|_| "http://localhost:8011".to_string())
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TableRegion {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcrWord {
+    pub text: String,
+    pub confidence: f64,
+    pub x0: f64,
+    pub y0: f64,
+    pub x1: f64,
+    pub y1: f64,
+}
+
+impl OcrWord {
+    fn height(&self) -> f64 {
+        self.y1 - self.y0
+    }
+
+    fn center_y(&self) -> f64 {
+        (self.y0 + self.y1) / 2.0
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtractedTable {
+    /// First row, when it reads as a header
+    pub headers: Option<Vec<String>>,
+    pub rows: Vec<Vec<String>>,
+    pub csv: String,
+    /// Rows keyed by header, when there is one
+    pub records: Option<Vec<serde_json::Map<String, serde_json::Value>>>,
+    /// Mean OCR confidence of the words used
+    pub confidence: f64,
+}
+
+struct Cell {
+    text: String,
+    x0: f64,
+    x1: f64,
+}
+
+/// Words in a PNG (or any image the service reads), with pixel boxes.
+pub async fn recognize_words(image: &[u8], language: &str) -> Result<Vec<OcrWord>, String> {
+    use base64::{engine::general_purpose, Engine as _};
+
+    let response = crate::egress::send(
+        crate::bandwidth::Feature::Ocr,
+        crate::proxy::client()
+            .post(format!("{}/extract", ocr_url()))
+            .timeout(OCR_TIMEOUT)
+            .json(&serde_json::json!({
+                "image": general_purpose::STANDARD.encode(image),
+                "options": { "language": language, "confidence_threshold": MIN_CONFIDENCE },
+            })),
+    )
+    .await
+    .map_err(|e| format!("❌ OCR service unavailable: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("OCR service returned {}", response.status()));
+    }
+    let body: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+    let words = body["data"]["words"].as_array().ok_or("OCR response missing words")?;
+
+    Ok(words
+        .iter()
+        .filter_map(|word| {
+            let bbox = &word["bbox"];
+            Some(OcrWord {
+                text: word["text"].as_str()?.trim().to_string(),
+                confidence: word["confidence"].as_f64().unwrap_or(0.0),
+                x0: bbox["x0"].as_f64()?,
+                y0: bbox["y0"].as_f64()?,
+                x1: bbox["x1"].as_f64()?,
+                y1: bbox["y1"].as_f64()?,
+            })
+        })
+        .filter(|word| !word.text.is_empty())
+        .collect())
+}
+
+fn median(mut values: Vec<f64>) -> f64 {
+    values.sort_by(|a, b| a.total_cmp(b));
+    values.get(values.len() / 2).copied().unwrap_or(0.0)
+}
+
+/// Lines of words, top to bottom, each sorted left to right.
+fn group_rows(mut words: Vec<OcrWord>) -> Vec<Vec<OcrWord>> {
+    words.sort_by(|a, b| a.center_y().total_cmp(&b.center_y()));
+
+    let mut rows: Vec<Vec<OcrWord>> = Vec::new();
+    for word in words {
+        // Same line when the word's centre falls within the current line's vertical band
+        let joins = rows.last().map_or(false, |row| {
+            let top = row.iter().map(|w| w.y0).fold(f64::MAX, f64::min);
+            let bottom = row.iter().map(|w| w.y1).fold(f64::MIN, f64::max);
+            word.center_y() >= top && word.center_y() <= bottom
+        });
+        match rows.last_mut() {
+            Some(row) if joins => row.push(word),
+            _ => rows.push(vec![word]),
+        }
+    }
+    for row in &mut rows {
+        row.sort_by(|a, b| a.x0.total_cmp(&b.x0));
+    }
+    rows
+}
+
+fn split_cells(row: &[OcrWord], max_gap: f64) -> Vec<Cell> {
+    let mut cells: Vec<Cell> = Vec::new();
+    for word in row {
+        match cells.last_mut() {
+            Some(cell) if word.x0 - cell.x1 <= max_gap => {
+                cell.text.push(' ');
+                cell.text.push_str(&word.text);
+                cell.x1 = cell.x1.max(word.x1);
+            }
+            _ => cells.push(Cell { text: word.text.clone(), x0: word.x0, x1: word.x1 }),
+        }
+    }
+    cells
+}
+
+/// Column bands from the union of overlapping cell extents across multi-cell rows.
+fn column_bands(rows: &[Vec<Cell>]) -> Vec<(f64, f64)> {
+    let mut extents: Vec<(f64, f64)> = rows.iter().filter(|row| row.len() > 1).flatten().map(|cell| (cell.x0, cell.x1)).collect();
+    extents.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut bands: Vec<(f64, f64)> = Vec::new();
+    for (x0, x1) in extents {
+        match bands.last_mut() {
+            Some(band) if x0 <= band.1 => band.1 = band.1.max(x1),
+            _ => bands.push((x0, x1)),
+        }
+    }
+    bands
+}
+
+fn column_for(cell: &Cell, bands: &[(f64, f64)]) -> usize {
+    let overlap = |band: &(f64, f64)| cell.x1.min(band.1) - cell.x0.max(band.0);
+    let center = (cell.x0 + cell.x1) / 2.0;
+    let distance = |band: &(f64, f64)| (center - (band.0 + band.1) / 2.0).abs();
+
+    (0..bands.len())
+        .max_by(|a, b| {
+            overlap(&bands[*a])
+                .total_cmp(&overlap(&bands[*b]))
+                .then_with(|| distance(&bands[*b]).total_cmp(&distance(&bands[*a])))
+        })
+        .unwrap_or(0)
+}
+
+/// Lays OCR words out as a grid of cell texts.
+pub fn detect_table(words: Vec<OcrWord>) -> Result<Vec<Vec<String>>, String> {
+    if words.is_empty() {
+        return Err("❌ No text found in the image".to_string());
+    }
+    let max_gap = median(words.iter().map(OcrWord::height).collect()) * CELL_GAP_LINE_HEIGHTS;
+    let rows: Vec<Vec<Cell>> = group_rows(words).iter().map(|row| split_cells(row, max_gap)).collect();
+
+    let bands = column_bands(&rows);
+    let (Some(first), Some(last)) = (rows.iter().position(|row| row.len() > 1), rows.iter().rposition(|row| row.len() > 1)) else {
+        return Err("❌ No table found: the text has no columns".to_string());
+    };
+    if bands.len() < 2 {
+        return Err("❌ No table found: the text has no columns".to_string());
+    }
+
+    let mut grid: Vec<Vec<String>> = Vec::new();
+    for row in &rows[first..=last] {
+        let mut cells = vec![String::new(); bands.len()];
+        for cell in row {
+            let target = &mut cells[column_for(cell, &bands)];
+            if !target.is_empty() {
+                target.push(' ');
+            }
+            target.push_str(&cell.text);
+        }
+
+        // A lone cell between table rows is wrapped text belonging to the row above
+        match grid.last_mut() {
+            Some(previous) if row.len() == 1 => {
+                for (column, text) in cells.into_iter().enumerate().filter(|(_, text)| !text.is_empty()) {
+                    if !previous[column].is_empty() {
+                        previous[column].push(' ');
+                    }
+                    previous[column].push_str(&text);
+                }
+            }
+            _ => grid.push(cells),
+        }
+    }
+    Ok(grid)
+}
+
+fn to_delimited(rows: &[Vec<String>], delimiter: char) -> String {
+    let escape = |field: &String| {
+        if field.contains(delimiter) || field.contains('"') || field.contains('\n') {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.clone()
+        }
+    };
+    rows.iter()
+        .map(|row| row.iter().map(escape).collect::<Vec<_>>().join(&delimiter.to_string()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The first row is a header when every cell is filled, unique and not a number.
+fn header_of(rows: &[Vec<String>]) -> Option<Vec<String>> {
+    let first = rows.first()?;
+    let mut seen = std::collections::BTreeSet::new();
+    let is_label = |cell: &String| !cell.is_empty() && cell.replace([',', '.', '%', '$'], "").parse::<f64>().is_err();
+    let is_header = rows.len() > 1 && first.iter().all(|cell| is_label(cell) && seen.insert(cell.to_lowercase()));
+    is_header.then(|| first.clone())
+}
+
+async fn source_image(image: Option<String>, region: Option<TableRegion>) -> Result<Vec<u8>, String> {
+    match (image, region) {
+        (Some(image), region) => {
+            let mut decoded = crate::imaging::decode_base64_image(&image)?;
+            if let Some(region) = region {
+                let bounds = (decoded.width(), decoded.height());
+                let (x, y, width, height) =
+                    crate::imaging::clamp_region(region.x, region.y, region.width.max(0) as u32, region.height.max(0) as u32, bounds)
+                        .ok_or("❌ Region lies outside the image")?;
+                decoded = decoded.crop_imm(x, y, width, height);
+            }
+            let mut cursor = std::io::Cursor::new(Vec::new());
+            decoded.write_to(&mut cursor, image::ImageOutputFormat::Png).map_err(|e| e.to_string())?;
+            Ok(cursor.into_inner())
+        }
+        (None, Some(region)) => crate::commands::capture_screen_region_png(region.x, region.y, region.width, region.height).await,
+        (None, None) => Err("❌ Provide an image or a screen region".to_string()),
+    }
+}
+
+/// Extracts the table in `image` (base64 or data URL, optionally cropped to `region`) or in a screen `region`;
+/// `copy` puts it on the clipboard as tab-separated text, which spreadsheets paste as cells.
+#[tauri::command]
+pub async fn extract_table(
+    app: AppHandle,
+    image: Option<String>,
+    region: Option<TableRegion>,
+    language: Option<String>,
+    copy: Option<bool>,
+) -> Result<ExtractedTable, String> {
+    let png = source_image(image, region).await?;
+    let words = recognize_words(&png, language.as_deref().unwrap_or("eng+sin")).await?;
+    let confidence = words.iter().map(|word| word.confidence).sum::<f64>() / words.len().max(1) as f64;
+    let rows = detect_table(words)?;
+
+    let headers = header_of(&rows);
+    let records = headers.as_ref().map(|headers| {
+        rows[1..]
+            .iter()
+            .map(|row| headers.iter().cloned().zip(row.iter().map(|cell| serde_json::json!(cell))).collect())
+            .collect()
+    });
+
+    if copy.unwrap_or(false) {
+        app.clipboard_manager().write_text(to_delimited(&rows, '\t')).map_err(|e| e.to_string())?;
+    }
+
+    println!("📊 Extracted a {}×{} table", rows.len(), rows.first().map_or(0, Vec::len));
+    Ok(ExtractedTable { headers, csv: to_delimited(&rows, ','), rows, records, confidence })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A word on a 10px-high line starting at (x, y), 8px per character.
+    fn word(text: &str, x: f64, y: f64) -> OcrWord {
+        OcrWord { text: text.to_string(), confidence: 90.0, x0: x, y0: y, x1: x + 8.0 * text.len() as f64, y1: y + 10.0 }
+    }
+
+    fn strings(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter().map(|row| row.iter().map(|cell| cell.to_string()).collect()).collect()
+    }
+
+    #[test]
+    fn lays_out_rows_and_columns() {
+        let words = vec![
+            word("Name", 0.0, 0.0), word("Qty", 100.0, 0.0), word("Price", 200.0, 0.0),
+            word("Green", 0.0, 20.0), word("apple", 44.0, 20.0), word("3", 100.0, 20.0), word("1.50", 200.0, 20.0),
+            word("Pear", 0.0, 40.0), word("12", 100.0, 40.0), word("0.80", 200.0, 40.0),
+        ];
+
+        let grid = detect_table(words).unwrap();
+        assert_eq!(grid, strings(&[&["Name", "Qty", "Price"], &["Green apple", "3", "1.50"], &["Pear", "12", "0.80"]]));
+        assert_eq!(header_of(&grid), Some(grid[0].clone()));
+    }
+
+    #[test]
+    fn appends_wrapped_lines_to_the_row_above() {
+        let words = vec![
+            word("Item", 0.0, 0.0), word("Note", 100.0, 0.0),
+            word("Banana", 0.0, 20.0), word("ripe", 100.0, 20.0),
+            word("split", 0.0, 32.0),
+            word("Cherry", 0.0, 50.0), word("sour", 100.0, 50.0),
+            word("today", 100.0, 62.0),
+            word("Date", 0.0, 80.0), word("sweet", 100.0, 80.0),
+        ];
+
+        let grid = detect_table(words).unwrap();
+        assert_eq!(grid, strings(&[&["Item", "Note"], &["Banana split", "ripe"], &["Cherry", "sour today"], &["Date", "sweet"]]));
+    }
+
+    #[test]
+    fn leaves_missing_cells_of_ragged_rows_empty() {
+        let words = vec![
+            word("Name", 0.0, 0.0), word("Qty", 100.0, 0.0), word("Price", 200.0, 0.0),
+            word("Cherry", 0.0, 20.0), word("2.00", 200.0, 20.0),
+            word("Plum", 0.0, 40.0), word("7", 100.0, 40.0),
+        ];
+
+        let grid = detect_table(words).unwrap();
+        assert_eq!(grid, strings(&[&["Name", "Qty", "Price"], &["Cherry", "", "2.00"], &["Plum", "7", ""]]));
+    }
+
+    #[test]
+    fn ignores_single_column_text_around_the_table() {
+        let words = vec![
+            word("Prices", 0.0, 0.0),
+            word("Name", 0.0, 20.0), word("Price", 100.0, 20.0),
+            word("Fig", 0.0, 40.0), word("3.10", 100.0, 40.0),
+            word("Updated", 0.0, 60.0), word("daily", 60.0, 60.0),
+        ];
+
+        let grid = detect_table(words).unwrap();
+        assert_eq!(grid[0], strings(&[&["Name", "Price"]])[0]);
+        assert_eq!(grid.len(), 2);
+    }
+
+    #[test]
+    fn rejects_input_without_a_table() {
+        assert_eq!(detect_table(Vec::new()), Err("❌ No text found in the image".to_string()));
+
+        let prose = vec![word("Dear", 0.0, 0.0), word("team,", 36.0, 0.0), word("thanks", 0.0, 20.0), word("again", 52.0, 20.0)];
+        assert_eq!(detect_table(prose), Err("❌ No table found: the text has no columns".to_string()));
+    }
+
+    #[test]
+    fn quotes_fields_containing_the_delimiter() {
+        let rows = strings(&[&["Name", "Note"], &["Fig", "sweet, \"dried\""]]);
+        assert_eq!(to_delimited(&rows, ','), "Name,Note\nFig,\"sweet, \"\"dried\"\"\"");
+    }
+}
@@ -0,0 +1,88 @@
+/**
+ * Text input module - IME-safe text insertion through platform Unicode APIs
+ */
+
+/// Languages whose input usually goes through an IME, where per-key simulation breaks.
+const IME_LAYOUTS: [&str; 5] = ["si", "ta", "zh", "ja", "ko"];
+
+/// Decides whether text should bypass per-key simulation.
+pub fn should_use_unicode_input(text: &str, layout: &str) -> bool {
+    !text.is_ascii() || IME_LAYOUTS.contains(&layout)
+}
+
+#[cfg(target_os = "macos")]
+fn insert_text_native(text: &str) -> Result<(), String> {
+    use core_graphics::event::{CGEvent, CGEventTapLocation};
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+        .map_err(|_| "Failed to create event source".to_string())?;
+
+    // CGEventKeyboardSetUnicodeString only honours ~20 UTF-16 units per event
+    let units: Vec<u16> = text.encode_utf16().collect();
+    for chunk in units.chunks(20) {
+        let chunk = String::from_utf16_lossy(chunk);
+        for key_down in [true, false].iter() {
+            let event = CGEvent::new_keyboard_event(source.clone(), 0, *key_down)
+                .map_err(|_| "Failed to create keyboard event".to_string())?;
+            event.set_string(&chunk);
+            event.post(CGEventTapLocation::HID);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn insert_text_native(text: &str) -> Result<(), String> {
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP, KEYEVENTF_UNICODE, VIRTUAL_KEY,
+    };
+
+    let mut inputs = Vec::new();
+    for unit in text.encode_utf16() {
+        for flags in [KEYEVENTF_UNICODE, KEYEVENTF_UNICODE | KEYEVENTF_KEYUP].iter() {
+            inputs.push(INPUT {
+                r#type: INPUT_KEYBOARD,
+                Anonymous: INPUT_0 {
+                    ki: KEYBDINPUT {
+                        wVk: VIRTUAL_KEY(0),
+                        wScan: unit,
+                        dwFlags: *flags,
+                        time: 0,
+                        dwExtraInfo: 0,
+                    },
+                },
+            });
+        }
+    }
+
+    let sent = unsafe { SendInput(&inputs, std::mem::size_of::<INPUT>() as i32) };
+    if sent as usize == inputs.len() {
+        Ok(())
+    } else {
+        Err(format!("SendInput injected {} of {} events", sent, inputs.len()))
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn insert_text_native(text: &str) -> Result<(), String> {
+    use crate::watchdog::WatchdogCommand;
+    use std::process::Command;
+
+    // xdotool remaps a spare keycode per character through XTest, which commits text directly
+    let output = Command::new("xdotool")
+        .args(&["type", "--clearmodifiers", "--delay", "0", "--", text])
+        .output_with_timeout()?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    }
+}
+
+/// Inserts text as committed Unicode, bypassing the active IME and keyboard layout.
+pub fn insert_text(text: &str) -> Result<(), String> {
+    insert_text_native(text)
+}
@@ -0,0 +1,149 @@
+/**
+ * Theme module - System appearance (light/dark/high-contrast, accent colour) detection
+ */
+
+use serde::Serialize;
+use std::process::Command;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use crate::watchdog::WatchdogCommand;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemTheme {
+    /// "light", "dark" or "high-contrast"
+    pub mode: String,
+    pub high_contrast: bool,
+    /// Accent colour as #RRGGBB when the platform exposes one
+    pub accent_color: Option<String>,
+}
+
+fn read_output(command: &mut Command) -> Option<String> {
+    let output = command.output_within(Duration::from_secs(3)).ok()?;
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn detect_theme() -> SystemTheme {
+    let dark = read_output(Command::new("defaults").args(&["read", "-g", "AppleInterfaceStyle"]))
+        .map_or(false, |style| style.eq_ignore_ascii_case("dark"));
+    let high_contrast = read_output(Command::new("defaults").args(&["read", "com.apple.universalaccess", "increaseContrast"]))
+        .map_or(false, |value| value == "1");
+
+    // AppleAccentColor is an index; missing means the default blue
+    let accent_color = match read_output(Command::new("defaults").args(&["read", "-g", "AppleAccentColor"])).as_deref() {
+        Some("-1") => "#8C8C8C",
+        Some("0") => "#FF5257",
+        Some("1") => "#F7821B",
+        Some("2") => "#FFC600",
+        Some("3") => "#62BA46",
+        Some("5") => "#A550A7",
+        Some("6") => "#F74F9E",
+        _ => "#007AFF",
+    };
+
+    SystemTheme {
+        mode: if high_contrast { "high-contrast" } else if dark { "dark" } else { "light" }.to_string(),
+        high_contrast,
+        accent_color: Some(accent_color.to_string()),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn detect_theme() -> SystemTheme {
+    fn reg_dword(key: &str, value: &str) -> Option<u32> {
+        let text = read_output(Command::new("reg").args(&["query", key, "/v", value]))?;
+        let hex = text.split_whitespace().last()?.trim_start_matches("0x");
+        u32::from_str_radix(hex, 16).ok()
+    }
+
+    let light = reg_dword(
+        "HKCU\\Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize",
+        "AppsUseLightTheme",
+    )
+    .map_or(true, |value| value == 1);
+    // HCF_HIGHCONTRASTON is bit 0 of the Flags value
+    let high_contrast = read_output(Command::new("reg").args(&["query", "HKCU\\Control Panel\\Accessibility\\HighContrast", "/v", "Flags"]))
+        .and_then(|text| text.split_whitespace().last().and_then(|flags| flags.parse::<u32>().ok()))
+        .map_or(false, |flags| flags & 1 == 1);
+    // AccentColor is stored as 0xAABBGGRR
+    let accent_color = reg_dword("HKCU\\Software\\Microsoft\\Windows\\DWM", "AccentColor")
+        .map(|abgr| format!("#{:02X}{:02X}{:02X}", abgr & 0xFF, (abgr >> 8) & 0xFF, (abgr >> 16) & 0xFF));
+
+    SystemTheme {
+        mode: if high_contrast { "high-contrast" } else if light { "light" } else { "dark" }.to_string(),
+        high_contrast,
+        accent_color,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_theme() -> SystemTheme {
+    let gsettings = |key: &str| {
+        read_output(Command::new("gsettings").args(&["get", "org.gnome.desktop.interface", key]))
+            .map(|value| value.trim_matches('\'').to_lowercase())
+    };
+
+    let gtk_theme = gsettings("gtk-theme").unwrap_or_default();
+    let high_contrast = gtk_theme.contains("highcontrast")
+        || read_output(Command::new("gsettings").args(&["get", "org.gnome.desktop.a11y.interface", "high-contrast"]))
+            .map_or(false, |value| value == "true");
+    let dark = gsettings("color-scheme").map_or(false, |scheme| scheme.contains("dark")) || gtk_theme.contains("dark");
+
+    // GNOME 47+ exposes named accent colours
+    let accent_color = gsettings("accent-color").and_then(|name| {
+        let hex = match name.as_str() {
+            "blue" => "#3584E4",
+            "teal" => "#2190A4",
+            "green" => "#3A944A",
+            "yellow" => "#C88800",
+            "orange" => "#ED5B00",
+            "red" => "#E62D42",
+            "pink" => "#D56199",
+            "purple" => "#9141AC",
+            "slate" => "#6F8396",
+            _ => return None,
+        };
+        Some(hex.to_string())
+    });
+
+    SystemTheme {
+        mode: if high_contrast { "high-contrast" } else if dark { "dark" } else { "light" }.to_string(),
+        high_contrast,
+        accent_color,
+    }
+}
+
+/// Polls the OS appearance and emits `system://theme-changed` whenever it differs.
+pub fn spawn_theme_watcher(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut last = tauri::async_runtime::spawn_blocking(detect_theme).await.ok();
+
+        loop {
+            tokio::time::sleep(Duration::from_secs(3)).await;
+
+            let current = match tauri::async_runtime::spawn_blocking(detect_theme).await {
+                Ok(theme) => theme,
+                Err(_) => continue,
+            };
+
+            if last.as_ref() != Some(&current) {
+                println!("🎨 System theme changed: {}", current.mode);
+                let _ = app.emit_all("system://theme-changed", current.clone());
+                last = Some(current);
+            }
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn get_system_theme() -> Result<SystemTheme, String> {
+    tauri::async_runtime::spawn_blocking(detect_theme)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,254 @@
+/**
+ * Tickers module - Stock and crypto price watches with threshold-crossing alerts
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TickerProvider {
+    /// Quote URL with a `{symbol}` placeholder
+    pub url_template: String,
+    /// JSON pointer to the price in the provider's response
+    pub price_pointer: String,
+    pub poll_interval_secs: u64,
+}
+
+impl Default for TickerProvider {
+    fn default() -> Self {
+        // Yahoo covers both equities (AAPL) and crypto pairs (BTC-USD)
+        TickerProvider {
+            url_template: "https://query1.finance.yahoo.com/v8/finance/chart/{symbol}".to_string(),
+            price_pointer: "/chart/result/0/meta/regularMarketPrice".to_string(),
+            poll_interval_secs: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CrossDirection {
+    Above,
+    Below,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TickerWatch {
+    pub id: u64,
+    pub symbol: String,
+    pub threshold: f64,
+    pub direction: CrossDirection,
+    pub last_price: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TickerState {
+    pub provider: TickerProvider,
+    pub watches: Vec<TickerWatch>,
+    pub next_id: u64,
+}
+
+fn tickers_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("tickers.json"))
+}
+
+fn save_tickers(app: &AppHandle, ticker_state: &TickerState) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(ticker_state).map_err(|e| e.to_string())?;
+    std::fs::write(tickers_path(app)?, json).map_err(|e| e.to_string())
+}
+
+async fn fetch_price(provider: &TickerProvider, symbol: &str) -> Result<f64, String> {
+    let url = provider.url_template.replace("{symbol}", symbol);
+    let response = crate::egress::send(
+        crate::bandwidth::Feature::MarketData,
+        crate::proxy::client()
+            .get(url)
+            .header("User-Agent", "JarvisX")
+            .timeout(Duration::from_secs(10)),
+    )
+    .await?;
+
+    if !response.status().is_success() {
+        return Err(format!("Price provider returned {} for {}", response.status(), symbol));
+    }
+
+    let body: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+    let price = body
+        .pointer(&provider.price_pointer)
+        .ok_or_else(|| format!("No price for {}", symbol))?;
+
+    // Some providers send prices as strings
+    price
+        .as_f64()
+        .or_else(|| price.as_str().and_then(|p| p.parse().ok()))
+        .ok_or_else(|| format!("Unreadable price for {}", symbol))
+}
+
+fn crossed(watch: &TickerWatch, previous: f64, price: f64) -> bool {
+    match watch.direction {
+        CrossDirection::Above => previous < watch.threshold && price >= watch.threshold,
+        CrossDirection::Below => previous > watch.threshold && price <= watch.threshold,
+    }
+}
+
+async fn poll_tickers(app: &AppHandle) {
+    let (provider, symbols) = {
+        let state = app.state::<Mutex<TickerState>>();
+        let ticker_state = state.lock().unwrap();
+        let mut symbols: Vec<String> = ticker_state.watches.iter().map(|watch| watch.symbol.clone()).collect();
+        symbols.sort();
+        symbols.dedup();
+        (ticker_state.provider.clone(), symbols)
+    };
+
+    let mut prices = BTreeMap::new();
+    for symbol in symbols {
+        match fetch_price(&provider, &symbol).await {
+            Ok(price) => {
+                prices.insert(symbol, price);
+            }
+            Err(e) => println!("⚠️  {}", e),
+        }
+    }
+
+    let alerts: Vec<(TickerWatch, f64)> = {
+        let state = app.state::<Mutex<TickerState>>();
+        let mut ticker_state = state.lock().unwrap();
+        let mut alerts = Vec::new();
+
+        for watch in ticker_state.watches.iter_mut() {
+            if let Some(&price) = prices.get(&watch.symbol) {
+                if matches!(watch.last_price, Some(previous) if crossed(watch, previous, price)) {
+                    alerts.push((watch.clone(), price));
+                }
+                watch.last_price = Some(price);
+            }
+        }
+
+        let _ = save_tickers(app, &ticker_state);
+        alerts
+    };
+
+    for (watch, price) in alerts {
+        let direction = if watch.direction == CrossDirection::Above { "above" } else { "below" };
+        println!("📈 {} crossed {} {}", watch.symbol, direction, watch.threshold);
+        crate::system::show_notification(
+            app,
+            &format!("{} price alert", watch.symbol),
+            &format!("{} is now {} ({} {})", watch.symbol, crate::locale::format_number(price, 2), direction, watch.threshold),
+        );
+        let _ = app.emit_all("ticker://threshold-crossed", serde_json::json!({
+            "watch": watch,
+            "price": price,
+        }));
+    }
+}
+
+/// Restores watches and polls the provider while any exist.
+pub fn spawn_ticker_watcher(app: AppHandle) {
+    let loaded: TickerState = tickers_path(&app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    *app.state::<Mutex<TickerState>>().lock().unwrap() = loaded;
+
+    tauri::async_runtime::spawn(async move {
+        let mut registered = false;
+
+        loop {
+            let (has_watches, interval) = {
+                let state = app.state::<Mutex<TickerState>>();
+                let ticker_state = state.lock().unwrap();
+                (!ticker_state.watches.is_empty(), ticker_state.provider.poll_interval_secs.max(15))
+            };
+
+            if has_watches != registered {
+                if has_watches {
+                    crate::scheduler::register_task(&app, "ticker-watch", crate::scheduler::TaskPriority::Normal);
+                } else {
+                    crate::scheduler::unregister_task(&app, "ticker-watch");
+                }
+                registered = has_watches;
+            }
+
+            if has_watches && !crate::scheduler::is_paused(&app, "ticker-watch") {
+                poll_tickers(&app).await;
+            }
+
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn watch_ticker(
+    app: AppHandle,
+    symbol: String,
+    threshold: f64,
+    direction: Option<CrossDirection>,
+) -> Result<TickerWatch, String> {
+    let symbol = symbol.trim().to_uppercase();
+    let provider = app.state::<Mutex<TickerState>>().lock().unwrap().provider.clone();
+    let price = fetch_price(&provider, &symbol).await?;
+
+    // Without an explicit direction, alert when the price moves to the threshold from where it is now
+    let direction = direction.unwrap_or(if price < threshold { CrossDirection::Above } else { CrossDirection::Below });
+
+    let state = app.state::<Mutex<TickerState>>();
+    let mut ticker_state = state.lock().unwrap();
+    ticker_state.next_id += 1;
+
+    let watch = TickerWatch {
+        id: ticker_state.next_id,
+        symbol,
+        threshold,
+        direction,
+        last_price: Some(price),
+    };
+    ticker_state.watches.push(watch.clone());
+    save_tickers(&app, &ticker_state)?;
+
+    println!("📈 Watching {} {:?} {}", watch.symbol, watch.direction, threshold);
+    Ok(watch)
+}
+
+#[tauri::command]
+pub async fn list_watched_tickers(state: State<'_, Mutex<TickerState>>) -> Result<Vec<TickerWatch>, String> {
+    Ok(state.lock().unwrap().watches.clone())
+}
+
+#[tauri::command]
+pub async fn remove_watch(app: AppHandle, state: State<'_, Mutex<TickerState>>, id: u64) -> Result<(), String> {
+    let mut ticker_state = state.lock().unwrap();
+    let before = ticker_state.watches.len();
+    ticker_state.watches.retain(|watch| watch.id != id);
+
+    if ticker_state.watches.len() == before {
+        return Err(format!("❌ Watch not found: {}", id));
+    }
+
+    save_tickers(&app, &ticker_state)
+}
+
+#[tauri::command]
+pub async fn set_ticker_provider(
+    app: AppHandle,
+    state: State<'_, Mutex<TickerState>>,
+    provider: TickerProvider,
+) -> Result<TickerProvider, String> {
+    if !provider.url_template.contains("{symbol}") {
+        return Err("Provider URL must contain {symbol}".to_string());
+    }
+
+    let mut ticker_state = state.lock().unwrap();
+    ticker_state.provider = provider.clone();
+    save_tickers(&app, &ticker_state)?;
+    Ok(provider)
+}
@@ -0,0 +1,380 @@
+/**
+ * Time context module - Time zone, local time, part of day, sunrise/sunset and public holidays, all computed offline
+ *
+ * Sunrise and sunset use the NOAA sunrise equation for the configured location (or a representative city for the
+ * time zone). Sri Lankan holidays are built in: fixed dates and Good Friday are exact, the gazetted list is bundled for
+ * the years it is known, and other years' Poya days come from the astronomical full moon (flagged as estimated).
+ * Other countries are plugged in by dropping `holidays/<country>.json` into the profile folder.
+ */
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, TimeZone, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tauri::AppHandle;
+
+/// Sun altitude at sunrise/sunset, allowing for refraction and the solar disc
+const SUN_ALTITUDE: f64 = -0.833;
+/// Sri Lanka Standard Time, which Poya days are reckoned in
+const SRI_LANKA_OFFSET_SECS: i32 = 5 * 3600 + 1800;
+const UPCOMING_DAYS: i64 = 30;
+
+/// Representative city per time zone, used when no location is configured.
+const TIME_ZONE_LOCATIONS: [(&str, f64, f64, &str); 13] = [
+    ("Asia/Colombo", 6.9271, 79.8612, "LK"),
+    ("Asia/Kolkata", 28.6139, 77.2090, "IN"),
+    ("Asia/Calcutta", 28.6139, 77.2090, "IN"),
+    ("Asia/Dubai", 25.2048, 55.2708, "AE"),
+    ("Asia/Singapore", 1.3521, 103.8198, "SG"),
+    ("Asia/Tokyo", 35.6762, 139.6503, "JP"),
+    ("Australia/Sydney", -33.8688, 151.2093, "AU"),
+    ("Europe/London", 51.5074, -0.1278, "GB"),
+    ("Europe/Berlin", 52.5200, 13.4050, "DE"),
+    ("America/New_York", 40.7128, -74.0060, "US"),
+    ("America/Chicago", 41.8781, -87.6298, "US"),
+    ("America/Denver", 39.7392, -104.9903, "US"),
+    ("America/Los_Angeles", 34.0522, -118.2437, "US"),
+];
+
+/// Gazetted Sri Lankan public holidays for years whose lunar and religious dates are confirmed.
+const SRI_LANKA_GAZETTED: [(&str, &str, &str); 25] = [
+    ("2025-01-13", "Duruthu Full Moon Poya Day", "poya"),
+    ("2025-01-14", "Tamil Thai Pongal Day", "public"),
+    ("2025-02-04", "National Day", "public"),
+    ("2025-02-12", "Navam Full Moon Poya Day", "poya"),
+    ("2025-02-26", "Mahasivarathri Day", "public"),
+    ("2025-03-13", "Medin Full Moon Poya Day", "poya"),
+    ("2025-03-31", "Id-Ul-Fitr (Ramazan Festival Day)", "public"),
+    ("2025-04-12", "Bak Full Moon Poya Day", "poya"),
+    ("2025-04-13", "Day prior to Sinhala and Tamil New Year Day", "public"),
+    ("2025-04-14", "Sinhala and Tamil New Year Day", "public"),
+    ("2025-04-18", "Good Friday", "public"),
+    ("2025-05-01", "May Day", "public"),
+    ("2025-05-12", "Vesak Full Moon Poya Day", "poya"),
+    ("2025-05-13", "Day following Vesak Full Moon Poya Day", "public"),
+    ("2025-06-07", "Id-Ul-Alha (Hadji Festival Day)", "public"),
+    ("2025-06-10", "Poson Full Moon Poya Day", "poya"),
+    ("2025-07-10", "Esala Full Moon Poya Day", "poya"),
+    ("2025-08-08", "Nikini Full Moon Poya Day", "poya"),
+    ("2025-09-05", "Milad-Un-Nabi (Holy Prophet's Birthday)", "public"),
+    ("2025-09-07", "Binara Full Moon Poya Day", "poya"),
+    ("2025-10-06", "Vap Full Moon Poya Day", "poya"),
+    ("2025-10-20", "Deepavali Festival Day", "public"),
+    ("2025-11-05", "Il Full Moon Poya Day", "poya"),
+    ("2025-12-04", "Unduvap Full Moon Poya Day", "poya"),
+    ("2025-12-25", "Christmas Day", "public"),
+];
+
+const POYA_NAMES: [&str; 12] = ["Duruthu", "Navam", "Medin", "Bak", "Vesak", "Poson", "Esala", "Nikini", "Binara", "Vap", "Il", "Unduvap"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Holiday {
+    pub date: NaiveDate,
+    pub name: String,
+    /// "public", "poya", "religious", ...; free-form for plugged-in calendars
+    pub kind: String,
+    #[serde(default)]
+    pub country: String,
+    /// Computed rather than taken from an official list; may be off by a day
+    #[serde(default)]
+    pub estimated: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeLocation {
+    pub latitude: f64,
+    pub longitude: f64,
+    /// ISO 3166 alpha-2 code choosing the holiday calendar
+    pub country: String,
+}
+
+/// `holidays/<country>.json` in the profile folder: a flat list of holidays, any years.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HolidayCalendar {
+    holidays: Vec<Holiday>,
+}
+
+fn location_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("time_context.json"))
+}
+
+fn time_zone_name() -> Option<String> {
+    iana_time_zone::get_timezone().ok()
+}
+
+/// The configured location, else the time zone's representative city, else the locale's country without coordinates.
+fn location(app: &AppHandle, time_zone: Option<&str>) -> (Option<TimeLocation>, &'static str) {
+    let saved = location_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str::<TimeLocation>(&json).ok());
+    if let Some(saved) = saved {
+        return (Some(saved), "settings");
+    }
+
+    if let Some((_, latitude, longitude, country)) = TIME_ZONE_LOCATIONS.iter().find(|(zone, ..)| Some(*zone) == time_zone) {
+        return (Some(TimeLocation { latitude: *latitude, longitude: *longitude, country: country.to_string() }), "timeZone");
+    }
+    (None, "unknown")
+}
+
+fn country_for(location: Option<&TimeLocation>) -> String {
+    match location {
+        Some(location) => location.country.to_uppercase(),
+        None => crate::locale::current_locale().split('-').nth(1).unwrap_or("US").to_uppercase(),
+    }
+}
+
+/// Country whose holiday calendar applies to the user.
+pub fn home_country(app: &AppHandle) -> String {
+    country_for(location(app, time_zone_name().as_deref()).0.as_ref())
+}
+
+fn julian_day(unix_secs: f64) -> f64 {
+    unix_secs / 86400.0 + 2440587.5
+}
+
+fn unix_from_julian(julian: f64) -> i64 {
+    ((julian - 2440587.5) * 86400.0).round() as i64
+}
+
+/// Sunrise and sunset (UTC) on `date` at the location; None during polar day or night.
+pub fn sun_times(date: NaiveDate, latitude: f64, longitude: f64) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let noon = date.and_hms_opt(12, 0, 0)?.and_utc().timestamp() as f64;
+    let day = (julian_day(noon) - 2451545.0 + 0.0008).ceil();
+    let mean_noon = day - longitude / 360.0;
+
+    let anomaly = (357.5291 + 0.98560028 * mean_noon).rem_euclid(360.0).to_radians();
+    let center = 1.9148 * anomaly.sin() + 0.02 * (2.0 * anomaly).sin() + 0.0003 * (3.0 * anomaly).sin();
+    let ecliptic_longitude = (anomaly.to_degrees() + center + 180.0 + 102.9372).rem_euclid(360.0).to_radians();
+    let transit = 2451545.0 + mean_noon + 0.0053 * anomaly.sin() - 0.0069 * (2.0 * ecliptic_longitude).sin();
+
+    let declination = (ecliptic_longitude.sin() * 23.4397f64.to_radians().sin()).asin();
+    let latitude = latitude.to_radians();
+    let cos_hour_angle = (SUN_ALTITUDE.to_radians().sin() - latitude.sin() * declination.sin()) / (latitude.cos() * declination.cos());
+    if !(-1.0..=1.0).contains(&cos_hour_angle) {
+        return None;
+    }
+    let hour_angle = cos_hour_angle.acos().to_degrees() / 360.0;
+
+    Some((
+        Utc.timestamp_opt(unix_from_julian(transit - hour_angle), 0).single()?,
+        Utc.timestamp_opt(unix_from_julian(transit + hour_angle), 0).single()?,
+    ))
+}
+
+/// Full moons in `year` (UTC), from Meeus' lunar phase series; accurate to a few minutes.
+fn full_moons(year: i32) -> Vec<DateTime<Utc>> {
+    let start = ((year as f64 - 2000.0) * 12.3685).floor() - 1.0;
+    (0..15)
+        .filter_map(|offset| {
+            let k = start + offset as f64 + 0.5;
+            let t = k / 1236.85;
+            let e = 1.0 - 0.002516 * t - 0.0000074 * t * t;
+            let sun = (2.5534 + 29.10535670 * k - 0.0000014 * t * t).to_radians();
+            let moon = (201.5643 + 385.81693528 * k + 0.0107582 * t * t).to_radians();
+            let latitude = (160.7108 + 390.67050284 * k - 0.0016118 * t * t).to_radians();
+            let node = (124.7746 - 1.56375588 * k + 0.0020672 * t * t).to_radians();
+
+            let julian = 2451550.09766 + 29.530588861 * k + 0.00015437 * t * t
+                - 0.40614 * moon.sin()
+                + 0.17302 * e * sun.sin()
+                + 0.01614 * (2.0 * moon).sin()
+                + 0.01043 * (2.0 * latitude).sin()
+                + 0.00734 * e * (moon - sun).sin()
+                - 0.00515 * e * (moon + sun).sin()
+                + 0.00209 * e * e * (2.0 * sun).sin()
+                - 0.00111 * (moon - 2.0 * latitude).sin()
+                - 0.00057 * (moon + 2.0 * latitude).sin()
+                + 0.00056 * e * (2.0 * moon + sun).sin()
+                - 0.00042 * (3.0 * moon).sin()
+                + 0.00042 * e * (sun + 2.0 * latitude).sin()
+                + 0.00038 * e * (sun - 2.0 * latitude).sin()
+                - 0.00024 * e * (2.0 * moon - sun).sin()
+                - 0.00017 * node.sin();
+            Utc.timestamp_opt(unix_from_julian(julian), 0).single()
+        })
+        .filter(|moment| moment.year() == year)
+        .collect()
+}
+
+/// Western Easter Sunday (anonymous Gregorian computus).
+fn easter(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = (19 * a + b - b / 4 - (b - (8 * b + 13) / 25) + 15) % 30;
+    let e = (32 + 2 * (b % 4) + 2 * (c / 4) - d - c % 4) % 7;
+    let f = d + e - 7 * ((a + 11 * d + 22 * e) / 451) + 114;
+    NaiveDate::from_ymd_opt(year, (f / 31) as u32, (f % 31 + 1) as u32)
+}
+
+fn sri_lanka_holidays(year: i32) -> Vec<Holiday> {
+    let holiday = |date: NaiveDate, name: &str, kind: &str, estimated: bool| Holiday {
+        date,
+        name: name.to_string(),
+        kind: kind.to_string(),
+        country: "LK".to_string(),
+        estimated,
+    };
+
+    let gazetted: Vec<Holiday> = SRI_LANKA_GAZETTED
+        .iter()
+        .filter_map(|(date, name, kind)| Some(holiday(NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?, name, kind, false)))
+        .filter(|entry| entry.date.year() == year)
+        .collect();
+    if !gazetted.is_empty() {
+        return gazetted;
+    }
+
+    let mut holidays = Vec::new();
+    let fixed = [
+        (1, 14, "Tamil Thai Pongal Day", true),
+        (2, 4, "National Day", false),
+        (4, 13, "Day prior to Sinhala and Tamil New Year Day", false),
+        (4, 14, "Sinhala and Tamil New Year Day", false),
+        (5, 1, "May Day", false),
+        (12, 25, "Christmas Day", false),
+    ];
+    for (month, day, name, estimated) in fixed {
+        if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
+            holidays.push(holiday(date, name, "public", estimated));
+        }
+    }
+    if let Some(good_friday) = easter(year).map(|easter| easter - Duration::days(2)) {
+        holidays.push(holiday(good_friday, "Good Friday", "public", false));
+    }
+
+    // Poya days fall on the full moon's date in Sri Lanka; a month with two gets an "Adhi" (extra) Poya first
+    let offset = FixedOffset::east_opt(SRI_LANKA_OFFSET_SECS).unwrap();
+    let poya_dates: Vec<NaiveDate> = full_moons(year).iter().map(|moment| moment.with_timezone(&offset).date_naive()).collect();
+    for (index, date) in poya_dates.iter().enumerate() {
+        let month_name = POYA_NAMES[date.month0() as usize];
+        let adhi = poya_dates.get(index + 1).map_or(false, |next| next.month() == date.month());
+        let name = format!("{}{} Full Moon Poya Day", if adhi { "Adhi " } else { "" }, month_name);
+        holidays.push(holiday(*date, &name, "poya", true));
+        if month_name == "Vesak" && !adhi {
+            holidays.push(holiday(*date + Duration::days(1), "Day following Vesak Full Moon Poya Day", "public", true));
+        }
+    }
+
+    holidays.sort_by_key(|entry| entry.date);
+    holidays
+}
+
+/// Holidays of `country` in `year`: a plugged-in calendar file wins over the built-in Sri Lankan one.
+pub fn holidays(app: &AppHandle, country: &str, year: i32) -> Vec<Holiday> {
+    let country = country.to_uppercase();
+    let plugged = crate::profiles::active_profile_dir(app)
+        .ok()
+        .map(|dir| dir.join("holidays").join(format!("{}.json", country.to_lowercase())))
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| match serde_json::from_str::<HolidayCalendar>(&json) {
+            Ok(calendar) => Some(calendar),
+            Err(e) => {
+                println!("⚠️  Ignoring holiday calendar for {}: {}", country, e);
+                None
+            }
+        });
+
+    match plugged {
+        Some(calendar) => calendar
+            .holidays
+            .into_iter()
+            .filter(|holiday| holiday.date.year() == year)
+            .map(|holiday| Holiday { country: country.clone(), ..holiday })
+            .collect(),
+        None if country == "LK" => sri_lanka_holidays(year),
+        None => Vec::new(),
+    }
+}
+
+/// "morning", "afternoon", "evening" or "night", with sunset ending the afternoon when it is known.
+fn part_of_day(now: DateTime<Local>, sunset: Option<DateTime<Local>>) -> &'static str {
+    match now.hour() {
+        4..=11 => "morning",
+        12..=16 if sunset.map_or(true, |sunset| now < sunset) => "afternoon",
+        12..=21 => "evening",
+        _ => "night",
+    }
+}
+
+#[tauri::command]
+pub async fn get_time_context(app: AppHandle) -> Result<serde_json::Value, String> {
+    let now = Local::now();
+    let today = now.date_naive();
+    let time_zone = tauri::async_runtime::spawn_blocking(time_zone_name).await.map_err(|e| e.to_string())?;
+    let (location, location_source) = location(&app, time_zone.as_deref());
+    let country = country_for(location.as_ref());
+
+    let sun = location.as_ref().and_then(|location| sun_times(today, location.latitude, location.longitude));
+    let (sunrise, sunset) = match sun {
+        Some((sunrise, sunset)) => (Some(sunrise.with_timezone(&Local)), Some(sunset.with_timezone(&Local))),
+        None => (None, None),
+    };
+    let part = part_of_day(now, sunset);
+    let is_daylight = match (sunrise, sunset) {
+        (Some(sunrise), Some(sunset)) => Some(now >= sunrise && now < sunset),
+        _ => None,
+    };
+
+    let mut calendar: BTreeMap<NaiveDate, Vec<Holiday>> = BTreeMap::new();
+    let horizon = today + Duration::days(UPCOMING_DAYS);
+    for year in today.year()..=horizon.year() {
+        for holiday in holidays(&app, &country, year) {
+            calendar.entry(holiday.date).or_default().push(holiday);
+        }
+    }
+    let todays: Vec<&Holiday> = calendar.get(&today).map(|entries| entries.iter().collect()).unwrap_or_default();
+    let upcoming: Vec<&Holiday> = calendar.range(today.succ_opt().unwrap_or(today)..=horizon).flat_map(|(_, entries)| entries).collect();
+
+    Ok(serde_json::json!({
+        "timeZone": time_zone,
+        "utcOffset": now.format("%:z").to_string(),
+        "localTime": now.to_rfc3339(),
+        "date": today,
+        "weekday": now.format("%A").to_string(),
+        "locale": crate::locale::current_locale(),
+        "formatted": crate::locale::format_date(now.timestamp(), true),
+        "partOfDay": part,
+        "greeting": crate::locale::t(&format!("greeting.{}", part)),
+        "location": location,
+        "locationSource": location_source,
+        "country": country,
+        "sunrise": sunrise.map(|time| time.to_rfc3339()),
+        "sunset": sunset.map(|time| time.to_rfc3339()),
+        "isDaylight": is_daylight,
+        "holidaysToday": todays,
+        "upcomingHolidays": upcoming,
+    }))
+}
+
+#[tauri::command]
+pub async fn set_time_location(app: AppHandle, latitude: f64, longitude: f64, country: String) -> Result<TimeLocation, String> {
+    if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+        return Err("❌ Latitude must be within ±90 and longitude within ±180".to_string());
+    }
+    if country.len() != 2 || !country.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(format!("❌ Country must be a two-letter code: {}", country));
+    }
+
+    let location = TimeLocation { latitude, longitude, country: country.to_uppercase() };
+    let json = serde_json::to_string_pretty(&location).map_err(|e| e.to_string())?;
+    std::fs::write(location_path(&app)?, json).map_err(|e| e.to_string())?;
+    println!("🌍 Time context location: {:.3}, {:.3} ({})", latitude, longitude, location.country);
+    Ok(location)
+}
+
+#[tauri::command]
+pub async fn list_holidays(app: AppHandle, year: Option<i32>, country: Option<String>) -> Result<Vec<Holiday>, String> {
+    let country = match country {
+        Some(country) => country,
+        None => {
+            let handle = app.clone();
+            tauri::async_runtime::spawn_blocking(move || home_country(&handle)).await.map_err(|e| e.to_string())?
+        }
+    };
+    Ok(holidays(&app, &country, year.unwrap_or_else(|| Local::now().year())))
+}
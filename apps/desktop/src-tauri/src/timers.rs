@@ -0,0 +1,287 @@
+/**
+ * Timers module - Named countdown timers and a stopwatch that survive the webview being hidden
+ *
+ * A timer runs for a duration ("1h30m") or until a spoken time ("tomorrow at half past six"), which makes it a reminder.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, State};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Timer {
+    pub id: u64,
+    pub name: String,
+    pub duration_secs: u64,
+    pub ends_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Stopwatch {
+    /// Set while running
+    pub started_at_ms: Option<u64>,
+    /// Time accumulated before the current run
+    pub accumulated_ms: u64,
+    /// Elapsed time at each lap
+    pub laps: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerState {
+    pub timers: Vec<Timer>,
+    pub stopwatch: Stopwatch,
+    pub next_id: u64,
+}
+
+/// Sleeping timer tasks by timer id, so cancelling or switching profiles stops them instead of letting them fire.
+static TASKS: Mutex<BTreeMap<u64, tauri::async_runtime::JoinHandle<()>>> = Mutex::new(BTreeMap::new());
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+impl Stopwatch {
+    fn elapsed_ms(&self) -> u64 {
+        self.accumulated_ms + self.started_at_ms.map_or(0, |started| now_ms().saturating_sub(started))
+    }
+}
+
+fn timers_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("timers.json"))
+}
+
+fn save_timers(app: &AppHandle, timer_state: &TimerState) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(timer_state).map_err(|e| e.to_string())?;
+    std::fs::write(timers_path(app)?, json).map_err(|e| e.to_string())
+}
+
+/// Parses "90", "90s", "5m", "1h30m" or "1h 30m 15s" into seconds; bare numbers are seconds.
+pub fn parse_duration(text: &str) -> Result<u64, String> {
+    let text: String = text.to_lowercase().split_whitespace().collect();
+    if let Ok(secs) = text.parse::<u64>() {
+        return Ok(secs);
+    }
+
+    let mut total = 0;
+    let mut number = String::new();
+    for c in text.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+
+        let value: u64 = number.parse().map_err(|_| format!("Invalid duration: {}", text))?;
+        number.clear();
+        total += match c {
+            'h' => value * 3600,
+            'm' => value * 60,
+            's' => value,
+            _ => return Err(format!("Invalid duration: {}", text)),
+        };
+    }
+
+    if !number.is_empty() || total == 0 {
+        return Err(format!("Invalid duration: {}", text));
+    }
+
+    Ok(total)
+}
+
+fn spawn_timer(app: AppHandle, timer: Timer) {
+    let id = timer.id;
+    let task = tauri::async_runtime::spawn(run_timer(app, timer));
+    if let Some(previous) = TASKS.lock().unwrap().insert(id, task) {
+        previous.abort();
+    }
+}
+
+async fn run_timer(app: AppHandle, timer: Timer) {
+    tokio::time::sleep(Duration::from_millis(timer.ends_at_ms.saturating_sub(now_ms()))).await;
+    TASKS.lock().unwrap().remove(&timer.id);
+
+    // Cancelled while sleeping
+    {
+        let state = app.state::<Mutex<TimerState>>();
+        let mut timer_state = state.lock().unwrap();
+        let before = timer_state.timers.len();
+        timer_state.timers.retain(|t| t.id != timer.id);
+        if timer_state.timers.len() == before {
+            return;
+        }
+        let _ = save_timers(&app, &timer_state);
+    }
+
+    println!("⏰ Timer done: {}", timer.name);
+    let message = format!("{} is done", timer.name);
+    crate::system::show_notification(&app, "Timer", &message);
+    let _ = app.emit_all("timer://completed", &timer);
+    if !crate::presentation::is_active() {
+        let _ = crate::speech::speak(&message, None).await;
+    }
+}
+
+/// Restores the active profile's timers and stopwatch, replacing any running ones; timers that expired while
+/// closed fire right away.
+pub fn load_timers(app: AppHandle) {
+    for (_, task) in std::mem::take(&mut *TASKS.lock().unwrap()) {
+        task.abort();
+    }
+
+    let loaded: TimerState = timers_path(&app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+
+    let timers = loaded.timers.clone();
+    *app.state::<Mutex<TimerState>>().lock().unwrap() = loaded;
+
+    println!("⏰ Restored {} timers", timers.len());
+    for timer in timers {
+        spawn_timer(app.clone(), timer);
+    }
+}
+
+#[tauri::command]
+pub async fn start_timer(
+    app: AppHandle,
+    state: State<'_, Mutex<TimerState>>,
+    name: Option<String>,
+    duration: Option<String>,
+    at: Option<String>,
+) -> Result<Timer, String> {
+    let duration_secs = match (duration, at) {
+        (Some(duration), _) => parse_duration(&duration)?,
+        (None, Some(at)) => {
+            let handle = app.clone();
+            let parsed = tauri::async_runtime::spawn_blocking(move || crate::datetime_parse::parse(&handle, &at, chrono::Local::now()))
+                .await
+                .map_err(|e| e.to_string())??;
+            let remaining_ms = parsed.timestamp_ms - now_ms() as i64;
+            if remaining_ms <= 0 {
+                return Err(format!("❌ {} has already passed", parsed.at.to_rfc3339()));
+            }
+            (remaining_ms as u64 + 999) / 1000
+        }
+        (None, None) => return Err("❌ A timer needs a duration or a time".to_string()),
+    };
+
+    let timer = {
+        let mut timer_state = state.lock().unwrap();
+        timer_state.next_id += 1;
+
+        let timer = Timer {
+            id: timer_state.next_id,
+            name: name.unwrap_or_else(|| "Timer".to_string()),
+            duration_secs,
+            ends_at_ms: now_ms() + duration_secs * 1000,
+        };
+        timer_state.timers.push(timer.clone());
+        save_timers(&app, &timer_state)?;
+        timer
+    };
+
+    println!("⏰ Timer started: {} ({}s)", timer.name, duration_secs);
+    spawn_timer(app, timer.clone());
+    Ok(timer)
+}
+
+#[tauri::command]
+pub async fn list_timers(state: State<'_, Mutex<TimerState>>) -> Result<Vec<serde_json::Value>, String> {
+    let now = now_ms();
+
+    Ok(state
+        .lock()
+        .unwrap()
+        .timers
+        .iter()
+        .map(|timer| serde_json::json!({
+            "id": timer.id,
+            "name": timer.name,
+            "durationSecs": timer.duration_secs,
+            "endsAtMs": timer.ends_at_ms,
+            "remainingSecs": timer.ends_at_ms.saturating_sub(now) / 1000,
+        }))
+        .collect())
+}
+
+#[tauri::command]
+pub async fn cancel_timer(app: AppHandle, state: State<'_, Mutex<TimerState>>, id: u64) -> Result<(), String> {
+    let mut timer_state = state.lock().unwrap();
+    let before = timer_state.timers.len();
+    timer_state.timers.retain(|timer| timer.id != id);
+
+    if timer_state.timers.len() == before {
+        return Err(format!("❌ Timer not found: {}", id));
+    }
+    if let Some(task) = TASKS.lock().unwrap().remove(&id) {
+        task.abort();
+    }
+
+    save_timers(&app, &timer_state)
+}
+
+fn stopwatch_status(stopwatch: &Stopwatch) -> serde_json::Value {
+    serde_json::json!({
+        "running": stopwatch.started_at_ms.is_some(),
+        "elapsedMs": stopwatch.elapsed_ms(),
+        "laps": stopwatch.laps,
+    })
+}
+
+#[tauri::command]
+pub async fn start_stopwatch(app: AppHandle, state: State<'_, Mutex<TimerState>>, reset: Option<bool>) -> Result<serde_json::Value, String> {
+    let mut timer_state = state.lock().unwrap();
+
+    if reset.unwrap_or(false) {
+        timer_state.stopwatch = Stopwatch::default();
+    }
+    if timer_state.stopwatch.started_at_ms.is_some() {
+        return Err("Stopwatch already running".to_string());
+    }
+
+    timer_state.stopwatch.started_at_ms = Some(now_ms());
+    save_timers(&app, &timer_state)?;
+    Ok(stopwatch_status(&timer_state.stopwatch))
+}
+
+#[tauri::command]
+pub async fn lap_stopwatch(app: AppHandle, state: State<'_, Mutex<TimerState>>) -> Result<serde_json::Value, String> {
+    let mut timer_state = state.lock().unwrap();
+
+    if timer_state.stopwatch.started_at_ms.is_none() {
+        return Err("Stopwatch not running".to_string());
+    }
+
+    let elapsed = timer_state.stopwatch.elapsed_ms();
+    timer_state.stopwatch.laps.push(elapsed);
+    save_timers(&app, &timer_state)?;
+    Ok(stopwatch_status(&timer_state.stopwatch))
+}
+
+#[tauri::command]
+pub async fn stop_stopwatch(app: AppHandle, state: State<'_, Mutex<TimerState>>) -> Result<serde_json::Value, String> {
+    let mut timer_state = state.lock().unwrap();
+
+    if timer_state.stopwatch.started_at_ms.is_none() {
+        return Err("Stopwatch not running".to_string());
+    }
+
+    timer_state.stopwatch.accumulated_ms = timer_state.stopwatch.elapsed_ms();
+    timer_state.stopwatch.started_at_ms = None;
+    save_timers(&app, &timer_state)?;
+    Ok(stopwatch_status(&timer_state.stopwatch))
+}
+
+#[tauri::command]
+pub async fn get_stopwatch(state: State<'_, Mutex<TimerState>>) -> Result<serde_json::Value, String> {
+    Ok(stopwatch_status(&state.lock().unwrap().stopwatch))
+}
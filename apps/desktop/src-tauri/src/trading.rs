@@ -0,0 +1,376 @@
+/**
+ * Trading module - Broker bridge for positions, quotes and orders with two-step order confirmation
+ *
+ * place_order never executes on the first call: it returns a single-use confirmation token bound to
+ * the exact order, and a second call carrying that token only reaches the broker once the user approves
+ * the order in a native prompt, which callers can't answer themselves. Every step is appended to the
+ * profile's trading audit log.
+ */
+
+use serde::{Deserialize, Serialize};
+use crate::confirmation::{Confirmations, CONFIRMATION_TTL};
+use std::io::Write;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, State};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BrokerKind {
+    Alpaca,
+    Binance,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerConfig {
+    pub kind: BrokerKind,
+    /// Overrides the broker's default endpoint (e.g. Alpaca paper vs live)
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRequest {
+    pub symbol: String,
+    /// "buy" or "sell"
+    pub side: String,
+    pub quantity: f64,
+    /// "market" or "limit"
+    pub order_type: String,
+    pub limit_price: Option<f64>,
+}
+
+#[derive(Default)]
+pub struct TradingState {
+    pending: Confirmations<OrderRequest>,
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn trading_dir(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = crate::profiles::active_profile_dir(app)?.join("trading");
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+/// Appends one line to the append-only trading audit log.
+fn audit(app: &AppHandle, event: &str, details: serde_json::Value) -> Result<(), String> {
+    let entry = serde_json::json!({ "timestamp": now_secs(), "event": event, "details": details });
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(trading_dir(app)?.join("audit.jsonl"))
+        .map_err(|e| e.to_string())?;
+    writeln!(file, "{}", entry).map_err(|e| e.to_string())
+}
+
+fn load_config(app: &AppHandle) -> Result<BrokerConfig, String> {
+    let path = trading_dir(app)?.join("broker.json");
+    let json = std::fs::read_to_string(path).map_err(|_| "No broker configured".to_string())?;
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
+
+fn secret_key(kind: BrokerKind, name: &str) -> String {
+    format!("broker:{:?}:{}", kind, name).to_lowercase()
+}
+
+/// Vault keys broker credentials may be stored under, for every broker.
+pub fn vault_keys() -> Vec<String> {
+    [BrokerKind::Alpaca, BrokerKind::Binance]
+        .into_iter()
+        .flat_map(|kind| ["key", "secret"].map(|name| secret_key(kind, name)))
+        .collect()
+}
+
+fn credentials(app: &AppHandle, kind: BrokerKind) -> Result<(String, String), String> {
+    let namespace = crate::profiles::active_secrets_namespace(app);
+    let key = crate::secrets::load_secret(&namespace, &secret_key(kind, "key"))?.ok_or("Broker API key not in vault")?;
+    let secret = crate::secrets::load_secret(&namespace, &secret_key(kind, "secret"))?.ok_or("Broker API secret not in vault")?;
+    Ok((key, secret))
+}
+
+fn base_url(config: &BrokerConfig) -> String {
+    config.base_url.clone().unwrap_or_else(|| match config.kind {
+        BrokerKind::Alpaca => "https://paper-api.alpaca.markets".to_string(),
+        BrokerKind::Binance => "https://api.binance.com".to_string(),
+    })
+}
+
+/// Binance signs the query string with HMAC-SHA256 of the API secret.
+fn binance_signed_query(secret: &str, query: &str) -> Result<String, String> {
+    use hmac::{Hmac, Mac};
+
+    let timestamp = format!("timestamp={}", now_secs() * 1000);
+    let query = if query.is_empty() { timestamp } else { format!("{}&{}", query, timestamp) };
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret.as_bytes()).map_err(|e| e.to_string())?;
+    mac.update(query.as_bytes());
+    let signature: String = mac.finalize().into_bytes().iter().map(|b| format!("{:02x}", b)).collect();
+    Ok(format!("{}&signature={}", query, signature))
+}
+
+async fn send(request: reqwest::RequestBuilder) -> Result<serde_json::Value, String> {
+    let response = crate::egress::send(crate::bandwidth::Feature::Trading, request.timeout(Duration::from_secs(15))).await?;
+    let status = response.status();
+    let body: serde_json::Value = response.json().await.unwrap_or(serde_json::Value::Null);
+
+    if !status.is_success() {
+        return Err(format!("Broker returned {}: {}", status, body));
+    }
+    Ok(body)
+}
+
+async fn broker_get(app: &AppHandle, path: &str, query: &str, signed: bool) -> Result<serde_json::Value, String> {
+    let config = load_config(app)?;
+    let (key, secret) = credentials(app, config.kind)?;
+    let client = crate::proxy::client();
+
+    let request = match config.kind {
+        BrokerKind::Alpaca => client
+            .get(format!("{}{}?{}", base_url(&config), path, query))
+            .header("APCA-API-KEY-ID", key)
+            .header("APCA-API-SECRET-KEY", secret),
+        BrokerKind::Binance => {
+            let query = if signed { binance_signed_query(&secret, query)? } else { query.to_string() };
+            client
+                .get(format!("{}{}?{}", base_url(&config), path, query))
+                .header("X-MBX-APIKEY", key)
+        }
+    };
+
+    send(request).await
+}
+
+#[tauri::command]
+pub async fn configure_broker(
+    app: AppHandle,
+    config: BrokerConfig,
+    api_key: String,
+    api_secret: String,
+) -> Result<BrokerConfig, String> {
+    let namespace = crate::profiles::active_secrets_namespace(&app);
+    crate::secrets::store_secret(&namespace, &secret_key(config.kind, "key"), &api_key)?;
+    crate::secrets::store_secret(&namespace, &secret_key(config.kind, "secret"), &api_secret)?;
+
+    let json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    std::fs::write(trading_dir(&app)?.join("broker.json"), json).map_err(|e| e.to_string())?;
+
+    audit(&app, "broker-configured", serde_json::json!({ "kind": config.kind, "baseUrl": config.base_url }))?;
+    Ok(config)
+}
+
+#[tauri::command]
+pub async fn get_positions(app: AppHandle) -> Result<Vec<serde_json::Value>, String> {
+    let config = load_config(&app)?;
+
+    match config.kind {
+        BrokerKind::Alpaca => {
+            let positions = broker_get(&app, "/v2/positions", "", false).await?;
+            Ok(positions
+                .as_array()
+                .map(|positions| {
+                    positions
+                        .iter()
+                        .map(|position| serde_json::json!({
+                            "symbol": position["symbol"],
+                            "quantity": position["qty"],
+                            "averagePrice": position["avg_entry_price"],
+                            "marketValue": position["market_value"],
+                            "unrealizedPl": position["unrealized_pl"],
+                        }))
+                        .collect()
+                })
+                .unwrap_or_default())
+        }
+        BrokerKind::Binance => {
+            let account = broker_get(&app, "/api/v3/account", "omitZeroBalances=true", true).await?;
+            Ok(account["balances"]
+                .as_array()
+                .map(|balances| {
+                    balances
+                        .iter()
+                        .map(|balance| serde_json::json!({
+                            "symbol": balance["asset"],
+                            "quantity": balance["free"],
+                            "locked": balance["locked"],
+                        }))
+                        .collect()
+                })
+                .unwrap_or_default())
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_quotes(app: AppHandle, symbols: Vec<String>) -> Result<Vec<serde_json::Value>, String> {
+    let config = load_config(&app)?;
+    let mut quotes = Vec::new();
+
+    for symbol in symbols {
+        let symbol = symbol.trim().to_uppercase();
+        let quote = match config.kind {
+            BrokerKind::Alpaca => {
+                // Quotes live on the market data host rather than the trading host
+                let (key, secret) = credentials(&app, config.kind)?;
+                let body = send(
+                    crate::proxy::client()
+                        .get(format!("https://data.alpaca.markets/v2/stocks/{}/quotes/latest", symbol))
+                        .header("APCA-API-KEY-ID", key)
+                        .header("APCA-API-SECRET-KEY", secret),
+                )
+                .await?;
+                serde_json::json!({
+                    "symbol": symbol,
+                    "bid": body["quote"]["bp"],
+                    "ask": body["quote"]["ap"],
+                    "timestamp": body["quote"]["t"],
+                })
+            }
+            BrokerKind::Binance => {
+                let body = broker_get(&app, "/api/v3/ticker/bookTicker", &format!("symbol={}", symbol), false).await?;
+                serde_json::json!({
+                    "symbol": symbol,
+                    "bid": body["bidPrice"],
+                    "ask": body["askPrice"],
+                })
+            }
+        };
+        quotes.push(quote);
+    }
+
+    Ok(quotes)
+}
+
+fn validate_order(order: &OrderRequest) -> Result<(), String> {
+    if !matches!(order.side.as_str(), "buy" | "sell") {
+        return Err("Order side must be buy or sell".to_string());
+    }
+    if !(order.quantity > 0.0 && order.quantity.is_finite()) {
+        return Err("Order quantity must be positive".to_string());
+    }
+    match (order.order_type.as_str(), order.limit_price) {
+        ("market", _) => Ok(()),
+        ("limit", Some(price)) if price > 0.0 => Ok(()),
+        ("limit", _) => Err("Limit orders need a positive limitPrice".to_string()),
+        _ => Err("Order type must be market or limit".to_string()),
+    }
+}
+
+async fn submit_order(app: &AppHandle, order: &OrderRequest) -> Result<serde_json::Value, String> {
+    let config = load_config(app)?;
+    let (key, secret) = credentials(app, config.kind)?;
+    let client = crate::proxy::client();
+
+    let request = match config.kind {
+        BrokerKind::Alpaca => {
+            let mut body = serde_json::json!({
+                "symbol": order.symbol,
+                "qty": order.quantity.to_string(),
+                "side": order.side,
+                "type": order.order_type,
+                "time_in_force": "day",
+            });
+            if let Some(price) = order.limit_price {
+                body["limit_price"] = serde_json::json!(price.to_string());
+            }
+            client
+                .post(format!("{}/v2/orders", base_url(&config)))
+                .header("APCA-API-KEY-ID", key)
+                .header("APCA-API-SECRET-KEY", secret)
+                .json(&body)
+        }
+        BrokerKind::Binance => {
+            let mut query = format!(
+                "symbol={}&side={}&type={}&quantity={}",
+                order.symbol,
+                order.side.to_uppercase(),
+                order.order_type.to_uppercase(),
+                order.quantity
+            );
+            if let Some(price) = order.limit_price {
+                query.push_str(&format!("&price={}&timeInForce=GTC", price));
+            }
+            client
+                .post(format!("{}/api/v3/order?{}", base_url(&config), binance_signed_query(&secret, &query)?))
+                .header("X-MBX-APIKEY", key)
+        }
+    };
+
+    send(request).await
+}
+
+/// First call (no token): validates the order and returns a confirmation token.
+/// Second call (with that token, same order): asks the user, then submits it to the broker.
+#[tauri::command]
+pub async fn place_order(
+    app: AppHandle,
+    state: State<'_, Mutex<TradingState>>,
+    order: OrderRequest,
+    confirmation_token: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let order = OrderRequest {
+        symbol: order.symbol.trim().to_uppercase(),
+        side: order.side.to_lowercase(),
+        order_type: order.order_type.to_lowercase(),
+        ..order
+    };
+    validate_order(&order)?;
+
+    let token = match confirmation_token {
+        None => {
+            let token = state.lock().unwrap().pending.issue(order.clone());
+            audit(&app, "order-requested", serde_json::json!({ "order": order, "token": token }))?;
+            return Ok(serde_json::json!({
+                "status": "confirmation_required",
+                "confirmationToken": token,
+                "expiresInSecs": CONFIRMATION_TTL.as_secs(),
+                "order": order,
+            }));
+        }
+        Some(token) => token,
+    };
+
+    let redeemed = state.lock().unwrap().pending.redeem(&token, &order, "Order does not match the confirmed order");
+    if let Err(reason) = redeemed {
+        audit(&app, "order-rejected", serde_json::json!({ "order": order, "token": token, "reason": reason }))?;
+        return Err(format!("❌ {}", reason));
+    }
+
+    // Whoever holds the token could send it straight back, so the user approves the order out of band
+    let parent = app.get_window("main");
+    let approved = tauri::api::dialog::blocking::ask(
+        parent.as_ref(),
+        "Confirm order",
+        format!(
+            "Place this order?\n\n{} {} {} ({}{})",
+            order.side,
+            order.quantity,
+            order.symbol,
+            order.order_type,
+            order.limit_price.map(|price| format!(" at {}", price)).unwrap_or_default()
+        ),
+    );
+    if !approved {
+        audit(&app, "order-declined", serde_json::json!({ "order": order, "token": token }))?;
+        return Err("❌ Order declined".to_string());
+    }
+
+    audit(&app, "order-confirmed", serde_json::json!({ "order": order, "token": token }))?;
+    println!("💹 Submitting {} {} {} ({})", order.side, order.quantity, order.symbol, order.order_type);
+
+    match submit_order(&app, &order).await {
+        Ok(result) => {
+            audit(&app, "order-submitted", serde_json::json!({ "order": order, "result": result }))?;
+            Ok(serde_json::json!({ "status": "submitted", "order": order, "broker": result }))
+        }
+        Err(e) => {
+            audit(&app, "order-failed", serde_json::json!({ "order": order, "error": e }))?;
+            Err(e)
+        }
+    }
+}
@@ -0,0 +1,180 @@
+/**
+ * Translation module - Cached text translation and auto-translation of incoming transcripts
+ */
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tauri::AppHandle;
+
+const CACHE_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+const API_KEY_SECRET: &str = "translation_api_key";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationSettings {
+    /// "service" for the local JarvisX translation service, "api" for a LibreTranslate-compatible endpoint
+    pub provider: String,
+    pub api_url: Option<String>,
+    /// Translate captions and transcripts into `preferred_language` as they arrive
+    pub auto_translate: bool,
+    pub preferred_language: String,
+}
+
+impl Default for TranslationSettings {
+    fn default() -> Self {
+        TranslationSettings {
+            provider: "service".to_string(),
+            api_url: None,
+            auto_translate: false,
+            preferred_language: "en".to_string(),
+        }
+    }
+}
+
+fn settings_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("translation.json"))
+}
+
+pub fn load_settings(app: &AppHandle) -> TranslationSettings {
+    settings_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+pub fn service_url() -> String {
+    std::env::var("JARVISX_TRANSLATION_URL").unwrap_or_else(|_| "http://localhost:8020".to_string())
+}
+
+async fn translate_with_service(text: &str, source: Option<&str>, target: &str) -> Result<String, String> {
+    let response = crate::egress::send(
+        crate::bandwidth::Feature::Translation,
+        crate::proxy::client()
+            .post(format!("{}/translate", service_url()))
+            .timeout(Duration::from_secs(15))
+            .json(&serde_json::json!({
+                "text": text,
+                "sourceLanguage": source,
+                "targetLanguage": target,
+            })),
+    )
+    .await?;
+
+    if !response.status().is_success() {
+        return Err(format!("Translation service returned {}", response.status()));
+    }
+
+    let body: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+    body.get("translatedText")
+        .and_then(|t| t.as_str())
+        .map(String::from)
+        .ok_or_else(|| "Translation service returned no text".to_string())
+}
+
+async fn translate_with_api(
+    app: &AppHandle,
+    api_url: &str,
+    text: &str,
+    source: Option<&str>,
+    target: &str,
+) -> Result<String, String> {
+    let api_key = crate::secrets::load_secret(&crate::profiles::active_secrets_namespace(app), API_KEY_SECRET)?;
+
+    let response = crate::egress::send(
+        crate::bandwidth::Feature::Translation,
+        crate::proxy::client()
+            .post(api_url)
+            .timeout(Duration::from_secs(15))
+            .json(&serde_json::json!({
+                "q": text,
+                "source": source.unwrap_or("auto"),
+                "target": target,
+                "format": "text",
+                "api_key": api_key,
+            })),
+    )
+    .await?;
+
+    if !response.status().is_success() {
+        return Err(format!("Translation API returned {}", response.status()));
+    }
+
+    let body: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+    body.get("translatedText")
+        .and_then(|t| t.as_str())
+        .map(String::from)
+        .ok_or_else(|| "Translation API returned no text".to_string())
+}
+
+/// Translates through the configured provider, serving repeats from the response cache.
+pub async fn translate(app: &AppHandle, text: &str, source: Option<&str>, target: &str) -> Result<String, String> {
+    let settings = load_settings(app);
+    let key = format!("translate:{}:{}:{}", source.unwrap_or("auto"), target, text);
+
+    let translated = crate::cache::cached(&key, CACHE_TTL, false, || async {
+        let translated = match (settings.provider.as_str(), settings.api_url.as_deref()) {
+            ("api", Some(api_url)) => {
+                let text = crate::redaction::redact(app, "translation", text);
+                translate_with_api(app, api_url, &text, source, target).await?
+            }
+            ("api", None) => return Err("Translation API URL not configured".to_string()),
+            _ => translate_with_service(text, source, target).await?,
+        };
+        Ok(serde_json::Value::String(translated))
+    })
+    .await?;
+
+    Ok(translated.as_str().unwrap_or_default().to_string())
+}
+
+/// Translation of an incoming transcript when auto-translate is on and the language differs.
+pub async fn auto_translate(app: &AppHandle, text: &str, language: &str) -> Option<String> {
+    let settings = load_settings(app);
+    if !settings.auto_translate || settings.preferred_language == language {
+        return None;
+    }
+
+    match translate(app, text, Some(language), &settings.preferred_language).await {
+        Ok(translated) => Some(translated),
+        Err(e) => {
+            println!("⚠️  Auto-translate failed: {}", e);
+            None
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn translate_text(
+    app: AppHandle,
+    text: String,
+    target_lang: String,
+    source_lang: Option<String>,
+) -> Result<String, String> {
+    println!("🌍 Translating {} chars to {}", text.len(), target_lang);
+    translate(&app, &text, source_lang.as_deref(), &target_lang).await
+}
+
+#[tauri::command]
+pub async fn set_translation_settings(
+    app: AppHandle,
+    settings: TranslationSettings,
+    api_key: Option<String>,
+) -> Result<TranslationSettings, String> {
+    if settings.provider == "api" && settings.api_url.is_none() {
+        return Err("The api provider needs an apiUrl".to_string());
+    }
+
+    if let Some(api_key) = api_key {
+        crate::secrets::store_secret(&crate::profiles::active_secrets_namespace(&app), API_KEY_SECRET, &api_key)?;
+    }
+
+    let json = serde_json::to_string_pretty(&settings).map_err(|e| e.to_string())?;
+    std::fs::write(settings_path(&app)?, json).map_err(|e| e.to_string())?;
+    Ok(settings)
+}
+
+#[tauri::command]
+pub async fn get_translation_settings(app: AppHandle) -> Result<TranslationSettings, String> {
+    Ok(load_settings(&app))
+}
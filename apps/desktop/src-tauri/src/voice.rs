@@ -1,13 +1,26 @@
 /**
  * Voice module - Microphone access and local Whisper integration
+ *
+ * Device changes come from the OS (IMMNotificationClient on Windows, a CoreAudio property listener on macOS,
+ * `pactl subscribe` on Linux), with polling only where none of those is available. Microphone capture uses
+ * the selected device and moves to a new one when the selection changes.
  */
 
-use std::sync::Mutex;
-use tauri::State;
+use cpal::traits::{DeviceTrait, HostTrait};
+use std::sync::{mpsc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+
+/// Rescan interval when the OS can't notify about device changes
+const DEVICE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Notifications come in bursts (one per endpoint and role), so rescans wait for them to settle
+const DEVICE_EVENT_SETTLE: Duration = Duration::from_millis(300);
 
 pub struct VoiceState {
     pub is_recording: bool,
     pub audio_device: Option<String>,
+    /// Capture devices in order of preference, matched by name substring
+    pub preferred_devices: Vec<String>,
 }
 
 impl Default for VoiceState {
@@ -15,22 +28,297 @@ impl Default for VoiceState {
         VoiceState {
             is_recording: false,
             audio_device: None,
+            preferred_devices: Vec::new(),
         }
     }
 }
 
-#[tauri::command]
-pub async fn start_microphone(state: State<'_, Mutex<VoiceState>>) -> Result<String, String> {
-    let mut voice_state = state.lock().unwrap();
-    
-    if voice_state.is_recording {
-        return Err("Microphone already recording".to_string());
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioDevices {
+    pub inputs: Vec<String>,
+    pub outputs: Vec<String>,
+}
+
+fn device_names(devices: impl Iterator<Item = cpal::Device>) -> Vec<String> {
+    let mut names: Vec<String> = devices.filter_map(|device| device.name().ok()).collect();
+    names.sort();
+    names
+}
+
+fn enumerate_audio_devices() -> AudioDevices {
+    let host = cpal::default_host();
+
+    AudioDevices {
+        inputs: host.input_devices().map(device_names).unwrap_or_default(),
+        outputs: host.output_devices().map(device_names).unwrap_or_default(),
+    }
+}
+
+/// First available input that matches the priority list, falling back to the system default.
+fn pick_preferred_input(preferred: &[String], inputs: &[String]) -> Option<String> {
+    preferred
+        .iter()
+        .find_map(|wanted| {
+            let wanted = wanted.to_lowercase();
+            inputs.iter().find(|name| name.to_lowercase().contains(&wanted)).cloned()
+        })
+        .or_else(|| cpal::default_host().default_input_device().and_then(|device| device.name().ok()))
+}
+
+fn preferred_devices_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("audio_devices.json"))
+}
+
+/// The capture device microphone streams should open, if one has been picked.
+pub fn capture_device(app: &AppHandle) -> Option<String> {
+    app.state::<Mutex<VoiceState>>().lock().unwrap().audio_device.clone()
+}
+
+/// Switches capture to the best available device, emitting if the choice changed.
+fn apply_preferred_input(app: &AppHandle, inputs: &[String]) {
+    let selected = {
+        let state = app.state::<Mutex<VoiceState>>();
+        let mut voice_state = state.lock().unwrap();
+        let selected = pick_preferred_input(&voice_state.preferred_devices, inputs);
+        if selected == voice_state.audio_device {
+            return;
+        }
+        voice_state.audio_device = selected.clone();
+        selected
+    };
+
+    println!("🎧 Capture device switched to: {:?}", selected);
+    let _ = app.emit_all("voice://capture-device-changed", serde_json::json!({ "device": selected }));
+
+    // A live microphone stream moves to the new device
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = crate::voice_activity::restart(&app).await {
+            println!("⚠️  Could not reopen the microphone: {}", e);
+        }
+    });
+}
+
+#[cfg(target_os = "windows")]
+#[windows::core::implement(windows::Win32::Media::Audio::IMMNotificationClient)]
+struct DeviceNotifier(Mutex<mpsc::Sender<()>>);
+
+#[cfg(target_os = "windows")]
+#[allow(non_snake_case)]
+impl windows::Win32::Media::Audio::IMMNotificationClient_Impl for DeviceNotifier {
+    fn OnDeviceStateChanged(&self, _: &windows::core::PCWSTR, _: u32) -> windows::core::Result<()> {
+        let _ = self.0.lock().unwrap().send(());
+        Ok(())
     }
 
-    voice_state.is_recording = true;
+    fn OnDeviceAdded(&self, _: &windows::core::PCWSTR) -> windows::core::Result<()> {
+        let _ = self.0.lock().unwrap().send(());
+        Ok(())
+    }
+
+    fn OnDeviceRemoved(&self, _: &windows::core::PCWSTR) -> windows::core::Result<()> {
+        let _ = self.0.lock().unwrap().send(());
+        Ok(())
+    }
+
+    fn OnDefaultDeviceChanged(
+        &self,
+        _: windows::Win32::Media::Audio::EDataFlow,
+        _: windows::Win32::Media::Audio::ERole,
+        _: &windows::core::PCWSTR,
+    ) -> windows::core::Result<()> {
+        let _ = self.0.lock().unwrap().send(());
+        Ok(())
+    }
+
+    fn OnPropertyValueChanged(
+        &self,
+        _: &windows::core::PCWSTR,
+        _: &windows::Win32::UI::Shell::PropertiesSystem::PROPERTYKEY,
+    ) -> windows::core::Result<()> {
+        Ok(())
+    }
+}
+
+/// Registers for endpoint notifications on a thread that keeps the registration alive.
+#[cfg(target_os = "windows")]
+fn subscribe_device_changes(changed: mpsc::Sender<()>) -> Result<(), String> {
+    use windows::Win32::Media::Audio::{IMMDeviceEnumerator, IMMNotificationClient, MMDeviceEnumerator};
+    use windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CLSCTX_ALL, COINIT_MULTITHREADED};
+
+    let (ready_tx, ready_rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let registered: windows::core::Result<(IMMDeviceEnumerator, IMMNotificationClient)> = unsafe {
+            let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+            CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL).and_then(|enumerator: IMMDeviceEnumerator| {
+                let client: IMMNotificationClient = DeviceNotifier(Mutex::new(changed)).into();
+                enumerator.RegisterEndpointNotificationCallback(&client)?;
+                Ok((enumerator, client))
+            })
+        };
+        let _ = ready_tx.send(registered.as_ref().map(|_| ()).map_err(|e| e.to_string()));
+        if registered.is_ok() {
+            loop {
+                std::thread::park();
+            }
+        }
+    });
+    ready_rx.recv().map_err(|e| e.to_string())?
+}
+
+#[cfg(target_os = "macos")]
+#[repr(C)]
+struct AudioObjectPropertyAddress {
+    selector: u32,
+    scope: u32,
+    element: u32,
+}
+
+#[cfg(target_os = "macos")]
+type AudioObjectPropertyListener =
+    extern "C" fn(object: u32, count: u32, addresses: *const AudioObjectPropertyAddress, data: *mut std::ffi::c_void) -> i32;
+
+#[cfg(target_os = "macos")]
+#[link(name = "CoreAudio", kind = "framework")]
+extern "C" {
+    fn AudioObjectAddPropertyListener(
+        object: u32,
+        address: *const AudioObjectPropertyAddress,
+        listener: AudioObjectPropertyListener,
+        data: *mut std::ffi::c_void,
+    ) -> i32;
+}
+
+#[cfg(target_os = "macos")]
+extern "C" fn on_core_audio_change(_: u32, _: u32, _: *const AudioObjectPropertyAddress, data: *mut std::ffi::c_void) -> i32 {
+    let changed = unsafe { &*(data as *const Mutex<mpsc::Sender<()>>) };
+    let _ = changed.lock().unwrap().send(());
+    0
+}
+
+/// Listens for the device list and the default input changing on the CoreAudio system object.
+#[cfg(target_os = "macos")]
+fn subscribe_device_changes(changed: mpsc::Sender<()>) -> Result<(), String> {
+    const SYSTEM_OBJECT: u32 = 1;
+    const SCOPE_GLOBAL: u32 = u32::from_be_bytes(*b"glob");
+    const ELEMENT_MAIN: u32 = 0;
+
+    // Lives as long as the listeners, i.e. the app
+    let data = Box::leak(Box::new(Mutex::new(changed))) as *mut Mutex<mpsc::Sender<()>> as *mut std::ffi::c_void;
+    for selector in [*b"dev#", *b"dIn "] {
+        let address = AudioObjectPropertyAddress { selector: u32::from_be_bytes(selector), scope: SCOPE_GLOBAL, element: ELEMENT_MAIN };
+        let status = unsafe { AudioObjectAddPropertyListener(SYSTEM_OBJECT, &address, on_core_audio_change, data) };
+        if status != 0 {
+            return Err(format!("CoreAudio listener failed with status {}", status));
+        }
+    }
+    Ok(())
+}
+
+/// Follows `pactl subscribe` (PulseAudio or PipeWire) for source, sink and default-device events.
+#[cfg(target_os = "linux")]
+fn subscribe_device_changes(changed: mpsc::Sender<()>) -> Result<(), String> {
+    use std::io::BufRead;
+
+    let mut child = std::process::Command::new("pactl")
+        .arg("subscribe")
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+    let stdout = child.stdout.take().ok_or("pactl produced no output")?;
+
+    std::thread::spawn(move || {
+        for line in std::io::BufReader::new(stdout).lines().map_while(Result::ok) {
+            if [" on source ", " on sink ", " on server "].iter().any(|kind| line.contains(kind)) && changed.send(()).is_err() {
+                break;
+            }
+        }
+        let _ = child.kill();
+        let _ = child.wait();
+    });
+    Ok(())
+}
+
+/// Rescans audio devices when the OS reports a change and emits `voice://devices-changed`.
+pub fn spawn_audio_device_watcher(app: AppHandle) {
+    if let Ok(json) = preferred_devices_path(&app).and_then(|path| std::fs::read_to_string(path).map_err(|e| e.to_string())) {
+        if let Ok(preferred) = serde_json::from_str::<Vec<String>>(&json) {
+            app.state::<Mutex<VoiceState>>().lock().unwrap().preferred_devices = preferred;
+        }
+    }
+
+    let (changed_tx, changed) = mpsc::channel();
+    let notified = match subscribe_device_changes(changed_tx) {
+        Ok(()) => true,
+        Err(e) => {
+            println!("⚠️  Audio device notifications unavailable, polling instead: {}", e);
+            false
+        }
+    };
+
+    std::thread::spawn(move || {
+        let mut last: Option<AudioDevices> = None;
+
+        loop {
+            let current = enumerate_audio_devices();
+            if let Some(previous) = &last {
+                if *previous != current {
+                    let added: Vec<&String> = current.inputs.iter().chain(&current.outputs)
+                        .filter(|name| !previous.inputs.contains(name) && !previous.outputs.contains(name))
+                        .collect();
+                    let removed: Vec<&String> = previous.inputs.iter().chain(&previous.outputs)
+                        .filter(|name| !current.inputs.contains(name) && !current.outputs.contains(name))
+                        .collect();
+
+                    println!("🎧 Audio devices changed: +{:?} -{:?}", added, removed);
+                    let _ = app.emit_all("voice://devices-changed", serde_json::json!({
+                        "inputs": current.inputs,
+                        "outputs": current.outputs,
+                        "added": added,
+                        "removed": removed,
+                    }));
+                }
+            }
+
+            if last.as_ref() != Some(&current) {
+                apply_preferred_input(&app, &current.inputs);
+                last = Some(current);
+            }
+
+            if notified {
+                if changed.recv().is_err() {
+                    return;
+                }
+                std::thread::sleep(DEVICE_EVENT_SETTLE);
+                while changed.try_recv().is_ok() {}
+            } else {
+                std::thread::sleep(DEVICE_POLL_INTERVAL);
+            }
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn start_microphone(app: AppHandle, state: State<'_, Mutex<VoiceState>>) -> Result<String, String> {
+    {
+        let mut voice_state = state.lock().unwrap();
+
+        if voice_state.is_recording {
+            return Err("Microphone already recording".to_string());
+        }
+
+        voice_state.is_recording = true;
+    }
+    crate::ducking::duck("listening");
     println!("🎤 Microphone started");
+
+    // The avatar's listening animation still works without it, just without a speaking signal
+    if let Err(e) = crate::voice_activity::start(&app).await {
+        println!("⚠️  Voice activity detection unavailable: {}", e);
+    }
     
-    // TODO: Integrate with cpal or rodio for actual audio capture
     // TODO: Pipe to Whisper.cpp for local STT
     
     Ok("Microphone started".to_string())
@@ -45,6 +333,8 @@ pub async fn stop_microphone(state: State<'_, Mutex<VoiceState>>) -> Result<Stri
     }
 
     voice_state.is_recording = false;
+    crate::ducking::restore("listening");
+    crate::voice_activity::stop();
     println!("🎤 Microphone stopped");
     
     Ok("Microphone stopped".to_string())
@@ -54,13 +344,91 @@ pub async fn stop_microphone(state: State<'_, Mutex<VoiceState>>) -> Result<Stri
 pub async fn get_audio_devices() -> Result<Vec<String>, String> {
     println!("🎧 Getting audio devices");
     
-    // TODO: Use cpal to enumerate actual audio devices
-    let devices = vec![
-        "Default Microphone".to_string(),
-        "Built-in Microphone".to_string(),
-    ];
+    let devices = tauri::async_runtime::spawn_blocking(enumerate_audio_devices)
+        .await
+        .map_err(|e| e.to_string())?;
     
-    Ok(devices)
+    Ok(devices.inputs)
+}
+
+#[tauri::command]
+pub async fn set_preferred_audio_devices(app: AppHandle, priority: Vec<String>) -> Result<Option<String>, String> {
+    let json = serde_json::to_string_pretty(&priority).map_err(|e| e.to_string())?;
+    std::fs::write(preferred_devices_path(&app)?, json).map_err(|e| e.to_string())?;
+
+    app.state::<Mutex<VoiceState>>().lock().unwrap().preferred_devices = priority;
+
+    let devices = tauri::async_runtime::spawn_blocking(enumerate_audio_devices)
+        .await
+        .map_err(|e| e.to_string())?;
+    apply_preferred_input(&app, &devices.inputs);
+
+    Ok(app.state::<Mutex<VoiceState>>().lock().unwrap().audio_device.clone())
+}
+
+#[tauri::command]
+pub async fn get_preferred_audio_devices(state: State<'_, Mutex<VoiceState>>) -> Result<serde_json::Value, String> {
+    let voice_state = state.lock().unwrap();
+
+    Ok(serde_json::json!({
+        "priority": voice_state.preferred_devices,
+        "activeDevice": voice_state.audio_device,
+    }))
+}
+
+pub fn stt_url() -> String {
+    std::env::var("JARVISX_STT_URL").unwrap_or_else(|_| "http://localhost:8001".to_string())
+}
+
+/// Wraps mono 16-bit PCM in a WAV container.
+pub fn encode_wav(samples: &[i16], sample_rate: u32) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut wav = Vec::with_capacity(44 + data_len as usize);
+
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    wav.extend_from_slice(&2u16.to_le_bytes());
+    wav.extend_from_slice(&16u16.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        wav.extend_from_slice(&sample.to_le_bytes());
+    }
+
+    wav
+}
+
+/// Sends a WAV clip to the STT service; the response carries `text`, `language` and optional `segments`.
+pub async fn transcribe_wav(wav: Vec<u8>, language: &str, timestamps: bool) -> Result<serde_json::Value, String> {
+    let file = reqwest::multipart::Part::bytes(wav)
+        .file_name("chunk.wav")
+        .mime_str("audio/wav")
+        .map_err(|e| e.to_string())?;
+    let form = reqwest::multipart::Form::new()
+        .part("file", file)
+        .text("language", language.to_string())
+        .text("timestamp", timestamps.to_string());
+
+    let response = crate::egress::send(
+        crate::bandwidth::Feature::SpeechToText,
+        crate::proxy::client()
+            .post(format!("{}/transcribe", stt_url()))
+            .timeout(Duration::from_secs(60))
+            .multipart(form),
+    )
+    .await?;
+
+    if !response.status().is_success() {
+        return Err(format!("STT service returned {}", response.status()));
+    }
+
+    response.json().await.map_err(|e| e.to_string())
 }
 
 #[tauri::command]
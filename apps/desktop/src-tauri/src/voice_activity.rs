@@ -0,0 +1,152 @@
+/**
+ * Voice activity module - Lightweight "is the user speaking" signal for the avatar
+ *
+ * While the microphone is on, short frames are gated by RMS level with hysteresis and streamed as
+ * `voice://activity` events ({ speaking, rms }) well before any transcription result is available.
+ * The same frames feed the `voice://spectrum` visualization stream.
+ */
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+const FRAME: Duration = Duration::from_millis(30);
+/// Level updates are throttled; speaking changes are sent immediately
+const LEVEL_INTERVAL: Duration = Duration::from_millis(100);
+/// Speech starts above the environment's start level for ONSET_FRAMES frames and ends after HANGOVER below its stop level
+const ONSET_FRAMES: u32 = 2;
+const HANGOVER: Duration = Duration::from_millis(400);
+/// Silent frames between ambient-noise checks against the active environment profile
+const AMBIENT_FRAMES: usize = 300;
+
+static STOP: Mutex<Option<Arc<AtomicBool>>> = Mutex::new(None);
+static SPEAKING: AtomicBool = AtomicBool::new(false);
+/// Latest frame RMS as f32 bits
+static LEVEL: AtomicU32 = AtomicU32::new(0);
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceActivity {
+    pub listening: bool,
+    pub speaking: bool,
+    pub rms: f32,
+}
+
+fn current() -> VoiceActivity {
+    VoiceActivity {
+        listening: STOP.lock().unwrap().is_some(),
+        speaking: SPEAKING.load(Ordering::Relaxed),
+        rms: f32::from_bits(LEVEL.load(Ordering::Relaxed)),
+    }
+}
+
+fn emit(app: &AppHandle) {
+    let _ = app.emit_all("voice://activity", current());
+}
+
+fn run_detector(app: AppHandle, buffer: crate::captions::SampleBuffer, sample_rate: u32, stop: Arc<AtomicBool>) {
+    let mut analyzer = crate::spectrum::SpectrumAnalyzer::new(sample_rate);
+    let mut loud_frames = 0;
+    let mut ambient: Vec<f32> = Vec::new();
+    let mut last_suggestion: Option<&'static str> = None;
+    let mut last_loud = Instant::now();
+    let mut last_emit = Instant::now();
+
+    while !stop.load(Ordering::SeqCst) {
+        std::thread::sleep(FRAME);
+
+        let samples = std::mem::take(&mut *buffer.lock().unwrap());
+        if samples.is_empty() {
+            continue;
+        }
+        let level = crate::captions::rms(&samples);
+        LEVEL.store(level.to_bits(), Ordering::Relaxed);
+        let _ = app.emit_all("voice://spectrum", analyzer.push(&samples));
+
+        let (start_rms, stop_rms) = crate::environment::vad_thresholds();
+        loud_frames = if level >= start_rms { loud_frames + 1 } else { 0 };
+        if level >= stop_rms {
+            last_loud = Instant::now();
+        }
+
+        let speaking = SPEAKING.load(Ordering::Relaxed);
+        let now_speaking = if speaking { last_loud.elapsed() < HANGOVER } else { loud_frames >= ONSET_FRAMES };
+
+        if !now_speaking {
+            ambient.push(crate::environment::to_db(level));
+            if ambient.len() >= AMBIENT_FRAMES {
+                ambient.sort_by(|a, b| a.total_cmp(b));
+                let ambient_db = ambient[ambient.len() / 2];
+                let suggested = crate::environment::suggest_for(ambient_db);
+                // Suggest once per change of surroundings; switching stays the user's call
+                if suggested != crate::environment::active_environment().name && last_suggestion != Some(suggested) {
+                    let _ = app.emit_all("voice://environment-suggested", serde_json::json!({ "ambientDb": ambient_db, "suggested": suggested }));
+                }
+                last_suggestion = Some(suggested);
+                ambient.clear();
+            }
+        }
+
+        if now_speaking != speaking {
+            SPEAKING.store(now_speaking, Ordering::Relaxed);
+            emit(&app);
+            last_emit = Instant::now();
+        } else if last_emit.elapsed() >= LEVEL_INTERVAL {
+            emit(&app);
+            last_emit = Instant::now();
+        }
+    }
+
+    SPEAKING.store(false, Ordering::Relaxed);
+    LEVEL.store(0, Ordering::Relaxed);
+    emit(&app);
+}
+
+/// Opens the microphone for activity detection; called when listening starts.
+pub async fn start(app: &AppHandle) -> Result<(), String> {
+    let stop = Arc::new(AtomicBool::new(false));
+    {
+        let mut current = STOP.lock().unwrap();
+        if current.is_some() {
+            return Ok(());
+        }
+        *current = Some(stop.clone());
+    }
+
+    let source = crate::captions::CaptureSource::Microphone(crate::voice::capture_device(app));
+    match crate::captions::open_source(source, stop.clone()).await {
+        Ok((buffer, device, sample_rate)) => {
+            println!("🗣️  Voice activity detection on {}", device);
+            let app = app.clone();
+            std::thread::spawn(move || run_detector(app, buffer, sample_rate, stop));
+            Ok(())
+        }
+        Err(e) => {
+            *STOP.lock().unwrap() = None;
+            Err(e)
+        }
+    }
+}
+
+pub fn stop() {
+    if let Some(stop) = STOP.lock().unwrap().take() {
+        stop.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Reopens the microphone on the current capture device if detection is running.
+pub async fn restart(app: &AppHandle) -> Result<(), String> {
+    if !current().listening {
+        return Ok(());
+    }
+    stop();
+    start(app).await
+}
+
+/// Current speaking state and level; subscribe to `voice://activity` for updates.
+#[tauri::command]
+pub async fn get_voice_activity() -> Result<VoiceActivity, String> {
+    Ok(current())
+}
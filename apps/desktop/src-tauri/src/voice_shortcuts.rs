@@ -0,0 +1,219 @@
+/**
+ * Voice shortcuts module - Phrase → action mappings matched locally after STT, no LLM round trip
+ */
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+/// Minimum similarity (0..1) for a transcript to trigger a shortcut.
+const MATCH_THRESHOLD: f64 = 0.8;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShortcutAction {
+    /// "command" (whitelisted script command), "script" (saved Rhai script) or "workflow" (run by the UI)
+    pub kind: String,
+    pub target: String,
+    #[serde(default)]
+    pub args: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceShortcut {
+    pub phrase: String,
+    pub action: ShortcutAction,
+    pub created_at: u64,
+}
+
+fn shortcuts_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::profiles::active_profile_dir(app)?.join("voice_shortcuts.json"))
+}
+
+fn load_shortcuts(app: &AppHandle) -> Vec<VoiceShortcut> {
+    shortcuts_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_shortcuts(app: &AppHandle, shortcuts: &[VoiceShortcut]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(shortcuts).map_err(|e| e.to_string())?;
+    std::fs::write(shortcuts_path(app)?, json).map_err(|e| e.to_string())
+}
+
+/// Lowercases and strips punctuation so "Lights out!" and "lights out" compare equal.
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c.is_whitespace() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            current[j + 1] = (previous[j] + cost).min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// American Soundex, so STT homophones ("lites out") still match.
+fn soundex(word: &str) -> String {
+    let code = |c: char| match c {
+        'b' | 'f' | 'p' | 'v' => Some('1'),
+        'c' | 'g' | 'j' | 'k' | 'q' | 's' | 'x' | 'z' => Some('2'),
+        'd' | 't' => Some('3'),
+        'l' => Some('4'),
+        'm' | 'n' => Some('5'),
+        'r' => Some('6'),
+        _ => None,
+    };
+
+    let mut chars = word.chars().filter(|c| c.is_ascii_alphabetic());
+    let first = match chars.next() {
+        Some(first) => first,
+        None => return word.to_string(),
+    };
+
+    let mut result = first.to_ascii_uppercase().to_string();
+    let mut last = code(first);
+    for c in chars {
+        let current = code(c);
+        if let Some(digit) = current.filter(|_| current != last) {
+            result.push(digit);
+        }
+        // h and w don't separate identical codes; vowels do
+        if c != 'h' && c != 'w' {
+            last = current;
+        }
+    }
+
+    format!("{:0<4}", &result[..result.len().min(4)])
+}
+
+fn similarity(transcript: &str, phrase: &str) -> f64 {
+    if transcript == phrase {
+        return 1.0;
+    }
+
+    let longest = transcript.chars().count().max(phrase.chars().count()).max(1);
+    let textual = 1.0 - levenshtein(transcript, phrase) as f64 / longest as f64;
+
+    let phonetic = |text: &str| text.split_whitespace().map(soundex).collect::<Vec<_>>();
+    let phonetic_match = if phonetic(transcript) == phonetic(phrase) { 0.9 } else { 0.0 };
+
+    textual.max(phonetic_match)
+}
+
+fn best_match<'a>(shortcuts: &'a [VoiceShortcut], transcript: &str) -> Option<(&'a VoiceShortcut, f64)> {
+    let transcript = normalize(transcript);
+
+    shortcuts
+        .iter()
+        .map(|shortcut| (shortcut, similarity(&transcript, &normalize(&shortcut.phrase))))
+        .filter(|(_, score)| *score >= MATCH_THRESHOLD)
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+}
+
+/// Runs a shortcut action; shared with hardware button mappings.
+pub fn run_action(app: &AppHandle, action: &ShortcutAction) -> Result<serde_json::Value, String> {
+    match action.kind.as_str() {
+        "command" => crate::scripting::dispatch(app, &action.target, action.args.clone(), None),
+        "script" => {
+            let source = crate::scripting::load_script(app, &action.target)?;
+            crate::scripting::execute(app, &source, action.args.clone(), crate::scripting::DEFAULT_TIME_LIMIT, None)
+        }
+        // Workflows live in the UI; it picks this up from the triggered event
+        "workflow" => Ok(serde_json::json!({ "workflow": action.target })),
+        other => Err(format!("❌ Unknown shortcut action kind: {}", other)),
+    }
+}
+
+#[tauri::command]
+pub async fn create_voice_shortcut(app: AppHandle, phrase: String, action: ShortcutAction) -> Result<VoiceShortcut, String> {
+    if normalize(&phrase).is_empty() {
+        return Err("Shortcut phrase must contain words".to_string());
+    }
+    if !["command", "script", "workflow"].contains(&action.kind.as_str()) {
+        return Err(format!("❌ Unknown shortcut action kind: {}", action.kind));
+    }
+
+    let mut shortcuts = load_shortcuts(&app);
+    shortcuts.retain(|shortcut| normalize(&shortcut.phrase) != normalize(&phrase));
+
+    let shortcut = VoiceShortcut {
+        phrase,
+        action,
+        created_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    };
+    shortcuts.push(shortcut.clone());
+    save_shortcuts(&app, &shortcuts)?;
+
+    println!("🗣️  Voice shortcut saved: \"{}\"", shortcut.phrase);
+    Ok(shortcut)
+}
+
+#[tauri::command]
+pub async fn list_voice_shortcuts(app: AppHandle) -> Result<Vec<VoiceShortcut>, String> {
+    Ok(load_shortcuts(&app))
+}
+
+#[tauri::command]
+pub async fn delete_voice_shortcut(app: AppHandle, phrase: String) -> Result<(), String> {
+    let mut shortcuts = load_shortcuts(&app);
+    let before = shortcuts.len();
+    shortcuts.retain(|shortcut| normalize(&shortcut.phrase) != normalize(&phrase));
+
+    if shortcuts.len() == before {
+        return Err(format!("❌ No voice shortcut for: {}", phrase));
+    }
+
+    save_shortcuts(&app, &shortcuts)
+}
+
+/// Matches an STT transcript against the shortcuts and runs the winner. Returns null when nothing matched.
+#[tauri::command]
+pub async fn match_voice_shortcut(app: AppHandle, transcript: String) -> Result<serde_json::Value, String> {
+    let shortcuts = load_shortcuts(&app);
+    let (shortcut, score) = match best_match(&shortcuts, &transcript) {
+        Some((shortcut, score)) => (shortcut.clone(), score),
+        None => return Ok(serde_json::Value::Null),
+    };
+
+    println!("🗣️  \"{}\" matched shortcut \"{}\" ({:.2})", transcript, shortcut.phrase, score);
+
+    let handle = app.clone();
+    let action = shortcut.action.clone();
+    let result = tauri::async_runtime::spawn_blocking(move || run_action(&handle, &action))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let payload = serde_json::json!({
+        "phrase": shortcut.phrase,
+        "transcript": transcript,
+        "score": score,
+        "action": shortcut.action,
+        "result": result.as_ref().ok(),
+        "error": result.as_ref().err(),
+    });
+    let _ = app.emit_all("voice://shortcut-triggered", payload.clone());
+
+    Ok(payload)
+}
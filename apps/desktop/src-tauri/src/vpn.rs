@@ -0,0 +1,264 @@
+/**
+ * VPN module - Status and toggling of WireGuard configs and platform VPN profiles
+ *
+ * Every toggle emits `vpn://status-changed` so triggers can react (e.g. only open a site once the VPN is up).
+ */
+
+use serde::Serialize;
+use std::path::PathBuf;
+use std::process::Command;
+use tauri::{AppHandle, Manager};
+
+use crate::watchdog::WatchdogCommand;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VpnProfile {
+    pub name: String,
+    /// "wireguard" for .conf files, "system" for OS-managed VPN connections
+    pub kind: String,
+    pub connected: bool,
+    pub config_path: Option<PathBuf>,
+}
+
+fn run(command: &mut Command) -> Result<String, String> {
+    let output = command.output_with_timeout()?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// WireGuard configs live in the profile's `vpn` folder (and /etc/wireguard on Unix).
+fn wireguard_config_dirs(app: &AppHandle) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Ok(profile_dir) = crate::profiles::active_profile_dir(app) {
+        dirs.push(profile_dir.join("vpn"));
+    }
+    #[cfg(unix)]
+    dirs.push(PathBuf::from("/etc/wireguard"));
+    dirs
+}
+
+fn active_wireguard_interfaces() -> Vec<String> {
+    #[cfg(target_os = "windows")]
+    let output = run(Command::new("sc").args(&["query", "type=", "service", "state=", "active"])).map(|text| {
+        text.lines()
+            .filter_map(|line| line.trim().strip_prefix("SERVICE_NAME: WireGuardTunnel$"))
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    });
+
+    #[cfg(not(target_os = "windows"))]
+    let output = run(Command::new("wg").args(&["show", "interfaces"]));
+
+    output
+        .map(|text| text.split_whitespace().map(|name| name.to_string()).collect())
+        .unwrap_or_default()
+}
+
+fn wireguard_profiles(app: &AppHandle) -> Vec<VpnProfile> {
+    let active = active_wireguard_interfaces();
+    let mut profiles: Vec<VpnProfile> = Vec::new();
+
+    for dir in wireguard_config_dirs(app) {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for path in entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()) {
+            if path.extension().and_then(|ext| ext.to_str()) != Some("conf") {
+                continue;
+            }
+            let name = match path.file_stem().and_then(|stem| stem.to_str()) {
+                Some(name) => name.to_string(),
+                None => continue,
+            };
+            if profiles.iter().any(|profile| profile.name == name) {
+                continue;
+            }
+
+            profiles.push(VpnProfile {
+                connected: active.contains(&name),
+                name,
+                kind: "wireguard".to_string(),
+                config_path: Some(path),
+            });
+        }
+    }
+
+    profiles
+}
+
+#[cfg(target_os = "macos")]
+fn system_profiles() -> Vec<VpnProfile> {
+    // * 8A2D... (Connected)      "Office VPN"   [IPSec]
+    run(Command::new("scutil").args(&["--nc", "list"]))
+        .map(|text| {
+            text.lines()
+                .filter_map(|line| {
+                    let name = line.split('"').nth(1)?;
+                    Some(VpnProfile {
+                        name: name.to_string(),
+                        kind: "system".to_string(),
+                        connected: line.contains("(Connected)"),
+                        config_path: None,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "windows")]
+fn system_profiles() -> Vec<VpnProfile> {
+    let script = "Get-VpnConnection | ForEach-Object { \"$($_.Name)|$($_.ConnectionStatus)\" }";
+    run(Command::new("powershell").args(&["-NoProfile", "-Command", script]))
+        .map(|text| {
+            text.lines()
+                .filter_map(|line| {
+                    let (name, status) = line.trim().split_once('|')?;
+                    Some(VpnProfile {
+                        name: name.to_string(),
+                        kind: "system".to_string(),
+                        connected: status == "Connected",
+                        config_path: None,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "linux")]
+fn system_profiles() -> Vec<VpnProfile> {
+    run(Command::new("nmcli").args(&["-t", "-f", "NAME,TYPE,STATE", "connection", "show"]))
+        .map(|text| {
+            text.lines()
+                .filter_map(|line| {
+                    let fields: Vec<&str> = line.split(':').collect();
+                    if fields.len() < 3 || !(fields[1] == "vpn" || fields[1] == "wireguard") {
+                        return None;
+                    }
+                    Some(VpnProfile {
+                        name: fields[0].to_string(),
+                        kind: "system".to_string(),
+                        connected: fields[2] == "activated",
+                        config_path: None,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn all_profiles(app: &AppHandle) -> Vec<VpnProfile> {
+    let mut profiles = wireguard_profiles(app);
+    for profile in system_profiles() {
+        if !profiles.iter().any(|existing| existing.name == profile.name) {
+            profiles.push(profile);
+        }
+    }
+    profiles
+}
+
+fn set_wireguard(config_path: &PathBuf, name: &str, up: bool) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        if up {
+            run(Command::new("wireguard").arg("/installtunnelservice").arg(config_path)).map(|_| ())
+        } else {
+            run(Command::new("wireguard").args(&["/uninstalltunnelservice", name])).map(|_| ())
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = name;
+        // wg-quick needs root; pkexec/osascript would prompt, so rely on a sudoers rule or a privileged helper
+        run(Command::new("wg-quick").arg(if up { "up" } else { "down" }).arg(config_path)).map(|_| ())
+    }
+}
+
+fn set_system_vpn(name: &str, up: bool) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    let result = run(Command::new("scutil").args(&["--nc", if up { "start" } else { "stop" }, name]));
+
+    #[cfg(target_os = "windows")]
+    let result = if up {
+        run(Command::new("rasdial").arg(name))
+    } else {
+        run(Command::new("rasdial").args(&[name, "/disconnect"]))
+    };
+
+    #[cfg(target_os = "linux")]
+    let result = run(Command::new("nmcli").args(&["connection", if up { "up" } else { "down" }, "id", name]));
+
+    result.map(|_| ())
+}
+
+fn vpn_status(app: &AppHandle) -> serde_json::Value {
+    let profiles = all_profiles(app);
+    let active: Vec<&String> = profiles.iter().filter(|profile| profile.connected).map(|profile| &profile.name).collect();
+
+    serde_json::json!({
+        "connected": !active.is_empty(),
+        "activeProfiles": active,
+        "profiles": profiles,
+    })
+}
+
+#[tauri::command]
+pub async fn get_vpn_status(app: AppHandle) -> Result<serde_json::Value, String> {
+    tauri::async_runtime::spawn_blocking(move || vpn_status(&app))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_vpn_connection(app: AppHandle, profile: String, enabled: bool) -> Result<serde_json::Value, String> {
+    println!("🛡️  {} VPN profile: {}", if enabled { "Enabling" } else { "Disabling" }, profile);
+
+    let handle = app.clone();
+    let status = tauri::async_runtime::spawn_blocking(move || -> Result<serde_json::Value, String> {
+        let target = all_profiles(&handle)
+            .into_iter()
+            .find(|candidate| candidate.name.eq_ignore_ascii_case(&profile))
+            .ok_or_else(|| format!("❌ Unknown VPN profile: {}", profile))?;
+
+        if target.connected != enabled {
+            match &target.config_path {
+                Some(config_path) => set_wireguard(config_path, &target.name, enabled)?,
+                None => set_system_vpn(&target.name, enabled)?,
+            }
+        }
+
+        Ok(vpn_status(&handle))
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    let _ = app.emit_all("vpn://status-changed", status.clone());
+    Ok(status)
+}
+
+#[tauri::command]
+pub async fn toggle_vpn(app: AppHandle, profile: String) -> Result<serde_json::Value, String> {
+    let handle = app.clone();
+    let name = profile.clone();
+    let connected = tauri::async_runtime::spawn_blocking(move || {
+        all_profiles(&handle)
+            .into_iter()
+            .find(|candidate| candidate.name.eq_ignore_ascii_case(&name))
+            .map(|candidate| candidate.connected)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .ok_or_else(|| format!("❌ Unknown VPN profile: {}", profile))?;
+
+    set_vpn_connection(app, profile, !connected).await
+}
@@ -0,0 +1,98 @@
+/**
+ * Watchdog module - Timeouts for shelled helper processes (osascript, powershell, wmctrl...)
+ */
+
+use std::io::Read;
+use std::process::{Child, Command, Output, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Error code prefix so the frontend can tell a hung helper from a failed one.
+pub const TIMEOUT_ERROR: &str = "E_TIMEOUT";
+
+static DEFAULT_TIMEOUT_MS: AtomicU64 = AtomicU64::new(15_000);
+
+pub fn default_timeout() -> Duration {
+    Duration::from_millis(DEFAULT_TIMEOUT_MS.load(Ordering::Relaxed))
+}
+
+pub trait WatchdogCommand {
+    /// Like `Command::output`, but kills the child once the default timeout expires.
+    fn output_with_timeout(&mut self) -> Result<Output, String>;
+    fn output_within(&mut self, timeout: Duration) -> Result<Output, String>;
+}
+
+fn drain<R: Read + Send + 'static>(pipe: Option<R>) -> std::thread::JoinHandle<Vec<u8>> {
+    // Read pipes on their own threads so a chatty child can't block on a full buffer
+    std::thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
+
+impl WatchdogCommand for Command {
+    fn output_with_timeout(&mut self) -> Result<Output, String> {
+        self.output_within(default_timeout())
+    }
+
+    fn output_within(&mut self, timeout: Duration) -> Result<Output, String> {
+        let program = self.get_program().to_string_lossy().to_string();
+        let child = self
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| e.to_string())?;
+
+        collect_output(child, &program, timeout)
+    }
+}
+
+/// Waits for a child spawned with piped stdout/stderr, killing it once `timeout` expires.
+pub fn collect_output(mut child: Child, program: &str, timeout: Duration) -> Result<Output, String> {
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+    let started = Instant::now();
+
+    let status = loop {
+        match child.try_wait().map_err(|e| e.to_string())? {
+            Some(status) => break status,
+            None if started.elapsed() >= timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                println!("⏱️  Killed {} after {:?}", program, timeout);
+                return Err(format!(
+                    "{}: {} did not finish within {}ms",
+                    TIMEOUT_ERROR,
+                    program,
+                    timeout.as_millis()
+                ));
+            }
+            None => std::thread::sleep(Duration::from_millis(20)),
+        }
+    };
+
+    Ok(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+#[tauri::command]
+pub async fn set_command_timeout(timeout_ms: u64) -> Result<u64, String> {
+    if timeout_ms < 100 {
+        return Err("Command timeout must be at least 100ms".to_string());
+    }
+
+    DEFAULT_TIMEOUT_MS.store(timeout_ms, Ordering::Relaxed);
+    Ok(timeout_ms)
+}
+
+#[tauri::command]
+pub async fn get_command_timeout() -> Result<u64, String> {
+    Ok(DEFAULT_TIMEOUT_MS.load(Ordering::Relaxed))
+}
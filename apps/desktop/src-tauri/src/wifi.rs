@@ -0,0 +1,247 @@
+/**
+ * Wi-Fi module - Network scanning and connection with credentials from the vault
+ */
+
+use serde::Serialize;
+use std::process::Command;
+use tauri::AppHandle;
+
+use crate::watchdog::WatchdogCommand;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WifiNetwork {
+    pub ssid: String,
+    /// Signal quality in percent (0-100)
+    pub signal: Option<u8>,
+    pub security: Option<String>,
+    pub connected: bool,
+}
+
+fn wifi_secret_key(ssid: &str) -> String {
+    format!("wifi:{}", ssid)
+}
+
+fn run(command: &mut Command) -> Result<String, String> {
+    let output = command.output_with_timeout()?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn wifi_interface() -> Result<String, String> {
+    let ports = run(Command::new("networksetup").arg("-listallhardwareports"))?;
+    let mut lines = ports.lines();
+
+    while let Some(line) = lines.next() {
+        if line.contains("Wi-Fi") || line.contains("AirPort") {
+            if let Some(device) = lines.next().and_then(|next| next.strip_prefix("Device: ")) {
+                return Ok(device.trim().to_string());
+            }
+        }
+    }
+
+    Err("No Wi-Fi interface found".to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn scan_networks() -> Result<Vec<WifiNetwork>, String> {
+    let interface = wifi_interface()?;
+    let current = run(Command::new("networksetup").args(&["-getairportnetwork", &interface]))
+        .ok()
+        .and_then(|line| line.split(": ").nth(1).map(|ssid| ssid.trim().to_string()));
+
+    let scan = run(Command::new(
+        "/System/Library/PrivateFrameworks/Apple80211.framework/Versions/Current/Resources/airport",
+    )
+    .arg("-s"))?;
+
+    // Columns: SSID BSSID RSSI CHANNEL HT CC SECURITY; SSIDs may contain spaces
+    Ok(scan
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let bssid_at = tokens.iter().position(|token| token.len() == 17 && token.matches(':').count() == 5)?;
+            let ssid = tokens[..bssid_at].join(" ");
+            let rssi = tokens.get(bssid_at + 1).and_then(|rssi| rssi.parse::<i32>().ok());
+            let security = tokens.get(bssid_at + 5..).map(|rest| rest.join(" ")).filter(|security| !security.is_empty());
+
+            Some(WifiNetwork {
+                connected: current.as_deref() == Some(ssid.as_str()),
+                ssid,
+                // Map -100..-50 dBm onto 0..100%
+                signal: rssi.map(|dbm| ((dbm + 100).clamp(0, 50) * 2) as u8),
+                security,
+            })
+        })
+        .collect())
+}
+
+#[cfg(target_os = "windows")]
+fn scan_networks() -> Result<Vec<WifiNetwork>, String> {
+    let current = run(Command::new("netsh").args(&["wlan", "show", "interfaces"]))
+        .ok()
+        .and_then(|text| {
+            text.lines()
+                .find(|line| line.trim_start().starts_with("SSID"))
+                .and_then(|line| line.splitn(2, ':').nth(1))
+                .map(|ssid| ssid.trim().to_string())
+        });
+
+    let scan = run(Command::new("netsh").args(&["wlan", "show", "networks", "mode=bssid"]))?;
+    let mut networks: Vec<WifiNetwork> = Vec::new();
+
+    for line in scan.lines().map(str::trim) {
+        let (key, value) = match line.split_once(':') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => continue,
+        };
+
+        if key.starts_with("SSID") {
+            networks.push(WifiNetwork {
+                ssid: value.to_string(),
+                signal: None,
+                security: None,
+                connected: current.as_deref() == Some(value),
+            });
+        } else if let Some(network) = networks.last_mut() {
+            match key {
+                "Authentication" => network.security = Some(value.to_string()),
+                // Keep the strongest BSSID's signal
+                "Signal" => {
+                    let signal = value.trim_end_matches('%').parse::<u8>().ok();
+                    network.signal = network.signal.max(signal);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(networks)
+}
+
+#[cfg(target_os = "linux")]
+fn scan_networks() -> Result<Vec<WifiNetwork>, String> {
+    let scan = run(Command::new("nmcli").args(&["-t", "-f", "IN-USE,SSID,SIGNAL,SECURITY", "dev", "wifi", "list"]))?;
+
+    Ok(scan
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(':').collect();
+            if fields.len() < 4 || fields[1].is_empty() {
+                return None;
+            }
+
+            Some(WifiNetwork {
+                ssid: fields[1].to_string(),
+                signal: fields[2].parse().ok(),
+                security: Some(fields[3].to_string()).filter(|security| !security.is_empty()),
+                connected: fields[0] == "*",
+            })
+        })
+        .collect())
+}
+
+#[cfg(target_os = "macos")]
+fn join_network(ssid: &str, password: Option<&str>) -> Result<(), String> {
+    let interface = wifi_interface()?;
+    let mut args = vec!["-setairportnetwork", interface.as_str(), ssid];
+    if let Some(password) = password {
+        args.push(password);
+    }
+
+    // networksetup exits 0 even on failure, reporting errors on stdout
+    let output = run(Command::new("networksetup").args(&args))?;
+    if output.trim().is_empty() {
+        Ok(())
+    } else {
+        Err(output.trim().to_string())
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn join_network(ssid: &str, password: Option<&str>) -> Result<(), String> {
+    if let Some(password) = password {
+        // netsh can only connect to known profiles, so register one first
+        let escape = |value: &str| value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+        let profile = format!(
+            r#"<?xml version="1.0"?>
+<WLANProfile xmlns="http://www.microsoft.com/networking/WLAN/profile/v1">
+    <name>{ssid}</name>
+    <SSIDConfig><SSID><name>{ssid}</name></SSID></SSIDConfig>
+    <connectionType>ESS</connectionType>
+    <connectionMode>auto</connectionMode>
+    <MSM><security>
+        <authEncryption><authentication>WPA2PSK</authentication><encryption>AES</encryption><useOneX>false</useOneX></authEncryption>
+        <sharedKey><keyType>passPhrase</keyType><protected>false</protected><keyMaterial>{key}</keyMaterial></sharedKey>
+    </security></MSM>
+</WLANProfile>"#,
+            ssid = escape(ssid),
+            key = escape(password),
+        );
+
+        let path = std::env::temp_dir().join(format!("jarvisx-wifi-{}.xml", std::process::id()));
+        std::fs::write(&path, profile).map_err(|e| e.to_string())?;
+        let added = run(Command::new("netsh").args(&["wlan", "add", "profile", &format!("filename={}", path.display())]));
+        let _ = std::fs::remove_file(&path);
+        added?;
+    }
+
+    run(Command::new("netsh").args(&["wlan", "connect", &format!("name={}", ssid)])).map(|_| ())
+}
+
+#[cfg(target_os = "linux")]
+fn join_network(ssid: &str, password: Option<&str>) -> Result<(), String> {
+    let mut args = vec!["dev", "wifi", "connect", ssid];
+    if let Some(password) = password {
+        args.push("password");
+        args.push(password);
+    }
+
+    run(Command::new("nmcli").args(&args)).map(|_| ())
+}
+
+#[tauri::command]
+pub async fn list_wifi_networks() -> Result<Vec<WifiNetwork>, String> {
+    println!("📡 Scanning Wi-Fi networks");
+
+    let mut networks = tauri::async_runtime::spawn_blocking(scan_networks)
+        .await
+        .map_err(|e| e.to_string())??;
+
+    networks.sort_by(|a, b| b.connected.cmp(&a.connected).then(b.signal.cmp(&a.signal)));
+    networks.dedup_by(|a, b| a.ssid == b.ssid);
+    Ok(networks)
+}
+
+/// Joins `ssid`, using the vault entry `credential_from_vault` (defaults to the SSID's saved credential).
+#[tauri::command]
+pub async fn connect_wifi(app: AppHandle, ssid: String, credential_from_vault: Option<String>) -> Result<String, String> {
+    let namespace = crate::profiles::active_secrets_namespace(&app);
+    let key = credential_from_vault.unwrap_or_else(|| wifi_secret_key(&ssid));
+    let password = crate::secrets::load_secret(&namespace, &key)?;
+
+    println!("📡 Connecting to Wi-Fi: {} ({})", ssid, if password.is_some() { "saved credential" } else { "open/known network" });
+
+    let target = ssid.clone();
+    tauri::async_runtime::spawn_blocking(move || join_network(&target, password.as_deref()))
+        .await
+        .map_err(|e| e.to_string())??;
+
+    Ok(format!("Connected to {}", ssid))
+}
+
+#[tauri::command]
+pub async fn save_wifi_credential(app: AppHandle, ssid: String, password: String) -> Result<(), String> {
+    crate::secrets::store_secret(&crate::profiles::active_secrets_namespace(&app), &wifi_secret_key(&ssid), &password)
+}
+
+#[tauri::command]
+pub async fn forget_wifi_credential(app: AppHandle, ssid: String) -> Result<(), String> {
+    crate::secrets::delete_secret(&crate::profiles::active_secrets_namespace(&app), &wifi_secret_key(&ssid))
+}
@@ -0,0 +1,479 @@
+/**
+ * Window ids module - Stable WindowId handles for other applications' windows
+ *
+ * A WindowId is "<kind>:<native id>": "hwnd:<handle>" on Windows, "cg:<window number>" on macOS, "x11:<0x…>" on
+ * Linux and "mock:<id>" on the mock platform. Ids stay valid for the window's lifetime, so commands given one act on
+ * exactly that window even when several share a title. Windows map to their owning process and back, so automations
+ * can correlate the process list with the window list.
+ */
+
+use serde::Serialize;
+
+#[cfg(not(target_os = "windows"))]
+use crate::watchdog::WatchdogCommand;
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowBounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeWindow {
+    pub window_id: String,
+    pub title: String,
+    /// Owning application or process name
+    pub app: String,
+    pub pid: Option<u32>,
+    pub bounds: Option<WindowBounds>,
+}
+
+impl NativeWindow {
+    /// Entry in the shape get_window_list has always returned, plus the id fields.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "id": self.window_id,
+            "windowId": self.window_id,
+            "name": self.app,
+            "title": self.title,
+            "pid": self.pid,
+            "bounds": self.bounds,
+        })
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn process_name(pid: u32) -> String {
+    process_info(pid).map(|process| process.name).unwrap_or_default()
+}
+
+fn mock_windows() -> Vec<NativeWindow> {
+    crate::mock_platform::window_list()
+        .iter()
+        .map(|window| NativeWindow {
+            window_id: window["windowId"].as_str().unwrap_or_default().to_string(),
+            title: crate::window_registry::window_title(window),
+            app: window["name"].as_str().unwrap_or_default().to_string(),
+            pid: window["pid"].as_u64().map(|pid| pid as u32),
+            bounds: None,
+        })
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn platform_windows() -> Result<Vec<NativeWindow>, String> {
+    use windows::Win32::Foundation::{BOOL, HWND, LPARAM, RECT};
+    use windows::Win32::UI::WindowsAndMessaging::*;
+
+    unsafe extern "system" fn collect(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let handles = &mut *(lparam.0 as *mut Vec<HWND>);
+        // Visible, titled, unowned top-level windows are what Alt+Tab shows
+        if IsWindowVisible(hwnd).as_bool() && GetWindowTextLengthW(hwnd) > 0 && GetWindow(hwnd, GW_OWNER).0 == 0 {
+            handles.push(hwnd);
+        }
+        BOOL(1)
+    }
+
+    let mut handles: Vec<HWND> = Vec::new();
+    unsafe { EnumWindows(Some(collect), LPARAM(&mut handles as *mut Vec<HWND> as isize)) }.map_err(|e| e.to_string())?;
+
+    Ok(handles
+        .into_iter()
+        .map(|hwnd| unsafe {
+            let mut title = vec![0u16; GetWindowTextLengthW(hwnd) as usize + 1];
+            let length = GetWindowTextW(hwnd, &mut title);
+            let mut pid = 0u32;
+            GetWindowThreadProcessId(hwnd, Some(&mut pid as *mut u32));
+            let mut rect = RECT::default();
+            let bounds = GetWindowRect(hwnd, &mut rect).ok().map(|_| WindowBounds {
+                x: rect.left,
+                y: rect.top,
+                width: rect.right - rect.left,
+                height: rect.bottom - rect.top,
+            });
+
+            NativeWindow {
+                window_id: format!("hwnd:{}", hwnd.0),
+                title: String::from_utf16_lossy(&title[..length.max(0) as usize]),
+                app: process_name(pid),
+                pid: Some(pid).filter(|pid| *pid != 0),
+                bounds,
+            }
+        })
+        .collect())
+}
+
+/// Front-to-back list of normal (layer 0) windows from CGWindowListCopyWindowInfo, via JXA.
+#[cfg(target_os = "macos")]
+fn platform_windows() -> Result<Vec<NativeWindow>, String> {
+    use std::process::Command;
+
+    const SCRIPT: &str = r#"
+ObjC.import('CoreGraphics');
+const list = ObjC.deepUnwrap(ObjC.castRefToObject($.CGWindowListCopyWindowInfo($.kCGWindowListOptionOnScreenOnly | $.kCGWindowListExcludeDesktopElements, $.kCGNullWindowID)));
+JSON.stringify(list.filter(w => w.kCGWindowLayer === 0).map(w => ({
+    number: w.kCGWindowNumber, pid: w.kCGWindowOwnerPID, owner: w.kCGWindowOwnerName || '', title: w.kCGWindowName || '',
+    x: w.kCGWindowBounds.X, y: w.kCGWindowBounds.Y, width: w.kCGWindowBounds.Width, height: w.kCGWindowBounds.Height,
+})));
+"#;
+
+    let output = Command::new("osascript").args(&["-l", "JavaScript", "-e", SCRIPT]).output_with_timeout()?;
+    if !output.status.success() {
+        return Err("Failed to get window list".to_string());
+    }
+
+    let windows: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).map_err(|e| e.to_string())?;
+    Ok(windows
+        .iter()
+        .map(|window| {
+            let owner = window["owner"].as_str().unwrap_or_default().to_string();
+            let int = |key: &str| window[key].as_f64().unwrap_or(0.0) as i32;
+            NativeWindow {
+                window_id: format!("cg:{}", window["number"].as_u64().unwrap_or(0)),
+                // Titles need screen-recording permission; the owner is the best label without it
+                title: window["title"].as_str().filter(|title| !title.is_empty()).unwrap_or(&owner).to_string(),
+                app: owner.clone(),
+                pid: window["pid"].as_u64().map(|pid| pid as u32),
+                bounds: Some(WindowBounds { x: int("x"), y: int("y"), width: int("width"), height: int("height") }),
+            }
+        })
+        .collect())
+}
+
+/// Canonical "x11:0x…" form; wmctrl zero-pads ids and xprop doesn't.
+#[cfg(target_os = "linux")]
+fn x11_id(raw: &str) -> Option<String> {
+    u64::from_str_radix(raw.trim().trim_start_matches("0x"), 16).ok().map(|id| format!("x11:0x{:08x}", id))
+}
+
+#[cfg(target_os = "linux")]
+fn platform_windows() -> Result<Vec<NativeWindow>, String> {
+    use std::process::Command;
+
+    // id, desktop, pid, x, y, width, height, host, title
+    let output = Command::new("wmctrl").arg("-lpG").output_with_timeout()?;
+    if !output.status.success() {
+        return Err("Failed to get window list".to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let mut rest = line;
+            let mut fields = Vec::new();
+            for _ in 0..8 {
+                let trimmed = rest.trim_start();
+                let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+                fields.push(&trimmed[..end]);
+                rest = &trimmed[end..];
+            }
+            let number = |index: usize| fields[index].parse::<i32>().unwrap_or(0);
+            let pid = fields[2].parse::<u32>().ok().filter(|pid| *pid != 0);
+
+            Some(NativeWindow {
+                window_id: x11_id(fields[0])?,
+                title: rest.trim().to_string(),
+                app: pid.map(process_name).unwrap_or_default(),
+                pid,
+                bounds: Some(WindowBounds { x: number(3), y: number(4), width: number(5), height: number(6) }),
+            })
+        })
+        .collect())
+}
+
+/// Every top-level window of other applications, with its WindowId.
+pub fn enumerate_windows() -> Result<Vec<NativeWindow>, String> {
+    if crate::mock_platform::is_active() {
+        return Ok(mock_windows());
+    }
+    platform_windows()
+}
+
+/// Looks up a live window; ids of closed windows are reported as not found rather than matched fuzzily.
+pub fn resolve(window_id: &str) -> Result<NativeWindow, String> {
+    enumerate_windows()?
+        .into_iter()
+        .find(|window| window.window_id == window_id)
+        .ok_or_else(|| format!("❌ Window not found: {} (it may have been closed)", window_id))
+}
+
+pub fn active_window() -> Option<NativeWindow> {
+    if crate::mock_platform::is_active() {
+        let windows = crate::mock_platform::window_list();
+        let focused = windows.iter().find(|window| window["focused"].as_bool() == Some(true))?;
+        return resolve(focused["windowId"].as_str()?).ok();
+    }
+
+    #[cfg(target_os = "windows")]
+    let window_id = {
+        let hwnd = unsafe { windows::Win32::UI::WindowsAndMessaging::GetForegroundWindow() };
+        Some(format!("hwnd:{}", hwnd.0)).filter(|_| hwnd.0 != 0)
+    };
+
+    #[cfg(target_os = "macos")]
+    // The CG list is ordered front to back
+    return platform_windows().ok()?.into_iter().next();
+
+    #[cfg(target_os = "linux")]
+    let window_id = {
+        let output = std::process::Command::new("xprop").args(&["-root", "_NET_ACTIVE_WINDOW"]).output_with_timeout().ok()?;
+        // "_NET_ACTIVE_WINDOW(WINDOW): window id # 0x3a00007"
+        String::from_utf8_lossy(&output.stdout).rsplit("# ").next().and_then(x11_id)
+    };
+
+    #[cfg(not(target_os = "macos"))]
+    resolve(&window_id?).ok()
+}
+
+#[cfg(target_os = "macos")]
+fn run_in_window(window: &NativeWindow, statement: &str) -> Result<(), String> {
+    use std::process::Command;
+
+    let pid = window.pid.ok_or("Window has no owning process")?;
+    let title = window.title.replace('\\', "\\\\").replace('"', "\\\"");
+    let script = format!(
+        "tell application \"System Events\" to tell (first process whose unix id is {})\n\
+         set target to window 1\n\
+         try\n\
+         set target to (first window whose name is \"{}\")\n\
+         end try\n\
+         {}\n\
+         end tell",
+        pid, title, statement
+    );
+    let output = Command::new("osascript").arg("-e").arg(script).output_with_timeout()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn hwnd(window: &NativeWindow) -> Result<windows::Win32::Foundation::HWND, String> {
+    window
+        .window_id
+        .strip_prefix("hwnd:")
+        .and_then(|handle| handle.parse().ok())
+        .map(windows::Win32::Foundation::HWND)
+        .ok_or_else(|| format!("❌ Not a window handle: {}", window.window_id))
+}
+
+#[cfg(target_os = "linux")]
+fn wmctrl(window: &NativeWindow, args: &[&str]) -> Result<(), String> {
+    let id = window.window_id.strip_prefix("x11:").ok_or_else(|| format!("❌ Not an X11 window: {}", window.window_id))?;
+    let output = std::process::Command::new("wmctrl").arg("-i").args(args.iter().map(|arg| arg.replace("{id}", id))).output_with_timeout()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+pub fn focus(window: &NativeWindow) -> Result<String, String> {
+    if crate::mock_platform::is_active() {
+        return crate::mock_platform::focus_id(&window.window_id);
+    }
+
+    #[cfg(target_os = "windows")]
+    let result = unsafe {
+        use windows::Win32::UI::WindowsAndMessaging::{IsIconic, SetForegroundWindow, ShowWindow, SW_RESTORE};
+        let hwnd = hwnd(window)?;
+        if IsIconic(hwnd).as_bool() {
+            let _ = ShowWindow(hwnd, SW_RESTORE);
+        }
+        if SetForegroundWindow(hwnd).as_bool() { Ok(()) } else { Err("the window refused focus".to_string()) }
+    };
+
+    #[cfg(target_os = "macos")]
+    let result = run_in_window(window, "set frontmost to true\nperform action \"AXRaise\" of target");
+
+    #[cfg(target_os = "linux")]
+    let result = wmctrl(window, &["-a", "{id}"]);
+
+    result
+        .map(|_| format!("✅ Focused window: {}", window.title))
+        .map_err(|e| format!("❌ Failed to focus window {}: {}", window.window_id, e))
+}
+
+pub fn minimize(window: &NativeWindow) -> Result<String, String> {
+    if crate::mock_platform::is_active() {
+        crate::mock_platform::record_input("window", serde_json::json!({ "action": "minimize", "windowId": window.window_id }));
+        return Ok(format!("✅ Minimized: {}", window.title));
+    }
+
+    #[cfg(target_os = "windows")]
+    let result = unsafe {
+        use windows::Win32::UI::WindowsAndMessaging::{ShowWindow, SW_MINIMIZE};
+        let _ = ShowWindow(hwnd(window)?, SW_MINIMIZE);
+        Ok::<(), String>(())
+    };
+
+    #[cfg(target_os = "macos")]
+    let result = run_in_window(window, "set value of attribute \"AXMinimized\" of target to true");
+
+    #[cfg(target_os = "linux")]
+    let result = wmctrl(window, &["-r", "{id}", "-b", "add,hidden"]);
+
+    result
+        .map(|_| format!("✅ Minimized: {}", window.title))
+        .map_err(|e| format!("❌ Failed to minimize {}: {}", window.window_id, e))
+}
+
+/// Moves a window, and resizes it when both `width` and `height` are given.
+pub fn move_to(window: &NativeWindow, x: i32, y: i32, size: Option<(i32, i32)>) -> Result<String, String> {
+    if crate::mock_platform::is_active() {
+        crate::mock_platform::record_input("window", serde_json::json!({
+            "action": "move", "windowId": window.window_id, "x": x, "y": y, "size": size,
+        }));
+        return Ok(format!("✅ Moved: {}", window.title));
+    }
+
+    #[cfg(target_os = "windows")]
+    let result = unsafe {
+        use windows::Win32::Foundation::HWND;
+        use windows::Win32::UI::WindowsAndMessaging::{SetWindowPos, SWP_NOACTIVATE, SWP_NOSIZE, SWP_NOZORDER};
+        let mut flags = SWP_NOZORDER | SWP_NOACTIVATE;
+        if size.is_none() {
+            flags |= SWP_NOSIZE;
+        }
+        let (width, height) = size.unwrap_or((0, 0));
+        SetWindowPos(hwnd(window)?, HWND(0), x, y, width, height, flags).map_err(|e| e.to_string())
+    };
+
+    #[cfg(target_os = "macos")]
+    let result = {
+        let mut statement = format!("set position of target to {{{}, {}}}", x, y);
+        if let Some((width, height)) = size {
+            statement.push_str(&format!("\nset size of target to {{{}, {}}}", width, height));
+        }
+        run_in_window(window, &statement)
+    };
+
+    #[cfg(target_os = "linux")]
+    let result = {
+        let (width, height) = size.unwrap_or((-1, -1));
+        let geometry = format!("0,{},{},{},{}", x, y, width, height);
+        wmctrl(window, &["-r", "{id}", "-e", &geometry])
+    };
+
+    result
+        .map(|_| format!("✅ Moved: {}", window.title))
+        .map_err(|e| format!("❌ Failed to move {}: {}", window.window_id, e))
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowProcess {
+    pub pid: u32,
+    pub name: String,
+    pub parent_pid: Option<u32>,
+    /// Bytes
+    pub memory: u64,
+    pub cpu_usage: f32,
+}
+
+pub fn process_info(pid: u32) -> Option<WindowProcess> {
+    use sysinfo::{ProcessExt, SystemExt};
+
+    let pid = sysinfo::Pid::from(pid as usize);
+    crate::subsystems::with_system(|sys| {
+        sys.refresh_process(pid);
+        sys.process(pid).map(|process| WindowProcess {
+            pid: pid.as_u32(),
+            name: process.name().to_string(),
+            parent_pid: process.parent().map(|parent| parent.as_u32()),
+            memory: process.memory(),
+            cpu_usage: process.cpu_usage(),
+        })
+    })
+}
+
+/// `pid` followed by its ancestors, nearest first.
+fn process_chain(pid: u32) -> Vec<u32> {
+    let mut chain = vec![pid];
+    while chain.len() < 32 {
+        match process_info(*chain.last().unwrap()).and_then(|process| process.parent_pid) {
+            Some(parent) if parent != 0 && !chain.contains(&parent) => chain.push(parent),
+            _ => break,
+        }
+    }
+    chain
+}
+
+/// Windows owned by `pid`. Helper processes (browser renderers, audio services) own none themselves, so the windows
+/// of the nearest ancestor that has some are returned instead.
+pub fn windows_for_process(pid: u32) -> Result<Vec<NativeWindow>, String> {
+    let windows = enumerate_windows()?;
+    let chain = if crate::mock_platform::is_active() { vec![pid] } else { process_chain(pid) };
+
+    Ok(chain
+        .iter()
+        .map(|owner| windows.iter().filter(|window| window.pid == Some(*owner)).cloned().collect::<Vec<_>>())
+        .find(|owned| !owned.is_empty())
+        .unwrap_or_default())
+}
+
+pub fn process_for_window(window_id: &str) -> Result<WindowProcess, String> {
+    let window = resolve(window_id)?;
+    let pid = window.pid.ok_or_else(|| format!("❌ No owning process known for {}", window_id))?;
+
+    if crate::mock_platform::is_active() {
+        return Ok(WindowProcess { pid, name: window.app, parent_pid: None, memory: 0, cpu_usage: 0.0 });
+    }
+    process_info(pid).ok_or_else(|| format!("❌ Process not found: {} (it may have exited)", pid))
+}
+
+#[tauri::command]
+pub async fn get_windows_for_process(pid: u32) -> Result<Vec<NativeWindow>, String> {
+    tauri::async_runtime::spawn_blocking(move || windows_for_process(pid))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+pub async fn get_process_for_window(window_id: String) -> Result<WindowProcess, String> {
+    tauri::async_runtime::spawn_blocking(move || process_for_window(&window_id))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// The frontmost window with its WindowId.
+#[tauri::command]
+pub async fn get_active_window_info() -> Result<NativeWindow, String> {
+    tauri::async_runtime::spawn_blocking(active_window)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "No active window".to_string())
+}
+
+#[tauri::command]
+pub async fn minimize_window(window_id: String) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || minimize(&resolve(&window_id)?))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+pub async fn move_window(window_id: String, x: i32, y: i32, width: Option<i32>, height: Option<i32>) -> Result<String, String> {
+    let size = width.zip(height);
+    tauri::async_runtime::spawn_blocking(move || move_to(&resolve(&window_id)?, x, y, size))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// PNG of one window's area (including anything overlapping it), returned as a `jarvisx://` blob.
+#[tauri::command]
+pub async fn capture_window(window_id: String) -> Result<crate::binary::BinaryRef, String> {
+    let window = tauri::async_runtime::spawn_blocking(move || resolve(&window_id))
+        .await
+        .map_err(|e| e.to_string())??;
+    let bounds = window.bounds.ok_or_else(|| format!("❌ No bounds known for {}", window.window_id))?;
+
+    let png = crate::commands::capture_screen_region_png(bounds.x, bounds.y, bounds.width, bounds.height).await?;
+    Ok(crate::binary::store_blob(png, "image/png"))
+}
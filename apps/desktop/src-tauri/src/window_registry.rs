@@ -0,0 +1,567 @@
+/**
+ * Window registry module - Cached window list with create/destroy/title-change events
+ *
+ * While subscribed, the list is re-queried when the platform reports a window change: WinEvent hooks on Windows,
+ * NSWorkspace and accessibility observers on macOS, and `_NET_CLIENT_LIST` and title property changes on X11.
+ * Where none of these can be installed (e.g. Wayland), the list is polled instead.
+ */
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::mpsc;
+
+/// How long a cached list is served before get_window_list re-queries the platform.
+const CACHE_TTL: Duration = Duration::from_millis(750);
+/// A new window arrives as a burst of events (created, shown, titled), so they're collected for this long.
+const EVENT_SETTLE: Duration = Duration::from_millis(100);
+/// Even with hooks the list is re-queried this often, for changes a platform doesn't report (macOS titles without
+/// accessibility access, for one).
+const HOOKED_RESYNC_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Wakes the watcher; only set while subscribed.
+static CHANGED: Mutex<Option<mpsc::UnboundedSender<()>>> = Mutex::new(None);
+/// Whether the platform hooks were installed; they stay installed for the life of the app.
+static HOOKS: OnceLock<Result<(), String>> = OnceLock::new();
+
+pub struct WindowRegistry {
+    pub windows: HashMap<String, serde_json::Value>,
+    pub refreshed_at: Option<Instant>,
+    pub subscribed: bool,
+}
+
+impl Default for WindowRegistry {
+    fn default() -> Self {
+        WindowRegistry {
+            windows: HashMap::new(),
+            refreshed_at: None,
+            subscribed: false,
+        }
+    }
+}
+
+/// Stable key for a window entry across the differently shaped platform outputs.
+pub fn window_key(window: &serde_json::Value) -> Option<String> {
+    ["id", "name", "Name"]
+        .iter()
+        .find_map(|field| window.get(*field).and_then(|v| v.as_str()))
+        .map(|key| key.to_string())
+}
+
+pub fn window_title(window: &serde_json::Value) -> String {
+    ["title", "MainWindowTitle"]
+        .iter()
+        .find_map(|field| window.get(*field).and_then(|v| v.as_str()))
+        .unwrap_or("")
+        .to_string()
+}
+
+enum WindowChange {
+    Created(serde_json::Value),
+    Destroyed(serde_json::Value),
+    TitleChanged { window: serde_json::Value, previous: String },
+}
+
+/// Replaces the registry contents and returns what changed since the last snapshot.
+fn apply_snapshot(registry: &mut WindowRegistry, snapshot: Vec<serde_json::Value>) -> Vec<WindowChange> {
+    let mut next = HashMap::new();
+    for window in snapshot {
+        if let Some(key) = window_key(&window) {
+            next.insert(key, window);
+        }
+    }
+
+    let mut changes = Vec::new();
+    for (key, window) in next.iter() {
+        match registry.windows.get(key) {
+            None => changes.push(WindowChange::Created(window.clone())),
+            Some(previous) if window_title(previous) != window_title(window) => {
+                changes.push(WindowChange::TitleChanged {
+                    window: window.clone(),
+                    previous: window_title(previous),
+                });
+            }
+            Some(_) => {}
+        }
+    }
+    for (key, window) in registry.windows.iter() {
+        if !next.contains_key(key) {
+            changes.push(WindowChange::Destroyed(window.clone()));
+        }
+    }
+
+    registry.windows = next;
+    registry.refreshed_at = Some(Instant::now());
+    changes
+}
+
+fn snapshot_list(registry: &WindowRegistry) -> Vec<serde_json::Value> {
+    let mut windows: Vec<serde_json::Value> = registry.windows.values().cloned().collect();
+    windows.sort_by_key(|window| window_key(window).unwrap_or_default());
+    windows
+}
+
+/// Serves the cached list while fresh (or while the watcher keeps it current); `refresh` forces a re-query.
+pub async fn cached_window_list(registry: &Mutex<WindowRegistry>, refresh: bool) -> Result<Vec<serde_json::Value>, String> {
+    if !refresh {
+        let registry = registry.lock().unwrap();
+        let fresh = registry.refreshed_at.map_or(false, |at| at.elapsed() < CACHE_TTL);
+        if fresh || (registry.subscribed && registry.refreshed_at.is_some()) {
+            return Ok(snapshot_list(&registry));
+        }
+    }
+
+    let snapshot = crate::retry::retry(&crate::retry::current_policy(), "query_window_list", crate::commands::query_window_list)
+        .await?
+        .value;
+    let mut registry = registry.lock().unwrap();
+    apply_snapshot(&mut registry, snapshot);
+    Ok(snapshot_list(&registry))
+}
+
+fn emit_changes(app: &AppHandle, changes: Vec<WindowChange>) {
+    for change in changes {
+        let _ = match change {
+            WindowChange::Created(window) => app.emit_all("window://created", window),
+            WindowChange::Destroyed(window) => app.emit_all("window://destroyed", window),
+            WindowChange::TitleChanged { window, previous } => app.emit_all(
+                "window://title-changed",
+                serde_json::json!({ "window": window, "previousTitle": previous }),
+            ),
+        };
+    }
+}
+
+fn signal_change() {
+    if let Some(changed) = CHANGED.lock().unwrap().as_ref() {
+        let _ = changed.send(());
+    }
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn on_win_event(
+    _: windows::Win32::UI::Accessibility::HWINEVENTHOOK,
+    _: u32,
+    _: windows::Win32::Foundation::HWND,
+    id_object: i32,
+    id_child: i32,
+    _: u32,
+    _: u32,
+) {
+    use windows::Win32::UI::WindowsAndMessaging::{CHILDID_SELF, OBJID_WINDOW};
+
+    // Only windows themselves, not the carets, cursors and controls inside them
+    if id_object == OBJID_WINDOW.0 && id_child == CHILDID_SELF as i32 {
+        signal_change();
+    }
+}
+
+/// Installs out-of-context WinEvent hooks for windows being created, destroyed, shown, hidden or renamed.
+#[cfg(windows)]
+fn subscribe_window_changes() -> Result<(), String> {
+    use windows::Win32::Foundation::{HMODULE, HWND};
+    use windows::Win32::UI::Accessibility::SetWinEventHook;
+    use windows::Win32::UI::WindowsAndMessaging::{
+        DispatchMessageW, GetMessageW, TranslateMessage, EVENT_OBJECT_CREATE, EVENT_OBJECT_HIDE, EVENT_OBJECT_NAMECHANGE, MSG,
+        WINEVENT_OUTOFCONTEXT, WINEVENT_SKIPOWNPROCESS,
+    };
+
+    let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        // Out-of-context hooks are delivered through the message queue of the thread that set them
+        let hooks = [(EVENT_OBJECT_CREATE, EVENT_OBJECT_HIDE), (EVENT_OBJECT_NAMECHANGE, EVENT_OBJECT_NAMECHANGE)].map(|(min, max)| unsafe {
+            SetWinEventHook(min, max, HMODULE(0), Some(on_win_event), 0, 0, WINEVENT_OUTOFCONTEXT | WINEVENT_SKIPOWNPROCESS)
+        });
+        if hooks.iter().any(|hook| hook.is_invalid()) {
+            let _ = ready_tx.send(Err("SetWinEventHook failed".to_string()));
+            return;
+        }
+        let _ = ready_tx.send(Ok(()));
+
+        let mut msg = MSG::default();
+        while unsafe { GetMessageW(&mut msg, HWND(0), 0, 0) }.as_bool() {
+            unsafe {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        }
+    });
+    ready_rx.recv().map_err(|e| e.to_string())?
+}
+
+#[cfg(target_os = "macos")]
+type AXObserverRef = *mut std::ffi::c_void;
+
+#[cfg(target_os = "macos")]
+type AXUIElementRef = *mut std::ffi::c_void;
+
+#[cfg(target_os = "macos")]
+type AXObserverCallback = extern "C" fn(
+    observer: AXObserverRef,
+    element: AXUIElementRef,
+    notification: core_foundation::string::CFStringRef,
+    refcon: *mut std::ffi::c_void,
+);
+
+#[cfg(target_os = "macos")]
+#[link(name = "ApplicationServices", kind = "framework")]
+extern "C" {
+    fn AXUIElementCreateApplication(pid: i32) -> AXUIElementRef;
+    fn AXUIElementCopyAttributeValue(
+        element: AXUIElementRef,
+        attribute: core_foundation::string::CFStringRef,
+        value: *mut core_foundation::base::CFTypeRef,
+    ) -> i32;
+    fn AXObserverCreate(pid: i32, callback: AXObserverCallback, observer: *mut AXObserverRef) -> i32;
+    fn AXObserverAddNotification(
+        observer: AXObserverRef,
+        element: AXUIElementRef,
+        notification: core_foundation::string::CFStringRef,
+        refcon: *mut std::ffi::c_void,
+    ) -> i32;
+    fn AXObserverGetRunLoopSource(observer: AXObserverRef) -> core_foundation::runloop::CFRunLoopSourceRef;
+}
+
+/// Apps launched (true) or terminated (false) since the observer thread last looked, by pid.
+#[cfg(target_os = "macos")]
+static APP_EVENTS: Mutex<Vec<(i32, bool)>> = Mutex::new(Vec::new());
+
+#[cfg(target_os = "macos")]
+unsafe fn ns_string(text: &str) -> *mut objc::runtime::Object {
+    use objc::{class, msg_send, sel, sel_impl};
+
+    let text = std::ffi::CString::new(text).unwrap_or_default();
+    msg_send![class!(NSString), stringWithUTF8String: text.as_ptr()]
+}
+
+#[cfg(target_os = "macos")]
+unsafe fn notification_pid(notification: *mut objc::runtime::Object) -> Option<i32> {
+    use objc::runtime::Object;
+    use objc::{msg_send, sel, sel_impl};
+
+    let user_info: *mut Object = msg_send![notification, userInfo];
+    if user_info.is_null() {
+        return None;
+    }
+    let application: *mut Object = msg_send![user_info, objectForKey: ns_string("NSWorkspaceApplicationKey")];
+    if application.is_null() {
+        return None;
+    }
+    let pid: i32 = msg_send![application, processIdentifier];
+    Some(pid)
+}
+
+#[cfg(target_os = "macos")]
+extern "C" fn on_app_launched(_: &objc::runtime::Object, _: objc::runtime::Sel, notification: *mut objc::runtime::Object) {
+    if let Some(pid) = unsafe { notification_pid(notification) } {
+        APP_EVENTS.lock().unwrap().push((pid, true));
+    }
+    signal_change();
+}
+
+#[cfg(target_os = "macos")]
+extern "C" fn on_app_terminated(_: &objc::runtime::Object, _: objc::runtime::Sel, notification: *mut objc::runtime::Object) {
+    if let Some(pid) = unsafe { notification_pid(notification) } {
+        APP_EVENTS.lock().unwrap().push((pid, false));
+    }
+    signal_change();
+}
+
+#[cfg(target_os = "macos")]
+extern "C" fn on_app_changed(_: &objc::runtime::Object, _: objc::runtime::Sel, _: *mut objc::runtime::Object) {
+    signal_change();
+}
+
+/// Observes NSWorkspace launches, terminations, activations and hides/unhides; returns the running regular apps.
+#[cfg(target_os = "macos")]
+unsafe fn observe_workspace() -> Result<Vec<i32>, String> {
+    use objc::declare::ClassDecl;
+    use objc::runtime::{Object, Sel};
+    use objc::{class, msg_send, sel, sel_impl};
+
+    let mut decl = ClassDecl::new("JarvisXWindowObserver", class!(NSObject)).ok_or("Window observer class already exists")?;
+    decl.add_method(sel!(applicationLaunched:), on_app_launched as extern "C" fn(&Object, Sel, *mut Object));
+    decl.add_method(sel!(applicationTerminated:), on_app_terminated as extern "C" fn(&Object, Sel, *mut Object));
+    decl.add_method(sel!(applicationChanged:), on_app_changed as extern "C" fn(&Object, Sel, *mut Object));
+    let observer: *mut Object = msg_send![decl.register(), new];
+
+    let workspace: *mut Object = msg_send![class!(NSWorkspace), sharedWorkspace];
+    let center: *mut Object = msg_send![workspace, notificationCenter];
+    let notifications = [
+        (sel!(applicationLaunched:), "NSWorkspaceDidLaunchApplicationNotification"),
+        (sel!(applicationTerminated:), "NSWorkspaceDidTerminateApplicationNotification"),
+        (sel!(applicationChanged:), "NSWorkspaceDidActivateApplicationNotification"),
+        (sel!(applicationChanged:), "NSWorkspaceDidHideApplicationNotification"),
+        (sel!(applicationChanged:), "NSWorkspaceDidUnhideApplicationNotification"),
+    ];
+    for (selector, name) in notifications {
+        let _: () = msg_send![center, addObserver: observer selector: selector name: ns_string(name) object: std::ptr::null_mut::<Object>()];
+    }
+
+    let applications: *mut Object = msg_send![workspace, runningApplications];
+    let count: usize = msg_send![applications, count];
+    Ok((0..count)
+        .filter_map(|index| {
+            let application: *mut Object = msg_send![applications, objectAtIndex: index];
+            let policy: isize = msg_send![application, activationPolicy];
+            let pid: i32 = msg_send![application, processIdentifier];
+            // Accessory and background apps rarely own windows in the list
+            (policy == 0).then_some(pid)
+        })
+        .collect())
+}
+
+#[cfg(target_os = "macos")]
+extern "C" fn on_ax_notification(
+    observer: AXObserverRef,
+    element: AXUIElementRef,
+    notification: core_foundation::string::CFStringRef,
+    _: *mut std::ffi::c_void,
+) {
+    use core_foundation::base::TCFType;
+    use core_foundation::string::CFString;
+
+    // New windows are observed themselves so their title changes and closing are reported too
+    if unsafe { CFString::wrap_under_get_rule(notification) } == "AXWindowCreated" {
+        observe_window(observer, element);
+    }
+    signal_change();
+}
+
+#[cfg(target_os = "macos")]
+fn observe_window(observer: AXObserverRef, window: AXUIElementRef) {
+    use core_foundation::base::TCFType;
+    use core_foundation::string::CFString;
+
+    for notification in ["AXUIElementDestroyed", "AXTitleChanged"] {
+        let notification = CFString::from_static_string(notification);
+        unsafe { AXObserverAddNotification(observer, window, notification.as_concrete_TypeRef(), std::ptr::null_mut()) };
+    }
+}
+
+/// Adds an accessibility observer for one app's windows to this thread's run loop. Fails until JarvisX has
+/// accessibility access (or while the app is still starting up).
+#[cfg(target_os = "macos")]
+fn observe_app(pid: i32) -> Option<(AXObserverRef, AXUIElementRef)> {
+    use core_foundation::array::{CFArrayGetCount, CFArrayGetValueAtIndex, CFArrayRef};
+    use core_foundation::base::{CFRelease, CFTypeRef, TCFType};
+    use core_foundation::runloop::{kCFRunLoopDefaultMode, CFRunLoopAddSource, CFRunLoopGetCurrent};
+    use core_foundation::string::CFString;
+
+    unsafe {
+        let mut observer: AXObserverRef = std::ptr::null_mut();
+        if AXObserverCreate(pid, on_ax_notification, &mut observer) != 0 {
+            return None;
+        }
+        let application = AXUIElementCreateApplication(pid);
+        let created = CFString::from_static_string("AXWindowCreated");
+        if AXObserverAddNotification(observer, application, created.as_concrete_TypeRef(), std::ptr::null_mut()) != 0 {
+            CFRelease(application as CFTypeRef);
+            CFRelease(observer as CFTypeRef);
+            return None;
+        }
+
+        let mut windows: CFTypeRef = std::ptr::null();
+        let attribute = CFString::from_static_string("AXWindows");
+        if AXUIElementCopyAttributeValue(application, attribute.as_concrete_TypeRef(), &mut windows) == 0 {
+            for index in 0..CFArrayGetCount(windows as CFArrayRef) {
+                observe_window(observer, CFArrayGetValueAtIndex(windows as CFArrayRef, index) as AXUIElementRef);
+            }
+            CFRelease(windows);
+        }
+
+        CFRunLoopAddSource(CFRunLoopGetCurrent(), AXObserverGetRunLoopSource(observer), kCFRunLoopDefaultMode);
+        Some((observer, application))
+    }
+}
+
+/// Listens for apps launching and quitting through NSWorkspace, and for each app's windows being created,
+/// closed or retitled through accessibility observers on a dedicated run loop thread.
+#[cfg(target_os = "macos")]
+fn subscribe_window_changes() -> Result<(), String> {
+    use core_foundation::base::{CFRelease, CFTypeRef};
+    use core_foundation::runloop::{kCFRunLoopDefaultMode, kCFRunLoopRunFinished, CFRunLoopGetCurrent, CFRunLoopRemoveSource, CFRunLoopRunInMode};
+    use objc::runtime::Object;
+    use objc::{class, msg_send, sel, sel_impl};
+
+    /// Apps aren't observable the moment they launch, so a failed launch is retried this many times.
+    const LAUNCH_ATTEMPTS: u32 = 10;
+
+    let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let running = unsafe {
+            let pool: *mut Object = msg_send![class!(NSAutoreleasePool), new];
+            let running = observe_workspace();
+            let _: () = msg_send![pool, drain];
+            running
+        };
+        let running = match running {
+            Ok(running) => running,
+            Err(e) => {
+                let _ = ready_tx.send(Err(e));
+                return;
+            }
+        };
+        let _ = ready_tx.send(Ok(()));
+
+        let mut observers = HashMap::new();
+        let mut launching: Vec<(i32, u32)> = running.into_iter().map(|pid| (pid, LAUNCH_ATTEMPTS - 1)).collect();
+        loop {
+            for (pid, launched) in std::mem::take(&mut *APP_EVENTS.lock().unwrap()) {
+                if launched {
+                    launching.push((pid, LAUNCH_ATTEMPTS));
+                    continue;
+                }
+                launching.retain(|(launching_pid, _)| *launching_pid != pid);
+                if let Some((observer, application)) = observers.remove(&pid) {
+                    unsafe {
+                        CFRunLoopRemoveSource(CFRunLoopGetCurrent(), AXObserverGetRunLoopSource(observer), kCFRunLoopDefaultMode);
+                        CFRelease(observer as CFTypeRef);
+                        CFRelease(application as CFTypeRef);
+                    }
+                }
+            }
+            launching = launching
+                .into_iter()
+                .filter_map(|(pid, attempts)| match observe_app(pid) {
+                    Some(observed) => {
+                        observers.insert(pid, observed);
+                        None
+                    }
+                    None => (attempts > 1).then_some((pid, attempts - 1)),
+                })
+                .collect();
+
+            // Wakes every half second to pick up launched apps; with nothing observed the run loop returns at once
+            if unsafe { CFRunLoopRunInMode(kCFRunLoopDefaultMode, 0.5, 0) } == kCFRunLoopRunFinished {
+                std::thread::sleep(Duration::from_millis(500));
+            }
+        }
+    });
+    ready_rx.recv().map_err(|e| e.to_string())?
+}
+
+/// Watches the root window's `_NET_CLIENT_LIST` (windows mapped and unmapped) and each client's title properties.
+#[cfg(target_os = "linux")]
+fn subscribe_window_changes() -> Result<(), String> {
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{AtomEnum, ChangeWindowAttributesAux, ConnectionExt, EventMask};
+    use x11rb::protocol::Event;
+    use x11rb::rust_connection::RustConnection;
+
+    // Wayland sessions without XWayland have no X server to ask
+    let (connection, screen) = x11rb::connect(None).map_err(|e| e.to_string())?;
+    let root = connection.setup().roots[screen].root;
+    let atom = |name: &[u8]| -> Result<u32, String> {
+        Ok(connection.intern_atom(false, name).map_err(|e| e.to_string())?.reply().map_err(|e| e.to_string())?.atom)
+    };
+    let client_list = atom(b"_NET_CLIENT_LIST")?;
+    let net_wm_name = atom(b"_NET_WM_NAME")?;
+
+    let watch = |connection: &RustConnection, window: u32| {
+        let _ = connection.change_window_attributes(window, &ChangeWindowAttributesAux::new().event_mask(EventMask::PROPERTY_CHANGE));
+    };
+    // Titles are properties of each client window, so every client is watched as well as the root
+    let watch_clients = move |connection: &RustConnection| {
+        let clients: Vec<u32> = connection
+            .get_property(false, root, client_list, AtomEnum::WINDOW, 0, u32::MAX)
+            .ok()
+            .and_then(|cookie| cookie.reply().ok())
+            .and_then(|reply| reply.value32().map(|windows| windows.collect()))
+            .unwrap_or_default();
+        for window in clients {
+            watch(connection, window);
+        }
+        let _ = connection.flush();
+    };
+
+    watch(&connection, root);
+    watch_clients(&connection);
+    connection.flush().map_err(|e| e.to_string())?;
+
+    std::thread::spawn(move || loop {
+        match connection.wait_for_event() {
+            Ok(Event::PropertyNotify(event)) if event.window == root => {
+                if event.atom == client_list {
+                    watch_clients(&connection);
+                    signal_change();
+                }
+            }
+            Ok(Event::PropertyNotify(event)) if event.atom == net_wm_name || event.atom == u32::from(AtomEnum::WM_NAME) => signal_change(),
+            Ok(_) => {}
+            // The X server went away
+            Err(_) => return,
+        }
+    });
+    Ok(())
+}
+
+/// Re-queries the window list whenever the platform reports a change (or every `interval` without hooks) and
+/// emits the differences. Exits once unsubscribed, or when a newer watcher replaces it.
+async fn watch_windows(app: AppHandle, interval: Duration) {
+    let (changed_tx, mut changed) = mpsc::unbounded_channel();
+    *CHANGED.lock().unwrap() = Some(changed_tx);
+
+    let wait = match HOOKS.get_or_init(subscribe_window_changes) {
+        Ok(()) => HOOKED_RESYNC_INTERVAL,
+        Err(e) => {
+            println!("⚠️  Window event hooks unavailable, polling instead: {}", e);
+            interval
+        }
+    };
+
+    loop {
+        if let Ok(snapshot) = crate::commands::query_window_list().await {
+            let changes = {
+                let state = app.state::<Mutex<WindowRegistry>>();
+                let mut registry = state.lock().unwrap();
+                // The first snapshot only seeds the registry
+                let seeded = registry.refreshed_at.is_some();
+                let changes = apply_snapshot(&mut registry, snapshot);
+                if seeded { changes } else { Vec::new() }
+            };
+            emit_changes(&app, changes);
+        }
+
+        match tokio::time::timeout(wait, changed.recv()).await {
+            Ok(Some(())) => {
+                tokio::time::sleep(EVENT_SETTLE).await;
+                while changed.try_recv().is_ok() {}
+            }
+            // The sender was dropped by unsubscribing
+            Ok(None) => return,
+            // Resync (or poll)
+            Err(_) => {}
+        }
+    }
+}
+
+/// Starts emitting `window://created`, `window://destroyed` and `window://title-changed`. `interval_ms` only
+/// applies where the platform hooks are unavailable and the list has to be polled.
+#[tauri::command]
+pub async fn subscribe_window_events(
+    app: AppHandle,
+    state: State<'_, Mutex<WindowRegistry>>,
+    interval_ms: Option<u64>,
+) -> Result<(), String> {
+    {
+        let mut registry = state.lock().unwrap();
+        if registry.subscribed {
+            return Ok(());
+        }
+        registry.subscribed = true;
+    }
+
+    let interval = Duration::from_millis(interval_ms.unwrap_or(1000).max(250));
+    tauri::async_runtime::spawn(watch_windows(app, interval));
+    println!("🪟 Window events subscribed");
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn unsubscribe_window_events(state: State<'_, Mutex<WindowRegistry>>) -> Result<(), String> {
+    state.lock().unwrap().subscribed = false;
+    *CHANGED.lock().unwrap() = None;
+    Ok(())
+}